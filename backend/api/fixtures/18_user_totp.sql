@@ -0,0 +1,7 @@
+-- confirmed TOTP second factor for user 0, secret is the RFC 6238 test secret '12345678901234567890'
+insert into user_totp (user_id, secret, confirmed_at)
+values ('1f241e1b-b537-493f-a230-075cb16315be', '12345678901234567890'::bytea, '2020-08-08T00:11:21Z'::timestamptz);
+
+-- recovery code is 'testrecovery'
+insert into user_recovery_code (user_id, code_hash)
+values ('1f241e1b-b537-493f-a230-075cb16315be', '87c829478dfc50d043888d0711215addc6ef7e2d512ba4a0dc7b005210e12ff1');
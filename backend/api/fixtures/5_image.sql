@@ -71,6 +71,6 @@ values ('89125d88-ffaa-11eb-86a5-9fd50ab8d8df', '1f241e1b-b537-493f-a230-075cb16
        ('8a473dd6-ffaa-11eb-86a5-dba3538e5a15', '1f241e1b-b537-493f-a230-075cb16315be', '2021-06-03 22:30:48.451362',
         2);
 
-insert into user_image_upload (image_id, uploaded_at, processed_at, processing_result)
-select id, created_at, created_at + interval '5 minutes', true
+insert into user_image_upload (image_id, uploaded_at, processed_at, processing_result, file_size)
+select id, created_at, created_at + interval '5 minutes', true, 100000
 from user_image_library;
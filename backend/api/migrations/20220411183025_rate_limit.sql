@@ -0,0 +1,9 @@
+create table rate_limit (
+    key            text              primary key,
+
+    hits           int4              not null,
+
+    expires_at     timestamptz       not null
+);
+
+create index rate_limit_expires_at_idx on rate_limit (expires_at);
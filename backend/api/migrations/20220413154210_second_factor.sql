@@ -0,0 +1,24 @@
+create table user_totp (
+    user_id        uuid              primary key references "user" (id) on delete cascade,
+
+    secret         bytea             not null,
+
+    -- `null` until the user has proven they can generate codes for `secret`.
+    confirmed_at   timestamptz,
+
+    -- the last TOTP time step a code was accepted for, so that codes can't be replayed.
+    last_used_step int8,
+
+    created_at     timestamptz       not null default now()
+);
+
+create table user_recovery_code (
+    user_id        uuid              not null references "user" (id) on delete cascade,
+
+    -- sha256 of the code, hex encoded.
+    code_hash      text              not null,
+
+    used_at        timestamptz,
+
+    primary key (user_id, code_hash)
+);
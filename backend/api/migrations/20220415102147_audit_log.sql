@@ -0,0 +1,44 @@
+create table audit_log (
+    id             uuid              primary key default uuid_generate_v1mc(),
+
+    created_at     timestamptz       not null default now(),
+
+    -- no foreign keys, entries need to outlive the users and resources they refer to.
+    actor_id       uuid,
+
+    action         int2              not null,
+
+    target_id      uuid,
+
+    before         jsonb,
+
+    after          jsonb,
+
+    ip_address     text,
+
+    user_agent     text
+);
+
+create index audit_log_created_at_idx on audit_log (created_at);
+create index audit_log_actor_id_idx on audit_log (actor_id);
+create index audit_log_target_id_idx on audit_log (target_id);
+
+create function audit_log_append_only() returns trigger
+    language plpgsql
+as
+$$
+begin
+    raise exception 'audit_log is append-only';
+end;
+$$;
+
+create trigger audit_log_append_only
+    before update or delete
+    on audit_log
+    for each row
+execute procedure audit_log_append_only();
+
+create trigger audit_log_no_truncate
+    before truncate
+    on audit_log
+execute procedure audit_log_append_only();
@@ -0,0 +1,23 @@
+create table personal_access_token (
+    id           uuid              primary key default uuid_generate_v1mc(),
+
+    user_id      uuid              not null references "user" (id) on delete cascade,
+
+    name         text              not null,
+
+    -- sha256 of the token, hex encoded.
+    token_hash   text              not null unique,
+
+    -- a subset of the user's `user_scope`s at the time the token was created.
+    scopes       int2[]            not null default '{}',
+
+    expires_at   timestamptz       not null,
+
+    revoked_at   timestamptz,
+
+    last_used_at timestamptz,
+
+    created_at   timestamptz       not null default now()
+);
+
+create index personal_access_token_user_id_idx on personal_access_token (user_id);
@@ -0,0 +1,47 @@
+create table webhook_subscription (
+    id           uuid              primary key default uuid_generate_v1mc(),
+
+    user_id      uuid              not null references "user" (id) on delete cascade,
+
+    -- `null` for the user's own jigs, otherwise jigs of every user whose `user_profile.organization` matches.
+    organization text,
+
+    url          text              not null,
+
+    -- used to sign deliveries (HMAC-SHA256), so it has to be kept as is.
+    secret       text              not null,
+
+    events       int2[]            not null,
+
+    created_at   timestamptz       not null default now(),
+    updated_at   timestamptz
+);
+
+create index webhook_subscription_user_id_idx on webhook_subscription (user_id);
+create index webhook_subscription_organization_idx on webhook_subscription (organization) where organization is not null;
+
+create table webhook_delivery (
+    id              uuid           primary key default uuid_generate_v1mc(),
+
+    subscription_id uuid           not null references webhook_subscription (id) on delete cascade,
+
+    event           int2           not null,
+
+    payload         jsonb          not null,
+
+    attempts        int2           not null default 0,
+
+    next_attempt_at timestamptz    not null default now(),
+
+    -- at most one of these gets set, after which the delivery is no longer attempted.
+    delivered_at    timestamptz,
+    failed_at       timestamptz,
+
+    last_status     int2,
+    last_error      text,
+
+    created_at      timestamptz    not null default now()
+);
+
+create index webhook_delivery_pending_idx on webhook_delivery (next_attempt_at)
+    where delivered_at is null and failed_at is null;
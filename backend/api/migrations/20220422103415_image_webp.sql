@@ -0,0 +1,9 @@
+-- Whether WebP derivatives have been generated for the image (existing images get them from a backfill).
+alter table image_upload
+    add column has_webp bool not null default false;
+
+alter table user_image_upload
+    add column has_webp bool not null default false;
+
+alter table web_media_upload
+    add column has_webp bool not null default false;
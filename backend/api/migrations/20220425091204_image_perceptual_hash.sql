@@ -0,0 +1,19 @@
+-- 64 bit difference hash of the image's contents, used to find (near) duplicate uploads.
+alter table image_upload
+    add column perceptual_hash bigint;
+
+alter table user_image_upload
+    add column perceptual_hash bigint;
+
+create index image_upload_perceptual_hash_idx on image_upload (perceptual_hash);
+create index user_image_upload_perceptual_hash_idx on user_image_upload (perceptual_hash);
+
+-- number of differing bits between two perceptual hashes.
+create function perceptual_hash_distance(a bigint, b bigint) returns int
+    language sql
+    immutable
+    parallel safe
+as
+$$
+select length(replace((a # b)::bit(64)::text, '0', ''))
+$$;
@@ -0,0 +1,18 @@
+-- Where media came from, and under what license it can be used (see `shared::domain::media::License`).
+alter table image_metadata
+    add column license    int2 not null default 0 check (license >= 0),
+    add column author     text,
+    add column source_url text;
+
+alter table web_media_library
+    add column license    int2 not null default 0 check (license >= 0),
+    add column author     text,
+    add column source_url text;
+
+-- web media has always come from somewhere on the web.
+update web_media_library
+set source_url = (select media_url
+                  from web_media_library_url
+                  where media_id = web_media_library.id
+                  order by created_at
+                  limit 1);
@@ -0,0 +1,9 @@
+-- The format audio was uploaded in (see `shared::domain::audio::AudioFormat`), it's always transcoded to mp3.
+-- null until the audio has been processed.
+alter table user_audio_library
+    add column original_format int2 check (original_format >= 0);
+
+-- only mp3s could be uploaded before.
+update user_audio_library
+set original_format = 0
+where id in (select audio_id from user_audio_upload where processing_result is true);
@@ -0,0 +1,4 @@
+-- Extracted when a pdf is processed (alongside a thumbnail of its first page), null until then.
+alter table user_pdf_library
+    add column page_count int4 check (page_count >= 0),
+    add column title      text;
@@ -0,0 +1,11 @@
+-- The format an animation was uploaded in (see `shared::domain::animation::AnimationFormat`),
+-- and the layout/timing of the spritesheet it's converted into (`AnimationSpritesheet`).
+-- Both are null until the animation has been processed.
+alter table animation_metadata
+    add column original_format int2 check (original_format >= 0),
+    add column spritesheet     jsonb;
+
+-- only gifs could be uploaded before, but they didn't get spritesheets.
+update animation_metadata
+set original_format = 0
+where id in (select animation_id from global_animation_upload where processing_result is true);
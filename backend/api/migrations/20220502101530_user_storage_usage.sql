@@ -0,0 +1,10 @@
+-- The size of a user's upload in bytes, as requested when the upload session was created and then as actually
+-- stored once it has been processed. Null for uploads which predate storage quotas.
+alter table user_image_upload
+    add column file_size int8 check (file_size >= 0);
+
+alter table user_audio_upload
+    add column file_size int8 check (file_size >= 0);
+
+alter table user_pdf_upload
+    add column file_size int8 check (file_size >= 0);
@@ -0,0 +1,26 @@
+-- spaced repetition state of flashcards, per logged-in user or per player session instance
+create table flashcard_review
+(
+    jig_id           uuid        not null references jig (id) on delete cascade,
+    stable_module_id uuid        not null,
+    user_id          uuid references "user" (id) on delete cascade,
+    -- instances are deleted when they're completed, the state lives as long as their session
+    instance_id      uuid,
+    session_index    smallint references jig_player_session (index) on delete cascade,
+    -- identifies the pair by the contents of its cards, which (unlike its position) doesn't change when other pairs
+    -- are reordered or deleted
+    pair_key         text        not null,
+    leitner_box      smallint    not null check (leitner_box >= 0),
+    reviewed_at      timestamptz not null,
+    due_at           timestamptz not null,
+    check ((user_id is null) <> (instance_id is null)),
+    check ((instance_id is null) = (session_index is null))
+);
+
+create unique index flashcard_review_user_key
+    on flashcard_review (jig_id, stable_module_id, user_id, pair_key)
+    where user_id is not null;
+
+create unique index flashcard_review_instance_key
+    on flashcard_review (jig_id, stable_module_id, instance_id, pair_key)
+    where instance_id is not null;
@@ -0,0 +1,42 @@
+-- Time limits and attempts move into a shared `assessment` object for every module the student can
+-- get wrong, alongside the new `show_answer` and `retry` settings.
+
+-- Matching, Memory and CardQuiz keep it in `player_settings`
+update jig_data_module
+set contents = jsonb_set(
+        contents,
+        '{content, player_settings}',
+        (contents -> 'content' -> 'player_settings') - 'time_limit' - 'n_attempts'
+            || jsonb_build_object(
+                'assessment',
+                jsonb_build_object(
+                    'time_limit', contents -> 'content' -> 'player_settings' -> 'time_limit',
+                    'attempts', contents -> 'content' -> 'player_settings' -> 'n_attempts',
+                    'show_answer', false,
+                    'retry', 'Never'
+                )
+            )
+    )
+where kind = any (array [2, 3, 9])
+  and jsonb_typeof(contents -> 'content' -> 'player_settings') = 'object' -- has content and settings
+  and not (contents -> 'content' -> 'player_settings' ? 'assessment');    -- not migrated yet
+
+-- DragDrop keeps it in `play_settings`
+update jig_data_module
+set contents = jsonb_set(
+        contents,
+        '{content, play_settings}',
+        (contents -> 'content' -> 'play_settings') - 'time_limit'
+            || jsonb_build_object(
+                'assessment',
+                jsonb_build_object(
+                    'time_limit', contents -> 'content' -> 'play_settings' -> 'time_limit',
+                    'attempts', null,
+                    'show_answer', false,
+                    'retry', 'Never'
+                )
+            )
+    )
+where kind = 10
+  and jsonb_typeof(contents -> 'content' -> 'play_settings') = 'object'
+  and not (contents -> 'content' -> 'play_settings' ? 'assessment');
@@ -0,0 +1,33 @@
+-- Media is referenced in module contents as `{"id": <uuid>, "lib": <library>}`, at various depths.
+-- Rewrites the `id` of every such object in `lib` whose id is one of `ids` to `replacement`, leaving
+-- everything else (including the same uuid in other libraries or in other fields) untouched.
+create function jsonb_replace_media_id(value jsonb, lib text, ids uuid[], replacement uuid) returns jsonb
+    language plpgsql
+    immutable
+    parallel safe
+as
+$$
+begin
+    case jsonb_typeof(value)
+        when 'object' then
+            if value ->> 'lib' = lib and value ->> 'id' = any (ids::text[]) then
+                value := jsonb_set(value, '{id}', to_jsonb(replacement::text));
+            end if;
+
+            return coalesce(
+                (select jsonb_object_agg(key, jsonb_replace_media_id(child, lib, ids, replacement))
+                 from jsonb_each(value) as children(key, child)),
+                '{}'::jsonb
+            );
+        when 'array' then
+            return coalesce(
+                (select jsonb_agg(jsonb_replace_media_id(child, lib, ids, replacement) order by index)
+                 from jsonb_array_elements(value) with ordinality as children(child, index)),
+                '[]'::jsonb
+            );
+        else
+            return value;
+        end case;
+end;
+$$;
+
@@ -0,0 +1,476 @@
+//! Cleaning up uploaded audio (mostly recordings), so that it plays back at a consistent volume without long pauses.
+//!
+//! Uploads can be in any of the [`AudioFormat`]s, they're decoded in process (no external services) and always stored as mp3.
+//! Opus audio isn't supported, since there's no pure-Rust decoder for it. Uploads declaring it (see [`is_opus`]) are
+//! rejected before they're stored, anything that slips through fails to decode.
+
+use mp3lame_encoder::{Bitrate, Builder, FlushNoGap, InterleavedPcm, MonoPcm, Quality};
+use shared::domain::audio::{AudioFormat, AudioWaveform};
+use symphonia::core::{
+    audio::SampleBuffer,
+    codecs::{CodecParameters, DecoderOptions, CODEC_TYPE_OPUS},
+    errors::Error as DecodeError,
+    formats::{FormatOptions, FormatReader, Packet},
+    io::MediaSourceStream,
+    meta::MetadataOptions,
+    probe::Hint,
+};
+
+/// Samples quieter than this (-45 dBFS) are considered silence when trimming.
+const SILENCE_THRESHOLD: f32 = 0.0056;
+
+/// How much silence is kept before and after the trimmed audio, so that it doesn't start abruptly.
+const TRIM_PADDING_SECS: f32 = 0.15;
+
+/// Length of the windows that loudness is measured over.
+const WINDOW_SECS: f32 = 0.05;
+
+/// Windows quieter than this (-50 dBFS) don't count towards the loudness, so that pauses don't make speech louder.
+const LOUDNESS_GATE: f32 = 0.0032;
+
+/// The loudness (RMS) audio is normalized to, -18 dBFS.
+const TARGET_LOUDNESS: f32 = 0.126;
+
+/// Peaks are kept below -1 dBFS, to leave headroom for the mp3 encoder.
+const MAX_PEAK: f32 = 0.89;
+
+/// Very quiet recordings are mostly noise, which shouldn't be amplified too much (+20 dB).
+const MAX_GAIN: f32 = 10.0;
+
+const PEAKS_PER_SECOND: u32 = 20;
+
+/// Decoded audio.
+pub struct Audio {
+    pub sample_rate: u32,
+    pub channels: usize,
+    /// Interleaved samples, between -1.0 and 1.0.
+    pub samples: Vec<f32>,
+}
+
+impl Audio {
+    fn frames(&self) -> usize {
+        self.samples.len() / self.channels
+    }
+
+    fn frames_in(&self, secs: f32) -> usize {
+        ((self.sample_rate as f32 * secs) as usize).max(1)
+    }
+
+    /// The loudest sample (of any channel) in each chunk of `frames` frames.
+    fn chunk_peaks(&self, frames: usize) -> impl Iterator<Item = f32> + '_ {
+        self.samples
+            .chunks(frames * self.channels)
+            .map(|chunk| chunk.iter().fold(0.0_f32, |peak, it| peak.max(it.abs())))
+    }
+
+    #[must_use]
+    pub fn duration_ms(&self) -> u64 {
+        self.frames() as u64 * 1000 / u64::from(self.sample_rate)
+    }
+}
+
+/// Detects the (container) format of an audio file from its contents.
+///
+/// Returns `None` for formats that aren't supported.
+#[must_use]
+pub fn detect_format(data: &[u8]) -> Option<AudioFormat> {
+    match data {
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'A', b'V', b'E', ..] => Some(AudioFormat::Wav),
+        [b'O', b'g', b'g', b'S', ..] => Some(AudioFormat::Ogg),
+        [b'f', b'L', b'a', b'C', ..] => Some(AudioFormat::Flac),
+        [_, _, _, _, b'f', b't', b'y', b'p', ..] => Some(AudioFormat::M4a),
+        [0x1A, 0x45, 0xDF, 0xA3, ..] => Some(AudioFormat::WebM),
+        // either an ID3 tag, or straight into an mpeg audio frame.
+        [b'I', b'D', b'3', ..] => Some(AudioFormat::Mp3),
+        [0xFF, second, ..] if second & 0xE0 == 0xE0 => Some(AudioFormat::Mp3),
+        _ => None,
+    }
+}
+
+/// Whether a `content_type` (as declared by the uploader) is, or is likely to be, Opus audio.
+///
+/// WebM audio is always taken to be Opus, since that's what browsers record it as.
+#[must_use]
+pub fn is_opus(content_type: &str) -> bool {
+    let content_type = content_type.to_ascii_lowercase();
+
+    let mut parts = content_type.split(';').map(str::trim);
+
+    let essence = parts.next().unwrap_or_default();
+
+    let opus_codec = parts.any(|param| {
+        param.strip_prefix("codecs=").map_or(false, |codecs| {
+            codecs
+                .trim_matches('"')
+                .split(',')
+                .any(|codec| codec.trim() == "opus")
+        })
+    });
+
+    opus_codec || matches!(essence, "audio/opus" | "audio/webm" | "video/webm")
+}
+
+/// Returns `None` once the end of the file has been reached.
+fn next_packet(format: &mut dyn FormatReader) -> anyhow::Result<Option<Packet>> {
+    match format.next_packet() {
+        Ok(packet) => Ok(Some(packet)),
+        Err(DecodeError::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Decodes an audio file, the format is detected from its contents.
+pub fn decode(data: &[u8]) -> anyhow::Result<Audio> {
+    let source = MediaSourceStream::new(
+        Box::new(std::io::Cursor::new(data.to_vec())),
+        Default::default(),
+    );
+
+    let probed = symphonia::default::get_probe().format(
+        &Hint::new(),
+        source,
+        &FormatOptions::default(),
+        &MetadataOptions::default(),
+    )?;
+
+    let mut format = probed.format;
+
+    let track = format
+        .default_track()
+        .ok_or_else(|| anyhow::anyhow!("no audio track"))?;
+
+    let track_id = track.id;
+    let codec_params = track.codec_params.clone();
+
+    // symphonia can demux opus (from ogg and webm files), but can't decode it.
+    if codec_params.codec == CODEC_TYPE_OPUS {
+        anyhow::bail!("opus audio isn't supported");
+    }
+
+    let mut audio = decode_track(&mut *format, track_id, &codec_params)?;
+
+    if audio.samples.is_empty() || audio.channels == 0 {
+        anyhow::bail!("no audio decoded");
+    }
+
+    // the encoder only does mono and stereo.
+    if audio.channels > 2 {
+        audio.samples = audio
+            .samples
+            .chunks(audio.channels)
+            .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+            .collect();
+
+        audio.channels = 1;
+    }
+
+    Ok(audio)
+}
+
+fn decode_track(
+    format: &mut dyn FormatReader,
+    track_id: u32,
+    codec_params: &CodecParameters,
+) -> anyhow::Result<Audio> {
+    let mut decoder =
+        symphonia::default::get_codecs().make(codec_params, &DecoderOptions::default())?;
+
+    let mut audio = Audio {
+        sample_rate: codec_params.sample_rate.unwrap_or(44_100),
+        channels: codec_params.channels.map_or(1, |it| it.count()),
+        samples: Vec::new(),
+    };
+
+    while let Some(packet) = next_packet(format)? {
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            // a corrupt frame, recordings tend to have a few of these at the end.
+            Err(DecodeError::DecodeError(_)) => continue,
+            Err(e) => return Err(e.into()),
+        };
+
+        let spec = *decoded.spec();
+
+        audio.sample_rate = spec.rate;
+        audio.channels = spec.channels.count();
+
+        let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+        buffer.copy_interleaved_ref(decoded);
+
+        audio.samples.extend_from_slice(buffer.samples());
+    }
+
+    Ok(audio)
+}
+
+/// Removes silence from the start and end of `audio`, leaving a bit of padding.
+///
+/// Audio that's silent all the way through is left alone.
+pub fn trim_silence(audio: &mut Audio) {
+    let window = audio.frames_in(WINDOW_SECS / 5.0);
+
+    let loud: Vec<bool> = audio
+        .chunk_peaks(window)
+        .map(|peak| peak >= SILENCE_THRESHOLD)
+        .collect();
+
+    let (first, last) = match (
+        loud.iter().position(|it| *it),
+        loud.iter().rposition(|it| *it),
+    ) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return,
+    };
+
+    let padding = audio.frames_in(TRIM_PADDING_SECS);
+
+    let start = (first * window).saturating_sub(padding);
+    let end = ((last + 1) * window + padding).min(audio.frames());
+
+    audio.samples.truncate(end * audio.channels);
+    audio.samples.drain(..(start * audio.channels));
+}
+
+/// Amplifies (or attenuates) `audio` so that the loud parts of it are at a consistent level, without clipping.
+pub fn normalize_loudness(audio: &mut Audio) {
+    let window = audio.frames_in(WINDOW_SECS) * audio.channels;
+
+    let (power, windows) = audio
+        .samples
+        .chunks(window)
+        .map(|chunk| chunk.iter().map(|it| it * it).sum::<f32>() / chunk.len() as f32)
+        .filter(|power| power.sqrt() >= LOUDNESS_GATE)
+        .fold((0.0_f32, 0_usize), |(sum, count), power| {
+            (sum + power, count + 1)
+        });
+
+    if windows == 0 {
+        return;
+    }
+
+    let loudness = (power / windows as f32).sqrt();
+    let peak = audio.chunk_peaks(audio.frames()).fold(0.0_f32, f32::max);
+
+    let gain = (TARGET_LOUDNESS / loudness)
+        .min(MAX_PEAK / peak)
+        .min(MAX_GAIN);
+
+    for sample in &mut audio.samples {
+        *sample = (*sample * gain).clamp(-1.0, 1.0);
+    }
+}
+
+/// The peaks of `audio`, for drawing its waveform.
+#[must_use]
+pub fn waveform(audio: &Audio) -> AudioWaveform {
+    let frames = (audio.sample_rate / PEAKS_PER_SECOND).max(1) as usize;
+
+    AudioWaveform {
+        duration_ms: audio.duration_ms(),
+        peaks_per_second: PEAKS_PER_SECOND,
+        peaks: audio
+            .chunk_peaks(frames)
+            .map(|peak| (peak.min(1.0) * f32::from(u8::MAX)).round() as u8)
+            .collect(),
+    }
+}
+
+/// Encodes `audio` as an mp3.
+pub fn encode_mp3(audio: &Audio) -> anyhow::Result<Vec<u8>> {
+    let mut builder = Builder::new().ok_or_else(|| anyhow::anyhow!("failed to create encoder"))?;
+
+    builder
+        .set_num_channels(audio.channels as u8)
+        .map_err(|e| anyhow::anyhow!("invalid channel count: {:?}", e))?;
+
+    builder
+        .set_sample_rate(audio.sample_rate)
+        .map_err(|e| anyhow::anyhow!("invalid sample rate: {:?}", e))?;
+
+    builder
+        .set_brate(Bitrate::Kbps128)
+        .map_err(|e| anyhow::anyhow!("invalid bitrate: {:?}", e))?;
+
+    builder
+        .set_quality(Quality::Good)
+        .map_err(|e| anyhow::anyhow!("invalid quality: {:?}", e))?;
+
+    let mut encoder = builder
+        .build()
+        .map_err(|e| anyhow::anyhow!("failed to create encoder: {:?}", e))?;
+
+    let pcm: Vec<i16> = audio
+        .samples
+        .iter()
+        .map(|it| (it.clamp(-1.0, 1.0) * f32::from(i16::MAX)) as i16)
+        .collect();
+
+    let mut mp3 = Vec::with_capacity(mp3lame_encoder::max_required_buffer_size(pcm.len()));
+
+    let encoded = if audio.channels == 1 {
+        encoder.encode_to_vec(MonoPcm(&pcm), &mut mp3)
+    } else {
+        encoder.encode_to_vec(InterleavedPcm(&pcm), &mut mp3)
+    };
+
+    encoded.map_err(|e| anyhow::anyhow!("failed to encode mp3: {:?}", e))?;
+
+    encoder
+        .flush_to_vec::<FlushNoGap>(&mut mp3)
+        .map_err(|e| anyhow::anyhow!("failed to encode mp3: {:?}", e))?;
+
+    Ok(mp3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `secs` of silence, then `secs` of a 440Hz tone at `amplitude`, then `secs` of silence.
+    fn tone(amplitude: f32, secs: f32) -> Audio {
+        let sample_rate = 8000;
+        let frames = (sample_rate as f32 * secs) as usize;
+
+        let mut samples = vec![0.0; frames];
+
+        samples.extend((0..frames).map(|it| {
+            amplitude * (it as f32 * 440.0 * std::f32::consts::TAU / sample_rate as f32).sin()
+        }));
+
+        samples.resize(frames * 3, 0.0);
+
+        Audio {
+            sample_rate,
+            channels: 1,
+            samples,
+        }
+    }
+
+    /// A mono, 16 bit PCM wav file.
+    fn wav(samples: &[i16], sample_rate: u32) -> Vec<u8> {
+        let data_len = samples.len() as u32 * 2;
+
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+        wav.extend_from_slice(b"WAVEfmt ");
+        wav.extend_from_slice(&16_u32.to_le_bytes());
+        // pcm, 1 channel
+        wav.extend_from_slice(&1_u16.to_le_bytes());
+        wav.extend_from_slice(&1_u16.to_le_bytes());
+        wav.extend_from_slice(&sample_rate.to_le_bytes());
+        wav.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+        // block align, bits per sample
+        wav.extend_from_slice(&2_u16.to_le_bytes());
+        wav.extend_from_slice(&16_u16.to_le_bytes());
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&data_len.to_le_bytes());
+
+        for sample in samples {
+            wav.extend_from_slice(&sample.to_le_bytes());
+        }
+
+        wav
+    }
+
+    #[test]
+    fn detects_formats() {
+        let wav = wav(&[0; 16], 8000);
+
+        assert_eq!(detect_format(&wav), Some(AudioFormat::Wav));
+        assert_eq!(detect_format(b"OggS\0\x02"), Some(AudioFormat::Ogg));
+        assert_eq!(detect_format(b"\0\0\0\x20ftypM4A "), Some(AudioFormat::M4a));
+        assert_eq!(
+            detect_format(&[0x1A, 0x45, 0xDF, 0xA3, 0x9F]),
+            Some(AudioFormat::WebM)
+        );
+        assert_eq!(detect_format(b"ID3\x03\0"), Some(AudioFormat::Mp3));
+        assert_eq!(detect_format(&[0xFF, 0xFB, 0x90]), Some(AudioFormat::Mp3));
+        assert_eq!(detect_format(b"%PDF-1.4"), None);
+    }
+
+    #[test]
+    fn detects_opus_content_types() {
+        assert!(is_opus("audio/ogg; codecs=opus"));
+        assert!(is_opus("Audio/Ogg;Codecs=\"Opus\""));
+        assert!(is_opus("audio/opus"));
+        assert!(is_opus("audio/webm"));
+        assert!(is_opus("video/webm;codecs=vp8,opus"));
+
+        assert!(!is_opus("audio/ogg"));
+        assert!(!is_opus("audio/ogg; codecs=vorbis"));
+        assert!(!is_opus("audio/mpeg"));
+        assert!(!is_opus(""));
+    }
+
+    #[test]
+    fn decodes_wav() {
+        let samples: Vec<i16> = (0..800).map(|it| (it % 100) * 100).collect();
+
+        let audio = decode(&wav(&samples, 8000)).unwrap();
+
+        assert_eq!(audio.sample_rate, 8000);
+        assert_eq!(audio.channels, 1);
+        assert_eq!(audio.samples.len(), samples.len());
+        assert!((audio.samples[50] - 5000.0 / 32768.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn trims_silence() {
+        let mut audio = tone(0.5, 1.0);
+
+        trim_silence(&mut audio);
+
+        // the tone plus padding on either side (rounded to a window).
+        let duration = audio.duration_ms();
+        assert!((1300..=1320).contains(&duration), "{}", duration);
+    }
+
+    #[test]
+    fn normalizes_quiet_audio() {
+        let mut audio = tone(0.02, 1.0);
+
+        normalize_loudness(&mut audio);
+
+        let peak = audio
+            .samples
+            .iter()
+            .fold(0.0_f32, |peak, it| peak.max(it.abs()));
+
+        // a sine wave's RMS is its peak / sqrt(2).
+        assert!((peak / std::f32::consts::SQRT_2 - TARGET_LOUDNESS).abs() < 0.01);
+    }
+
+    #[test]
+    fn normalizing_does_not_clip() {
+        let mut audio = tone(0.02, 1.0);
+
+        // a click in an otherwise quiet recording.
+        audio.samples[12000] = 0.5;
+
+        normalize_loudness(&mut audio);
+
+        let peak = audio
+            .samples
+            .iter()
+            .fold(0.0_f32, |peak, it| peak.max(it.abs()));
+
+        assert!((peak - MAX_PEAK).abs() < 0.001);
+    }
+
+    #[test]
+    fn waveform_peaks() {
+        let audio = tone(0.5, 1.0);
+
+        let waveform = waveform(&audio);
+
+        assert_eq!(waveform.duration_ms, 3000);
+        assert_eq!(waveform.peaks.len(), 60);
+        assert_eq!(waveform.peaks[0], 0);
+        assert!((127..=128).contains(&waveform.peaks[30]));
+    }
+}
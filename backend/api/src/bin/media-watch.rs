@@ -270,9 +270,11 @@ async fn process_uploaded_media_trigger(
 
         upload::finalize_upload(
             &access_token,
+            &db,
             &notifications,
             &event_resource.library,
             &event_resource.id,
+            &event_resource.file_kind,
         )
         .await?;
 
@@ -296,7 +298,7 @@ pub async fn signal_status_and_process_media(
         .await?;
 
     let res = match file_kind {
-        FileKind::ImagePng(PngImageFile::Original) => match library {
+        FileKind::ImagePng(PngImageFile::Original) | FileKind::ImageSvg => match library {
             MediaLibrary::Global => upload::process_image(&db, &s3, *id).await,
             MediaLibrary::User => upload::process_user_image(&db, &s3, *id).await,
             MediaLibrary::Web => upload::process_web_media(&db, &s3, *id).await,
@@ -1,5 +1,6 @@
 pub(crate) mod animation;
 pub(crate) mod audio;
+pub(crate) mod audit_log;
 pub(crate) mod category;
 pub(crate) mod image;
 pub(crate) mod jig;
@@ -8,8 +9,13 @@ pub(crate) mod locale;
 pub(crate) mod media;
 pub(crate) mod meta;
 pub(crate) mod pdf;
+pub(crate) mod personal_access_token;
+pub(crate) mod rate_limit;
 pub(crate) mod session;
+pub(crate) mod storage_usage;
+pub(crate) mod totp;
 pub(crate) mod user;
+pub(crate) mod webhook;
 
 use core::config::DB_POOL_CONNECTIONS;
 use shared::domain::{
@@ -57,6 +57,8 @@ select  id,
         updated_at,
         kind,
         is_looping,
+        original_format,
+        spritesheet,
         array((select row (style_id) from animation_style where animation_id = animation_metadata.id)) as styles
 from animation_metadata inner join global_animation_upload gau on animation_id=id
 where id = $1 and processing_result is true
@@ -1,16 +1,19 @@
 pub mod user {
     use futures::stream::BoxStream;
-    use shared::domain::audio::{user::UserAudio, AudioId};
+    use shared::domain::audio::{user::UserAudio, AudioFormat, AudioId};
     use sqlx::PgPool;
+    use uuid::Uuid;
 
-    pub async fn create(db: &PgPool) -> sqlx::Result<AudioId> {
+    pub async fn create(db: &PgPool, user_id: Uuid) -> sqlx::Result<AudioId> {
         let mut txn = db.begin().await?;
 
         let id: AudioId = sqlx::query!(
             r#"
-insert into user_audio_library default values
+insert into user_audio_library (user_id)
+values ($1)
 returning id as "id: AudioId"
 "#,
+            user_id
         )
         .fetch_one(db)
         .await?
@@ -35,7 +38,11 @@ returning id as "id: AudioId"
     pub async fn get(db: &PgPool, image: AudioId) -> sqlx::Result<Option<UserAudio>> {
         sqlx::query_as!(
             UserAudio,
-            r#"select id as "id: AudioId" from user_audio_library where id = $1"#,
+            r#"
+select id as "id: AudioId", original_format as "original_format: AudioFormat"
+from user_audio_library
+where id = $1
+"#,
             image.0
         )
         .fetch_optional(db)
@@ -45,7 +52,11 @@ returning id as "id: AudioId"
     pub fn list(db: &PgPool) -> BoxStream<'_, sqlx::Result<UserAudio>> {
         sqlx::query_as!(
             UserAudio,
-            r#"select id as "id: AudioId" from user_audio_library order by created_at desc"#,
+            r#"
+select id as "id: AudioId", original_format as "original_format: AudioFormat"
+from user_audio_library
+order by created_at desc
+"#,
         )
         .fetch(db)
     }
@@ -0,0 +1,123 @@
+use chrono::{DateTime, Utc};
+use shared::domain::admin::{AuditAction, AuditLogEntry};
+use sqlx::{PgConnection, PgPool};
+use tracing::instrument;
+use uuid::Uuid;
+
+/// A new entry for the audit log, see [`insert`].
+///
+/// Entries are written in the same transaction as the change they record, so a change is never left unlogged.
+#[derive(Debug, Clone)]
+pub struct NewEntry {
+    pub actor_id: Option<Uuid>,
+    pub action: AuditAction,
+    pub target_id: Option<Uuid>,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+/// Filters for [`browse`] and [`filtered_count`].
+#[derive(Debug, Default)]
+pub struct Filter {
+    pub actor_id: Option<Uuid>,
+    pub target_id: Option<Uuid>,
+    pub action: Option<AuditAction>,
+    pub from_date: Option<DateTime<Utc>>,
+    pub to_date: Option<DateTime<Utc>>,
+}
+
+/// Appends `entry` to the audit log. The log can't be updated or deleted from.
+#[instrument(skip(conn))]
+pub async fn insert(conn: &mut PgConnection, entry: NewEntry) -> sqlx::Result<()> {
+    sqlx::query!(
+        //language=SQL
+        r#"
+insert into audit_log (actor_id, action, target_id, before, after, ip_address, user_agent)
+values ($1, $2, $3, $4, $5, $6, $7)
+"#,
+        entry.actor_id,
+        entry.action as i16,
+        entry.target_id,
+        entry.before,
+        entry.after,
+        entry.ip_address,
+        entry.user_agent,
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Gets the entries matching `filter`, newest first.
+///
+/// If `page_limit` is `None`, all of them are returned.
+#[instrument(skip(db))]
+pub async fn browse(
+    db: &PgPool,
+    filter: &Filter,
+    page: i64,
+    page_limit: Option<i64>,
+) -> sqlx::Result<Vec<AuditLogEntry>> {
+    sqlx::query_as!(
+        AuditLogEntry,
+        //language=SQL
+        r#"
+select id,
+       created_at,
+       actor_id,
+       action as "action: AuditAction",
+       target_id,
+       before,
+       after,
+       ip_address,
+       user_agent
+from audit_log
+where ($1::uuid is null or actor_id = $1)
+  and ($2::uuid is null or target_id = $2)
+  and ($3::int2 is null or action = $3)
+  and ($4::timestamptz is null or created_at >= $4)
+  and ($5::timestamptz is null or created_at <= $5)
+order by created_at desc, id
+offset $6 * coalesce($7, 0)
+limit $7
+"#,
+        filter.actor_id,
+        filter.target_id,
+        filter.action.map(|it| it as i16),
+        filter.from_date,
+        filter.to_date,
+        page,
+        page_limit,
+    )
+    .fetch_all(db)
+    .await
+}
+
+#[instrument(skip(db))]
+pub async fn filtered_count(db: &PgPool, filter: &Filter) -> sqlx::Result<u64> {
+    let count = sqlx::query!(
+        //language=SQL
+        r#"
+select count(*) as "count!"
+from audit_log
+where ($1::uuid is null or actor_id = $1)
+  and ($2::uuid is null or target_id = $2)
+  and ($3::int2 is null or action = $3)
+  and ($4::timestamptz is null or created_at >= $4)
+  and ($5::timestamptz is null or created_at <= $5)
+"#,
+        filter.actor_id,
+        filter.target_id,
+        filter.action.map(|it| it as i16),
+        filter.from_date,
+        filter.to_date,
+    )
+    .fetch_one(db)
+    .await?
+    .count;
+
+    Ok(count as u64)
+}
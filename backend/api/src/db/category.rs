@@ -1,3 +1,4 @@
+use super::audit_log::{self, NewEntry};
 use crate::{
     domain::{build_tree, RawCategory},
     error::{self, Delete},
@@ -5,7 +6,7 @@ use crate::{
 use futures::TryStreamExt;
 use shared::domain::category::{Category, CategoryId};
 use shared::domain::user::UserScope;
-use sqlx::{Executor, PgPool};
+use sqlx::{Executor, PgConnection, PgPool};
 use std::convert::TryFrom;
 use tracing::instrument;
 use uuid::Uuid;
@@ -80,6 +81,45 @@ order by t.ord
     .await
 }
 
+/// Gets the current state of a category for the audit log.
+async fn audit_snapshot(
+    conn: &mut PgConnection,
+    id: Uuid,
+) -> sqlx::Result<Option<serde_json::Value>> {
+    let category = sqlx::query!(
+        //language=SQL
+        r#"
+select id as "id: CategoryId",
+       name,
+       created_at,
+       updated_at,
+       user_scopes
+from category
+where id = $1
+"#,
+        id
+    )
+    .fetch_optional(conn)
+    .await?
+    .map(|it| Category {
+        id: it.id,
+        name: it.name,
+        created_at: it.created_at,
+        updated_at: it.updated_at,
+        children: vec![],
+        user_scopes: it
+            .user_scopes
+            .into_iter()
+            .map(|x| UserScope::try_from(x).expect("failed to convert to UserScope"))
+            .collect(),
+    });
+
+    category
+        .map(serde_json::to_value)
+        .transpose()
+        .map_err(|e| sqlx::Error::Decode(e.into()))
+}
+
 #[instrument(skip(db))]
 pub async fn get_subtree(db: &sqlx::PgPool, ids: &[Uuid]) -> sqlx::Result<Vec<Category>> {
     sqlx::query_file_as!(RawCategory, "query/category/get_subtree.sql", ids)
@@ -118,11 +158,15 @@ pub async fn get_ancestor_tree(db: &sqlx::PgPool, ids: &[Uuid]) -> sqlx::Result<
 }
 
 #[instrument(skip(db))]
+/// Creates a category, recording `audit` (with the new category as `after`) in the same transaction.
 pub async fn create(
     db: &sqlx::PgPool,
     name: &str,
     parent_id: Option<CategoryId>,
+    audit: &NewEntry,
 ) -> sqlx::Result<(CategoryId, u16)> {
+    let mut txn = db.begin().await?;
+
     let res = sqlx::query!(
         r#"
 insert into category (index, parent_id, name, user_scopes)
@@ -131,9 +175,23 @@ returning index, id"#,
         parent_id.map(|it| it.0),
         name,
     )
-    .fetch_one(db)
+    .fetch_one(&mut txn)
     .await?;
 
+    let after = audit_snapshot(&mut txn, res.id).await?;
+
+    audit_log::insert(
+        &mut txn,
+        NewEntry {
+            target_id: Some(res.id),
+            after,
+            ..audit.clone()
+        },
+    )
+    .await?;
+
+    txn.commit().await?;
+
     Ok((CategoryId(res.id), res.index as u16))
 }
 
@@ -189,6 +247,7 @@ async fn update_slow(
     new_parent: Option<Option<Uuid>>,
     name: Option<&str>,
     index: Option<i16>,
+    audit: &NewEntry,
 ) -> Result<(), UpdateLoopError> {
     let mut txn = db.begin().await?;
 
@@ -205,6 +264,8 @@ select parent_id, index from category where id = $1 for update
     .await?
     .ok_or(error::CategoryUpdate::CategoryNotFound)?;
 
+    let before = audit_snapshot(&mut txn, id).await?;
+
     if let Some(name) = name {
         #[allow(clippy::suspicious_else_formatting)]
         sqlx::query!("update category set name = $1 where id = $2", name, id)
@@ -285,11 +346,24 @@ where id = $2
         }
     }
 
+    let after = audit_snapshot(&mut txn, id).await?;
+
+    audit_log::insert(
+        &mut txn,
+        NewEntry {
+            before,
+            after,
+            ..audit.clone()
+        },
+    )
+    .await?;
+
     txn.commit().await?;
 
     Ok(())
 }
 
+/// Updates a category, recording `audit` (with the category before and after) in the same transaction.
 #[instrument(skip(db))]
 pub async fn update(
     db: &sqlx::PgPool,
@@ -298,19 +372,24 @@ pub async fn update(
     name: Option<&str>,
     index: Option<i16>,
     user_scopes: Option<Vec<UserScope>>,
+    audit: &NewEntry,
 ) -> Result<(), error::CategoryUpdate> {
     // fast track for if we're only updating the `name`:
     // the reasoning is due to an observation:
     // * we have to have retry logic for anything that involves transactions here (which updating the parent id and index _requires_)
     // * This is a single query, which can execute faster than opening a transaction and doing all the other stuff does.
     if parent_id.is_none() && index.is_none() {
+        let mut txn = db.begin().await?;
+
+        let before = audit_snapshot(&mut txn, id).await?;
+
         if let Some(name) = name {
             let rows_updated = sqlx::query!(
                 "update category set name = $1, updated_at = now() where id = $2",
                 name,
                 id
             )
-            .execute(db)
+            .execute(&mut txn)
             .await?
             .rows_affected();
 
@@ -329,7 +408,7 @@ pub async fn update(
                 scopes.as_slice(),
                 id
             )
-            .execute(db)
+            .execute(&mut txn)
             .await?
             .rows_affected();
 
@@ -340,6 +419,20 @@ pub async fn update(
             }
         }
 
+        let after = audit_snapshot(&mut txn, id).await?;
+
+        audit_log::insert(
+            &mut txn,
+            NewEntry {
+                before,
+                after,
+                ..audit.clone()
+            },
+        )
+        .await?;
+
+        txn.commit().await?;
+
         // Regardless of if the name is updated,
         // we know that neither parent_id nor index are going to be updated,
         // so we can just return here.
@@ -351,7 +444,7 @@ pub async fn update(
 
     let new_parent = parent_id.map(|id| id.map(|it| it.0));
     loop {
-        match update_slow(db, id, new_parent, name, index).await {
+        match update_slow(db, id, new_parent, name, index, audit).await {
             Ok(()) => return Ok(()),
             Err(UpdateLoopError::UpdateError(e)) => return Err(e),
             Err(UpdateLoopError::Sqlx(sqlx::Error::Database(e)))
@@ -384,13 +477,16 @@ where index > $1 and index <= $2 is not false and parent_id is not distinct from
     .map(drop)
 }
 
-pub async fn delete(db: &PgPool, id: CategoryId) -> Result<(), Delete> {
-    async fn inner(db: &PgPool, id: CategoryId) -> sqlx::Result<()> {
+/// Deletes a category, recording `audit` (with the category as `before`) in the same transaction.
+pub async fn delete(db: &PgPool, id: CategoryId, audit: &NewEntry) -> Result<(), Delete> {
+    async fn inner(db: &PgPool, id: CategoryId, audit: &NewEntry) -> sqlx::Result<()> {
         let mut txn = db.begin().await?;
 
         txn.execute("set transaction isolation level repeatable read")
             .await?;
 
+        let before = audit_snapshot(&mut txn, id.0).await?;
+
         let res = sqlx::query!(
             "delete from category where id = $1 returning index, parent_id",
             id.0
@@ -402,13 +498,22 @@ pub async fn delete(db: &PgPool, id: CategoryId) -> Result<(), Delete> {
             backshift(&mut txn, res.parent_id, res.index, None).await?;
         }
 
+        audit_log::insert(
+            &mut txn,
+            NewEntry {
+                before,
+                ..audit.clone()
+            },
+        )
+        .await?;
+
         txn.commit().await?;
 
         Ok(())
     }
 
     loop {
-        match inner(db, id).await {
+        match inner(db, id, audit).await {
             Ok(()) => return Ok(()),
             Err(sqlx::Error::Database(e)) if e.code().as_deref() == Some("40001") => {}
             Err(e) => return Err(e.into()),
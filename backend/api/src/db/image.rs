@@ -1,10 +1,14 @@
 use super::{recycle_metadata, recycle_tags};
 use chrono::{DateTime, Utc};
 use futures::stream::BoxStream;
-use shared::domain::{
-    category::CategoryId,
-    image::{ImageId, ImageKind, ImageMetadata},
-    meta::{AffiliationId, AgeRangeId, ImageStyleId, ImageTagIndex},
+use shared::{
+    domain::{
+        category::CategoryId,
+        image::{ImageId, ImageKind, ImageMetadata, SimilarImage},
+        media::Provenance,
+        meta::{AffiliationId, AgeRangeId, ImageStyleId, ImageTagIndex},
+    },
+    media::MediaLibrary,
 };
 use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
@@ -13,6 +17,9 @@ pub mod recent;
 pub mod tag;
 pub mod user;
 
+/// Default number of bits two perceptual hashes may differ by for the images to be considered similar.
+pub const DEFAULT_SIMILAR_DISTANCE: u32 = 6;
+
 pub async fn create(
     conn: &mut PgConnection,
     name: &str,
@@ -20,10 +27,12 @@ pub async fn create(
     is_premium: bool,
     publish_at: Option<DateTime<Utc>>,
     kind: ImageKind,
+    provenance: &Provenance,
 ) -> sqlx::Result<ImageId> {
     let id: ImageId = sqlx::query!(
         r#"
-insert into image_metadata (name, description, is_premium, publish_at, kind) values ($1, $2, $3, $4, $5)
+insert into image_metadata (name, description, is_premium, publish_at, kind, license, author, source_url)
+values ($1, $2, $3, $4, $5, $6, $7, $8)
 returning id as "id: ImageId"
         "#,
         name,
@@ -31,6 +40,9 @@ returning id as "id: ImageId"
         is_premium,
         publish_at,
         kind as i16,
+        provenance.license as i16,
+        provenance.author,
+        provenance.source_url,
     )
     .fetch_one(&mut *conn)
     .await?
@@ -84,6 +96,7 @@ pub async fn update(
     description: Option<&str>,
     is_premium: Option<bool>,
     publish_at: Option<Option<DateTime<Utc>>>,
+    provenance: Option<&Provenance>,
 ) -> anyhow::Result<bool> {
     if !sqlx::query!(
         r#"select exists(select 1 from image_metadata where id = $1) as "exists!""#,
@@ -124,6 +137,25 @@ where id = $1 and $2 is distinct from description"#,
         .await?;
     }
 
+    if let Some(provenance) = provenance {
+        sqlx::query!(
+            r#"
+update image_metadata
+set license    = $2,
+    author     = $3,
+    source_url = $4,
+    updated_at = now()
+where id = $1
+  and ($2, $3, $4) is distinct from (license, author, source_url)"#,
+            id.0,
+            provenance.license as i16,
+            provenance.author,
+            provenance.source_url,
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
     sqlx::query!(
         //language=SQL
         r#"
@@ -144,7 +176,7 @@ where id = $1
     Ok(true)
 }
 
-pub async fn get_one(db: &PgPool, id: ImageId) -> sqlx::Result<Option<ImageMetadata>> {
+pub async fn get_one(conn: &mut PgConnection, id: ImageId) -> sqlx::Result<Option<ImageMetadata>> {
     sqlx::query_as(
 r#"
 select id,
@@ -156,6 +188,9 @@ select id,
        publish_at,
        created_at,
        updated_at,
+       license,
+       author,
+       source_url,
        translated_description,
        array((select row (category_id) from image_category where image_id = id))       as categories,
        array((select row (style_id) from image_style where image_id = id))             as styles,
@@ -168,7 +203,7 @@ where id = $1
   and processing_result is true
 "#)
     .bind(id)
-    .fetch_optional(db)
+    .fetch_optional(conn)
     .await
 }
 
@@ -190,6 +225,9 @@ select id,
        publish_at,
        created_at,
        updated_at,
+       license,
+       author,
+       source_url,
        array((select row (category_id) from image_category where image_id = id))       as categories,
        array((select row (style_id) from image_style where image_id = id))             as styles,
        array((select row (age_range_id) from image_age_range where image_id = id))     as age_ranges,
@@ -243,6 +281,9 @@ select id,
        publish_at,
        created_at,
        updated_at,
+       license,
+       author,
+       source_url,
        array((select row (category_id) from image_category where image_id = id))       as categories,
        array((select row (style_id) from image_style where image_id = id))             as styles,
        array((select row (age_range_id) from image_age_range where image_id = id))     as age_ranges,
@@ -262,9 +303,15 @@ order by id
 pub async fn delete(db: &PgPool, image: ImageId) -> sqlx::Result<()> {
     let mut conn = db.begin().await?;
 
+    delete_with(&mut conn, image).await?;
+
+    conn.commit().await
+}
+
+async fn delete_with(conn: &mut PgConnection, image: ImageId) -> sqlx::Result<()> {
     // first, clear any metadata it might have.
     update_metadata(
-        &mut conn,
+        &mut *conn,
         image,
         Some(&[]),
         Some(&[]),
@@ -275,13 +322,203 @@ pub async fn delete(db: &PgPool, image: ImageId) -> sqlx::Result<()> {
     .await?;
 
     sqlx::query!("delete from image_upload where image_id = $1", image.0)
-        .execute(&mut conn)
+        .execute(&mut *conn)
         .await?;
 
     // then drop.
     sqlx::query!("delete from image_metadata where id = $1", image.0)
-        .execute(&mut conn)
+        .execute(&mut *conn)
         .await?;
 
-    conn.commit().await
+    Ok(())
+}
+
+/// Images in the global library whose perceptual hash is within `max_distance` of `image`'s.
+///
+/// Returns `None` if the image doesn't exist.
+pub async fn similar(
+    db: &PgPool,
+    image: ImageId,
+    max_distance: u32,
+) -> sqlx::Result<Option<Vec<SimilarImage>>> {
+    let exists = sqlx::query!(
+        r#"select exists(select 1 from image_upload where image_id = $1) as "exists!""#,
+        image.0
+    )
+    .fetch_one(db)
+    .await?
+    .exists;
+
+    if !exists {
+        return Ok(None);
+    }
+
+    let images = sqlx::query!(
+        //language=SQL
+        r#"
+select other.image_id                                                          as "id!: ImageId",
+       perceptual_hash_distance(this.perceptual_hash, other.perceptual_hash) as "distance!"
+from image_upload this
+         inner join image_upload other on other.image_id <> this.image_id
+where this.image_id = $1
+  and other.processing_result is true
+  and perceptual_hash_distance(this.perceptual_hash, other.perceptual_hash) <= $2
+order by 2, other.image_id
+"#,
+        image.0,
+        max_distance as i32
+    )
+    .fetch_all(db)
+    .await?
+    .into_iter()
+    .map(|it| SimilarImage {
+        id: it.id,
+        library: MediaLibrary::Global,
+        distance: it.distance as u32,
+    })
+    .collect();
+
+    Ok(Some(images))
+}
+
+/// Whether any other (processed) image in the global library is within `max_distance` of `image`.
+pub async fn has_similar(db: &PgPool, image: ImageId, max_distance: u32) -> sqlx::Result<bool> {
+    sqlx::query!(
+        //language=SQL
+        r#"
+select exists(select 1
+              from image_upload this
+                       inner join image_upload other on other.image_id <> this.image_id
+              where this.image_id = $1
+                and other.processing_result is true
+                and perceptual_hash_distance(this.perceptual_hash, other.perceptual_hash) <= $2) as "exists!"
+"#,
+        image.0,
+        max_distance as i32
+    )
+    .fetch_one(db)
+    .await
+    .map(|it| it.exists)
+}
+
+/// Pairs of (processed) images in the global library whose perceptual hashes are within `max_distance` of each other,
+/// closest first, at most `limit` of them.
+///
+/// Hashes are split into `max_distance + 1` bands and only images sharing a band are compared, two hashes that differ
+/// by at most `max_distance` bits always have at least one band in common.
+pub async fn similar_pairs(
+    db: &PgPool,
+    max_distance: u32,
+    limit: u32,
+) -> sqlx::Result<Vec<(ImageId, ImageId)>> {
+    sqlx::query!(
+        //language=SQL
+        r#"
+with bands as (
+    select image_id,
+           perceptual_hash,
+           band,
+           (perceptual_hash >> (band * 64 / ($1 + 1))) &
+           case
+               when $1 = 0 then -1
+               else (1::int8 << ((band + 1) * 64 / ($1 + 1) - band * 64 / ($1 + 1))) - 1
+               end as value
+    from image_upload
+             cross join generate_series(0, $1) as band
+    where processing_result is true
+      and perceptual_hash is not null
+)
+select a as "a!: ImageId", b as "b!: ImageId"
+from (select distinct a.image_id                                                     as a,
+                      b.image_id                                                     as b,
+                      perceptual_hash_distance(a.perceptual_hash, b.perceptual_hash) as distance
+      from bands a
+               inner join bands b on a.band = b.band and a.value = b.value and a.image_id < b.image_id
+      where perceptual_hash_distance(a.perceptual_hash, b.perceptual_hash) <= $1) as pairs
+order by distance, a, b
+limit $2
+"#,
+        max_distance as i32,
+        limit as i64,
+    )
+    .fetch_all(db)
+    .await
+    .map(|rows| rows.into_iter().map(|it| (it.a, it.b)).collect())
+}
+
+/// Replaces references to `duplicates` with `keep` and then deletes them.
+///
+/// Returns `false` (and does nothing) if any of the images don't exist.
+pub async fn merge(
+    txn: &mut PgConnection,
+    keep: ImageId,
+    duplicates: &[ImageId],
+) -> sqlx::Result<bool> {
+    let ids: Vec<_> = duplicates.iter().map(|it| it.0).collect();
+
+    let found = sqlx::query!(
+        r#"
+select count(*) as "count!"
+from image_metadata
+where id = any(array_append($2::uuid[], $1))
+"#,
+        keep.0,
+        &ids
+    )
+    .fetch_one(&mut *txn)
+    .await?
+    .count;
+
+    if found as usize != duplicates.len() + 1 {
+        return Ok(false);
+    }
+
+    // modules (including covers) reference images as `{"id": <uuid>, "lib": "Global"}` somewhere in their contents,
+    // only those objects are rewritten.
+    sqlx::query!(
+        r#"
+update jig_data_module
+set contents = jsonb_replace_media_id(contents, 'Global', $2, $1)
+where exists(select 1
+             from jsonb_path_query(contents, 'strict $.**') as item
+             where jsonb_typeof(item) = 'object'
+               and item ->> 'lib' = 'Global'
+               and item ->> 'id' = any ($2::uuid[]::text[]))
+"#,
+        keep.0,
+        &ids
+    )
+    .execute(&mut *txn)
+    .await?;
+
+    sqlx::query!(
+        r#"
+insert into user_recent_image (user_id, image_id, media_library, last_used)
+select user_id, $1, $3, max(last_used)
+from user_recent_image
+where image_id = any($2) and media_library = $3
+group by user_id
+on conflict (user_id, image_id) do update
+set last_used = greatest(user_recent_image.last_used, excluded.last_used)
+"#,
+        keep.0,
+        &ids,
+        MediaLibrary::Global as i16,
+    )
+    .execute(&mut *txn)
+    .await?;
+
+    sqlx::query!(
+        "delete from user_recent_image where image_id = any($1) and media_library = $2",
+        &ids,
+        MediaLibrary::Global as i16,
+    )
+    .execute(&mut *txn)
+    .await?;
+
+    for duplicate in duplicates {
+        delete_with(&mut *txn, *duplicate).await?;
+    }
+
+    Ok(true)
 }
@@ -1,5 +1,8 @@
 use futures::stream::BoxStream;
-use shared::domain::image::{user::UserImage, ImageId, ImageKind};
+use shared::{
+    domain::image::{user::UserImage, ImageId, ImageKind, SimilarImage},
+    media::MediaLibrary,
+};
 use sqlx::{PgConnection, PgPool};
 use tracing::{instrument, Instrument};
 use uuid::Uuid;
@@ -87,29 +90,126 @@ order by created_at desc
     .fetch(db)
 }
 
-/// checks if the user owns the image requested.
+/// checks if the user owns the image requested, returning its kind.
 ///
 /// Returns ResourceNotFound even if the image exists but the user does not h
 pub async fn auth_user_image(
     txn: &mut PgConnection,
     user_id: &Uuid,
     image_id: &ImageId,
-) -> anyhow::Result<(), crate::error::Upload> {
-    let exists = sqlx::query!(
+) -> anyhow::Result<ImageKind, crate::error::Upload> {
+    let kind = sqlx::query!(
         //language=SQL
         r#"
-select exists(select 1 from user_image_library where user_id = $1 and id = $2) as "exists!"
+select kind as "kind: ImageKind" from user_image_library where user_id = $1 and id = $2
     "#,
         user_id,
         image_id.0
     )
-    .fetch_one(txn)
+    .fetch_optional(txn)
     .await?
-    .exists;
+    .ok_or(crate::error::Upload::ResourceNotFound)?
+    .kind;
 
-    if !exists {
-        return Err(crate::error::Upload::ResourceNotFound);
-    }
+    Ok(kind)
+}
+
+/// Images in the user's library (and the global library) whose perceptual hash is within `max_distance` of `image_id`'s.
+///
+/// Returns `None` if the user doesn't own the image.
+pub async fn similar(
+    db: &PgPool,
+    user_id: Uuid,
+    image_id: ImageId,
+    max_distance: u32,
+) -> sqlx::Result<Option<Vec<SimilarImage>>> {
+    let hash = sqlx::query!(
+        r#"
+select perceptual_hash
+from user_image_library
+         inner join user_image_upload on user_image_library.id = user_image_upload.image_id
+where user_id = $1
+  and id = $2
+"#,
+        user_id,
+        image_id.0
+    )
+    .fetch_optional(db)
+    .await?;
+
+    let hash = match hash {
+        Some(row) => row.perceptual_hash,
+        None => return Ok(None),
+    };
+
+    let images = sqlx::query!(
+        //language=SQL
+        r#"
+select id as "id!: ImageId", library as "library!: MediaLibrary", distance as "distance!"
+from (select image_id                                       as id,
+             $4::int2                                       as library,
+             perceptual_hash_distance($3, perceptual_hash) as distance
+      from user_image_upload
+               inner join user_image_library on user_image_library.id = user_image_upload.image_id
+      where user_id = $1
+        and image_id <> $2
+        and processing_result is true
+      union all
+      select image_id, $5::int2, perceptual_hash_distance($3, perceptual_hash)
+      from image_upload
+      where processing_result is true) as candidates
+where distance <= $6
+order by distance, library, id
+"#,
+        user_id,
+        image_id.0,
+        hash,
+        MediaLibrary::User as i16,
+        MediaLibrary::Global as i16,
+        max_distance as i32,
+    )
+    .fetch_all(db)
+    .await?
+    .into_iter()
+    .map(|it| SimilarImage {
+        id: it.id,
+        library: it.library,
+        distance: it.distance as u32,
+    })
+    .collect();
+
+    Ok(Some(images))
+}
 
-    Ok(())
+/// Whether any other (processed) image in the owner's library, or in the global library, is within `max_distance` of
+/// `image_id`.
+pub async fn has_similar(db: &PgPool, image_id: ImageId, max_distance: u32) -> sqlx::Result<bool> {
+    sqlx::query!(
+        //language=SQL
+        r#"
+with this as (
+    select user_id, perceptual_hash
+    from user_image_upload
+             inner join user_image_library on user_image_library.id = user_image_upload.image_id
+    where image_id = $1
+)
+select exists(select 1
+              from this
+                       inner join user_image_library other_image on other_image.user_id = this.user_id
+                       inner join user_image_upload other on other.image_id = other_image.id
+              where other.image_id <> $1
+                and other.processing_result is true
+                and perceptual_hash_distance(this.perceptual_hash, other.perceptual_hash) <= $2)
+    or exists(select 1
+              from this
+                       inner join image_upload other
+                                  on perceptual_hash_distance(this.perceptual_hash, other.perceptual_hash) <= $2
+              where other.processing_result is true) as "exists!"
+"#,
+        image_id.0,
+        max_distance as i32
+    )
+    .fetch_one(db)
+    .await
+    .map(|it| it.exists)
 }
@@ -12,9 +12,12 @@ use shared::domain::{
         DeleteUserJigs, DraftOrLive, JigAdminData, JigData, JigFocus, JigId, JigPlayerSettings,
         JigRating, JigResponse, LiteModule, ModuleKind, PrivacyLevel, TextDirection,
     },
+    media::{License, MediaAttribution, Provenance},
     meta::{AffiliationId, AgeRangeId, ResourceTypeId as TypeId},
     user::UserScope,
+    webhook::WebhookEvent,
 };
+use shared::media::MediaLibrary;
 use sqlx::{types::Json, PgConnection, PgPool};
 use std::collections::HashMap;
 use tracing::{instrument, Instrument};
@@ -24,6 +27,7 @@ use crate::error;
 
 pub(crate) mod additional_resource;
 pub(crate) mod curation;
+pub(crate) mod flashcard_review;
 pub(crate) mod module;
 pub(crate) mod player;
 pub(crate) mod report;
@@ -238,10 +242,11 @@ select cte.jig_id                                          as "jig_id: JigId",
              from jig_data_age_range
              where jig_data_id = cte.draft_or_live_id)     as "age_ranges!: Vec<(AgeRangeId,)>",
        array(
-             select row (jdar.id, jdar.display_name, resource_type_id, resource_content)
+             select row (jdar.id, jdar.display_name, resource_type_id, resource_content, pdf.page_count, pdf.title)
              from jig_data_additional_resource "jdar"
+             left join user_pdf_library "pdf" on pdf.id::text = jdar.resource_content ->> 'pdfId'
              where jdar.jig_data_id = cte.draft_or_live_id
-       )                                                    as "additional_resource!: Vec<(AddId, String, TypeId, Value)>"
+       )                                                    as "additional_resource!: Vec<(AddId, String, TypeId, Value, Option<i32>, Option<String>)>"
 from jig_data
          inner join cte on cte.draft_or_live_id = jig_data.id
 "#,
@@ -288,14 +293,17 @@ from jig_data
                 .additional_resource
                 .into_iter()
                 .map(
-                    |(id, display_name, resource_type_id, resource_content)| AdditionalResource {
-                        id,
-                        display_name,
-                        resource_type_id,
-                        resource_content: serde_json::from_value::<ResourceContent>(
-                            resource_content,
-                        )
-                        .unwrap(),
+                    |(id, display_name, resource_type_id, resource_content, page_count, title)| {
+                        AdditionalResource {
+                            id,
+                            display_name,
+                            resource_type_id,
+                            resource_content: serde_json::from_value::<ResourceContent>(
+                                resource_content,
+                            )
+                            .unwrap(),
+                            pdf_preview: super::pdf::user::preview(page_count, title),
+                        }
                     },
                 )
                 .collect(),
@@ -407,10 +415,11 @@ select id,
              from jig_data_age_range
              where jig_data_id = jig_data.id)     as "age_ranges!: Vec<(AgeRangeId,)>",
        array(
-                select row (jdar.id, jdar.display_name, resource_type_id, resource_content)
+                select row (jdar.id, jdar.display_name, resource_type_id, resource_content, pdf.page_count, pdf.title)
                 from jig_data_additional_resource "jdar"
+                left join user_pdf_library "pdf" on pdf.id::text = jdar.resource_content ->> 'pdfId'
                 where jdar.jig_data_id = jig_data.id
-            )                                               as "additional_resource!: Vec<(AddId, String, TypeId, Value)>",
+            )                                               as "additional_resource!: Vec<(AddId, String, TypeId, Value, Option<i32>, Option<String>)>",
        privacy_level                              as "privacy_level!: PrivacyLevel",
        locked                                     as "locked!",
        other_keywords                             as "other_keywords!",
@@ -478,17 +487,27 @@ where draft_or_live is not null
                 additional_resources: jig_data_row
                     .additional_resource
                     .into_iter()
-                    .map(|(id, display_name, resource_type_id, resource_content)| {
-                        AdditionalResource {
+                    .map(
+                        |(
                             id,
                             display_name,
                             resource_type_id,
-                            resource_content: serde_json::from_value::<ResourceContent>(
-                                resource_content,
-                            )
-                            .unwrap(),
-                        }
-                    })
+                            resource_content,
+                            page_count,
+                            title,
+                        )| {
+                            AdditionalResource {
+                                id,
+                                display_name,
+                                resource_type_id,
+                                resource_content: serde_json::from_value::<ResourceContent>(
+                                    resource_content,
+                                )
+                                .unwrap(),
+                                pdf_preview: super::pdf::user::preview(page_count, title),
+                            }
+                        },
+                    )
                     .collect(),
                 audio_background: jig_data_row.audio_background,
                 audio_effects: AudioEffects {
@@ -605,10 +624,11 @@ select jig.id                                              as "jig_id: JigId",
          from jig_data_age_range
          where jig_data_id = jig_data.id)     as "age_ranges!: Vec<(AgeRangeId,)>",
    array(
-            select row (jdar.id, jdar.display_name, resource_type_id, resource_content)
+            select row (jdar.id, jdar.display_name, resource_type_id, resource_content, pdf.page_count, pdf.title)
             from jig_data_additional_resource "jdar"
+            left join user_pdf_library "pdf" on pdf.id::text = jdar.resource_content ->> 'pdfId'
             where jdar.jig_data_id = jig_data.id
-        )                                               as "additional_resource!: Vec<(AddId, String, TypeId, Value)>",
+        )                                               as "additional_resource!: Vec<(AddId, String, TypeId, Value, Option<i32>, Option<String>)>",
    locked                                     as "locked!",
    other_keywords                             as "other_keywords!",
    translated_keywords                        as "translated_keywords!",
@@ -686,17 +706,27 @@ limit $7
                 additional_resources: jig_data_row
                     .additional_resource
                     .into_iter()
-                    .map(|(id, display_name, resource_type_id, resource_content)| {
-                        AdditionalResource {
+                    .map(
+                        |(
                             id,
                             display_name,
                             resource_type_id,
-                            resource_content: serde_json::from_value::<ResourceContent>(
-                                resource_content,
-                            )
-                            .unwrap(),
-                        }
-                    })
+                            resource_content,
+                            page_count,
+                            title,
+                        )| {
+                            AdditionalResource {
+                                id,
+                                display_name,
+                                resource_type_id,
+                                resource_content: serde_json::from_value::<ResourceContent>(
+                                    resource_content,
+                                )
+                                .unwrap(),
+                                pdf_preview: super::pdf::user::preview(page_count, title),
+                            }
+                        },
+                    )
                     .collect(),
                 audio_background: jig_data_row.audio_background,
                 audio_effects: AudioEffects {
@@ -938,6 +968,8 @@ pub async fn delete(pool: &PgPool, id: JigId) -> Result<(), error::Delete> {
         .await
         .ok_or(error::Delete::ResourceNotFound)?;
 
+    super::webhook::enqueue(&mut txn, WebhookEvent::JigDeleted, id).await?;
+
     sqlx::query!(
         //language=SQL
         r#"
@@ -968,6 +1000,10 @@ pub async fn delete_all_jigs(
 
     let jig_ids: Vec<DeleteUserJigs> = get_user_jig_ids(&mut txn, user_id).await?;
 
+    for it in &jig_ids {
+        super::webhook::enqueue(&mut txn, WebhookEvent::JigDeleted, it.jig_id).await?;
+    }
+
     sqlx::query!(
         //language=SQL
         r#"
@@ -1322,14 +1358,12 @@ where jig_id = $1;
 }
 
 pub async fn update_admin_data(
-    pool: &PgPool,
+    txn: &mut PgConnection,
     jig_id: JigId,
     rating: Option<JigRating>,
     blocked: Option<bool>,
     curated: Option<bool>,
 ) -> Result<(), error::NotFound> {
-    let mut txn = pool.begin().await?;
-
     if let Some(rating) = rating {
         sqlx::query!(
             //language=SQL
@@ -1341,12 +1375,12 @@ where jig_id = $1 and $2 is distinct from rating
             jig_id.0,
             rating as i16
         )
-        .execute(&mut txn)
+        .execute(&mut *txn)
         .await?;
     }
 
     if let Some(blocked) = blocked {
-        sqlx::query!(
+        let changed = sqlx::query!(
             //language=SQL
             r#"
 update jig_admin_data
@@ -1356,8 +1390,14 @@ where jig_id = $1 and $2 is distinct from blocked
             jig_id.0,
             blocked
         )
-        .execute(&mut txn)
-        .await?;
+        .execute(&mut *txn)
+        .await?
+        .rows_affected()
+            > 0;
+
+        if blocked && changed {
+            super::webhook::enqueue(&mut *txn, WebhookEvent::JigBlocked, jig_id).await?;
+        }
 
         sqlx::query!(
             //language=SQL
@@ -1369,7 +1409,7 @@ where jig.live_id = $1
             "#,
             jig_id.0,
         )
-        .execute(&mut txn)
+        .execute(&mut *txn)
         .await?;
     }
 
@@ -1384,15 +1424,108 @@ where jig_id = $1 and $2 is distinct from curated
             jig_id.0,
             curated
         )
-        .execute(&mut txn)
+        .execute(&mut *txn)
         .await?;
     }
 
-    txn.commit().await?;
-
     Ok(())
 }
 
+pub async fn get_admin_data(
+    conn: &mut PgConnection,
+    jig_id: JigId,
+) -> sqlx::Result<Option<JigAdminData>> {
+    sqlx::query_as!(
+        JigAdminData,
+        //language=SQL
+        r#"
+select rating as "rating?: JigRating",
+       blocked,
+       curated
+from jig_admin_data
+where jig_id = $1
+"#,
+        jig_id.0
+    )
+    .fetch_optional(conn)
+    .await
+}
+
+/// Lists the licensing information for every global and web library image used in the live version of a jig.
+///
+/// Returns `None` if the jig doesn't exist.
+pub async fn attribution(db: &PgPool, id: JigId) -> sqlx::Result<Option<Vec<MediaAttribution>>> {
+    let exists = sqlx::query!(
+        //language=SQL
+        r#"select exists(select 1 from jig where id = $1) as "exists!""#,
+        id.0
+    )
+    .fetch_one(db)
+    .await?
+    .exists;
+
+    if !exists {
+        return Ok(None);
+    }
+
+    // images are stored in module contents as `{"id": <uuid>, "lib": <library>}`, at various depths.
+    let media = sqlx::query!(
+        //language=SQL
+        r#"
+with media as (
+    select distinct case
+                        when item ->> 'id' ~* '^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$'
+                            then (item ->> 'id')::uuid
+                        end         as id,
+                    item ->> 'lib' as lib
+    from jig
+             inner join jig_data_module on jig_data_module.jig_data_id = jig.live_id
+             cross join lateral jsonb_path_query(contents, 'strict $.**') as item
+    where jig.id = $1
+      and jsonb_typeof(item) = 'object'
+      and item ? 'id'
+      and item ? 'lib'
+)
+select id                  as "id!",
+       $2::int2            as "library!: MediaLibrary",
+       license             as "license!: License",
+       author,
+       source_url
+from image_metadata
+         inner join media using (id)
+where lib = 'Global'
+union all
+select id,
+       $3::int2,
+       license,
+       author,
+       source_url
+from web_media_library
+         inner join media using (id)
+where lib = 'Web'
+order by 2, 1
+"#,
+        id.0,
+        MediaLibrary::Global as i16,
+        MediaLibrary::Web as i16,
+    )
+    .fetch_all(db)
+    .await?
+    .into_iter()
+    .map(|it| MediaAttribution {
+        id: it.id,
+        library: it.library,
+        provenance: Provenance {
+            license: it.license,
+            author: it.author,
+            source_url: it.source_url,
+        },
+    })
+    .collect();
+
+    Ok(Some(media))
+}
+
 pub async fn jig_like(db: &PgPool, user_id: Uuid, id: JigId) -> anyhow::Result<()> {
     let mut txn = db.begin().await?;
 
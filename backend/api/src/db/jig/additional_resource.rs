@@ -6,9 +6,9 @@ use shared::domain::{
     jig::additional_resource::{AdditionalResourceId, ResourceContent},
     jig::{DraftOrLive, JigId},
     meta::ResourceTypeId,
-    pdf::PdfId,
+    pdf::{PdfId, PdfPreview},
 };
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 use url::Url;
 
 use crate::error;
@@ -22,14 +22,14 @@ pub struct ResourceObject {
 }
 
 pub async fn create(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     jig_id: JigId,
     display_name: String,
     resource_type_id: ResourceTypeId,
     resource_content: ResourceContent,
 ) -> anyhow::Result<AdditionalResourceId> {
     // Checks if Audio and Image IDs exists
-    let resource: serde_json::Value = check_content(pool, resource_content).await?;
+    let resource: serde_json::Value = check_content(&mut *conn, resource_content).await?;
 
     sqlx::query!(
         r#"
@@ -42,7 +42,7 @@ returning id as "id!: AdditionalResourceId"
         resource,
         display_name
     )
-    .fetch_one(pool)
+    .fetch_one(&mut *conn)
     .await
     .map(|it| it.id)
     .map_err(Into::into)
@@ -53,7 +53,8 @@ pub async fn get(
     jig_id: JigId,
     draft_or_live: DraftOrLive,
     id: AdditionalResourceId,
-) -> anyhow::Result<(String, ResourceTypeId, ResourceContent), error::NotFound> {
+) -> anyhow::Result<(String, ResourceTypeId, ResourceContent, Option<PdfPreview>), error::NotFound>
+{
     let mut txn = pool.begin().await?;
 
     let (draft_id, live_id) = super::get_draft_and_live_ids(&mut txn, jig_id)
@@ -85,8 +86,11 @@ select exists(select 1 from jig_data_additional_resource "jdar" where jig_data_i
         r#"
 select display_name         as "display_name!",
        resource_type_id     as "resource_type_id!: ResourceTypeId",
-       resource_content    as "resource_content!"
+       resource_content    as "resource_content!",
+       pdf.page_count       as "page_count?",
+       pdf.title            as "title?"
 from jig_data_additional_resource "jdar"
+left join user_pdf_library "pdf" on pdf.id::text = jdar.resource_content ->> 'pdfId'
 where jig_data_id = $1
   and jdar.id = $2
         "#,
@@ -100,7 +104,9 @@ where jig_data_id = $1
 
     txn.rollback().await?;
 
-    Ok((res.display_name, res.resource_type_id, content))
+    let pdf_preview = crate::db::pdf::user::preview(res.page_count, res.title);
+
+    Ok((res.display_name, res.resource_type_id, content, pdf_preview))
 }
 
 pub async fn update(
@@ -202,14 +208,17 @@ where jig_data_id = $1
     Ok(())
 }
 
-pub async fn check_content(db: &PgPool, content: ResourceContent) -> anyhow::Result<Value> {
+pub async fn check_content(
+    conn: &mut PgConnection,
+    content: ResourceContent,
+) -> anyhow::Result<Value> {
     let resource: serde_json::Value = match content {
         ResourceContent::ImageId(data) => {
             sqlx::query!(
                 r#"select id as "id: ImageId" from user_image_library where id = $1"#,
                 data.0
             )
-            .fetch_one(db)
+            .fetch_one(&mut *conn)
             .await
             .map_err(|_| anyhow::anyhow!("Image Id does not exist"))?;
 
@@ -220,7 +229,7 @@ pub async fn check_content(db: &PgPool, content: ResourceContent) -> anyhow::Res
                 r#"select id as "id: AudioId" from user_audio_library where id = $1"#,
                 data.0
             )
-            .fetch_one(db)
+            .fetch_one(&mut *conn)
             .await
             .map_err(|_| anyhow::anyhow!("Audio Id does not exist"))?;
 
@@ -236,7 +245,7 @@ pub async fn check_content(db: &PgPool, content: ResourceContent) -> anyhow::Res
                 r#"select id as "id: PdfId" from user_pdf_library where id = $1"#,
                 data.0
             )
-            .fetch_one(db)
+            .fetch_one(&mut *conn)
             .await
             .map_err(|_| anyhow::anyhow!("Pdf Id does not exist"))?;
 
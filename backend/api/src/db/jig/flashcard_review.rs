@@ -0,0 +1,250 @@
+use chrono::{DateTime, Utc};
+use shared::domain::jig::{
+    module::{
+        body::{
+            _groups::cards::CardPair,
+            flashcards::review::{pair_key, CardReview},
+        },
+        ModuleKind, StableModuleId,
+    },
+    JigId,
+};
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+/// Who a flashcards review state belongs to.
+#[derive(Debug, Clone, Copy)]
+pub enum Reviewer {
+    /// A logged-in user.
+    User(Uuid),
+    /// A player session instance.
+    Instance(Uuid),
+}
+
+impl Reviewer {
+    fn user_id(self) -> Option<Uuid> {
+        match self {
+            Self::User(id) => Some(id),
+            Self::Instance(_) => None,
+        }
+    }
+
+    fn instance_id(self) -> Option<Uuid> {
+        match self {
+            Self::User(_) => None,
+            Self::Instance(id) => Some(id),
+        }
+    }
+}
+
+/// Returns the [`pair_key`]s of the pairs in the jig's flashcards module, or `None` if it doesn't have one.
+///
+/// Players can be playing either the draft or the live module, so the keys of both are returned.
+#[instrument(skip(db))]
+pub async fn pair_keys(
+    db: &PgPool,
+    jig_id: JigId,
+    module_id: StableModuleId,
+) -> sqlx::Result<Option<Vec<String>>> {
+    let rows = sqlx::query!(
+        //language=SQL
+        r#"
+select contents #> '{content,base,pairs}' as "pairs"
+from jig_data_module
+         inner join jig_data on jig_data.id = jig_data_module.jig_data_id
+         inner join jig on jig_data.id in (jig.live_id, jig.draft_id)
+where jig.id = $1
+  and stable_id = $2
+  and kind = $3
+"#,
+        jig_id.0,
+        module_id.0,
+        ModuleKind::Flashcards as i16,
+    )
+    .fetch_all(db)
+    .await?;
+
+    if rows.is_empty() {
+        return Ok(None);
+    }
+
+    let mut keys = Vec::new();
+
+    for pairs in rows.into_iter().filter_map(|it| it.pairs) {
+        let pairs: Vec<CardPair> =
+            serde_json::from_value(pairs).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+
+        keys.extend(pairs.iter().map(pair_key));
+    }
+
+    keys.sort_unstable();
+    keys.dedup();
+
+    Ok(Some(keys))
+}
+
+/// Returns the player session a player session instance belongs to, if it's for `jig_id`.
+#[instrument(skip(db))]
+pub async fn instance_session(
+    db: &PgPool,
+    instance_id: Uuid,
+    jig_id: JigId,
+) -> sqlx::Result<Option<i16>> {
+    sqlx::query!(
+        //language=SQL
+        r#"
+select session_index
+from jig_player_session_instance
+         inner join jig_player_session on session_index = index
+where id = $1
+  and jig_id = $2
+"#,
+        instance_id,
+        jig_id.0,
+    )
+    .fetch_optional(db)
+    .await
+    .map(|it| it.map(|it| it.session_index))
+}
+
+#[instrument(skip(db))]
+pub async fn list(
+    db: &PgPool,
+    jig_id: JigId,
+    module_id: StableModuleId,
+    reviewer: Reviewer,
+) -> sqlx::Result<Vec<CardReview>> {
+    let rows = sqlx::query!(
+        //language=SQL
+        r#"
+select pair_key, leitner_box, reviewed_at, due_at
+from flashcard_review
+where jig_id = $1
+  and stable_module_id = $2
+  and user_id is not distinct from $3
+  and instance_id is not distinct from $4
+order by pair_key
+"#,
+        jig_id.0,
+        module_id.0,
+        reviewer.user_id(),
+        reviewer.instance_id(),
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|it| review(it.pair_key, it.leitner_box, it.reviewed_at, it.due_at))
+        .collect())
+}
+
+#[instrument(skip(db))]
+pub async fn get(
+    db: &PgPool,
+    jig_id: JigId,
+    module_id: StableModuleId,
+    reviewer: Reviewer,
+    pair_key: &str,
+) -> sqlx::Result<Option<CardReview>> {
+    sqlx::query!(
+        //language=SQL
+        r#"
+select pair_key, leitner_box, reviewed_at, due_at
+from flashcard_review
+where jig_id = $1
+  and stable_module_id = $2
+  and user_id is not distinct from $3
+  and instance_id is not distinct from $4
+  and pair_key = $5
+"#,
+        jig_id.0,
+        module_id.0,
+        reviewer.user_id(),
+        reviewer.instance_id(),
+        pair_key,
+    )
+    .fetch_optional(db)
+    .await
+    .map(|it| it.map(|it| review(it.pair_key, it.leitner_box, it.reviewed_at, it.due_at)))
+}
+
+/// Saves the review state of a card.
+///
+/// `session_index` is the player session of an [`Instance`](Reviewer::Instance) reviewer, the state is deleted with it.
+#[instrument(skip(db))]
+pub async fn upsert(
+    db: &PgPool,
+    jig_id: JigId,
+    module_id: StableModuleId,
+    reviewer: Reviewer,
+    session_index: Option<i16>,
+    review: &CardReview,
+) -> sqlx::Result<()> {
+    match reviewer {
+        Reviewer::User(user_id) => {
+            sqlx::query!(
+                //language=SQL
+                r#"
+insert into flashcard_review (jig_id, stable_module_id, user_id, pair_key, leitner_box, reviewed_at, due_at)
+values ($1, $2, $3, $4, $5, $6, $7)
+on conflict (jig_id, stable_module_id, user_id, pair_key) where user_id is not null
+    do update set leitner_box = $5,
+                  reviewed_at = $6,
+                  due_at      = $7
+"#,
+                jig_id.0,
+                module_id.0,
+                user_id,
+                &review.pair_key,
+                i16::from(review.leitner_box),
+                review.reviewed_at,
+                review.due_at,
+            )
+            .execute(db)
+            .await?;
+        }
+
+        Reviewer::Instance(instance_id) => {
+            sqlx::query!(
+                //language=SQL
+                r#"
+insert into flashcard_review (jig_id, stable_module_id, instance_id, session_index, pair_key, leitner_box,
+                              reviewed_at, due_at)
+values ($1, $2, $3, $4, $5, $6, $7, $8)
+on conflict (jig_id, stable_module_id, instance_id, pair_key) where instance_id is not null
+    do update set leitner_box = $6,
+                  reviewed_at = $7,
+                  due_at      = $8
+"#,
+                jig_id.0,
+                module_id.0,
+                instance_id,
+                session_index,
+                &review.pair_key,
+                i16::from(review.leitner_box),
+                review.reviewed_at,
+                review.due_at,
+            )
+            .execute(db)
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+fn review(
+    pair_key: String,
+    leitner_box: i16,
+    reviewed_at: DateTime<Utc>,
+    due_at: DateTime<Utc>,
+) -> CardReview {
+    CardReview {
+        pair_key,
+        leitner_box: leitner_box as u8,
+        reviewed_at,
+        due_at,
+    }
+}
@@ -11,9 +11,12 @@ fn map_module_contents(body: &ModuleBody) -> anyhow::Result<(ModuleKind, serde_j
 
     let body = match body {
         ModuleBody::CardQuiz(body) => serde_json::to_value(body)?,
+        ModuleBody::WordGrid(body) => serde_json::to_value(body)?,
         ModuleBody::Cover(body) => serde_json::to_value(body)?,
         ModuleBody::ResourceCover(body) => serde_json::to_value(body)?,
         ModuleBody::DragDrop(body) => serde_json::to_value(body)?,
+        ModuleBody::Puzzle(body) => serde_json::to_value(body)?,
+        ModuleBody::TalkType(body) => serde_json::to_value(body)?,
         ModuleBody::Flashcards(body) => serde_json::to_value(body)?,
         ModuleBody::Matching(body) => serde_json::to_value(body)?,
         ModuleBody::MemoryGame(body) => serde_json::to_value(body)?,
@@ -34,11 +37,14 @@ fn transform_response_kind(
 ) -> anyhow::Result<ModuleBody> {
     match kind {
         ModuleKind::CardQuiz => Ok(ModuleBody::CardQuiz(serde_json::from_value(contents)?)),
+        ModuleKind::WordGrid => Ok(ModuleBody::WordGrid(serde_json::from_value(contents)?)),
         ModuleKind::Cover => Ok(ModuleBody::Cover(serde_json::from_value(contents)?)),
         ModuleKind::ResourceCover => {
             Ok(ModuleBody::ResourceCover(serde_json::from_value(contents)?))
         }
         ModuleKind::DragDrop => Ok(ModuleBody::DragDrop(serde_json::from_value(contents)?)),
+        ModuleKind::Puzzle => Ok(ModuleBody::Puzzle(serde_json::from_value(contents)?)),
+        ModuleKind::TalkType => Ok(ModuleBody::TalkType(serde_json::from_value(contents)?)),
         ModuleKind::Flashcards => Ok(ModuleBody::Flashcards(serde_json::from_value(contents)?)),
         ModuleKind::Matching => Ok(ModuleBody::Matching(serde_json::from_value(contents)?)),
         ModuleKind::Memory => Ok(ModuleBody::MemoryGame(serde_json::from_value(contents)?)),
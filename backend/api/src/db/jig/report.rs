@@ -1,7 +1,11 @@
 use crate::error;
-use shared::domain::jig::{
-    report::{JigReport, JigReportEmail, JigReportType, ReportId},
-    JigId,
+use chrono::Utc;
+use shared::domain::{
+    jig::{
+        report::{JigReport, JigReportEmail, JigReportType, ReportId},
+        JigId,
+    },
+    webhook::{WebhookEvent, WebhookPayload},
 };
 use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
@@ -14,36 +18,37 @@ pub async fn create_report(
 ) -> Result<ReportId, error::ReportError> {
     check_jig(pool, jig_id).await?;
 
-    if let Some(user_id) = user_id {
-        sqlx::query!(
-            r#"
+    let mut txn = pool.begin().await?;
+
+    let report_id = sqlx::query!(
+        r#"
     insert into jig_report(jig_id, report_type, reporter_id)
     values ($1, $2, $3)
     returning id as "id!: ReportId"
             "#,
-            jig_id.0,
-            report_type as i16,
-            user_id
-        )
-        .fetch_one(pool)
-        .await
-        .map(|it| it.id)
-        .map_err(Into::into)
-    } else {
-        sqlx::query!(
-            r#"
-    insert into jig_report(jig_id, report_type)
-    values ($1, $2)
-    returning id as "id!: ReportId"
-            "#,
-            jig_id.0,
-            report_type as i16,
-        )
-        .fetch_one(pool)
-        .await
-        .map(|it| it.id)
-        .map_err(Into::into)
-    }
+        jig_id.0,
+        report_type as i16,
+        user_id
+    )
+    .fetch_one(&mut txn)
+    .await?
+    .id;
+
+    crate::db::webhook::enqueue_payload(
+        &mut txn,
+        &WebhookPayload {
+            event: WebhookEvent::JigReported,
+            jig_id,
+            occurred_at: Utc::now(),
+            report_id: Some(report_id),
+            report_type: Some(report_type),
+        },
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    Ok(report_id)
 }
 
 pub async fn get_report(
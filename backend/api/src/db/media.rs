@@ -6,7 +6,10 @@ use actix_web::{http::StatusCode, web::Bytes};
 use anyhow::Context;
 use core::config::{ANIMATION_BODY_SIZE_LIMIT, IMAGE_BODY_SIZE_LIMIT};
 use sha2::Digest;
-use shared::media::{FileKind, MediaLibrary, PngImageFile};
+use shared::{
+    domain::{image::ImageKind, media::Provenance},
+    media::{FileKind, MediaLibrary, PngImageFile},
+};
 use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
 
@@ -19,10 +22,33 @@ const fn max(a: usize, b: usize) -> usize {
     }
 }
 
+/// Records the provenance of media that was added before it was known.
+async fn record_provenance(
+    conn: &mut PgConnection,
+    id: Uuid,
+    provenance: &Provenance,
+) -> sqlx::Result<()> {
+    sqlx::query!(
+        r#"
+update web_media_library
+set license = $2, author = $3, source_url = $4
+where id = $1 and license = 0 and $2 <> 0
+"#,
+        id,
+        provenance.license as i16,
+        provenance.author,
+        provenance.source_url,
+    )
+    .execute(conn)
+    .await
+    .map(drop)
+}
+
 pub async fn create(
     pool: &PgPool,
     s3: &ServiceData<s3::Client>,
     url_string: &String,
+    provenance: &Provenance,
 ) -> anyhow::Result<(Uuid, MediaKind, StatusCode)> {
     // If we can already find the image, return early.
     if let Some(record) = sqlx::query!(
@@ -39,6 +65,8 @@ where media_url = $1"#,
     {
         log::trace!("Found the url");
 
+        record_provenance(&mut *pool.acquire().await?, record.media_id, provenance).await?;
+
         return Ok((record.media_id, record.kind, StatusCode::OK));
     }
 
@@ -51,6 +79,8 @@ where media_url = $1"#,
     let (hash, id, kind) = hash_media_file(url_string.to_string(), &mut txn, &data).await?;
 
     if let (Some(id), Some(kind)) = (id, kind) {
+        record_provenance(&mut txn, id, provenance).await?;
+
         txn.commit().await?;
 
         return Ok((id, kind, StatusCode::OK));
@@ -69,9 +99,16 @@ where media_url = $1"#,
     log::debug!("detected image kind as: {:?}", kind);
 
     let id = sqlx::query!(
-        r#"insert into web_media_library ("hash", kind) values($1, $2) returning id"#,
+        r#"
+insert into web_media_library ("hash", kind, license, author, source_url)
+values ($1, $2, $3, $4, $5)
+returning id
+"#,
         &hash,
-        kind as i16
+        kind as i16,
+        provenance.license as i16,
+        provenance.author,
+        provenance.source_url,
     )
     .fetch_one(&mut txn)
     .await?
@@ -94,19 +131,177 @@ where media_url = $1"#,
 
     txn.commit().await?;
 
+    let file_kind = match kind {
+        MediaKind::SvgStickerImage => FileKind::ImageSvg,
+        _ => FileKind::ImagePng(PngImageFile::Original),
+    };
+
     // upload to media processing bucket
-    s3.upload_media_for_processing(
-        data.to_vec(),
-        MediaLibrary::Web,
-        id,
-        FileKind::ImagePng(PngImageFile::Original),
-    )
-    .await
-    .context("failed to upload media for processing")?;
+    s3.upload_media_for_processing(data.to_vec(), MediaLibrary::Web, id, file_kind)
+        .await
+        .context("failed to upload media for processing")?;
 
     Ok((id, kind, StatusCode::CREATED))
 }
 
+/// Which formats an image is stored in.
+pub struct ImageFormats {
+    pub has_webp: bool,
+    pub is_vector: bool,
+}
+
+/// Returns `None` if the image doesn't exist.
+pub async fn image_formats(
+    db: &PgPool,
+    library: MediaLibrary,
+    id: Uuid,
+) -> sqlx::Result<Option<ImageFormats>> {
+    let formats = match library {
+        MediaLibrary::Global => sqlx::query!(
+            r#"
+select has_webp, kind as "kind: ImageKind"
+from image_upload
+inner join image_metadata on id = image_id
+where image_id = $1
+"#,
+            id
+        )
+        .fetch_optional(db)
+        .await?
+        .map(|it| ImageFormats {
+            has_webp: it.has_webp,
+            is_vector: it.kind.is_vector(),
+        }),
+
+        MediaLibrary::User => sqlx::query!(
+            r#"
+select has_webp, kind as "kind: ImageKind"
+from user_image_upload
+inner join user_image_library on id = image_id
+where image_id = $1
+"#,
+            id
+        )
+        .fetch_optional(db)
+        .await?
+        .map(|it| ImageFormats {
+            has_webp: it.has_webp,
+            is_vector: it.kind.is_vector(),
+        }),
+
+        MediaLibrary::Web => sqlx::query!(
+            r#"
+select has_webp, kind as "kind: MediaKind"
+from web_media_upload
+inner join web_media_library on id = media_id
+where media_id = $1
+"#,
+            id
+        )
+        .fetch_optional(db)
+        .await?
+        .map(|it| ImageFormats {
+            has_webp: it.has_webp,
+            is_vector: matches!(it.kind, MediaKind::SvgStickerImage),
+        }),
+    };
+
+    Ok(formats)
+}
+
+/// Locks an image's upload, returning its kind and whether it has WebP derivatives.
+///
+/// Returns `None` if the image doesn't exist (or, for the web library, isn't an image).
+pub async fn webp_status_for_update(
+    txn: &mut PgConnection,
+    library: MediaLibrary,
+    id: Uuid,
+) -> sqlx::Result<Option<(ImageKind, bool)>> {
+    let status = match library {
+        MediaLibrary::Global => sqlx::query!(
+            r#"
+select kind as "kind: ImageKind", has_webp
+from image_metadata
+inner join image_upload on id = image_id
+where id = $1
+for no key update of image_upload
+"#,
+            id
+        )
+        .fetch_optional(&mut *txn)
+        .await?
+        .map(|it| (it.kind, it.has_webp)),
+
+        MediaLibrary::User => sqlx::query!(
+            r#"
+select kind as "kind: ImageKind", has_webp
+from user_image_library
+inner join user_image_upload on id = image_id
+where id = $1
+for no key update of user_image_upload
+"#,
+            id
+        )
+        .fetch_optional(&mut *txn)
+        .await?
+        .map(|it| (it.kind, it.has_webp)),
+
+        MediaLibrary::Web => sqlx::query!(
+            r#"
+select has_webp
+from web_media_library
+inner join web_media_upload on id = media_id
+where id = $1 and kind = $2
+for no key update of web_media_upload
+"#,
+            id,
+            MediaKind::PngStickerImage as i16,
+        )
+        .fetch_optional(&mut *txn)
+        .await?
+        .map(|it| (ImageKind::Sticker, it.has_webp)),
+    };
+
+    Ok(status)
+}
+
+pub async fn set_has_webp(
+    txn: &mut PgConnection,
+    library: MediaLibrary,
+    id: Uuid,
+) -> sqlx::Result<()> {
+    match library {
+        MediaLibrary::Global => {
+            sqlx::query!(
+                "update image_upload set has_webp = true where image_id = $1",
+                id
+            )
+            .execute(&mut *txn)
+            .await?
+        }
+
+        MediaLibrary::User => {
+            sqlx::query!(
+                "update user_image_upload set has_webp = true where image_id = $1",
+                id
+            )
+            .execute(&mut *txn)
+            .await?
+        }
+
+        MediaLibrary::Web => {
+            sqlx::query!(
+                "update web_media_upload set has_webp = true where media_id = $1",
+                id
+            )
+            .execute(&mut *txn)
+            .await?
+        }
+    };
+
+    Ok(())
+}
+
 async fn download_media_file(url_string: &str) -> anyhow::Result<Vec<u8>> {
     const MAX_RESPONSE_SIZE: usize = max(ANIMATION_BODY_SIZE_LIMIT, IMAGE_BODY_SIZE_LIMIT);
 
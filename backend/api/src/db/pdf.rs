@@ -1,12 +1,11 @@
 pub mod user {
-    use futures::stream::BoxStream;
-    use shared::domain::pdf::{user::UserPdf, PdfId};
-    use sqlx::PgPool;
+    use futures::stream::{BoxStream, StreamExt, TryStreamExt};
+    use shared::domain::pdf::{user::UserPdf, PdfId, PdfPreview};
+    use sqlx::{PgConnection, PgPool};
     use uuid::Uuid;
 
-    pub async fn create(db: &PgPool, user_id: Uuid) -> sqlx::Result<PdfId> {
-        let mut txn = db.begin().await?;
-
+    /// Creates an empty pdf, along with its upload state.
+    pub async fn create(conn: &mut PgConnection, user_id: Uuid) -> sqlx::Result<PdfId> {
         let id: PdfId = sqlx::query!(
             r#"
 insert into user_pdf_library(user_id)
@@ -15,16 +14,14 @@ returning id as "id: PdfId"
         "#,
             user_id
         )
-        .fetch_one(db)
+        .fetch_one(&mut *conn)
         .await?
         .id;
 
         sqlx::query!("insert into user_pdf_upload (pdf_id) values($1)", id.0)
-            .execute(&mut *txn)
+            .execute(&mut *conn)
             .await?;
 
-        txn.commit().await?;
-
         Ok(id)
     }
 
@@ -36,20 +33,49 @@ returning id as "id: PdfId"
     }
 
     pub async fn get(db: &PgPool, pdf: PdfId) -> sqlx::Result<Option<UserPdf>> {
-        sqlx::query_as!(
-            UserPdf,
-            r#"select id as "id: PdfId" from user_pdf_library where id = $1"#,
+        sqlx::query!(
+            r#"
+select id as "id: PdfId",
+       page_count,
+       title
+from user_pdf_library
+where id = $1
+"#,
             pdf.0
         )
         .fetch_optional(db)
         .await
+        .map(|it| {
+            it.map(|row| UserPdf {
+                id: row.id,
+                preview: preview(row.page_count, row.title),
+            })
+        })
     }
 
     pub fn list(db: &PgPool) -> BoxStream<'_, sqlx::Result<UserPdf>> {
-        sqlx::query_as!(
-            UserPdf,
-            r#"select id as "id: PdfId" from user_pdf_library order by created_at desc"#,
+        sqlx::query!(
+            r#"
+select id as "id: PdfId",
+       page_count,
+       title
+from user_pdf_library
+order by created_at desc
+"#,
         )
         .fetch(db)
+        .map_ok(|row| UserPdf {
+            id: row.id,
+            preview: preview(row.page_count, row.title),
+        })
+        .boxed()
+    }
+
+    /// `page_count` is only set once the pdf has been processed.
+    pub(crate) fn preview(page_count: Option<i32>, title: Option<String>) -> Option<PdfPreview> {
+        page_count.map(|page_count| PdfPreview {
+            page_count: page_count as u32,
+            title,
+        })
     }
 }
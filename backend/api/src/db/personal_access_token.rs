@@ -0,0 +1,117 @@
+use chrono::{DateTime, Utc};
+use shared::domain::user::{PersonalAccessToken, UserScope};
+use sqlx::PgPool;
+use std::convert::TryFrom;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::token::SessionMask;
+
+pub struct ScopeAccess {
+    /// Whether the user has all of the scopes.
+    pub has_scopes: bool,
+    pub second_factor_enrolled: bool,
+    /// Whether the session has passed the second factor.
+    pub second_factor_passed: bool,
+}
+
+/// What's needed to decide whether the session can create a token with `scopes`.
+#[instrument(skip(db, session))]
+pub async fn scope_access(
+    db: &PgPool,
+    user_id: Uuid,
+    session: &str,
+    scopes: &[i16],
+) -> sqlx::Result<ScopeAccess> {
+    sqlx::query_as!(
+        ScopeAccess,
+        r#"
+select $3::int2[] <@ array(select scope from user_scope where user_id = $1) as "has_scopes!",
+       exists(select 1 from user_totp where user_id = $1 and confirmed_at is not null) as "second_factor_enrolled!",
+       exists(select 1 from session where token = $2 and (scope_mask & $4) = $4) as "second_factor_passed!"
+"#,
+        user_id,
+        session,
+        scopes,
+        SessionMask::SECOND_FACTOR.bits()
+    )
+    .fetch_one(db)
+    .await
+}
+
+#[instrument(skip(db, token_hash))]
+pub async fn create(
+    db: &PgPool,
+    user_id: Uuid,
+    name: &str,
+    token_hash: &str,
+    scopes: &[i16],
+    expires_at: DateTime<Utc>,
+) -> sqlx::Result<Uuid> {
+    sqlx::query!(
+        r#"
+insert into personal_access_token (user_id, name, token_hash, scopes, expires_at)
+values ($1, $2, $3, $4, $5)
+returning id
+"#,
+        user_id,
+        name,
+        token_hash,
+        scopes,
+        expires_at
+    )
+    .fetch_one(db)
+    .await
+    .map(|it| it.id)
+}
+
+/// Lists the user's tokens that haven't been revoked, newest first.
+#[instrument(skip(db))]
+pub async fn list(db: &PgPool, user_id: Uuid) -> anyhow::Result<Vec<PersonalAccessToken>> {
+    let rows = sqlx::query!(
+        r#"
+select id, name, scopes, created_at, expires_at, last_used_at
+from personal_access_token
+where user_id = $1 and revoked_at is null
+order by created_at desc
+"#,
+        user_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    rows.into_iter()
+        .map(|row| {
+            Ok(PersonalAccessToken {
+                id: row.id,
+                name: row.name,
+                scopes: row
+                    .scopes
+                    .into_iter()
+                    .map(UserScope::try_from)
+                    .collect::<Result<Vec<_>, _>>()?,
+                created_at: row.created_at,
+                expires_at: row.expires_at,
+                last_used_at: row.last_used_at,
+            })
+        })
+        .collect()
+}
+
+/// Returns `false` if the token doesn't exist, belongs to someone else, or was already revoked.
+#[instrument(skip(db))]
+pub async fn revoke(db: &PgPool, user_id: Uuid, id: Uuid) -> sqlx::Result<bool> {
+    let res = sqlx::query!(
+        r#"
+update personal_access_token
+set revoked_at = now()
+where id = $1 and user_id = $2 and revoked_at is null
+"#,
+        id,
+        user_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(res.rows_affected() > 0)
+}
@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use tracing::instrument;
+
+/// Records a hit against `key`, starting a new window of `window_secs` if the previous one expired.
+///
+/// Returns the number of hits in the current window and when the window expires.
+#[instrument(skip(db))]
+pub async fn hit(db: &PgPool, key: &str, window_secs: i64) -> sqlx::Result<(i32, DateTime<Utc>)> {
+    let row = sqlx::query!(
+        //language=SQL
+        r#"
+insert into rate_limit (key, hits, expires_at)
+values ($1, 1, now() + $2::int8 * interval '1 second')
+on conflict (key) do update
+set hits       = case when rate_limit.expires_at <= now() then 1 else rate_limit.hits + 1 end,
+    expires_at = case when rate_limit.expires_at <= now() then excluded.expires_at else rate_limit.expires_at end
+returning hits, expires_at
+"#,
+        key,
+        window_secs
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok((row.hits, row.expires_at))
+}
+
+/// Gets the number of hits in the current window for `key` (if there is one) and when it expires.
+#[instrument(skip(db))]
+pub async fn get(db: &PgPool, key: &str) -> sqlx::Result<Option<(i32, DateTime<Utc>)>> {
+    let row = sqlx::query!(
+        r#"select hits, expires_at from rate_limit where key = $1 and expires_at > now()"#,
+        key
+    )
+    .fetch_optional(db)
+    .await?;
+
+    Ok(row.map(|it| (it.hits, it.expires_at)))
+}
+
+#[instrument(skip(db))]
+pub async fn clear(db: &PgPool, key: &str) -> sqlx::Result<()> {
+    sqlx::query!("delete from rate_limit where key = $1", key)
+        .execute(db)
+        .await?;
+
+    Ok(())
+}
+
+/// Deletes all expired windows.
+#[instrument(skip(db))]
+pub async fn prune(db: &PgPool) -> sqlx::Result<()> {
+    sqlx::query!("delete from rate_limit where expires_at <= now()")
+        .execute(db)
+        .await?;
+
+    Ok(())
+}
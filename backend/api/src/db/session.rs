@@ -79,3 +79,17 @@ pub async fn delete(txn: &mut PgConnection, token: &str) -> sqlx::Result<()> {
 
     Ok(())
 }
+
+/// Adds `mask` to the scope mask of the session with `token`.
+#[instrument(skip_all)]
+pub async fn add_mask(conn: &mut PgConnection, token: &str, mask: SessionMask) -> sqlx::Result<()> {
+    sqlx::query!(
+        "update session set scope_mask = scope_mask | $2 where token = $1",
+        token,
+        mask.bits()
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
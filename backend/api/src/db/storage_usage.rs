@@ -0,0 +1,234 @@
+//! How much storage users' media libraries take up.
+//!
+//! Only uploads which haven't failed processing are counted, the size of each is kept in the `file_size` column of
+//! the library's upload table.
+
+use shared::domain::{admin::StorageConsumer, user::UserMediaGroup};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+/// The bytes and number of files in one of a user's media libraries.
+pub struct GroupUsage {
+    pub group: UserMediaGroup,
+    pub used_bytes: u64,
+    pub file_count: u64,
+}
+
+/// Gets the usage of each of the user's media libraries.
+pub async fn get(db: &PgPool, user_id: Uuid) -> sqlx::Result<Vec<GroupUsage>> {
+    let row = sqlx::query!(
+        //language=SQL
+        r#"
+select image.used_bytes as "image_bytes!",
+       image.file_count as "image_count!",
+       audio.used_bytes as "audio_bytes!",
+       audio.file_count as "audio_count!",
+       pdf.used_bytes   as "pdf_bytes!",
+       pdf.file_count   as "pdf_count!"
+from (select coalesce(sum(file_size), 0)::int8 as used_bytes, count(*) as file_count
+      from user_image_library
+               inner join user_image_upload on id = image_id
+      where user_id = $1
+        and uploaded_at is not null
+        and processing_result is distinct from false) as image,
+     (select coalesce(sum(file_size), 0)::int8 as used_bytes, count(*) as file_count
+      from user_audio_library
+               inner join user_audio_upload on id = audio_id
+      where user_id = $1
+        and uploaded_at is not null
+        and processing_result is distinct from false) as audio,
+     (select coalesce(sum(file_size), 0)::int8 as used_bytes, count(*) as file_count
+      from user_pdf_library
+               inner join user_pdf_upload on id = pdf_id
+      where user_id = $1
+        and uploaded_at is not null
+        and processing_result is distinct from false) as pdf
+"#,
+        user_id
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(vec![
+        GroupUsage {
+            group: UserMediaGroup::Image,
+            used_bytes: row.image_bytes as u64,
+            file_count: row.image_count as u64,
+        },
+        GroupUsage {
+            group: UserMediaGroup::Audio,
+            used_bytes: row.audio_bytes as u64,
+            file_count: row.audio_count as u64,
+        },
+        GroupUsage {
+            group: UserMediaGroup::Pdf,
+            used_bytes: row.pdf_bytes as u64,
+            file_count: row.pdf_count as u64,
+        },
+    ])
+}
+
+/// Locks the user's storage usage until the end of the transaction, so that concurrent uploads are checked against
+/// the quota one at a time, instead of each fitting on its own.
+pub async fn lock(txn: &mut PgConnection, user_id: Uuid) -> sqlx::Result<()> {
+    sqlx::query!(
+        "select from pg_advisory_xact_lock(hashtextextended('storage_usage:' || $1::text, 0))",
+        user_id
+    )
+    .execute(txn)
+    .await?;
+
+    Ok(())
+}
+
+/// Gets the bytes used by one of the user's media libraries, not counting the file `exclude_id` (which is
+/// about to be replaced).
+pub async fn used_bytes(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    group: UserMediaGroup,
+    exclude_id: Uuid,
+) -> sqlx::Result<u64> {
+    let used_bytes = match group {
+        UserMediaGroup::Image => {
+            sqlx::query!(
+                //language=SQL
+                r#"
+select coalesce(sum(file_size), 0)::int8 as "used_bytes!"
+from user_image_library
+         inner join user_image_upload on id = image_id
+where user_id = $1
+  and id <> $2
+  and uploaded_at is not null
+  and processing_result is distinct from false
+"#,
+                user_id,
+                exclude_id
+            )
+            .fetch_one(conn)
+            .await?
+            .used_bytes
+        }
+
+        UserMediaGroup::Audio => {
+            sqlx::query!(
+                //language=SQL
+                r#"
+select coalesce(sum(file_size), 0)::int8 as "used_bytes!"
+from user_audio_library
+         inner join user_audio_upload on id = audio_id
+where user_id = $1
+  and id <> $2
+  and uploaded_at is not null
+  and processing_result is distinct from false
+"#,
+                user_id,
+                exclude_id
+            )
+            .fetch_one(conn)
+            .await?
+            .used_bytes
+        }
+
+        UserMediaGroup::Pdf => {
+            sqlx::query!(
+                //language=SQL
+                r#"
+select coalesce(sum(file_size), 0)::int8 as "used_bytes!"
+from user_pdf_library
+         inner join user_pdf_upload on id = pdf_id
+where user_id = $1
+  and id <> $2
+  and uploaded_at is not null
+  and processing_result is distinct from false
+"#,
+                user_id,
+                exclude_id
+            )
+            .fetch_one(conn)
+            .await?
+            .used_bytes
+        }
+    };
+
+    Ok(used_bytes as u64)
+}
+
+/// Gets the `limit` users using the most storage, either in total or in `group`.
+pub async fn top_consumers(
+    db: &PgPool,
+    group: Option<UserMediaGroup>,
+    limit: i64,
+) -> sqlx::Result<Vec<StorageConsumer>> {
+    let group = group.map(|it| match it {
+        UserMediaGroup::Image => 0_i16,
+        UserMediaGroup::Audio => 1,
+        UserMediaGroup::Pdf => 2,
+    });
+
+    let consumers = sqlx::query!(
+        //language=SQL
+        r#"
+with usage as (
+    select user_id, coalesce(file_size, 0) as image_bytes, 0::int8 as audio_bytes, 0::int8 as pdf_bytes
+    from user_image_library
+             inner join user_image_upload on id = image_id
+    where uploaded_at is not null
+      and processing_result is distinct from false
+    union all
+    select user_id, 0, coalesce(file_size, 0), 0
+    from user_audio_library
+             inner join user_audio_upload on id = audio_id
+    where uploaded_at is not null
+      and processing_result is distinct from false
+    union all
+    select user_id, 0, 0, coalesce(file_size, 0)
+    from user_pdf_library
+             inner join user_pdf_upload on id = pdf_id
+    where uploaded_at is not null
+      and processing_result is distinct from false
+),
+     totals as (
+         select user_id,
+                sum(image_bytes)::int8 as image_bytes,
+                sum(audio_bytes)::int8 as audio_bytes,
+                sum(pdf_bytes)::int8   as pdf_bytes
+         from usage
+         where user_id is not null
+         group by user_id
+     )
+select totals.user_id                                       as "user_id!",
+       username                                             as "username?",
+       image_bytes                                          as "image_bytes!",
+       audio_bytes                                          as "audio_bytes!",
+       pdf_bytes                                            as "pdf_bytes!",
+       (image_bytes + audio_bytes + pdf_bytes)::int8        as "used_bytes!"
+from totals
+         left join user_profile on user_profile.user_id = totals.user_id
+order by case $1::int2
+             when 0 then image_bytes
+             when 1 then audio_bytes
+             when 2 then pdf_bytes
+             else image_bytes + audio_bytes + pdf_bytes
+             end desc,
+         totals.user_id
+limit $2
+"#,
+        group,
+        limit
+    )
+    .fetch_all(db)
+    .await?
+    .into_iter()
+    .map(|row| StorageConsumer {
+        user_id: row.user_id,
+        username: row.username,
+        used_bytes: row.used_bytes as u64,
+        image_bytes: row.image_bytes as u64,
+        audio_bytes: row.audio_bytes as u64,
+        pdf_bytes: row.pdf_bytes as u64,
+    })
+    .collect();
+
+    Ok(consumers)
+}
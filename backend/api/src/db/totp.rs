@@ -0,0 +1,153 @@
+use sqlx::{PgConnection, PgPool};
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct UserTotp {
+    pub secret: Vec<u8>,
+    pub confirmed: bool,
+    pub last_used_step: Option<i64>,
+}
+
+#[instrument(skip(db))]
+pub async fn get(db: &PgPool, user_id: Uuid) -> sqlx::Result<Option<UserTotp>> {
+    sqlx::query_as!(
+        UserTotp,
+        r#"
+select secret,
+       confirmed_at is not null as "confirmed!",
+       last_used_step
+from user_totp
+where user_id = $1
+"#,
+        user_id
+    )
+    .fetch_optional(db)
+    .await
+}
+
+/// Starts (or restarts) enrollment with a new `secret`.
+///
+/// Returns `false` if the user already has a confirmed secret, which is left untouched.
+#[instrument(skip(conn, secret))]
+pub async fn enroll(conn: &mut PgConnection, user_id: Uuid, secret: &[u8]) -> sqlx::Result<bool> {
+    let res = sqlx::query!(
+        //language=SQL
+        r#"
+insert into user_totp (user_id, secret)
+values ($1, $2)
+on conflict (user_id) do update
+set secret         = excluded.secret,
+    last_used_step = null,
+    created_at     = now()
+where user_totp.confirmed_at is null
+"#,
+        user_id,
+        secret
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(res.rows_affected() > 0)
+}
+
+/// Records that a code for `step` was used, failing if a code for the same or a later step already was.
+#[instrument(skip(conn))]
+pub async fn use_step(conn: &mut PgConnection, user_id: Uuid, step: i64) -> sqlx::Result<bool> {
+    let res = sqlx::query!(
+        r#"
+update user_totp
+set last_used_step = $2
+where user_id = $1 and (last_used_step is null or last_used_step < $2)
+"#,
+        user_id,
+        step
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(res.rows_affected() > 0)
+}
+
+#[instrument(skip(conn))]
+pub async fn confirm(conn: &mut PgConnection, user_id: Uuid) -> sqlx::Result<()> {
+    sqlx::query!(
+        "update user_totp set confirmed_at = now() where user_id = $1",
+        user_id
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Removes the user's secret and all of their recovery codes.
+#[instrument(skip(conn))]
+pub async fn delete(conn: &mut PgConnection, user_id: Uuid) -> sqlx::Result<()> {
+    sqlx::query!("delete from user_recovery_code where user_id = $1", user_id)
+        .execute(&mut *conn)
+        .await?;
+
+    sqlx::query!("delete from user_totp where user_id = $1", user_id)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(())
+}
+
+/// Replaces all of the user's recovery codes with `code_hashes`.
+#[instrument(skip(conn, code_hashes))]
+pub async fn replace_recovery_codes(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    code_hashes: &[String],
+) -> sqlx::Result<()> {
+    sqlx::query!("delete from user_recovery_code where user_id = $1", user_id)
+        .execute(&mut *conn)
+        .await?;
+
+    sqlx::query!(
+        r#"
+insert into user_recovery_code (user_id, code_hash)
+select $1, code_hash from unnest($2::text[]) as code_hash
+"#,
+        user_id,
+        code_hashes
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Marks the recovery code with `code_hash` as used, returning `false` if there's no such unused code.
+#[instrument(skip(conn, code_hash))]
+pub async fn use_recovery_code(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    code_hash: &str,
+) -> sqlx::Result<bool> {
+    let res = sqlx::query!(
+        r#"
+update user_recovery_code
+set used_at = now()
+where user_id = $1 and code_hash = $2 and used_at is null
+"#,
+        user_id,
+        code_hash
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(res.rows_affected() > 0)
+}
+
+#[instrument(skip(db))]
+pub async fn recovery_codes_remaining(db: &PgPool, user_id: Uuid) -> sqlx::Result<i64> {
+    sqlx::query!(
+        r#"select count(*) as "count!" from user_recovery_code where user_id = $1 and used_at is null"#,
+        user_id
+    )
+    .fetch_one(db)
+    .await
+    .map(|it| it.count)
+}
@@ -685,6 +685,22 @@ where user_id = $1
     Ok(given_name)
 }
 
+pub async fn get_email(db: &sqlx::PgPool, user_id: Uuid) -> sqlx::Result<Option<String>> {
+    let email = sqlx::query!(
+        r#"
+select email::text as "email!"
+from user_email
+where user_id = $1
+        "#,
+        user_id
+    )
+    .fetch_optional(db)
+    .await?
+    .map(|it| it.email);
+
+    Ok(email)
+}
+
 pub async fn get_fonts(db: &sqlx::PgPool, user_id: Uuid) -> sqlx::Result<Vec<String>> {
     let font_names = sqlx::query!(
         r#"
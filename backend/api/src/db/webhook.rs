@@ -0,0 +1,252 @@
+use chrono::{DateTime, Utc};
+use shared::domain::{
+    jig::JigId,
+    webhook::{Webhook, WebhookEvent, WebhookId, WebhookPayload},
+};
+use sqlx::{PgConnection, PgPool};
+use tracing::instrument;
+use uuid::Uuid;
+
+#[instrument(skip(db, secret))]
+pub async fn create(
+    db: &PgPool,
+    user_id: Uuid,
+    organization: Option<&str>,
+    url: &str,
+    secret: &str,
+    events: &[WebhookEvent],
+) -> sqlx::Result<WebhookId> {
+    let events: Vec<i16> = events.iter().map(|it| *it as i16).collect();
+
+    sqlx::query!(
+        r#"
+insert into webhook_subscription (user_id, organization, url, secret, events)
+values ($1, $2, $3, $4, $5)
+returning id as "id: WebhookId"
+"#,
+        user_id,
+        organization,
+        url,
+        secret,
+        &events
+    )
+    .fetch_one(db)
+    .await
+    .map(|it| it.id)
+}
+
+#[instrument(skip(db))]
+pub async fn list(db: &PgPool, user_id: Uuid) -> sqlx::Result<Vec<Webhook>> {
+    sqlx::query_as!(
+        Webhook,
+        r#"
+select id           as "id: WebhookId",
+       url,
+       events       as "events: Vec<WebhookEvent>",
+       organization,
+       created_at,
+       updated_at
+from webhook_subscription
+where user_id = $1
+order by created_at
+"#,
+        user_id
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// Returns `false` if the subscription doesn't exist (or belongs to someone else).
+#[instrument(skip(db, secret))]
+pub async fn update(
+    db: &PgPool,
+    user_id: Uuid,
+    id: WebhookId,
+    url: Option<&str>,
+    secret: Option<&str>,
+    events: Option<&[WebhookEvent]>,
+) -> sqlx::Result<bool> {
+    let events: Option<Vec<i16>> = events.map(|it| it.iter().map(|it| *it as i16).collect());
+
+    let res = sqlx::query!(
+        r#"
+update webhook_subscription
+set url        = coalesce($3, url),
+    secret     = coalesce($4, secret),
+    events     = coalesce($5, events),
+    updated_at = now()
+where id = $1 and user_id = $2
+"#,
+        id.0,
+        user_id,
+        url,
+        secret,
+        events.as_deref()
+    )
+    .execute(db)
+    .await?;
+
+    Ok(res.rows_affected() > 0)
+}
+
+/// Returns `false` if the subscription doesn't exist (or belongs to someone else).
+#[instrument(skip(db))]
+pub async fn delete(db: &PgPool, user_id: Uuid, id: WebhookId) -> sqlx::Result<bool> {
+    let res = sqlx::query!(
+        "delete from webhook_subscription where id = $1 and user_id = $2",
+        id.0,
+        user_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(res.rows_affected() > 0)
+}
+
+/// Queues a delivery of `event` to every subscription interested in the jig.
+///
+/// Has to be called before the jig is deleted, since subscriptions are matched on its creator.
+#[instrument(skip(conn))]
+pub async fn enqueue(
+    conn: &mut PgConnection,
+    event: WebhookEvent,
+    jig_id: JigId,
+) -> anyhow::Result<()> {
+    enqueue_payload(
+        conn,
+        &WebhookPayload {
+            event,
+            jig_id,
+            occurred_at: Utc::now(),
+            report_id: None,
+            report_type: None,
+        },
+    )
+    .await
+}
+
+#[instrument(skip(conn))]
+pub async fn enqueue_payload(
+    conn: &mut PgConnection,
+    payload: &WebhookPayload,
+) -> anyhow::Result<()> {
+    sqlx::query!(
+        r#"
+insert into webhook_delivery (subscription_id, event, payload)
+select webhook_subscription.id, $2, $3
+from webhook_subscription
+    inner join jig on jig.id = $1
+    left join user_profile on user_profile.user_id = jig.creator_id
+where
+    $2 = any(webhook_subscription.events) and
+    (
+        (webhook_subscription.organization is null and webhook_subscription.user_id = jig.creator_id) or
+        webhook_subscription.organization = user_profile.organization
+    )
+"#,
+        payload.jig_id.0,
+        payload.event as i16,
+        serde_json::to_value(payload)?
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+pub struct PendingDelivery {
+    pub id: Uuid,
+    pub event: WebhookEvent,
+    pub payload: serde_json::Value,
+    pub attempts: i16,
+    pub url: String,
+    pub secret: String,
+}
+
+/// Claims up to `limit` deliveries that are due, by pushing their next attempt back by `lease`,
+/// so that concurrent dispatchers don't send them twice.
+#[instrument(skip(db))]
+pub async fn claim_due(
+    db: &PgPool,
+    limit: i64,
+    lease: DateTime<Utc>,
+) -> sqlx::Result<Vec<PendingDelivery>> {
+    sqlx::query_as!(
+        PendingDelivery,
+        r#"
+update webhook_delivery
+set next_attempt_at = $2
+from webhook_subscription
+where
+    webhook_delivery.id in (
+        select id
+        from webhook_delivery
+        where delivered_at is null and failed_at is null and next_attempt_at <= now()
+        order by next_attempt_at
+        limit $1
+        for update skip locked
+    ) and
+    webhook_subscription.id = webhook_delivery.subscription_id
+returning webhook_delivery.id,
+          webhook_delivery.event   as "event: WebhookEvent",
+          webhook_delivery.payload,
+          webhook_delivery.attempts,
+          webhook_subscription.url,
+          webhook_subscription.secret
+"#,
+        limit,
+        lease
+    )
+    .fetch_all(db)
+    .await
+}
+
+#[instrument(skip(db))]
+pub async fn mark_delivered(db: &PgPool, id: Uuid, status: i16) -> sqlx::Result<()> {
+    sqlx::query!(
+        r#"
+update webhook_delivery
+set delivered_at = now(),
+    attempts     = attempts + 1,
+    last_status  = $2,
+    last_error   = null
+where id = $1
+"#,
+        id,
+        status
+    )
+    .execute(db)
+    .await?;
+
+    Ok(())
+}
+
+/// Records a failed attempt. If `retry_at` is `None` the delivery is given up on.
+#[instrument(skip(db))]
+pub async fn mark_attempt_failed(
+    db: &PgPool,
+    id: Uuid,
+    status: Option<i16>,
+    error: &str,
+    retry_at: Option<DateTime<Utc>>,
+) -> sqlx::Result<()> {
+    sqlx::query!(
+        r#"
+update webhook_delivery
+set attempts        = attempts + 1,
+    last_status     = $2,
+    last_error      = $3,
+    next_attempt_at = coalesce($4, next_attempt_at),
+    failed_at       = case when $4::timestamptz is null then now() end
+where id = $1
+"#,
+        id,
+        status,
+        error,
+        retry_at
+    )
+    .execute(db)
+    .await?;
+
+    Ok(())
+}
@@ -115,6 +115,300 @@ impl Into<actix_web::Error> for Server {
     }
 }
 
+#[derive(Debug)]
+pub enum RateLimit {
+    InternalServerError(anyhow::Error),
+    /// The client (or account) has to wait this long before trying again.
+    TooManyRequests(chrono::Duration),
+}
+
+impl<T: Into<anyhow::Error>> From<T> for RateLimit {
+    fn from(e: T) -> Self {
+        Self::InternalServerError(e.into())
+    }
+}
+
+impl Into<actix_web::Error> for RateLimit {
+    fn into(self) -> actix_web::Error {
+        match self {
+            Self::InternalServerError(e) => ise(e),
+            Self::TooManyRequests(retry_after) => {
+                // `Retry-After` is in whole seconds, and `0` would invite an immediate retry.
+                let retry_after = retry_after.num_seconds().max(1);
+
+                let resp = HttpResponse::TooManyRequests()
+                    .insert_header((
+                        actix_web::http::header::RETRY_AFTER,
+                        retry_after.to_string(),
+                    ))
+                    .json(BasicError::with_message(
+                        http::StatusCode::TOO_MANY_REQUESTS,
+                        "Too many requests, try again later".to_owned(),
+                    ));
+
+                actix_web::error::InternalError::from_response("", resp).into()
+            }
+        }
+    }
+}
+
+pub enum SecondFactor {
+    /// The code doesn't match (or was already used) while enrolling.
+    InvalidCode,
+    /// The code doesn't match (or was already used), or there's no second factor to check it against.
+    Unauthorized,
+    /// There's no second factor (or enrollment) to act on.
+    NotFound,
+    /// The user already has a confirmed second factor.
+    Conflict,
+    /// Too many failed attempts, see [`RateLimit::TooManyRequests`].
+    TooManyRequests(chrono::Duration),
+    InternalServerError(anyhow::Error),
+}
+
+impl<T: Into<anyhow::Error>> From<T> for SecondFactor {
+    fn from(e: T) -> Self {
+        Self::InternalServerError(e.into())
+    }
+}
+
+impl From<RateLimit> for SecondFactor {
+    fn from(e: RateLimit) -> Self {
+        match e {
+            RateLimit::InternalServerError(e) => Self::InternalServerError(e),
+            RateLimit::TooManyRequests(retry_after) => Self::TooManyRequests(retry_after),
+        }
+    }
+}
+
+impl Into<actix_web::Error> for SecondFactor {
+    fn into(self) -> actix_web::Error {
+        match self {
+            Self::InvalidCode => {
+                BasicError::with_message(http::StatusCode::BAD_REQUEST, "Invalid code".to_owned())
+                    .into()
+            }
+
+            Self::Unauthorized => BasicError::new(http::StatusCode::UNAUTHORIZED).into(),
+
+            Self::NotFound => BasicError::with_message(
+                http::StatusCode::NOT_FOUND,
+                "No second factor found for user".to_owned(),
+            )
+            .into(),
+
+            Self::Conflict => BasicError::with_message(
+                http::StatusCode::CONFLICT,
+                "Second factor already enabled".to_owned(),
+            )
+            .into(),
+
+            Self::TooManyRequests(retry_after) => RateLimit::TooManyRequests(retry_after).into(),
+
+            Self::InternalServerError(e) => ise(e),
+        }
+    }
+}
+
+pub enum PersonalAccessToken {
+    /// The request is invalid, the message says why.
+    BadRequest(&'static str),
+    /// The user doesn't have one of the requested scopes.
+    Forbidden,
+    /// One of the requested scopes needs a second factor, which the session hasn't passed.
+    SecondFactorRequired,
+    ResourceNotFound,
+    InternalServerError(anyhow::Error),
+}
+
+impl<T: Into<anyhow::Error>> From<T> for PersonalAccessToken {
+    fn from(e: T) -> Self {
+        Self::InternalServerError(e.into())
+    }
+}
+
+impl Into<actix_web::Error> for PersonalAccessToken {
+    fn into(self) -> actix_web::Error {
+        match self {
+            Self::BadRequest(message) => {
+                BasicError::with_message(http::StatusCode::BAD_REQUEST, message.to_owned()).into()
+            }
+
+            Self::Forbidden => BasicError::with_message(
+                http::StatusCode::FORBIDDEN,
+                "Forbidden: user doesn't have all of the requested scopes".to_owned(),
+            )
+            .into(),
+
+            Self::SecondFactorRequired => BasicError::with_message(
+                http::StatusCode::FORBIDDEN,
+                "Forbidden: this session needs to pass a second factor".to_owned(),
+            )
+            .into(),
+
+            Self::ResourceNotFound => BasicError::new(http::StatusCode::NOT_FOUND).into(),
+
+            Self::InternalServerError(e) => ise(e),
+        }
+    }
+}
+
+pub enum ImageMerge {
+    /// The request is invalid, the message says why.
+    BadRequest(&'static str),
+    ResourceNotFound,
+    InternalServerError(anyhow::Error),
+}
+
+impl<T: Into<anyhow::Error>> From<T> for ImageMerge {
+    fn from(e: T) -> Self {
+        Self::InternalServerError(e.into())
+    }
+}
+
+impl Into<actix_web::Error> for ImageMerge {
+    fn into(self) -> actix_web::Error {
+        match self {
+            Self::BadRequest(message) => {
+                BasicError::with_message(http::StatusCode::BAD_REQUEST, message.to_owned()).into()
+            }
+
+            Self::ResourceNotFound => BasicError::new(http::StatusCode::NOT_FOUND).into(),
+
+            Self::InternalServerError(e) => ise(e),
+        }
+    }
+}
+
+pub enum Webhook {
+    /// The request is invalid, the message says why.
+    BadRequest(&'static str),
+    Forbidden,
+    ResourceNotFound,
+    InternalServerError(anyhow::Error),
+}
+
+impl<T: Into<anyhow::Error>> From<T> for Webhook {
+    fn from(e: T) -> Self {
+        Self::InternalServerError(e.into())
+    }
+}
+
+impl From<Auth> for Webhook {
+    fn from(e: Auth) -> Self {
+        match e {
+            Auth::InternalServerError(e) => Self::InternalServerError(e),
+            Auth::Forbidden => Self::Forbidden,
+            Auth::ResourceNotFound(_) => Self::ResourceNotFound,
+        }
+    }
+}
+
+impl Into<actix_web::Error> for Webhook {
+    fn into(self) -> actix_web::Error {
+        match self {
+            Self::BadRequest(message) => {
+                BasicError::with_message(http::StatusCode::BAD_REQUEST, message.to_owned()).into()
+            }
+
+            Self::Forbidden => BasicError::new(http::StatusCode::FORBIDDEN).into(),
+
+            Self::ResourceNotFound => BasicError::new(http::StatusCode::NOT_FOUND).into(),
+
+            Self::InternalServerError(e) => ise(e),
+        }
+    }
+}
+
+pub enum FlashcardReview {
+    /// There's no player session instance token and the user isn't logged in.
+    Unauthorized,
+    /// The request is invalid, the message says why.
+    BadRequest(&'static str),
+    ResourceNotFound,
+    InternalServerError(anyhow::Error),
+}
+
+impl<T: Into<anyhow::Error>> From<T> for FlashcardReview {
+    fn from(e: T) -> Self {
+        Self::InternalServerError(e.into())
+    }
+}
+
+impl Into<actix_web::Error> for FlashcardReview {
+    fn into(self) -> actix_web::Error {
+        match self {
+            Self::Unauthorized => BasicError::new(http::StatusCode::UNAUTHORIZED).into(),
+
+            Self::BadRequest(message) => {
+                BasicError::with_message(http::StatusCode::BAD_REQUEST, message.to_owned()).into()
+            }
+
+            Self::ResourceNotFound => BasicError::new(http::StatusCode::NOT_FOUND).into(),
+
+            Self::InternalServerError(e) => ise(e),
+        }
+    }
+}
+
+pub enum Printable {
+    Forbidden,
+    ResourceNotFound,
+    /// The module can't be printed, the message says why.
+    BadRequest(&'static str),
+    /// The pdf would take the user's pdf library over its storage quota.
+    QuotaExceeded,
+    InternalServerError(anyhow::Error),
+}
+
+impl<T: Into<anyhow::Error>> From<T> for Printable {
+    fn from(e: T) -> Self {
+        Self::InternalServerError(e.into())
+    }
+}
+
+impl From<Auth> for Printable {
+    fn from(e: Auth) -> Self {
+        match e {
+            Auth::InternalServerError(e) => Self::InternalServerError(e),
+            Auth::Forbidden => Self::Forbidden,
+            Auth::ResourceNotFound(_) => Self::ResourceNotFound,
+        }
+    }
+}
+
+impl From<Upload> for Printable {
+    fn from(e: Upload) -> Self {
+        match e {
+            Upload::QuotaExceeded => Self::QuotaExceeded,
+            Upload::InternalServerError(e) => Self::InternalServerError(e),
+            _ => Self::InternalServerError(anyhow::anyhow!("failed to store the printed pdf")),
+        }
+    }
+}
+
+impl Into<actix_web::Error> for Printable {
+    fn into(self) -> actix_web::Error {
+        match self {
+            Self::Forbidden => BasicError::new(http::StatusCode::FORBIDDEN).into(),
+
+            Self::ResourceNotFound => BasicError::new(http::StatusCode::NOT_FOUND).into(),
+
+            Self::BadRequest(message) => {
+                BasicError::with_message(http::StatusCode::BAD_REQUEST, message.to_owned()).into()
+            }
+
+            Self::QuotaExceeded => BasicError::with_message(
+                http::StatusCode::INSUFFICIENT_STORAGE,
+                "Storage Quota Exceeded".to_owned(),
+            )
+            .into(),
+
+            Self::InternalServerError(e) => ise(e),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub enum ServiceKind {
     Algolia,
@@ -127,6 +421,7 @@ pub enum ServiceKind {
     FirebaseCloudMessaging,
     UploadCleaner,
     GoogleTranslate,
+    WebhookDispatcher,
 }
 
 impl ServiceKind {
@@ -142,6 +437,7 @@ impl ServiceKind {
             Self::FirebaseCloudMessaging => "Firebase Cloud Messaging",
             Self::UploadCleaner => "Media Upload Cleaner",
             Self::GoogleTranslate => "Google Translate",
+            Self::WebhookDispatcher => "Webhook Dispatcher",
         }
     }
 }
@@ -329,7 +625,11 @@ impl Into<actix_web::Error> for CategoryUpdate {
 pub enum Upload {
     ResourceNotFound,
     InvalidMedia,
+    /// The upload is in a format that can't be processed, the message says which.
+    UnsupportedFormat(&'static str),
     FileTooLarge,
+    /// The upload would take the user's media library over its storage quota.
+    QuotaExceeded,
     StorageClient(Storage),
     InternalServerError(anyhow::Error),
 }
@@ -353,11 +653,21 @@ impl Into<actix_web::Error> for Upload {
                 "Invalid Content".to_owned(),
             )
             .into(),
+            Self::UnsupportedFormat(message) => BasicError::with_message(
+                http::StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                message.to_owned(),
+            )
+            .into(),
             Self::FileTooLarge => BasicError::with_message(
                 http::StatusCode::PAYLOAD_TOO_LARGE,
                 "File Exceeds Upload Limit".to_owned(),
             )
             .into(),
+            Self::QuotaExceeded => BasicError::with_message(
+                http::StatusCode::INSUFFICIENT_STORAGE,
+                "Storage Quota Exceeded".to_owned(),
+            )
+            .into(),
             Self::StorageClient(e) => e.into(),
             Self::InternalServerError(e) => ise(e),
         }
@@ -379,6 +689,8 @@ pub enum CreateWithMetadata {
     Forbidden,
     MissingMetadata(MetadataNotFound),
     ResourceNotFound,
+    /// The request is invalid, the message says why.
+    BadRequest(&'static str),
 }
 
 impl From<Auth> for CreateWithMetadata {
@@ -409,6 +721,9 @@ impl Into<actix_web::Error> for CreateWithMetadata {
             Self::Forbidden => BasicError::new(http::StatusCode::FORBIDDEN).into(),
             Self::InternalServerError(e) => ise(e),
             Self::ResourceNotFound => BasicError::new(http::StatusCode::BAD_REQUEST).into(),
+            Self::BadRequest(message) => {
+                BasicError::with_message(http::StatusCode::BAD_REQUEST, message.to_owned()).into()
+            }
         }
     }
 }
@@ -418,6 +733,8 @@ pub enum UpdateWithMetadata {
     InternalServerError(anyhow::Error),
     MissingMetadata(MetadataNotFound),
     Forbidden,
+    /// The request is invalid, the message says why.
+    BadRequest(&'static str),
 }
 
 impl From<Auth> for UpdateWithMetadata {
@@ -454,6 +771,10 @@ impl Into<actix_web::Error> for UpdateWithMetadata {
 
             Self::Forbidden => BasicError::new(http::StatusCode::FORBIDDEN).into(),
 
+            Self::BadRequest(message) => {
+                BasicError::with_message(http::StatusCode::BAD_REQUEST, message.to_owned()).into()
+            }
+
             Self::InternalServerError(e) => ise(e),
         }
     }
@@ -1,8 +1,12 @@
 use crate::{
     domain::RegistrationStatus,
     error::BasicError,
+    http::rate_limit::RateLimiter,
     more_futures::ReadyOrNot,
-    token::{check_login_token, SessionClaims, SessionMask},
+    token::{
+        check_login_token, check_personal_access_token, is_personal_access_token, SessionClaims,
+        SessionMask,
+    },
 };
 
 use actix_http::Payload;
@@ -29,10 +33,12 @@ use sqlx::postgres::PgPool;
 use std::{borrow::Cow, marker::PhantomData};
 use uuid::Uuid;
 
+/// Personal access tokens are only accepted through the `Authorization` header, so they don't end up in logs.
 fn token_from_query(query_string: &str) -> Option<String> {
     serde_urlencoded::from_str::<SessionTokenQuery>(query_string)
         .map(|it| it.access_token)
         .unwrap_or(None)
+        .filter(|it| !is_personal_access_token(it))
 }
 
 fn token_from_header(headers: &HeaderMap) -> Option<String> {
@@ -54,6 +60,10 @@ fn token_from_header(headers: &HeaderMap) -> Option<String> {
         .map_or_else(|_| None, parse_for_token)
 }
 
+fn personal_access_token_from_header(headers: &HeaderMap) -> Option<String> {
+    token_from_header(headers).filter(|it| is_personal_access_token(it))
+}
+
 fn csrf_header(headers: &HeaderMap) -> Option<String> {
     headers
         .get(CSRF_HEADER_NAME)?
@@ -93,6 +103,10 @@ async fn claims_for_scope(
     csrf: Option<&str>,
     user_scope: UserScope,
 ) -> Result<SessionClaims, actix_web::Error> {
+    if is_personal_access_token(token_string) {
+        return personal_access_token_claims_for_scope(db, token_string, user_scope).await;
+    }
+
     let claims = check_login_token(
         &db,
         &token_string,
@@ -102,33 +116,92 @@ async fn claims_for_scope(
     )
     .await?;
 
-    let has_scope = sqlx::query!(
-        r#"select exists(
-            select 1
-            from "user_scope"
-            where
-                user_id = $1 and
-                (scope = $2 or scope = $3)
-        ) as "exists!""#,
+    let access = sqlx::query!(
+        r#"
+select array(
+           select scope
+           from "user_scope"
+           where
+               user_id = $1 and
+               (scope = $2 or scope = $3)
+       ) as "scopes!",
+       exists(select 1 from user_totp where user_id = $1 and confirmed_at is not null) as "second_factor_enrolled!",
+       exists(select 1 from session where token = $4 and (scope_mask & $5) = $5) as "second_factor_passed!"
+"#,
         claims.user_id,
         user_scope as i16,
-        UserScope::Admin as i16
+        UserScope::Admin as i16,
+        &claims.token,
+        SessionMask::SECOND_FACTOR.bits()
     )
-    .fetch_optional(db)
+    .fetch_one(db)
     .await
     .map_err(Into::into)
-    .map_err(crate::error::ise)?
-    .map(|it| it.exists)
-    .ok_or_else(|| BasicError::new(StatusCode::FORBIDDEN))?;
+    .map_err(crate::error::ise)?;
 
-    if !has_scope {
+    if access.scopes.is_empty() {
         // todo: message for which scope is needed
         return Err(BasicError::new(StatusCode::FORBIDDEN).into());
     }
 
+    let second_factor_required = access.second_factor_enrolled
+        || access.scopes.iter().any(|scope| {
+            settings
+                .second_factor_required_scopes
+                .iter()
+                .any(|required| *required as i16 == *scope)
+        });
+
+    if second_factor_required && !access.second_factor_passed {
+        return Err(BasicError::with_message(
+            StatusCode::FORBIDDEN,
+            "Forbidden: this session needs to pass a second factor".to_owned(),
+        )
+        .into());
+    }
+
     Ok(claims)
 }
 
+/// The token has to carry the scope, and the user has to still have it.
+///
+/// The second factor isn't checked here, since it had to be passed to create a token with a scope that needs it.
+async fn personal_access_token_claims_for_scope(
+    db: &PgPool,
+    token_string: &str,
+    user_scope: UserScope,
+) -> Result<SessionClaims, actix_web::Error> {
+    let token = check_personal_access_token(db, token_string).await?;
+
+    let has_scope = sqlx::query!(
+        r#"
+select exists(
+    select 1
+    from "user_scope"
+    where
+        user_id = $1 and
+        scope = any($2) and
+        (scope = $3 or scope = $4)
+) as "has_scope!"
+"#,
+        token.claims.user_id,
+        &token.scopes,
+        user_scope as i16,
+        UserScope::Admin as i16,
+    )
+    .fetch_one(db)
+    .await
+    .map_err(Into::into)
+    .map_err(crate::error::ise)?
+    .has_scope;
+
+    if !has_scope {
+        return Err(BasicError::new(StatusCode::FORBIDDEN).into());
+    }
+
+    Ok(token.claims)
+}
+
 #[repr(transparent)]
 pub struct TokenUser(pub SessionClaims);
 
@@ -163,6 +236,11 @@ impl FromRequest for TokenUser {
         };
 
         async move {
+            if is_personal_access_token(&token_string) {
+                let token = check_personal_access_token(&db, &token_string).await?;
+                return Ok(Self(token.claims));
+            }
+
             let csrf = csrf;
             let claims = check_login_token(
                 &db,
@@ -294,6 +372,8 @@ impl<S: Scope> FromRequest for TokenUserWithScope<S> {
 /// Special extractor which doesn't compare the user's CSRF token.1
 ///
 /// **Note** that this extractor should only be used on GET requests which have _no_ side-effects.
+///
+/// Also accepts personal access tokens.
 #[repr(transparent)]
 pub struct TokenUserNoCsrfWithScope<S: Scope> {
     pub claims: SessionClaims,
@@ -316,9 +396,10 @@ impl<S: Scope> FromRequest for TokenUserNoCsrfWithScope<S> {
 
         let cookie = req.cookie(AUTH_COOKIE_NAME);
 
-        let token_string = match cookie {
-            Some(cookie) => cookie.value().to_owned(),
-            None => {
+        let token_string = match (personal_access_token_from_header(req.headers()), cookie) {
+            (Some(token_string), _) => token_string,
+            (None, Some(cookie)) => cookie.value().to_owned(),
+            (None, None) => {
                 return futures::future::err(
                     BasicError::with_message(
                         StatusCode::UNAUTHORIZED,
@@ -372,6 +453,19 @@ impl SessionMaskRequirement for SessionDelete {
     const REQUIREMENTS: SessionMask = SessionMask::DELETE_ACCOUNT;
 }
 
+/// Any session that can use the general API, but not a personal access token.
+pub struct SessionGeneral;
+impl SessionMaskRequirement for SessionGeneral {
+    const REQUIREMENTS: SessionMask = SessionMask::GENERAL_API;
+}
+
+pub struct SessionSecondFactor;
+impl SessionMaskRequirement for SessionSecondFactor {
+    const REQUIREMENTS: SessionMask = SessionMask::from_bits_truncate(
+        SessionMask::GENERAL_API.bits() | SessionMask::SECOND_FACTOR.bits(),
+    );
+}
+
 #[repr(transparent)]
 pub struct TokenSessionOf<S: SessionMaskRequirement> {
     pub claims: SessionClaims,
@@ -445,6 +539,9 @@ impl FromRequest for EmailBasicUser {
         let db: &Data<PgPool> = req.app_data().unwrap();
         let db = db.as_ref().clone();
 
+        let rate_limiter: &Data<RateLimiter> = req.app_data().expect("Missing rate limiter?");
+        let rate_limiter = Data::clone(rate_limiter);
+
         let basic = match req.get_header::<Authorization<Basic>>() {
             Some(basic) => basic.into_scheme(),
             None => return future::err(BasicError::new(StatusCode::UNAUTHORIZED).into()).into(),
@@ -456,6 +553,11 @@ impl FromRequest for EmailBasicUser {
         };
 
         async move {
+            rate_limiter
+                .check_lockout(&email)
+                .await
+                .map_err(Into::<actix_web::Error>::into)?;
+
             let user = sqlx::query!(
                 r#"
 select
@@ -511,9 +613,25 @@ from user_auth_basic where email = $1::text
             .await;
 
             match res {
-                Ok(Ok(res)) => Ok(res),
+                Ok(Ok(res)) => {
+                    rate_limiter
+                        .clear_failed_logins(&email)
+                        .await
+                        .map_err(Into::<actix_web::Error>::into)?;
+
+                    Ok(res)
+                }
                 Ok(Err(Either::Right(e))) => Err(crate::error::ise(e)),
-                Ok(Err(Either::Left(e))) => Err(e.into()),
+                Ok(Err(Either::Left(e))) => {
+                    if e.code == StatusCode::UNAUTHORIZED {
+                        rate_limiter
+                            .record_failed_login(&email)
+                            .await
+                            .map_err(Into::<actix_web::Error>::into)?;
+                    }
+
+                    Err(e.into())
+                }
                 Err(e) => Err(crate::error::ise(anyhow::anyhow!("{}", e))),
             }
         }
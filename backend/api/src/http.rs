@@ -21,12 +21,13 @@ use tracing_actix_web::{root_span, DefaultRootSpanBuilder, RootSpanBuilder, Trac
 
 use crate::{
     error::BasicError,
-    service::{self, mail, s3, upload::cleaner, ServiceData},
+    service::{self, mail, s3, upload::cleaner, webhook, ServiceData},
     translate,
 };
 
 mod cors;
 mod endpoints;
+pub(crate) mod rate_limit;
 
 fn log_ise<B: MessageBody, T>(
     request: ServiceRequest,
@@ -122,6 +123,7 @@ pub async fn build_and_run(
     algolia_manager: Option<crate::algolia::Manager>,
     media_upload_cleaner: Option<cleaner::UploadCleaner>,
     google_translate: Option<translate::GoogleTranslate>,
+    webhook_dispatcher: Option<webhook::Dispatcher>,
 ) -> anyhow::Result<()> {
     let app = build(
         pool,
@@ -136,6 +138,7 @@ pub async fn build_and_run(
         algolia_manager,
         media_upload_cleaner,
         google_translate,
+        webhook_dispatcher,
     )?;
     app.run_until_stopped().await?;
 
@@ -155,6 +158,7 @@ pub fn build(
     algolia_manager: Option<crate::algolia::Manager>,
     media_upload_cleaner: Option<cleaner::UploadCleaner>,
     google_translate: Option<translate::GoogleTranslate>,
+    webhook_dispatcher: Option<webhook::Dispatcher>,
 ) -> anyhow::Result<Application> {
     let local_insecure = settings.is_local();
     let api_port = settings.api_port;
@@ -168,6 +172,8 @@ pub fn build(
     let algolia_manager = algolia_manager.map(ServiceData::new);
     let media_upload_cleaner = media_upload_cleaner.map(ServiceData::new);
     let google_translate = google_translate.map(ServiceData::new);
+    let webhook_dispatcher = webhook_dispatcher.map(ServiceData::new);
+    let rate_limiter = rate_limit::RateLimiter::new(settings.rate_limit.clone(), pool.clone());
 
     let server = actix_web::HttpServer::new(move || {
         let app = actix_web::App::new()
@@ -217,10 +223,16 @@ pub fn build(
             Some(google_translate) => app.app_data(google_translate),
             None => app,
         };
+        let app = match webhook_dispatcher.clone() {
+            Some(webhook_dispatcher) => app.app_data(webhook_dispatcher),
+            None => app,
+        };
 
         let enable_tracing_logs = env_bool("ENABLE_TRACING_LOGS");
 
         app.app_data(Data::from(jwk_verifier.clone()))
+            .app_data(Data::new(rate_limiter.clone()))
+            .wrap(rate_limit::RateLimit::new(rate_limiter.clone()))
             .wrap(cors::get(local_insecure))
             .wrap(Condition::new(
                 !enable_tracing_logs,
@@ -267,6 +279,7 @@ pub fn build(
             .configure(endpoints::locale::configure)
             .configure(endpoints::scheduler::configure)
             .configure(endpoints::pdf::configure)
+            .configure(endpoints::webhook::configure)
             .route("/", method(http::Method::GET).to(no_content_response))
     });
 
@@ -11,7 +11,10 @@ use serde::ser::Serialize;
 use shared::{
     api::{endpoints::admin, ApiEndpoint},
     domain::{
-        admin::{AdminListMediaResponse, AdminMediaItem, ExportDataRequest, ExportType},
+        admin::{
+            AdminListMediaResponse, AdminMediaItem, AuditAction, AuditLogEntry, AuditLogQuery,
+            AuditLogResponse, ExportDataRequest, ExportType, StorageConsumersResponse,
+        },
         image::ImageKind,
         session::NewSessionResponse,
     },
@@ -22,8 +25,8 @@ use uuid::Uuid;
 
 use crate::{
     db, error,
-    extractor::{ScopeAdmin, TokenUserNoCsrfWithScope, TokenUserWithScope},
-    image_ops::{regenerate_images, MediaKind},
+    extractor::{IPAddress, ScopeAdmin, TokenUserNoCsrfWithScope, TokenUserWithScope, UserAgent},
+    image_ops::{generate_webp_images, regenerate_images, MediaKind},
     service::{s3, ServiceData},
     token::{create_auth_token, SessionMask},
 };
@@ -34,6 +37,8 @@ async fn impersonate(
     settings: Data<RuntimeSettings>,
     db: Data<PgPool>,
     user: Path<Uuid>,
+    ip_address: IPAddress,
+    user_agent: UserAgent,
 ) -> actix_web::Result<HttpResponse, error::UserNotFound> {
     let user_id = user.into_inner();
 
@@ -47,8 +52,10 @@ async fn impersonate(
         .login_token_valid_duration
         .unwrap_or(Duration::weeks(2));
 
+    let mut txn = db.begin().await?;
+
     let session = db::session::create(
-        &mut *db.acquire().await?,
+        &mut txn,
         user_id,
         Some(&(Utc::now() + login_ttl)),
         SessionMask::GENERAL_API,
@@ -56,6 +63,22 @@ async fn impersonate(
     )
     .await?;
 
+    db::audit_log::insert(
+        &mut txn,
+        db::audit_log::NewEntry {
+            actor_id: Some(auth.claims.user_id),
+            action: AuditAction::Impersonate,
+            target_id: Some(user_id),
+            before: None,
+            after: None,
+            ip_address: ip_address.0,
+            user_agent: user_agent.0,
+        },
+    )
+    .await?;
+
+    txn.commit().await?;
+
     let (csrf, cookie) = create_auth_token(
         &settings.token_secret,
         settings.is_local(),
@@ -106,20 +129,28 @@ async fn refresh_image_files(
         .map(|it| (it.uploaded_at, ImageKind::Sticker)),
 
         MediaLibrary::User => sqlx::query!(
-            "select uploaded_at from user_image_upload where image_id = $1 for update",
+            r#"
+            select uploaded_at, kind as "kind: ImageKind"
+            from user_image_upload
+            inner join user_image_library on id = image_id
+            where image_id = $1 for update of user_image_upload"#,
             id
         )
         .fetch_optional(&mut txn)
         .await?
-        .map(|it| (it.uploaded_at, ImageKind::Sticker)),
+        .map(|it| (it.uploaded_at, it.kind)),
 
         MediaLibrary::Global => sqlx::query!(
-            r#"select uploaded_at from image_upload where image_id = $1 for update"#,
+            r#"
+            select uploaded_at, kind as "kind: ImageKind"
+            from image_upload
+            inner join image_metadata on id = image_id
+            where image_id = $1 for update of image_upload"#,
             id
         )
         .fetch_optional(&mut txn)
         .await?
-        .map(|it| (it.uploaded_at, ImageKind::Sticker)),
+        .map(|it| (it.uploaded_at, it.kind)),
     };
 
     let (uploaded_at, kind): (Option<DateTime<Utc>>, ImageKind) =
@@ -177,7 +208,7 @@ async fn refresh_image_files(
 
     match library {
         MediaLibrary::Global => {
-            s3.back_copy_unprocessed_file(library, id, FileKind::ImagePng(PngImageFile::Original))
+            s3.back_copy_unprocessed_file(library, id, FileKind::original_image(kind))
                 .await?;
 
             sqlx::query!("update image_upload set uploaded_at = now(), processing_result = null where image_id = $1", id)
@@ -188,7 +219,7 @@ async fn refresh_image_files(
         }
 
         MediaLibrary::User => {
-            s3.back_copy_unprocessed_file(library, id, FileKind::ImagePng(PngImageFile::Original))
+            s3.back_copy_unprocessed_file(library, id, FileKind::original_image(kind))
                 .await?;
 
             sqlx::query!("update user_image_upload set uploaded_at = now(), processing_result = null where image_id = $1", id)
@@ -201,19 +232,24 @@ async fn refresh_image_files(
         _ => {}
     }
 
-    let (resized, thumbnail) = actix_web::web::block(move || -> Result<_, error::Refresh> {
+    let (resized, thumbnail, webp) = actix_web::web::block(move || -> Result<_, error::Refresh> {
         let original = image::load_from_memory(&original)?;
 
-        regenerate_images(&original, kind).map_err(|e| error::Refresh::from(e))
+        let (resized, thumbnail) =
+            regenerate_images(&original, kind).map_err(|e| error::Refresh::from(e))?;
+
+        Ok((resized, thumbnail, generate_webp_images(&original, kind)))
     })
     .await??;
 
     s3.upload_png_images_resized_thumb(library, id, resized, thumbnail)
         .await?;
 
+    s3.upload_webp_images(library, id, webp).await?;
+
     match library {
         MediaLibrary::Web => sqlx::query!(
-            "update web_media_upload set uploaded_at = now(), processing_result = null where media_id = $1",
+            "update web_media_upload set uploaded_at = now(), processing_result = null, has_webp = true where media_id = $1",
             id
         )
         .execute(&mut txn)
@@ -227,6 +263,46 @@ async fn refresh_image_files(
     Ok(HttpResponse::NoContent().finish())
 }
 
+/// Generate the WebP files for an image that was processed before they existed.
+async fn generate_webp_files(
+    _auth: TokenUserWithScope<ScopeAdmin>,
+    s3: ServiceData<s3::Client>,
+    db: Data<PgPool>,
+    path: Path<(MediaLibrary, Uuid)>,
+) -> actix_web::Result<HttpResponse, error::Refresh> {
+    let (library, id) = path.into_inner();
+
+    let mut txn = db.begin().await?;
+
+    let (kind, has_webp) = db::media::webp_status_for_update(&mut txn, library, id)
+        .await?
+        .ok_or(error::Refresh::ResourceNotFound)?;
+
+    if has_webp {
+        return Err(error::Refresh::PreconditionFailed);
+    }
+
+    let original = s3
+        .download_media_file(library, id, FileKind::ImagePng(PngImageFile::Original))
+        .await?
+        .ok_or(error::Refresh::ResourceNotFound)?;
+
+    let webp = actix_web::web::block(move || -> Result<_, error::Refresh> {
+        let original = image::load_from_memory(&original)?;
+
+        Ok(generate_webp_images(&original, kind))
+    })
+    .await??;
+
+    s3.upload_webp_images(library, id, webp).await?;
+
+    db::media::set_has_webp(&mut txn, library, id).await?;
+
+    txn.commit().await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
 async fn list_media(
     _auth: TokenUserWithScope<ScopeAdmin>,
     db: Data<PgPool>,
@@ -315,6 +391,137 @@ async fn export_data(
     Ok(file.into_response(&req))
 }
 
+const AUDIT_LOG_MAX_PAGE_LIMIT: u32 = 100;
+const AUDIT_LOG_DEFAULT_PAGE_LIMIT: u32 = 20;
+
+fn audit_log_filter(query: &AuditLogQuery) -> db::audit_log::Filter {
+    db::audit_log::Filter {
+        actor_id: query.actor_id,
+        target_id: query.target_id,
+        action: query.action,
+        from_date: query.from_date,
+        to_date: query.to_date,
+    }
+}
+
+/// Browse the audit log.
+async fn audit_log(
+    _auth: TokenUserWithScope<ScopeAdmin>,
+    db: Data<PgPool>,
+    query: Option<Query<<admin::AuditLog as ApiEndpoint>::Req>>,
+) -> Result<Json<<admin::AuditLog as ApiEndpoint>::Res>, error::Server> {
+    let query = query.map_or_else(Default::default, Query::into_inner);
+
+    let page_limit = query
+        .page_limit
+        .unwrap_or(AUDIT_LOG_DEFAULT_PAGE_LIMIT)
+        .clamp(1, AUDIT_LOG_MAX_PAGE_LIMIT);
+
+    let filter = audit_log_filter(&query);
+
+    let (entries, total_count) = futures::try_join!(
+        db::audit_log::browse(
+            &db,
+            &filter,
+            query.page.unwrap_or(0) as i64,
+            Some(page_limit as i64)
+        ),
+        db::audit_log::filtered_count(&db, &filter),
+    )?;
+
+    let pages = (total_count / (page_limit as u64)
+        + (total_count % (page_limit as u64) != 0) as u64) as u32;
+
+    Ok(Json(AuditLogResponse {
+        entries,
+        pages,
+        total_count,
+    }))
+}
+
+/// A flattened [`AuditLogEntry`], as CSV can't contain nested data.
+#[derive(serde::Serialize)]
+struct AuditLogCsvRow {
+    id: Uuid,
+    created_at: DateTime<Utc>,
+    actor_id: Option<Uuid>,
+    action: AuditAction,
+    target_id: Option<Uuid>,
+    before: Option<String>,
+    after: Option<String>,
+    ip_address: Option<String>,
+    user_agent: Option<String>,
+}
+
+impl From<AuditLogEntry> for AuditLogCsvRow {
+    fn from(entry: AuditLogEntry) -> Self {
+        Self {
+            id: entry.id,
+            created_at: entry.created_at,
+            actor_id: entry.actor_id,
+            action: entry.action,
+            target_id: entry.target_id,
+            before: entry.before.map(|it| it.to_string()),
+            after: entry.after.map(|it| it.to_string()),
+            ip_address: entry.ip_address,
+            user_agent: entry.user_agent,
+        }
+    }
+}
+
+/// Export the (filtered) audit log as CSV.
+async fn export_audit_log(
+    _auth: TokenUserNoCsrfWithScope<ScopeAdmin>,
+    db: Data<PgPool>,
+    query: Option<Query<<admin::ExportAuditLog as ApiEndpoint>::Req>>,
+) -> actix_web::Result<HttpResponse, error::Server> {
+    let query = query.map_or_else(Default::default, Query::into_inner);
+
+    let entries = db::audit_log::browse(&db, &audit_log_filter(&query), 0, None).await?;
+
+    let mut writer = csv::WriterBuilder::new()
+        .quote_style(csv::QuoteStyle::Necessary)
+        .from_writer(vec![]);
+
+    for entry in entries {
+        writer.serialize(AuditLogCsvRow::from(entry))?;
+    }
+
+    let body = writer.into_inner().map_err(|e| anyhow::anyhow!("{}", e))?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("text/csv")
+        .insert_header((
+            header::CONTENT_DISPOSITION,
+            format!(
+                "attachment; filename=\"jigzi_audit_log_{}.csv\"",
+                Utc::now().format("%Y-%m-%d")
+            ),
+        ))
+        .body(body))
+}
+
+const STORAGE_CONSUMERS_MAX_LIMIT: u32 = 100;
+const STORAGE_CONSUMERS_DEFAULT_LIMIT: u32 = 20;
+
+/// List the users using the most storage.
+async fn storage_consumers(
+    _auth: TokenUserWithScope<ScopeAdmin>,
+    db: Data<PgPool>,
+    query: Option<Query<<admin::StorageConsumers as ApiEndpoint>::Req>>,
+) -> Result<Json<<admin::StorageConsumers as ApiEndpoint>::Res>, error::Server> {
+    let query = query.map_or_else(Default::default, Query::into_inner);
+
+    let limit = query
+        .limit
+        .unwrap_or(STORAGE_CONSUMERS_DEFAULT_LIMIT)
+        .clamp(1, STORAGE_CONSUMERS_MAX_LIMIT);
+
+    let users = db::storage_usage::top_consumers(&db, query.group, limit as i64).await?;
+
+    Ok(Json(StorageConsumersResponse { users }))
+}
+
 pub fn configure(cfg: &mut ServiceConfig) {
     cfg.route(
         admin::Impersonate::PATH,
@@ -324,6 +531,12 @@ pub fn configure(cfg: &mut ServiceConfig) {
         admin::RefreshFiles::PATH,
         admin::RefreshFiles::METHOD.route().to(refresh_image_files),
     )
+    .route(
+        admin::GenerateWebpFiles::PATH,
+        admin::GenerateWebpFiles::METHOD
+            .route()
+            .to(generate_webp_files),
+    )
     .route(
         admin::ListMedia::PATH,
         admin::ListMedia::METHOD.route().to(list_media),
@@ -331,5 +544,19 @@ pub fn configure(cfg: &mut ServiceConfig) {
     .route(
         admin::ExportData::PATH,
         admin::ExportData::METHOD.route().to(export_data),
+    )
+    .route(
+        admin::AuditLog::PATH,
+        admin::AuditLog::METHOD.route().to(audit_log),
+    )
+    .route(
+        admin::ExportAuditLog::PATH,
+        admin::ExportAuditLog::METHOD.route().to(export_audit_log),
+    )
+    .route(
+        admin::StorageConsumers::PATH,
+        admin::StorageConsumers::METHOD
+            .route()
+            .to(storage_consumers),
     );
 }
@@ -6,7 +6,7 @@ use chrono::{DateTime, Utc};
 use shared::{
     api::{endpoints::animation, ApiEndpoint},
     domain::{
-        animation::{AnimationId, AnimationKind, AnimationResponse, AnimationUploadResponse},
+        animation::{AnimationId, AnimationResponse, AnimationUploadResponse},
         CreateResponse,
     },
     media::{FileKind, MediaLibrary},
@@ -38,15 +38,15 @@ async fn delete(
         .await
         .map_err(check_conflict_delete)?;
 
-    if let Some(kind) = kind {
-        let file = match kind {
-            AnimationKind::Gif => FileKind::AnimationGif,
-            // todo:
-            _ => return Err(anyhow::anyhow!("Unsupported animation kind").into()),
-        };
-
-        s3.delete_media(MediaLibrary::Global, file, animation.0)
-            .await;
+    if kind.is_some() {
+        for file in &[
+            FileKind::AnimationGif,
+            FileKind::AnimationSpritesheet,
+            FileKind::AnimationSpritesheetJson,
+        ] {
+            s3.delete_media(MediaLibrary::Global, *file, animation.0)
+                .await;
+        }
     }
 
     Ok(HttpResponse::NoContent().finish())
@@ -18,6 +18,7 @@ pub mod user {
         web::{Data, Json, Path},
         HttpResponse,
     };
+    use core::settings::RuntimeSettings;
     use futures::TryStreamExt;
     use shared::{
         api::{endpoints, ApiEndpoint},
@@ -28,6 +29,7 @@ pub mod user {
                 },
                 AudioId,
             },
+            user::UserMediaGroup,
             CreateResponse,
         },
         media::{FileKind, MediaLibrary},
@@ -35,17 +37,18 @@ pub mod user {
     use sqlx::PgPool;
 
     use crate::{
-        db, error,
+        audio_ops, db, error,
         extractor::{RequestOrigin, TokenUser},
+        http::endpoints::user,
         service::{s3, storage, GcpAccessKeyStore, ServiceData},
     };
 
     /// Create a audio file in the user's audio library.
     pub(super) async fn create(
         db: Data<PgPool>,
-        _claims: TokenUser,
+        claims: TokenUser,
     ) -> Result<HttpResponse, error::NotFound> {
-        let id = db::audio::user::create(db.as_ref()).await?;
+        let id = db::audio::user::create(db.as_ref(), claims.0.user_id).await?;
         Ok(HttpResponse::Created().json(CreateResponse { id }))
     }
 
@@ -54,7 +57,8 @@ pub mod user {
         db: Data<PgPool>,
         gcp_key_store: ServiceData<GcpAccessKeyStore>,
         gcs: ServiceData<storage::Client>,
-        _claims: TokenUser,
+        settings: Data<RuntimeSettings>,
+        claims: TokenUser,
         id: Path<AudioId>,
         origin: RequestOrigin,
         req: Json<<endpoints::audio::user::Upload as ApiEndpoint>::Req>,
@@ -64,8 +68,9 @@ pub mod user {
         let mut txn = db.begin().await?;
 
         let exists = sqlx::query!(
-        r#"select exists(select 1 from user_audio_upload where audio_id = $1 for no key update) as "exists!""#,
-        id.0
+        r#"select exists(select 1 from user_audio_upload inner join user_audio_library on id = audio_id where audio_id = $1 and user_id = $2 for no key update of user_audio_upload) as "exists!""#,
+        id.0,
+        claims.0.user_id
     )
             .fetch_one(&mut txn)
             .await?.exists;
@@ -74,7 +79,19 @@ pub mod user {
             return Err(error::Upload::ResourceNotFound);
         }
 
-        let upload_content_length = req.into_inner().file_size;
+        let req = req.into_inner();
+
+        if req
+            .content_type
+            .as_deref()
+            .map_or(false, audio_ops::is_opus)
+        {
+            return Err(error::Upload::UnsupportedFormat(
+                "Opus audio isn't supported, upload mp3, wav, m4a, flac or ogg vorbis instead",
+            ));
+        }
+
+        let upload_content_length = req.file_size;
 
         if let Some(file_limit) = gcs.file_size_limit(&FileKind::AudioMp3) {
             if file_limit < upload_content_length {
@@ -82,6 +99,16 @@ pub mod user {
             }
         }
 
+        user::storage::check_quota(
+            &mut txn,
+            &settings.storage_quota,
+            claims.0.user_id,
+            UserMediaGroup::Audio,
+            id.0,
+            upload_content_length,
+        )
+        .await?;
+
         let access_token = gcp_key_store.fetch_token().await?.to_owned();
 
         let resp = gcs
@@ -96,8 +123,9 @@ pub mod user {
             .await?;
 
         sqlx::query!(
-        "update user_audio_upload set uploaded_at = now(), processing_result = null where audio_id = $1",
-        id.0
+        "update user_audio_upload set uploaded_at = now(), processing_result = null, file_size = $2 where audio_id = $1",
+        id.0,
+        upload_content_length as i64,
     )
         .execute(&mut txn)
         .await?;
@@ -122,6 +150,9 @@ pub mod user {
         s3.delete_media(MediaLibrary::User, FileKind::AudioMp3, audio.0)
             .await;
 
+        s3.delete_media(MediaLibrary::User, FileKind::AudioWaveform, audio.0)
+            .await;
+
         Ok(HttpResponse::NoContent().finish())
     }
 
@@ -4,9 +4,12 @@ use actix_web::{
 };
 use shared::{
     api::endpoints::{category, ApiEndpoint},
-    domain::category::{
-        CategoryId, CategoryResponse, CategoryTreeScope, CreateCategoryRequest, GetCategoryRequest,
-        NewCategoryResponse, UpdateCategoryRequest,
+    domain::{
+        admin::AuditAction,
+        category::{
+            CategoryId, CategoryResponse, CategoryTreeScope, CreateCategoryRequest,
+            GetCategoryRequest, NewCategoryResponse, UpdateCategoryRequest,
+        },
     },
 };
 use sqlx::PgPool;
@@ -14,7 +17,7 @@ use tracing::instrument;
 
 use crate::{
     db, error,
-    extractor::{ScopeManageCategory, TokenUserWithScope},
+    extractor::{IPAddress, ScopeManageCategory, TokenUserWithScope, UserAgent},
 };
 
 pub enum CreateError {
@@ -69,12 +72,24 @@ async fn get_categories(
 /// Create a category.
 async fn create_category(
     db: Data<PgPool>,
-    _claims: TokenUserWithScope<ScopeManageCategory>,
+    claims: TokenUserWithScope<ScopeManageCategory>,
     req: Json<<category::Create as ApiEndpoint>::Req>,
+    ip_address: IPAddress,
+    user_agent: UserAgent,
 ) -> actix_web::Result<HttpResponse, CreateError> {
     let CreateCategoryRequest { name, parent_id } = req.into_inner();
 
-    let (id, index) = db::category::create(&db, &name, parent_id).await?;
+    let audit = db::audit_log::NewEntry {
+        actor_id: Some(claims.claims.user_id),
+        action: AuditAction::CreateCategory,
+        target_id: None,
+        before: None,
+        after: None,
+        ip_address: ip_address.0,
+        user_agent: user_agent.0,
+    };
+
+    let (id, index) = db::category::create(&db, &name, parent_id, &audit).await?;
 
     Ok(HttpResponse::Created().json(NewCategoryResponse { id, index }))
 }
@@ -82,10 +97,14 @@ async fn create_category(
 /// Update a category.
 async fn update_category(
     db: Data<PgPool>,
-    _claims: TokenUserWithScope<ScopeManageCategory>,
+    claims: TokenUserWithScope<ScopeManageCategory>,
     req: Option<Json<<category::Update as ApiEndpoint>::Req>>,
     path: web::Path<CategoryId>,
+    ip_address: IPAddress,
+    user_agent: UserAgent,
 ) -> actix_web::Result<HttpResponse, error::CategoryUpdate> {
+    let id = path.into_inner();
+
     let UpdateCategoryRequest {
         name,
         parent_id,
@@ -93,13 +112,24 @@ async fn update_category(
         user_scopes,
     } = req.map_or_else(Default::default, Json::into_inner);
 
+    let audit = db::audit_log::NewEntry {
+        actor_id: Some(claims.claims.user_id),
+        action: AuditAction::UpdateCategory,
+        target_id: Some(id.0),
+        before: None,
+        after: None,
+        ip_address: ip_address.0,
+        user_agent: user_agent.0,
+    };
+
     db::category::update(
         &db,
-        path.into_inner(),
+        id,
         parent_id,
         name.as_deref(),
         index.map(|it| it as i16),
         user_scopes,
+        &audit,
     )
     .await?;
 
@@ -109,10 +139,24 @@ async fn update_category(
 /// Delete a category.
 async fn delete_category(
     db: Data<PgPool>,
-    _claims: TokenUserWithScope<ScopeManageCategory>,
+    claims: TokenUserWithScope<ScopeManageCategory>,
     path: web::Path<CategoryId>,
+    ip_address: IPAddress,
+    user_agent: UserAgent,
 ) -> actix_web::Result<HttpResponse, error::Delete> {
-    db::category::delete(&db, path.into_inner()).await?;
+    let id = path.into_inner();
+
+    let audit = db::audit_log::NewEntry {
+        actor_id: Some(claims.claims.user_id),
+        action: AuditAction::DeleteCategory,
+        target_id: Some(id.0),
+        before: None,
+        after: None,
+        ip_address: ip_address.0,
+        user_agent: user_agent.0,
+    };
+
+    db::category::delete(&db, id, &audit).await?;
 
     Ok(HttpResponse::NoContent().finish())
 }
@@ -6,18 +6,26 @@ use chrono::{DateTime, Utc};
 use futures::TryStreamExt;
 use shared::{
     api::{endpoints, ApiEndpoint},
-    domain::image::{
-        CreateResponse, ImageBrowseResponse, ImageId, ImageMetadata, ImageResponse,
-        ImageSearchResponse, ImageUpdateRequest, ImageUploadResponse,
+    domain::{
+        admin::AuditAction,
+        image::{
+            CreateResponse, ImageBrowseResponse, ImageDuplicateCluster, ImageDuplicatesResponse,
+            ImageId, ImageKind, ImageMetadata, ImageResponse, ImageSearchResponse,
+            ImageUpdateRequest, ImageUploadResponse, SimilarImagesResponse,
+        },
     },
-    media::{FileKind, MediaLibrary, PngImageFile},
+    media::{FileKind, MediaLibrary},
 };
 use sqlx::{postgres::PgDatabaseError, PgPool};
+use std::collections::HashMap;
+use uuid::Uuid;
 
 use crate::{
     db::{self, meta::handle_metadata_err, nul_if_empty},
     error::{self, ServiceKind},
-    extractor::{RequestOrigin, ScopeManageImage, TokenUser, TokenUserWithScope},
+    extractor::{
+        IPAddress, RequestOrigin, ScopeManageImage, TokenUser, TokenUserWithScope, UserAgent,
+    },
     service::{self, s3, ServiceData},
 };
 
@@ -28,6 +36,18 @@ pub mod user;
 const MAX_PAGE_LIMIT: u32 = 100;
 const DEFAULT_PAGE_LIMIT: u32 = 20;
 
+/// Beyond this, mostly unrelated images start to match.
+const MAX_SIMILAR_DISTANCE: u32 = 16;
+
+/// At most this many pairs are clustered when listing duplicates, the closest first.
+const MAX_DUPLICATE_PAIRS: u32 = 1000;
+
+fn similar_distance(max_distance: Option<u32>) -> u32 {
+    max_distance
+        .unwrap_or(db::image::DEFAULT_SIMILAR_DISTANCE)
+        .min(MAX_SIMILAR_DISTANCE)
+}
+
 /// Create an image in the global image library.
 async fn create(
     db: Data<PgPool>,
@@ -36,6 +56,10 @@ async fn create(
 ) -> Result<HttpResponse, error::CreateWithMetadata> {
     let req = req.into_inner();
 
+    req.provenance
+        .validate()
+        .map_err(error::CreateWithMetadata::BadRequest)?;
+
     let mut txn = db.begin().await?;
     let id = db::image::create(
         &mut txn,
@@ -44,6 +68,7 @@ async fn create(
         req.is_premium,
         req.publish_at.map(DateTime::<Utc>::from),
         req.kind,
+        &req.provenance,
     )
     .await?;
 
@@ -78,20 +103,26 @@ async fn upload(
 
     let id = path.into_inner();
 
-    let exists = sqlx::query!(
-        r#"select exists(select 1 from image_upload where image_id = $1 for no key update) as "exists!""#,
+    let kind = sqlx::query!(
+        r#"
+select kind as "kind: ImageKind"
+from image_metadata
+inner join image_upload on id = image_id
+where id = $1
+for no key update of image_upload
+"#,
         id.0
     )
-    .fetch_one(&mut txn)
-    .await?.exists;
+    .fetch_optional(&mut txn)
+    .await?
+    .ok_or(error::Upload::ResourceNotFound)?
+    .kind;
 
-    if !exists {
-        return Err(error::Upload::ResourceNotFound);
-    }
+    let file_kind = FileKind::original_image(kind);
 
     let upload_content_length = req.into_inner().file_size;
 
-    if let Some(file_limit) = gcs.file_size_limit(&FileKind::ImagePng(PngImageFile::Original)) {
+    if let Some(file_limit) = gcs.file_size_limit(&file_kind) {
         if file_limit < upload_content_length {
             return Err(error::Upload::FileTooLarge);
         }
@@ -105,7 +136,7 @@ async fn upload(
             upload_content_length,
             MediaLibrary::Global,
             id.0,
-            FileKind::ImagePng(PngImageFile::Original),
+            file_kind,
             origin,
         )
         .await?;
@@ -128,7 +159,7 @@ async fn get_one(
     _claims: TokenUser,
     req: Path<ImageId>,
 ) -> Result<Json<<endpoints::image::Get as ApiEndpoint>::Res>, error::NotFound> {
-    let metadata = db::image::get_one(&db, req.into_inner())
+    let metadata = db::image::get_one(&mut *db.acquire().await?, req.into_inner())
         .await?
         .ok_or(error::NotFound::ResourceNotFound)?;
 
@@ -220,14 +251,25 @@ async fn browse(
 /// Update an image in the global image library.
 async fn update(
     db: Data<PgPool>,
-    _claims: TokenUserWithScope<ScopeManageImage>,
+    claims: TokenUserWithScope<ScopeManageImage>,
     req: Option<Json<<endpoints::image::UpdateMetadata as ApiEndpoint>::Req>>,
     id: Path<ImageId>,
+    ip_address: IPAddress,
+    user_agent: UserAgent,
 ) -> Result<HttpResponse, error::UpdateWithMetadata> {
     let req = req.map_or_else(ImageUpdateRequest::default, Json::into_inner);
     let id = id.into_inner();
+
+    if let Some(provenance) = &req.provenance {
+        provenance
+            .validate()
+            .map_err(error::UpdateWithMetadata::BadRequest)?;
+    }
+
     let mut txn = db.begin().await?;
 
+    let before = db::image::get_one(&mut txn, id).await?;
+
     let exists = db::image::update(
         &mut txn,
         id,
@@ -235,6 +277,7 @@ async fn update(
         req.description.as_deref(),
         req.is_premium,
         req.publish_at.map(|it| it.map(DateTime::<Utc>::from)),
+        req.provenance.as_ref(),
     )
     .await?;
 
@@ -254,6 +297,22 @@ async fn update(
     .await
     .map_err(handle_metadata_err)?;
 
+    let after = db::image::get_one(&mut txn, id).await?;
+
+    db::audit_log::insert(
+        &mut txn,
+        db::audit_log::NewEntry {
+            actor_id: Some(claims.claims.user_id),
+            action: AuditAction::UpdateImage,
+            target_id: Some(id.0),
+            before: before.map(serde_json::to_value).transpose()?,
+            after: after.map(serde_json::to_value).transpose()?,
+            ip_address: ip_address.0,
+            user_agent: user_agent.0,
+        },
+    )
+    .await?;
+
     txn.commit().await?;
 
     Ok(HttpResponse::NoContent().finish())
@@ -283,11 +342,8 @@ async fn delete(
 
     // todo: 501 when algolia is disabled.
 
-    let delete = |kind| s3.delete_media(MediaLibrary::Global, FileKind::ImagePng(kind), image.0);
-    let ((), (), (), ()) = futures::future::join4(
-        delete(PngImageFile::Original),
-        delete(PngImageFile::Resized),
-        delete(PngImageFile::Thumbnail),
+    let ((), ()) = futures::future::join(
+        s3.delete_image_files(MediaLibrary::Global, image.0),
         algolia.delete_image(image),
     )
     .await;
@@ -295,6 +351,167 @@ async fn delete(
     Ok(HttpResponse::NoContent().finish())
 }
 
+/// Find images in the global image library that look like the given one.
+async fn similar(
+    db: Data<PgPool>,
+    _claims: TokenUserWithScope<ScopeManageImage>,
+    path: Path<ImageId>,
+    query: Option<Query<<endpoints::image::Similar as ApiEndpoint>::Req>>,
+) -> Result<Json<<endpoints::image::Similar as ApiEndpoint>::Res>, error::NotFound> {
+    let query = query.map_or_else(Default::default, Query::into_inner);
+
+    let images = db::image::similar(&db, path.into_inner(), similar_distance(query.max_distance))
+        .await?
+        .ok_or(error::NotFound::ResourceNotFound)?;
+
+    Ok(Json(SimilarImagesResponse { images }))
+}
+
+/// Groups pairs of similar images into clusters (the connected components of the pairs).
+fn cluster(pairs: Vec<(ImageId, ImageId)>) -> Vec<Vec<Uuid>> {
+    fn root(parents: &mut HashMap<Uuid, Uuid>, id: Uuid) -> Uuid {
+        let parent = parents[&id];
+        if parent == id {
+            return id;
+        }
+
+        let root = root(parents, parent);
+        parents.insert(id, root);
+        root
+    }
+
+    let mut parents = HashMap::new();
+
+    for (ImageId(a), ImageId(b)) in pairs {
+        parents.entry(a).or_insert(a);
+        parents.entry(b).or_insert(b);
+
+        let (a, b) = (root(&mut parents, a), root(&mut parents, b));
+        if a != b {
+            parents.insert(b, a);
+        }
+    }
+
+    let ids: Vec<_> = parents.keys().copied().collect();
+
+    let mut clusters: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for id in ids {
+        let root = root(&mut parents, id);
+        clusters.entry(root).or_default().push(id);
+    }
+
+    clusters.into_values().collect()
+}
+
+/// List clusters of near-duplicate images in the global image library.
+async fn duplicates(
+    db: Data<PgPool>,
+    _claims: TokenUserWithScope<ScopeManageImage>,
+    query: Option<Query<<endpoints::image::Duplicates as ApiEndpoint>::Req>>,
+) -> Result<Json<<endpoints::image::Duplicates as ApiEndpoint>::Res>, error::Server> {
+    let query = query.map_or_else(Default::default, Query::into_inner);
+
+    let pairs = db::image::similar_pairs(
+        &db,
+        similar_distance(query.max_distance),
+        MAX_DUPLICATE_PAIRS,
+    )
+    .await?;
+
+    let clusters = cluster(pairs);
+
+    let ids: Vec<_> = clusters.iter().flatten().copied().collect();
+
+    let mut metadata: HashMap<Uuid, ImageMetadata> = db::image::get(&db, &ids)
+        .map_ok(|it| (it.id.0, it))
+        .try_collect()
+        .await?;
+
+    let mut clusters: Vec<_> = clusters
+        .into_iter()
+        .map(|ids| {
+            let mut images: Vec<_> = ids
+                .into_iter()
+                .filter_map(|id| metadata.remove(&id))
+                .collect();
+
+            images.sort_by_key(|it| it.created_at);
+
+            images
+        })
+        .filter(|images| images.len() > 1)
+        .collect();
+
+    clusters.sort_by_key(|images| images[0].created_at);
+
+    let clusters = clusters
+        .into_iter()
+        .map(|images| ImageDuplicateCluster {
+            images: images
+                .into_iter()
+                .map(|metadata| ImageResponse { metadata })
+                .collect(),
+        })
+        .collect();
+
+    Ok(Json(ImageDuplicatesResponse { clusters }))
+}
+
+/// Merge duplicate images in the global image library into one.
+async fn merge_duplicates(
+    db: Data<PgPool>,
+    algolia: ServiceData<crate::algolia::Client>,
+    s3: ServiceData<s3::Client>,
+    claims: TokenUserWithScope<ScopeManageImage>,
+    req: Json<<endpoints::image::MergeDuplicates as ApiEndpoint>::Req>,
+    ip_address: IPAddress,
+    user_agent: UserAgent,
+) -> Result<HttpResponse, error::ImageMerge> {
+    let mut req = req.into_inner();
+
+    req.duplicates.sort_by_key(|it| it.0);
+    req.duplicates.dedup();
+
+    if req.duplicates.contains(&req.keep) {
+        return Err(error::ImageMerge::BadRequest(
+            "The kept image can't also be a duplicate",
+        ));
+    }
+
+    let mut txn = db.begin().await?;
+
+    if !db::image::merge(&mut txn, req.keep, &req.duplicates).await? {
+        return Err(error::ImageMerge::ResourceNotFound);
+    }
+
+    db::audit_log::insert(
+        &mut txn,
+        db::audit_log::NewEntry {
+            actor_id: Some(claims.claims.user_id),
+            action: AuditAction::MergeImages,
+            target_id: Some(req.keep.0),
+            before: Some(serde_json::json!({ "duplicates": req.duplicates })),
+            after: None,
+            ip_address: ip_address.0,
+            user_agent: user_agent.0,
+        },
+    )
+    .await?;
+
+    txn.commit().await?;
+
+    futures::future::join_all(req.duplicates.iter().map(|image| async move {
+        let ((), ()) = futures::future::join(
+            s3.delete_image_files(MediaLibrary::Global, image.0),
+            algolia.delete_image(*image),
+        )
+        .await;
+    }))
+    .await;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
 async fn page_limit(page_limit: Option<u32>) -> anyhow::Result<u32> {
     if let Some(limit) = page_limit {
         match limit > 0 && limit <= MAX_PAGE_LIMIT {
@@ -323,6 +540,18 @@ pub fn configure(cfg: &mut ServiceConfig) {
         image::Browse::PATH,
         image::Browse::METHOD.route().to(browse),
     )
+    .route(
+        image::Duplicates::PATH,
+        image::Duplicates::METHOD.route().to(duplicates),
+    )
+    .route(
+        image::MergeDuplicates::PATH,
+        image::MergeDuplicates::METHOD.route().to(merge_duplicates),
+    )
+    .route(
+        image::Similar::PATH,
+        image::Similar::METHOD.route().to(similar),
+    )
     .route(image::Get::PATH, image::Get::METHOD.route().to(get_one))
     .route(
         image::UpdateMetadata::PATH,
@@ -356,6 +585,10 @@ pub fn configure(cfg: &mut ServiceConfig) {
         image::user::List::PATH,
         image::user::List::METHOD.route().to(self::user::list),
     )
+    .route(
+        image::user::Similar::PATH,
+        image::user::Similar::METHOD.route().to(self::user::similar),
+    )
     .route(
         image::tag::Create::PATH,
         image::tag::Create::METHOD.route().to(self::tag::create),
@@ -2,23 +2,26 @@ use actix_web::{
     web::{Data, Json, Path, Query},
     HttpResponse,
 };
+use core::settings::RuntimeSettings;
 use futures::TryStreamExt;
 use shared::{
     api::{endpoints, ApiEndpoint},
     domain::{
         image::{
             user::{UserImage, UserImageListResponse, UserImageResponse, UserImageUploadResponse},
-            ImageId,
+            ImageId, SimilarImagesResponse,
         },
+        user::UserMediaGroup,
         CreateResponse,
     },
-    media::{FileKind, MediaLibrary, PngImageFile},
+    media::{FileKind, MediaLibrary},
 };
 use sqlx::PgPool;
 
 use crate::{
     db, error,
     extractor::{RequestOrigin, TokenUser},
+    http::endpoints::user,
     service::{s3, storage, GcpAccessKeyStore, ServiceData},
 };
 
@@ -42,6 +45,7 @@ pub(super) async fn upload(
     db: Data<PgPool>,
     gcp_key_store: ServiceData<GcpAccessKeyStore>,
     gcs: ServiceData<storage::Client>,
+    settings: Data<RuntimeSettings>,
     claims: TokenUser,
     path: Path<ImageId>,
     origin: RequestOrigin,
@@ -51,16 +55,28 @@ pub(super) async fn upload(
 
     let mut txn = db.begin().await?;
 
-    db::image::user::auth_user_image(&mut txn, &claims.0.user_id, &id).await?;
+    let kind = db::image::user::auth_user_image(&mut txn, &claims.0.user_id, &id).await?;
+
+    let file_kind = FileKind::original_image(kind);
 
     let upload_content_length = req.into_inner().file_size;
 
-    if let Some(file_limit) = gcs.file_size_limit(&FileKind::ImagePng(PngImageFile::Original)) {
+    if let Some(file_limit) = gcs.file_size_limit(&file_kind) {
         if file_limit < upload_content_length {
             return Err(error::Upload::FileTooLarge);
         }
     }
 
+    user::storage::check_quota(
+        &mut txn,
+        &settings.storage_quota,
+        claims.0.user_id,
+        UserMediaGroup::Image,
+        id.0,
+        upload_content_length,
+    )
+    .await?;
+
     let access_token = gcp_key_store.fetch_token().await?;
 
     let resp = gcs
@@ -69,14 +85,15 @@ pub(super) async fn upload(
             upload_content_length,
             MediaLibrary::User,
             id.0,
-            FileKind::ImagePng(PngImageFile::Original),
+            file_kind,
             origin,
         )
         .await?;
 
     sqlx::query!(
-            "update user_image_upload set uploaded_at = now(), processing_result = null where image_id = $1",
-            id.0
+            "update user_image_upload set uploaded_at = now(), processing_result = null, file_size = $2 where image_id = $1",
+            id.0,
+            upload_content_length as i64,
         )
         .execute(&mut txn)
         .await?;
@@ -99,13 +116,7 @@ pub(super) async fn delete(
         .await
         .map_err(super::check_conflict_delete)?;
 
-    let delete = |kind| s3.delete_media(MediaLibrary::User, FileKind::ImagePng(kind), id.0);
-    let ((), (), ()) = futures::future::join3(
-        delete(PngImageFile::Original),
-        delete(PngImageFile::Resized),
-        delete(PngImageFile::Thumbnail),
-    )
-    .await;
+    s3.delete_image_files(MediaLibrary::User, id.0).await;
 
     Ok(HttpResponse::NoContent().finish())
 }
@@ -139,3 +150,24 @@ pub(super) async fn list(
 
     Ok(Json(UserImageListResponse { images }))
 }
+
+/// Find images in the user's image library (and the global image library) that look like the given one.
+pub(super) async fn similar(
+    db: Data<PgPool>,
+    claims: TokenUser,
+    path: Path<ImageId>,
+    query: Option<Query<<endpoints::image::user::Similar as ApiEndpoint>::Req>>,
+) -> Result<Json<<endpoints::image::user::Similar as ApiEndpoint>::Res>, error::NotFound> {
+    let query = query.map_or_else(Default::default, Query::into_inner);
+
+    let images = db::image::user::similar(
+        &db,
+        claims.0.user_id,
+        path.into_inner(),
+        super::similar_distance(query.max_distance),
+    )
+    .await?
+    .ok_or(error::NotFound::ResourceNotFound)?;
+
+    Ok(Json(SimilarImagesResponse { images }))
+}
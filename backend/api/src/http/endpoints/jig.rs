@@ -7,10 +7,13 @@ use futures::try_join;
 use shared::{
     api::{endpoints::jig, ApiEndpoint},
     domain::{
+        admin::AuditAction,
         jig::{
-            DeleteUserJigs, DraftOrLive, JigBrowseResponse, JigCountResponse, JigCreateRequest,
-            JigId, JigLikedResponse, JigSearchResponse, PrivacyLevel, UserOrMe,
+            DeleteUserJigs, DraftOrLive, JigAttributionResponse, JigBrowseResponse,
+            JigCountResponse, JigCreateRequest, JigId, JigLikedResponse, JigSearchResponse,
+            PrivacyLevel, UserOrMe,
         },
+        webhook::WebhookEvent,
         CreateResponse,
     },
 };
@@ -21,7 +24,7 @@ use uuid::Uuid;
 use crate::{
     db::{self, jig::CreateJigError},
     error::{self, ServiceKind},
-    extractor::{ScopeAdmin, TokenUser, TokenUserWithScope},
+    extractor::{IPAddress, ScopeAdmin, TokenUser, TokenUserWithScope, UserAgent},
     service::ServiceData,
 };
 
@@ -107,6 +110,18 @@ async fn get_live(
     Ok(Json(jig_response))
 }
 
+/// Credits for the media used in a jig.
+async fn attribution(
+    db: Data<PgPool>,
+    path: web::Path<JigId>,
+) -> Result<Json<<jig::Attribution as ApiEndpoint>::Res>, error::NotFound> {
+    let media = db::jig::attribution(&db, path.into_inner())
+        .await?
+        .ok_or(error::NotFound::ResourceNotFound)?;
+
+    Ok(Json(JigAttributionResponse { media }))
+}
+
 async fn get_draft(
     db: Data<PgPool>,
     path: web::Path<JigId>,
@@ -327,6 +342,8 @@ delete from jig_data where id = $1
 
     log::info!("AOSIJDOAIJSD");
 
+    db::webhook::enqueue(&mut txn, WebhookEvent::JigPublished, jig_id).await?;
+
     txn.commit().await?;
 
     Ok(HttpResponse::NoContent().finish())
@@ -401,18 +418,42 @@ async fn search(
 /// Update a JIG's admin data.
 async fn update_admin_data(
     db: Data<PgPool>,
-    _auth: TokenUserWithScope<ScopeAdmin>,
+    auth: TokenUserWithScope<ScopeAdmin>,
     req: Option<Json<<jig::JigAdminDataUpdate as ApiEndpoint>::Req>>,
     path: web::Path<JigId>,
+    ip_address: IPAddress,
+    user_agent: UserAgent,
 ) -> Result<HttpResponse, error::NotFound> {
     let id = path.into_inner();
 
     let req = req.map_or_else(Default::default, Json::into_inner);
 
-    db::jig::update_admin_data(&*db, id, req.rating, req.blocked, req.curated)
+    let mut txn = db.begin().await?;
+
+    let before = db::jig::get_admin_data(&mut txn, id).await?;
+
+    db::jig::update_admin_data(&mut txn, id, req.rating, req.blocked, req.curated)
         .await
         .map_err(|_| error::NotFound::ResourceNotFound)?;
 
+    let after = db::jig::get_admin_data(&mut txn, id).await?;
+
+    db::audit_log::insert(
+        &mut txn,
+        db::audit_log::NewEntry {
+            actor_id: Some(auth.claims.user_id),
+            action: AuditAction::UpdateJigAdminData,
+            target_id: Some(id.0),
+            before: before.map(serde_json::to_value).transpose()?,
+            after: after.map(serde_json::to_value).transpose()?,
+            ip_address: ip_address.0,
+            user_agent: user_agent.0,
+        },
+    )
+    .await?;
+
+    txn.commit().await?;
+
     Ok(HttpResponse::NoContent().finish())
 }
 
@@ -533,6 +574,10 @@ pub fn configure(cfg: &mut ServiceConfig) {
             jig::GetLive::PATH,
             jig::GetLive::METHOD.route().to(get_live),
         )
+        .route(
+            jig::Attribution::PATH,
+            jig::Attribution::METHOD.route().to(attribution),
+        )
         .route(
             jig::GetDraft::PATH,
             jig::GetDraft::METHOD.route().to(get_draft),
@@ -37,7 +37,7 @@ async fn create(
     let req = req.into_inner();
 
     let id = db::jig::additional_resource::create(
-        &*db,
+        &mut *db.acquire().await?,
         parent_id,
         req.display_name,
         req.resource_type_id,
@@ -83,19 +83,21 @@ async fn get_draft(
 ) -> Result<Json<<additional_resource::GetDraft as ApiEndpoint>::Res>, error::NotFound> {
     let (parent_id, additional_resource_id) = path.into_inner();
 
-    let (display_name, resource_type_id, resource_content) = db::jig::additional_resource::get(
-        &db,
-        parent_id,
-        DraftOrLive::Draft,
-        additional_resource_id,
-    )
-    .await?;
+    let (display_name, resource_type_id, resource_content, pdf_preview) =
+        db::jig::additional_resource::get(
+            &db,
+            parent_id,
+            DraftOrLive::Draft,
+            additional_resource_id,
+        )
+        .await?;
 
     Ok(Json(AdditionalResource {
         id: additional_resource_id,
         display_name,
         resource_type_id,
         resource_content,
+        pdf_preview,
     }))
 }
 
@@ -107,19 +109,21 @@ async fn get_live(
 ) -> Result<Json<<additional_resource::GetDraft as ApiEndpoint>::Res>, error::NotFound> {
     let (parent_id, additional_resource_id) = path.into_inner();
 
-    let (display_name, resource_type_id, resource_content) = db::jig::additional_resource::get(
-        &db,
-        parent_id,
-        DraftOrLive::Live,
-        additional_resource_id,
-    )
-    .await?;
+    let (display_name, resource_type_id, resource_content, pdf_preview) =
+        db::jig::additional_resource::get(
+            &db,
+            parent_id,
+            DraftOrLive::Live,
+            additional_resource_id,
+        )
+        .await?;
 
     Ok(Json(AdditionalResource {
         id: additional_resource_id,
         display_name,
         resource_type_id,
         resource_content,
+        pdf_preview,
     }))
 }
 
@@ -1,22 +1,43 @@
+use std::collections::HashMap;
+
 use actix_web::{
-    web::{self, Data, Json, Path, ServiceConfig},
+    web::{self, Data, Json, Path, Query, ServiceConfig},
     HttpResponse,
 };
+use chrono::Utc;
+use core::settings::RuntimeSettings;
 use shared::{
     api::{endpoints::jig::module, ApiEndpoint},
     domain::{
         jig::{
-            module::{ModuleId, ModuleResponse, StableOrUniqueId},
+            additional_resource::ResourceContent,
+            module::{
+                body::flashcards::review::{CardReview, FlashcardReviewResponse},
+                printable::PrintableResponse,
+                ModuleId, ModuleResponse, StableModuleId, StableOrUniqueId,
+            },
             JigId,
         },
+        user::UserMediaGroup,
         CreateResponse,
     },
+    media::{FileKind, MediaLibrary, PngImageFile},
 };
 use sqlx::PgPool;
 
 // use serde_qs::actix::QsQuery;
 
-use crate::{db, error, extractor::TokenUser};
+use crate::{
+    db::{self, jig::flashcard_review::Reviewer},
+    error,
+    extractor::TokenUser,
+    http::endpoints::user,
+    pdf_ops,
+    service::{s3, ServiceData},
+    token::validate_token,
+};
+
+use super::player::instance::InstanceToken;
 
 /// Create a new module on a draft JIG.
 async fn create(
@@ -116,6 +137,210 @@ async fn delete(
     Ok(HttpResponse::NoContent().finish())
 }
 
+/// Finds who a flashcards review request is for, the player session instance when there's a token, otherwise the user.
+fn reviewer(
+    settings: &RuntimeSettings,
+    auth: Option<TokenUser>,
+    token: Option<&str>,
+) -> Result<Reviewer, error::FlashcardReview> {
+    match (token, auth) {
+        (Some(token), _) => {
+            let token = validate_token(token, None, &settings.token_secret)
+                .map_err(|_| error::FlashcardReview::Unauthorized)?;
+
+            let token: InstanceToken = serde_json::from_value(token)?;
+
+            Ok(Reviewer::Instance(token.sub))
+        }
+        (None, Some(auth)) => Ok(Reviewer::User(auth.0.user_id)),
+        (None, None) => Err(error::FlashcardReview::Unauthorized),
+    }
+}
+
+/// Get the player's review state for a flashcards module.
+async fn get_flashcard_reviews(
+    settings: Data<RuntimeSettings>,
+    db: Data<PgPool>,
+    auth: Option<TokenUser>,
+    path: Path<(JigId, StableModuleId)>,
+    query: Option<Query<<module::flashcards::GetReviews as ApiEndpoint>::Req>>,
+) -> Result<Json<<module::flashcards::GetReviews as ApiEndpoint>::Res>, error::FlashcardReview> {
+    let (jig_id, module_id) = path.into_inner();
+    let query = query.map_or_else(Default::default, Query::into_inner);
+
+    let reviewer = reviewer(&settings, auth, query.token.as_deref())?;
+
+    db::jig::flashcard_review::pair_keys(&db, jig_id, module_id)
+        .await?
+        .ok_or(error::FlashcardReview::ResourceNotFound)?;
+
+    let reviews = db::jig::flashcard_review::list(&db, jig_id, module_id, reviewer).await?;
+
+    Ok(Json(FlashcardReviewResponse { reviews }))
+}
+
+/// Mark a card in a flashcards module known or unknown.
+async fn review_flashcard(
+    settings: Data<RuntimeSettings>,
+    db: Data<PgPool>,
+    auth: Option<TokenUser>,
+    path: Path<(JigId, StableModuleId)>,
+    req: Json<<module::flashcards::Review as ApiEndpoint>::Req>,
+) -> Result<Json<<module::flashcards::Review as ApiEndpoint>::Res>, error::FlashcardReview> {
+    let (jig_id, module_id) = path.into_inner();
+    let req = req.into_inner();
+
+    let reviewer = reviewer(&settings, auth, req.token.as_deref())?;
+
+    let pair_keys = db::jig::flashcard_review::pair_keys(&db, jig_id, module_id)
+        .await?
+        .ok_or(error::FlashcardReview::ResourceNotFound)?;
+
+    if !pair_keys.contains(&req.pair_key) {
+        return Err(error::FlashcardReview::BadRequest(
+            "the module doesn't have a pair with this key",
+        ));
+    }
+
+    let session_index = match reviewer {
+        Reviewer::User(_) => None,
+        Reviewer::Instance(instance_id) => Some(
+            db::jig::flashcard_review::instance_session(&db, instance_id, jig_id)
+                .await?
+                .ok_or(error::FlashcardReview::ResourceNotFound)?,
+        ),
+    };
+
+    let now = Utc::now();
+
+    let review =
+        match db::jig::flashcard_review::get(&db, jig_id, module_id, reviewer, &req.pair_key)
+            .await?
+        {
+            Some(review) => review.review(req.known, now),
+            None => CardReview::new(req.pair_key, req.known, now),
+        };
+
+    db::jig::flashcard_review::upsert(&db, jig_id, module_id, reviewer, session_index, &review)
+        .await?;
+
+    Ok(Json(review))
+}
+
+/// Print a module of a draft JIG and attach the pdf to the JIG as an additional resource.
+///
+/// The pdf is added to the user's pdf library, and processed by the media watcher like any other upload.
+async fn printable(
+    db: Data<PgPool>,
+    settings: Data<RuntimeSettings>,
+    s3: ServiceData<s3::Client>,
+    auth: TokenUser,
+    path: Path<(JigId, ModuleId)>,
+    req: Json<<module::Printable as ApiEndpoint>::Req>,
+) -> Result<Json<<module::Printable as ApiEndpoint>::Res>, error::Printable> {
+    let (jig_id, module_id) = path.into_inner();
+    let req = req.into_inner();
+    let user_id = auth.0.user_id;
+
+    db::jig::authz(&*db, user_id, Some(jig_id)).await?;
+
+    let module = db::jig::module::get_draft(&db, jig_id, StableOrUniqueId::Unique(module_id))
+        .await?
+        .ok_or(error::Printable::ResourceNotFound)?;
+
+    let kind = module.body.kind();
+
+    if !kind.is_printable() {
+        return Err(error::Printable::BadRequest(
+            "modules of this kind can't be printed",
+        ));
+    }
+
+    let mut images = Vec::new();
+
+    for image in pdf_ops::printable::images(&module.body) {
+        let file = s3
+            .download_media_file(
+                image.lib,
+                image.id.0,
+                FileKind::ImagePng(PngImageFile::Resized),
+            )
+            .await?;
+
+        if let Some(file) = file {
+            images.push((image.id, file));
+        }
+    }
+
+    let display_name = req
+        .display_name
+        .unwrap_or_else(|| kind.display_name().to_owned());
+
+    let pdf = {
+        let title = display_name.clone();
+        let (layout, answer_key) = (req.layout, req.answer_key);
+
+        tokio::task::spawn_blocking(move || {
+            // A broken image is left out rather than failing the whole document, like a missing one.
+            let images: HashMap<_, _> = images
+                .into_iter()
+                .filter_map(|(id, file)| Some((id, image::load_from_memory(&file).ok()?)))
+                .collect();
+
+            pdf_ops::printable::render(&module.body, &title, layout, answer_key, &images)
+        })
+        .await??
+        .ok_or(error::Printable::BadRequest(
+            "the module has nothing to print",
+        ))?
+    };
+
+    let file_size = pdf.len();
+
+    let mut txn = db.begin().await?;
+
+    let pdf_id = db::pdf::user::create(&mut txn, user_id).await?;
+
+    user::storage::check_quota(
+        &mut txn,
+        &settings.storage_quota,
+        user_id,
+        UserMediaGroup::Pdf,
+        pdf_id.0,
+        file_size,
+    )
+    .await?;
+
+    sqlx::query!(
+        "update user_pdf_upload set uploaded_at = now(), processing_result = null, file_size = $2 where pdf_id = $1",
+        pdf_id.0,
+        file_size as i64,
+    )
+    .execute(&mut txn)
+    .await?;
+
+    let additional_resource_id = db::jig::additional_resource::create(
+        &mut txn,
+        jig_id,
+        display_name,
+        req.resource_type_id,
+        ResourceContent::PdfId(pdf_id),
+    )
+    .await?;
+
+    // Uploaded last, so nothing is stored if anything above fails. The media watcher retries until the
+    // transaction is committed and it can see the upload.
+    s3.upload_media_for_processing(pdf, MediaLibrary::User, pdf_id.0, FileKind::DocumentPdf)
+        .await?;
+
+    txn.commit().await?;
+
+    Ok(Json(PrintableResponse {
+        pdf_id,
+        additional_resource_id,
+    }))
+}
+
 pub fn configure(cfg: &mut ServiceConfig) {
     cfg.route(
         module::Create::PATH,
@@ -136,5 +361,21 @@ pub fn configure(cfg: &mut ServiceConfig) {
     .route(
         module::Delete::PATH,
         module::Delete::METHOD.route().to(delete),
+    )
+    .route(
+        module::flashcards::GetReviews::PATH,
+        module::flashcards::GetReviews::METHOD
+            .route()
+            .to(get_flashcard_reviews),
+    )
+    .route(
+        module::flashcards::Review::PATH,
+        module::flashcards::Review::METHOD
+            .route()
+            .to(review_flashcard),
+    )
+    .route(
+        module::Printable::PATH,
+        module::Printable::METHOD.route().to(printable),
     );
 }
@@ -114,7 +114,7 @@ pub mod instance {
     }
 
     #[derive(Deserialize)]
-    struct InstanceToken {
+    pub(in crate::http::endpoints::jig) struct InstanceToken {
         /// The instance this token is for.
         pub sub: Uuid,
     }
@@ -1,14 +1,19 @@
 use actix_web::{
+    http::header,
     web::{Data, Json, Path, ServiceConfig},
-    HttpResponse,
+    HttpRequest, HttpResponse,
 };
+use core::settings::RuntimeSettings;
 use shared::{
     api::{endpoints, ApiEndpoint},
     domain::{
-        media::{UrlCreatedResponse, WebMediaMetadataResponse, WebMediaUrlCreateRequest},
+        media::{
+            License, Provenance, UrlCreatedResponse, WebMediaMetadataResponse,
+            WebMediaUrlCreateRequest,
+        },
         Base64,
     },
-    media::{FileKind, MediaLibrary, PngImageFile},
+    media::{media_key, preferred_image_file, FileKind, MediaLibrary, PngImageFile},
 };
 use sqlx::PgPool;
 use url::Url;
@@ -31,7 +36,12 @@ pub async fn create(
 
     let url_string = request.url.to_string();
 
-    let (id, kind, status_code) = db::media::create(&pool, &s3, &url_string).await?;
+    let mut provenance = request.provenance.unwrap_or_default();
+    if provenance.source_url.is_none() {
+        provenance.source_url = Some(url_string.clone());
+    }
+
+    let (id, kind, status_code) = db::media::create(&pool, &s3, &url_string, &provenance).await?;
 
     Ok(HttpResponse::build(status_code).json(UrlCreatedResponse {
         id,
@@ -63,19 +73,14 @@ async fn delete_media(
         None => return Ok(HttpResponse::NoContent().finish()),
     };
 
-    let delete = |file_kind| s3.delete_media(MediaLibrary::Web, file_kind, id);
     match kind {
-        MediaKind::PngStickerImage => {
-            futures::future::join3(
-                delete(FileKind::ImagePng(PngImageFile::Original)),
-                delete(FileKind::ImagePng(PngImageFile::Resized)),
-                delete(FileKind::ImagePng(PngImageFile::Thumbnail)),
-            )
-            .await;
+        MediaKind::PngStickerImage | MediaKind::SvgStickerImage => {
+            s3.delete_image_files(MediaLibrary::Web, id).await;
         }
 
         MediaKind::GifAnimation => {
-            delete(FileKind::AnimationGif).await;
+            s3.delete_media(MediaLibrary::Web, FileKind::AnimationGif, id)
+                .await;
         }
 
         kind => return Err(anyhow::anyhow!("unsupported media kind {:?}", kind).into()),
@@ -113,6 +118,9 @@ select id,
        kind as "kind: MediaKind",
        created_at,
        updated_at,
+       license as "license: License",
+       author,
+       source_url,
        array(select media_url from web_media_library_url where media_id = $1) as "urls!"
 from web_media_library
 where id = $1"#,
@@ -132,6 +140,11 @@ where id = $1"#,
             .collect::<Result<Vec<_>, _>>()?,
         created_at: media.created_at,
         updated_at: media.updated_at,
+        provenance: Provenance {
+            license: media.license,
+            author: media.author,
+            source_url: media.source_url,
+        },
     }))
 }
 
@@ -151,6 +164,9 @@ select id,
        kind as "kind: MediaKind",
        created_at,
        updated_at,
+       license as "license: License",
+       author,
+       source_url,
        array(select media_url from web_media_library_url where media_id = id) as "urls!"
 from web_media_library
 where id = (select media_id from web_media_library_url where media_url = $1)
@@ -171,9 +187,46 @@ where id = (select media_id from web_media_library_url where media_url = $1)
             .collect::<Result<Vec<_>, _>>()?,
         created_at: media.created_at,
         updated_at: media.updated_at,
+        provenance: Provenance {
+            license: media.license,
+            author: media.author,
+            source_url: media.source_url,
+        },
     }))
 }
 
+/// Redirect to a file of an image, in the best format the client accepts.
+async fn get_image_file(
+    pool: Data<PgPool>,
+    settings: Data<RuntimeSettings>,
+    path: Path<(MediaLibrary, Uuid, PngImageFile)>,
+    req: HttpRequest,
+) -> Result<HttpResponse, error::NotFound> {
+    let (library, id, file) = path.into_inner();
+
+    let formats = db::media::image_formats(&pool, library, id)
+        .await?
+        .ok_or(error::NotFound::ResourceNotFound)?;
+
+    let accept = req
+        .headers()
+        .get(header::ACCEPT)
+        .and_then(|it| it.to_str().ok());
+
+    let file_kind = preferred_image_file(accept, file, formats.has_webp, formats.is_vector);
+
+    let location = format!(
+        "{}/{}",
+        settings.remote_target().uploads_url(),
+        media_key(library, id, file_kind)
+    );
+
+    Ok(HttpResponse::TemporaryRedirect()
+        .insert_header((header::LOCATION, location))
+        .insert_header((header::VARY, "Accept"))
+        .finish())
+}
+
 pub fn configure(cfg: &mut ServiceConfig) {
     cfg.route(
         endpoints::media::Create::PATH,
@@ -194,5 +247,11 @@ pub fn configure(cfg: &mut ServiceConfig) {
     .route(
         endpoints::media::DeleteUrl::PATH,
         endpoints::media::DeleteUrl::METHOD.route().to(delete_media),
+    )
+    .route(
+        endpoints::media::GetImageFile::PATH,
+        endpoints::media::GetImageFile::METHOD
+            .route()
+            .to(get_image_file),
     );
 }
@@ -13,3 +13,4 @@ pub mod scheduler;
 pub mod search;
 pub mod session;
 pub mod user;
+pub mod webhook;
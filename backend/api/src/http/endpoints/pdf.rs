@@ -18,6 +18,7 @@ pub mod user {
         web::{Data, Json, Path},
         HttpResponse,
     };
+    use core::settings::RuntimeSettings;
     use futures::TryStreamExt;
     use shared::{
         api::{endpoints, ApiEndpoint},
@@ -26,6 +27,7 @@ pub mod user {
                 user::{UserPdf, UserPdfListResponse, UserPdfResponse, UserPdfUploadResponse},
                 PdfId,
             },
+            user::UserMediaGroup,
             CreateResponse,
         },
         media::{FileKind, MediaLibrary},
@@ -35,6 +37,7 @@ pub mod user {
     use crate::{
         db, error,
         extractor::{RequestOrigin, TokenUser},
+        http::endpoints::user,
         service::{s3, storage, GcpAccessKeyStore, ServiceData},
     };
 
@@ -43,7 +46,12 @@ pub mod user {
         db: Data<PgPool>,
         claims: TokenUser,
     ) -> Result<HttpResponse, error::NotFound> {
-        let id = db::pdf::user::create(db.as_ref(), claims.0.user_id).await?;
+        let mut txn = db.begin().await?;
+
+        let id = db::pdf::user::create(&mut txn, claims.0.user_id).await?;
+
+        txn.commit().await?;
+
         Ok(HttpResponse::Created().json(CreateResponse { id }))
     }
 
@@ -52,7 +60,8 @@ pub mod user {
         db: Data<PgPool>,
         gcp_key_store: ServiceData<GcpAccessKeyStore>,
         gcs: ServiceData<storage::Client>,
-        _claims: TokenUser,
+        settings: Data<RuntimeSettings>,
+        claims: TokenUser,
         id: Path<PdfId>,
         origin: RequestOrigin,
         req: Json<<endpoints::pdf::user::Upload as ApiEndpoint>::Req>,
@@ -62,8 +71,9 @@ pub mod user {
         let mut txn = db.begin().await?;
 
         let exists = sqlx::query!(
-        r#"select exists(select 1 from user_pdf_upload where pdf_id = $1 for no key update) as "exists!""#,
-        id.0
+        r#"select exists(select 1 from user_pdf_upload inner join user_pdf_library on id = pdf_id where pdf_id = $1 and user_id = $2 for no key update of user_pdf_upload) as "exists!""#,
+        id.0,
+        claims.0.user_id
     )
             .fetch_one(&mut txn)
             .await?.exists;
@@ -80,6 +90,16 @@ pub mod user {
             }
         }
 
+        user::storage::check_quota(
+            &mut txn,
+            &settings.storage_quota,
+            claims.0.user_id,
+            UserMediaGroup::Pdf,
+            id.0,
+            upload_content_length,
+        )
+        .await?;
+
         let access_token = gcp_key_store.fetch_token().await?.to_owned();
 
         let resp = gcs
@@ -94,8 +114,9 @@ pub mod user {
             .await?;
 
         sqlx::query!(
-        "update user_pdf_upload set uploaded_at = now(), processing_result = null where pdf_id = $1",
-        id.0
+        "update user_pdf_upload set uploaded_at = now(), processing_result = null, file_size = $2 where pdf_id = $1",
+        id.0,
+        upload_content_length as i64,
     )
         .execute(&mut txn)
         .await?;
@@ -120,6 +141,9 @@ pub mod user {
         s3.delete_media(MediaLibrary::User, FileKind::DocumentPdf, pdf.0)
             .await;
 
+        s3.delete_media(MediaLibrary::User, FileKind::DocumentThumbnail, pdf.0)
+            .await;
+
         Ok(HttpResponse::NoContent().finish())
     }
 
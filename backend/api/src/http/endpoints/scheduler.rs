@@ -9,7 +9,7 @@ use crate::{
     error,
     extractor::UserAgent,
     jwk::{IdentityClaims, JwkVerifier},
-    service::{upload::cleaner::UploadCleaner, ServiceData},
+    service::{upload::cleaner::UploadCleaner, webhook, ServiceData},
     translate::GoogleTranslate,
 };
 
@@ -79,6 +79,28 @@ async fn translate_descriptions(
     Ok(HttpResponse::Ok().finish())
 }
 
+async fn webhook_deliveries(
+    webhook_dispatcher: ServiceData<webhook::Dispatcher>,
+    bearer_auth: BearerAuth,
+    jwks: Data<JwkVerifier>,
+    user_agent: UserAgent,
+) -> Result<HttpResponse, error::ServiceSession> {
+    if user_agent
+        .0
+        .map_or(true, |it| it != "Google-Cloud-Scheduler")
+    {
+        return Err(error::ServiceSession::Unauthorized);
+    }
+
+    let _claims: IdentityClaims = jwks
+        .verify_iam_api_invoker_oauth(bearer_auth.token(), 3)
+        .await?;
+
+    webhook_dispatcher.spawn_cron_jobs().await?;
+
+    Ok(HttpResponse::Ok().finish())
+}
+
 pub fn configure(cfg: &mut ServiceConfig) {
     cfg.route(
         "/v1/scheduler/update-algolia",
@@ -92,4 +114,8 @@ pub fn configure(cfg: &mut ServiceConfig) {
         "/v1/scheduler/translate-descriptions",
         method(http::Method::POST).to(translate_descriptions),
     );
+    cfg.route(
+        "/v1/scheduler/webhook-deliveries",
+        method(http::Method::POST).to(webhook_deliveries),
+    );
 }
@@ -1,12 +1,12 @@
 use actix_web::{
-    web::{Data, ServiceConfig},
+    web::{Data, Json, ServiceConfig},
     HttpResponse,
 };
 use chrono::{Duration, Utc};
 use core::settings::RuntimeSettings;
 use shared::{
     api::{endpoints::session, ApiEndpoint},
-    domain::session::{CreateSessionResponse, NewSessionResponse},
+    domain::session::{CreateSecondFactorRequest, CreateSessionResponse, NewSessionResponse},
 };
 use sqlx::PgPool;
 
@@ -14,8 +14,10 @@ use crate::{
     db,
     domain::{NoContentClearAuth, RegistrationStatus},
     error,
-    extractor::{EmailBasicUser, SessionAny, TokenSessionOf},
+    extractor::{EmailBasicUser, SessionAny, TokenSessionOf, TokenUser},
+    http::rate_limit::RateLimiter,
     token::{create_auth_token, SessionMask},
+    totp,
 };
 
 mod oauth;
@@ -67,6 +69,60 @@ async fn create_session(
     Ok(HttpResponse::Created().cookie(cookie).json(response))
 }
 
+/// Pass the second factor for the current session, with either a TOTP code or a recovery code.
+///
+/// Failed attempts count towards a lockout for the user, the same as failed logins.
+async fn create_second_factor(
+    db: Data<PgPool>,
+    rate_limiter: Data<RateLimiter>,
+    user: TokenUser,
+    req: Json<CreateSecondFactorRequest>,
+) -> Result<HttpResponse, error::SecondFactor> {
+    let user_id = user.0.user_id;
+    let lockout_key = format!("second-factor:{}", user_id);
+
+    rate_limiter.check_lockout(&lockout_key).await?;
+
+    let user_totp = db::totp::get(&db, user_id)
+        .await?
+        .filter(|it| it.confirmed)
+        .ok_or(error::SecondFactor::Unauthorized)?;
+
+    let mut txn = db.begin().await?;
+
+    let passed = match req.into_inner() {
+        CreateSecondFactorRequest::Totp { code } => {
+            match totp::verify(
+                &user_totp.secret,
+                &code,
+                Utc::now(),
+                user_totp.last_used_step,
+            ) {
+                Some(step) => db::totp::use_step(&mut txn, user_id, step).await?,
+                None => false,
+            }
+        }
+
+        CreateSecondFactorRequest::RecoveryCode { code } => {
+            db::totp::use_recovery_code(&mut txn, user_id, &totp::hash_recovery_code(&code)).await?
+        }
+    };
+
+    if !passed {
+        txn.rollback().await?;
+        rate_limiter.record_failed_login(&lockout_key).await?;
+        return Err(error::SecondFactor::Unauthorized);
+    }
+
+    db::session::add_mask(&mut txn, &user.0.token, SessionMask::SECOND_FACTOR).await?;
+
+    txn.commit().await?;
+
+    rate_limiter.clear_failed_logins(&lockout_key).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
 /// Logout
 async fn delete_session(
     db: Data<PgPool>,
@@ -88,6 +144,12 @@ pub fn configure(cfg: &mut ServiceConfig) {
         session::Create::PATH,
         session::Create::METHOD.route().to(create_session),
     )
+    .route(
+        session::CreateSecondFactor::PATH,
+        session::CreateSecondFactor::METHOD
+            .route()
+            .to(create_second_factor),
+    )
     .route(
         session::Delete::PATH,
         session::Delete::METHOD.route().to(delete_session),
@@ -14,13 +14,16 @@ use shared::domain::user::{ResetEmailResponse, VerifyResetEmailRequest};
 use shared::{
     api::endpoints::{
         user::{
-            ChangePassword, Create, CreateColor, CreateFont, CreateProfile, Delete, DeleteColor,
-            DeleteFont, GetColors, GetFonts, PatchProfile, Profile, ResetEmail, ResetPassword,
-            UpdateColor, UpdateFont, UserLookup, VerifyEmail, VerifyResetEmail,
+            ChangePassword, ConfirmTotp, Create, CreateColor, CreateFont, CreateProfile,
+            CreateToken, Delete, DeleteColor, DeleteFont, DeleteToken, DeleteTotp, EnrollTotp,
+            GetColors, GetFonts, GetTotp, ListTokens, PatchProfile, Profile,
+            RegenerateRecoveryCodes, ResetEmail, ResetPassword, StorageUsage, UpdateColor,
+            UpdateFont, UserLookup, VerifyEmail, VerifyResetEmail,
         },
         ApiEndpoint,
     },
     domain::{
+        admin::AuditAction,
         image::{ImageId, ImageKind},
         session::{NewSessionResponse, OAuthProvider},
         user::{ChangePasswordRequest, CreateProfileRequest, UserLookupQuery, VerifyEmailRequest},
@@ -36,13 +39,18 @@ use crate::{
     db::{self, user::upsert_profile},
     domain::NoContentClearAuth,
     error,
-    extractor::{SessionCreateProfile, SessionDelete, TokenSessionOf, TokenUser},
+    extractor::{
+        IPAddress, SessionCreateProfile, SessionDelete, TokenSessionOf, TokenUser, UserAgent,
+    },
     service::{mail, s3, ServiceData},
     token::{create_auth_token, SessionMask},
 };
 
 mod color;
 mod font;
+pub(crate) mod storage;
+mod token;
+mod totp;
 
 #[instrument(skip(txn, email_address, mail))]
 async fn send_verification_email(
@@ -488,12 +496,16 @@ async fn create_user_profile_image(
     let data = Arc::new(data);
 
     // process
-    let (original, resized, thumbnail) = actix_web::web::block(move || {
-        let original = image::load_from_memory(&data)?;
-        crate::image_ops::generate_images(&original, ImageKind::Sticker)
-    })
-    .instrument(tracing::info_span!("process image"))
-    .await??;
+    let ((original, resized, thumbnail), webp) =
+        actix_web::web::block(move || -> anyhow::Result<_> {
+            let original = image::load_from_memory(&data)?;
+            Ok((
+                crate::image_ops::generate_images(&original, ImageKind::Sticker)?,
+                crate::image_ops::generate_webp_images(&original, ImageKind::Sticker),
+            ))
+        })
+        .instrument(tracing::info_span!("process image"))
+        .await??;
 
     // upload to ID
     s3.upload_png_images(
@@ -505,13 +517,17 @@ async fn create_user_profile_image(
     )
     .await?;
 
+    s3.upload_webp_images(MediaLibrary::User, profile_image_id.0, webp)
+        .await?;
+
     sqlx::query!(
         //language=SQL
         r#"
 update user_image_upload
 set uploaded_at       = now(),
     processed_at      = now(),
-    processing_result = true
+    processing_result = true,
+    has_webp          = true
 where image_id = $1
 "#,
         profile_image_id.0
@@ -789,14 +805,35 @@ async fn get_profile(
 async fn delete(
     db: Data<PgPool>,
     session: TokenSessionOf<SessionDelete>,
+    ip_address: IPAddress,
+    user_agent: UserAgent,
 ) -> Result<NoContentClearAuth, error::Server> {
+    let mut txn = db.begin().await?;
+
     sqlx::query!(
         r#"delete from "user" where id = $1"#,
         session.claims.user_id
     )
-    .execute(db.as_ref())
+    .execute(&mut txn)
     .await?;
 
+    // the user's data is gone, so there's nothing to keep as `before`.
+    db::audit_log::insert(
+        &mut txn,
+        db::audit_log::NewEntry {
+            actor_id: Some(session.claims.user_id),
+            action: AuditAction::DeleteUser,
+            target_id: Some(session.claims.user_id),
+            before: None,
+            after: None,
+            ip_address: ip_address.0,
+            user_agent: user_agent.0,
+        },
+    )
+    .await?;
+
+    txn.commit().await?;
+
     Ok(NoContentClearAuth)
 }
 
@@ -993,5 +1030,37 @@ pub fn configure(cfg: &mut ServiceConfig) {
         .route(
             DeleteFont::PATH,
             DeleteFont::METHOD.route().to(font::delete),
+        )
+        .route(GetTotp::PATH, GetTotp::METHOD.route().to(totp::get))
+        .route(
+            EnrollTotp::PATH,
+            EnrollTotp::METHOD.route().to(totp::enroll),
+        )
+        .route(
+            ConfirmTotp::PATH,
+            ConfirmTotp::METHOD.route().to(totp::confirm),
+        )
+        .route(
+            DeleteTotp::PATH,
+            DeleteTotp::METHOD.route().to(totp::delete),
+        )
+        .route(
+            RegenerateRecoveryCodes::PATH,
+            RegenerateRecoveryCodes::METHOD
+                .route()
+                .to(totp::regenerate_recovery_codes),
+        )
+        .route(ListTokens::PATH, ListTokens::METHOD.route().to(token::list))
+        .route(
+            CreateToken::PATH,
+            CreateToken::METHOD.route().to(token::create),
+        )
+        .route(
+            DeleteToken::PATH,
+            DeleteToken::METHOD.route().to(token::delete),
+        )
+        .route(
+            StorageUsage::PATH,
+            StorageUsage::METHOD.route().to(storage::get),
         );
 }
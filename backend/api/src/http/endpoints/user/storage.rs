@@ -0,0 +1,61 @@
+use crate::{db, error, extractor::TokenUser};
+
+use actix_web::web::{Data, Json};
+use core::settings::{RuntimeSettings, StorageQuotaSettings};
+use shared::{
+    api::endpoints::{user::StorageUsage, ApiEndpoint},
+    domain::user::{MediaGroupUsage, StorageUsageResponse, UserMediaGroup},
+};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+pub async fn get(
+    db: Data<PgPool>,
+    settings: Data<RuntimeSettings>,
+    claims: TokenUser,
+) -> Result<Json<<StorageUsage as ApiEndpoint>::Res>, error::Server> {
+    let groups: Vec<_> = db::storage_usage::get(&db, claims.0.user_id)
+        .await?
+        .into_iter()
+        .map(|it| MediaGroupUsage {
+            group: it.group,
+            used_bytes: it.used_bytes,
+            file_count: it.file_count,
+            quota_bytes: settings.storage_quota.for_group(it.group),
+        })
+        .collect();
+
+    Ok(Json(StorageUsageResponse {
+        used_bytes: groups.iter().map(|it| it.used_bytes).sum(),
+        groups,
+    }))
+}
+
+/// Checks that uploading `file_size` bytes as the file `id` won't take the user's `group` over its quota.
+///
+/// The file's previous upload (if any) doesn't count, as it's about to be replaced.
+///
+/// Other uploads by the user wait for `txn` to finish, so that the new file size has to be written in it.
+pub(crate) async fn check_quota(
+    txn: &mut PgConnection,
+    quota: &StorageQuotaSettings,
+    user_id: Uuid,
+    group: UserMediaGroup,
+    id: Uuid,
+    file_size: usize,
+) -> Result<(), error::Upload> {
+    let quota = match quota.for_group(group) {
+        Some(quota) => quota,
+        None => return Ok(()),
+    };
+
+    db::storage_usage::lock(&mut *txn, user_id).await?;
+
+    let used_bytes = db::storage_usage::used_bytes(txn, user_id, group, id).await?;
+
+    if used_bytes.saturating_add(file_size as u64) > quota {
+        return Err(error::Upload::QuotaExceeded);
+    }
+
+    Ok(())
+}
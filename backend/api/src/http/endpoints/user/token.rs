@@ -0,0 +1,110 @@
+use crate::{
+    db, error,
+    extractor::{SessionGeneral, TokenSessionOf},
+    token,
+};
+
+use actix_web::{
+    web::{Data, Json, Path},
+    HttpResponse,
+};
+use chrono::{Duration, Utc};
+use core::settings::RuntimeSettings;
+use shared::{
+    api::endpoints::{user::ListTokens, ApiEndpoint},
+    domain::user::{CreateTokenRequest, CreateTokenResponse, ListTokensResponse},
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// How far in the future a token's expiry can be.
+const MAX_TOKEN_LIFETIME_DAYS: i64 = 366;
+
+pub async fn create(
+    db: Data<PgPool>,
+    settings: Data<RuntimeSettings>,
+    session: TokenSessionOf<SessionGeneral>,
+    req: Json<CreateTokenRequest>,
+) -> Result<HttpResponse, error::PersonalAccessToken> {
+    let req = req.into_inner();
+    let user_id = session.claims.user_id;
+
+    let name = req.name.trim();
+    if name.is_empty() {
+        return Err(error::PersonalAccessToken::BadRequest(
+            "Token name can't be empty",
+        ));
+    }
+
+    let now = Utc::now();
+    if req.expires_at <= now {
+        return Err(error::PersonalAccessToken::BadRequest(
+            "Token expiry must be in the future",
+        ));
+    }
+
+    if req.expires_at > now + Duration::days(MAX_TOKEN_LIFETIME_DAYS) {
+        return Err(error::PersonalAccessToken::BadRequest(
+            "Token expiry can't be more than a year away",
+        ));
+    }
+
+    let mut scopes: Vec<i16> = req.scopes.iter().map(|it| *it as i16).collect();
+    scopes.sort_unstable();
+    scopes.dedup();
+
+    let access =
+        db::personal_access_token::scope_access(&db, user_id, &session.claims.token, &scopes)
+            .await?;
+
+    if !access.has_scopes {
+        return Err(error::PersonalAccessToken::Forbidden);
+    }
+
+    // tokens skip the second factor when they're used, so it has to be passed when they're created.
+    let second_factor_required = !scopes.is_empty()
+        && (access.second_factor_enrolled
+            || settings
+                .second_factor_required_scopes
+                .iter()
+                .any(|required| scopes.contains(&(*required as i16))));
+
+    if second_factor_required && !access.second_factor_passed {
+        return Err(error::PersonalAccessToken::SecondFactorRequired);
+    }
+
+    let token = token::generate_personal_access_token();
+
+    let id = db::personal_access_token::create(
+        &db,
+        user_id,
+        name,
+        &token::hash_personal_access_token(&token),
+        &scopes,
+        req.expires_at,
+    )
+    .await?;
+
+    Ok(HttpResponse::Created().json(CreateTokenResponse { id, token }))
+}
+
+pub async fn list(
+    db: Data<PgPool>,
+    session: TokenSessionOf<SessionGeneral>,
+) -> Result<Json<<ListTokens as ApiEndpoint>::Res>, error::Server> {
+    let tokens = db::personal_access_token::list(&db, session.claims.user_id).await?;
+
+    Ok(Json(ListTokensResponse { tokens }))
+}
+
+pub async fn delete(
+    db: Data<PgPool>,
+    session: TokenSessionOf<SessionGeneral>,
+    path: Path<Uuid>,
+) -> Result<HttpResponse, error::PersonalAccessToken> {
+    if !db::personal_access_token::revoke(&db, session.claims.user_id, path.into_inner()).await? {
+        return Err(error::PersonalAccessToken::ResourceNotFound);
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
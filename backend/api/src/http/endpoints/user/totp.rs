@@ -0,0 +1,160 @@
+use crate::{
+    db, error,
+    extractor::{SessionGeneral, SessionSecondFactor, TokenSessionOf},
+    token::SessionMask,
+    totp,
+};
+
+use actix_web::{
+    web::{Data, Json},
+    HttpResponse,
+};
+use chrono::Utc;
+use shared::{
+    api::endpoints::{user::GetTotp, ApiEndpoint},
+    domain::user::{
+        RecoveryCodesResponse, TotpConfirmRequest, TotpEnrollResponse, TotpStatusResponse,
+    },
+};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+async fn replace_recovery_codes(
+    txn: &mut PgConnection,
+    user_id: Uuid,
+) -> sqlx::Result<RecoveryCodesResponse> {
+    let recovery_codes = totp::generate_recovery_codes();
+
+    let hashes: Vec<_> = recovery_codes
+        .iter()
+        .map(|code| totp::hash_recovery_code(code))
+        .collect();
+
+    db::totp::replace_recovery_codes(txn, user_id, &hashes).await?;
+
+    Ok(RecoveryCodesResponse { recovery_codes })
+}
+
+pub async fn get(
+    db: Data<PgPool>,
+    session: TokenSessionOf<SessionGeneral>,
+) -> Result<Json<<GetTotp as ApiEndpoint>::Res>, error::Server> {
+    let user_id = session.claims.user_id;
+
+    let enabled = db::totp::get(&db, user_id)
+        .await?
+        .map_or(false, |it| it.confirmed);
+
+    let recovery_codes_remaining = match enabled {
+        true => db::totp::recovery_codes_remaining(&db, user_id).await? as u32,
+        false => 0,
+    };
+
+    Ok(Json(TotpStatusResponse {
+        enabled,
+        recovery_codes_remaining,
+    }))
+}
+
+pub async fn enroll(
+    db: Data<PgPool>,
+    session: TokenSessionOf<SessionGeneral>,
+) -> Result<HttpResponse, error::SecondFactor> {
+    let user_id = session.claims.user_id;
+
+    let email = db::user::get_email(&db, user_id)
+        .await?
+        .ok_or(error::SecondFactor::NotFound)?;
+
+    let secret = totp::generate_secret();
+
+    let mut txn = db.begin().await?;
+
+    if !db::totp::enroll(&mut txn, user_id, &secret).await? {
+        return Err(error::SecondFactor::Conflict);
+    }
+
+    txn.commit().await?;
+
+    Ok(HttpResponse::Created().json(TotpEnrollResponse {
+        secret: totp::encode_secret(&secret),
+        uri: totp::provisioning_uri(&secret, &email),
+    }))
+}
+
+pub async fn confirm(
+    db: Data<PgPool>,
+    session: TokenSessionOf<SessionGeneral>,
+    req: Json<TotpConfirmRequest>,
+) -> Result<Json<RecoveryCodesResponse>, error::SecondFactor> {
+    let user_id = session.claims.user_id;
+
+    let user_totp = db::totp::get(&db, user_id)
+        .await?
+        .ok_or(error::SecondFactor::NotFound)?;
+
+    if user_totp.confirmed {
+        return Err(error::SecondFactor::Conflict);
+    }
+
+    let step = totp::verify(
+        &user_totp.secret,
+        &req.code,
+        Utc::now(),
+        user_totp.last_used_step,
+    )
+    .ok_or(error::SecondFactor::InvalidCode)?;
+
+    let mut txn = db.begin().await?;
+
+    if !db::totp::use_step(&mut txn, user_id, step).await? {
+        return Err(error::SecondFactor::InvalidCode);
+    }
+
+    db::totp::confirm(&mut txn, user_id).await?;
+
+    let response = replace_recovery_codes(&mut txn, user_id).await?;
+
+    // the user just proved they have the second factor, no need to ask again for this session.
+    db::session::add_mask(&mut txn, &session.claims.token, SessionMask::SECOND_FACTOR).await?;
+
+    txn.commit().await?;
+
+    Ok(Json(response))
+}
+
+pub async fn delete(
+    db: Data<PgPool>,
+    session: TokenSessionOf<SessionSecondFactor>,
+) -> Result<HttpResponse, error::Server> {
+    let mut txn = db.begin().await?;
+
+    db::totp::delete(&mut txn, session.claims.user_id).await?;
+
+    txn.commit().await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub async fn regenerate_recovery_codes(
+    db: Data<PgPool>,
+    session: TokenSessionOf<SessionSecondFactor>,
+) -> Result<Json<RecoveryCodesResponse>, error::SecondFactor> {
+    let user_id = session.claims.user_id;
+
+    let enabled = db::totp::get(&db, user_id)
+        .await?
+        .map_or(false, |it| it.confirmed);
+
+    if !enabled {
+        return Err(error::SecondFactor::NotFound);
+    }
+
+    let mut txn = db.begin().await?;
+
+    let response = replace_recovery_codes(&mut txn, user_id).await?;
+
+    txn.commit().await?;
+
+    Ok(Json(response))
+}
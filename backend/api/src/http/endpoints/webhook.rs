@@ -0,0 +1,176 @@
+use actix_web::{
+    web::{Data, Json, Path, ServiceConfig},
+    HttpResponse,
+};
+use core::settings::RuntimeSettings;
+use shared::{
+    api::{endpoints::webhook, ApiEndpoint},
+    domain::{
+        webhook::{CreateWebhookRequest, ListWebhooksResponse, UpdateWebhookRequest, WebhookId},
+        CreateResponse,
+    },
+};
+use sqlx::PgPool;
+use url::{Host, Url};
+
+use crate::{db, error, extractor::TokenUser, service::webhook::is_private_ip};
+
+const MIN_SECRET_LEN: usize = 16;
+
+/// Deliveries are sent from inside our network, so outside of local development
+/// they have to go to public `https` URLs.
+///
+/// Names are only resolved (and checked again) when a delivery is sent, see [`Dispatcher`](crate::service::webhook::Dispatcher).
+fn validate_url(url: &str, local: bool) -> Result<(), error::Webhook> {
+    let url = Url::parse(url).map_err(|_| error::Webhook::BadRequest("Invalid URL"))?;
+
+    match (url.scheme(), local) {
+        ("https", _) | ("http", true) => {}
+        _ => return Err(error::Webhook::BadRequest("URL must use https")),
+    }
+
+    if local {
+        return Ok(());
+    }
+
+    let private = match url.host() {
+        Some(Host::Domain(domain)) => {
+            domain.eq_ignore_ascii_case("localhost") || domain.ends_with(".internal")
+        }
+        Some(Host::Ipv4(ip)) => is_private_ip(ip.into()),
+        Some(Host::Ipv6(ip)) => is_private_ip(ip.into()),
+        None => true,
+    };
+
+    if private {
+        return Err(error::Webhook::BadRequest(
+            "URL must point to a public host",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_secret(secret: &str) -> Result<(), error::Webhook> {
+    if secret.len() < MIN_SECRET_LEN {
+        return Err(error::Webhook::BadRequest(
+            "Secret must be at least 16 characters",
+        ));
+    }
+
+    Ok(())
+}
+
+async fn create(
+    db: Data<PgPool>,
+    settings: Data<RuntimeSettings>,
+    claims: TokenUser,
+    req: Json<CreateWebhookRequest>,
+) -> Result<HttpResponse, error::Webhook> {
+    let req = req.into_inner();
+    let user_id = claims.0.user_id;
+
+    validate_url(&req.url, settings.is_local())?;
+    validate_secret(&req.secret)?;
+
+    if req.events.is_empty() {
+        return Err(error::Webhook::BadRequest("At least one event is required"));
+    }
+
+    let organization = req
+        .organization
+        .as_deref()
+        .map(str::trim)
+        .filter(|it| !it.is_empty());
+
+    if organization.is_some() && !db::jig::is_admin(&db, user_id).await? {
+        return Err(error::Webhook::Forbidden);
+    }
+
+    let id = db::webhook::create(
+        &db,
+        user_id,
+        organization,
+        &req.url,
+        &req.secret,
+        &req.events,
+    )
+    .await?;
+
+    Ok(HttpResponse::Created().json(CreateResponse { id }))
+}
+
+async fn list(
+    db: Data<PgPool>,
+    claims: TokenUser,
+) -> Result<Json<<webhook::List as ApiEndpoint>::Res>, error::Server> {
+    let webhooks = db::webhook::list(&db, claims.0.user_id).await?;
+
+    Ok(Json(ListWebhooksResponse { webhooks }))
+}
+
+async fn update(
+    db: Data<PgPool>,
+    settings: Data<RuntimeSettings>,
+    claims: TokenUser,
+    path: Path<WebhookId>,
+    req: Json<UpdateWebhookRequest>,
+) -> Result<HttpResponse, error::Webhook> {
+    let req = req.into_inner();
+
+    if let Some(url) = &req.url {
+        validate_url(url, settings.is_local())?;
+    }
+
+    if let Some(secret) = &req.secret {
+        validate_secret(secret)?;
+    }
+
+    if req.events.as_ref().map_or(false, Vec::is_empty) {
+        return Err(error::Webhook::BadRequest("At least one event is required"));
+    }
+
+    let exists = db::webhook::update(
+        &db,
+        claims.0.user_id,
+        path.into_inner(),
+        req.url.as_deref(),
+        req.secret.as_deref(),
+        req.events.as_deref(),
+    )
+    .await?;
+
+    if !exists {
+        return Err(error::Webhook::ResourceNotFound);
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+async fn delete(
+    db: Data<PgPool>,
+    claims: TokenUser,
+    path: Path<WebhookId>,
+) -> Result<HttpResponse, error::Webhook> {
+    if !db::webhook::delete(&db, claims.0.user_id, path.into_inner()).await? {
+        return Err(error::Webhook::ResourceNotFound);
+    }
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+pub fn configure(cfg: &mut ServiceConfig) {
+    cfg.route(
+        webhook::Create::PATH,
+        webhook::Create::METHOD.route().to(create),
+    )
+    .route(webhook::List::PATH, webhook::List::METHOD.route().to(list))
+    .route(
+        webhook::Update::PATH,
+        webhook::Update::METHOD.route().to(update),
+    )
+    .route(
+        webhook::Delete::PATH,
+        webhook::Delete::METHOD.route().to(delete),
+    );
+}
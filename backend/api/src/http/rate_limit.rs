@@ -0,0 +1,412 @@
+//! Throttling for the auth and kids-code endpoints.
+//!
+//! Requests to the endpoints in [`RULES`] are counted per IP address and, where the request names
+//! one, per account (email). Once a counter exceeds its limit within the configured window the
+//! request is rejected with `429 - Too Many Requests` and a `Retry-After` header.
+//!
+//! Failed logins are counted separately, see [`RateLimiter::check_lockout`].
+
+use std::{
+    collections::HashMap,
+    rc::Rc,
+    sync::{Arc, Mutex},
+};
+
+use actix_http::Payload;
+use actix_service::{forward_ready, Service, Transform};
+use actix_web::{
+    dev::{ServiceRequest, ServiceResponse},
+    web::BytesMut,
+    HttpMessage,
+};
+use actix_web_httpauth::headers::authorization::{Authorization, Basic};
+use chrono::{DateTime, Duration, Utc};
+use core::{
+    config::JSON_BODY_LIMIT,
+    settings::{RateLimitSettings, RateLimitStore},
+};
+use futures::{
+    future::{ready, LocalBoxFuture, Ready},
+    StreamExt,
+};
+use rand::Rng;
+use shared::api::{
+    endpoints::{jig::player, session, user},
+    ApiEndpoint, Method,
+};
+use sqlx::PgPool;
+
+use crate::{db, error};
+
+/// Once the in-memory store holds this many windows, expired ones get dropped.
+const MEMORY_PRUNE_THRESHOLD: usize = 10_000;
+
+/// Roughly one in this many hits against the postgres store deletes expired windows.
+const POSTGRES_PRUNE_CHANCE: u32 = 100;
+
+/// How the account for a throttled request is identified.
+#[derive(Copy, Clone)]
+enum AccountKey {
+    /// The request doesn't belong to an account.
+    None,
+
+    /// The email in the `Basic` authorization header.
+    BasicAuth,
+
+    /// The email in the JSON body.
+    JsonEmail,
+}
+
+#[derive(Copy, Clone)]
+struct Rule {
+    method: Method,
+    path: &'static str,
+    account: AccountKey,
+}
+
+const RULES: &[Rule] = &[
+    Rule {
+        method: <session::Create as ApiEndpoint>::METHOD,
+        path: <session::Create as ApiEndpoint>::PATH,
+        account: AccountKey::BasicAuth,
+    },
+    Rule {
+        method: <user::ResetPassword as ApiEndpoint>::METHOD,
+        path: <user::ResetPassword as ApiEndpoint>::PATH,
+        account: AccountKey::JsonEmail,
+    },
+    Rule {
+        method: <user::VerifyEmail as ApiEndpoint>::METHOD,
+        path: <user::VerifyEmail as ApiEndpoint>::PATH,
+        account: AccountKey::JsonEmail,
+    },
+    Rule {
+        method: <session::CreateSecondFactor as ApiEndpoint>::METHOD,
+        path: <session::CreateSecondFactor as ApiEndpoint>::PATH,
+        account: AccountKey::None,
+    },
+    // kids only have a 4 digit code to go on, so this is the one that's trivially enumerable.
+    Rule {
+        method: <player::instance::Create as ApiEndpoint>::METHOD,
+        path: <player::instance::Create as ApiEndpoint>::PATH,
+        account: AccountKey::None,
+    },
+];
+
+fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+fn email_from_basic_auth(req: &ServiceRequest) -> Option<String> {
+    let basic = req.get_header::<Authorization<Basic>>()?.into_scheme();
+
+    Some(normalize_email(basic.user_id()))
+}
+
+fn email_from_json(body: &[u8]) -> Option<String> {
+    let value: serde_json::Value = serde_json::from_slice(body).ok()?;
+
+    // `ResetPasswordRequest` has the email at the top level, `VerifyEmailRequest::Resend` nests it.
+    let email = value
+        .get("email")
+        .or_else(|| value.get("resend")?.get("email"))?;
+
+    email.as_str().map(normalize_email)
+}
+
+/// Reads the (size limited) body out of `req` and puts it back so that the handler can still use it.
+async fn peek_body(req: &mut ServiceRequest) -> Option<BytesMut> {
+    let mut payload = req.take_payload();
+    let mut body = BytesMut::new();
+    let mut complete = true;
+
+    while let Some(chunk) = payload.next().await {
+        let chunk = match chunk {
+            Ok(chunk) => chunk,
+            Err(_) => {
+                complete = false;
+                break;
+            }
+        };
+
+        body.extend_from_slice(&chunk);
+
+        if body.len() as u64 > JSON_BODY_LIMIT {
+            complete = false;
+            break;
+        }
+    }
+
+    let (_, mut restored) = actix_http::h1::Payload::create(true);
+    restored.unread_data(body.clone().freeze());
+    req.set_payload(Payload::from(restored));
+
+    complete.then(|| body)
+}
+
+struct Window {
+    hits: u32,
+    expires_at: DateTime<Utc>,
+}
+
+#[derive(Clone)]
+enum Store {
+    Memory(Arc<Mutex<HashMap<String, Window>>>),
+    Postgres(PgPool),
+}
+
+/// Keeps track of fixed-window counters for rate limiting.
+#[derive(Clone)]
+pub struct RateLimiter {
+    settings: RateLimitSettings,
+    store: Store,
+}
+
+impl RateLimiter {
+    pub fn new(settings: RateLimitSettings, db: PgPool) -> Self {
+        let store = match settings.store {
+            RateLimitStore::Memory => Store::Memory(Arc::new(Mutex::new(HashMap::new()))),
+            RateLimitStore::Postgres => Store::Postgres(db),
+        };
+
+        Self { settings, store }
+    }
+
+    /// Records a hit against `key`, returning the hits in the current window and when it expires.
+    async fn hit(&self, key: &str, window: Duration) -> anyhow::Result<(u32, DateTime<Utc>)> {
+        match &self.store {
+            Store::Memory(windows) => {
+                let mut windows = windows.lock().expect("rate limit store poisoned");
+                let now = Utc::now();
+
+                if windows.len() >= MEMORY_PRUNE_THRESHOLD {
+                    windows.retain(|_, it| it.expires_at > now);
+                }
+
+                let entry = windows.entry(key.to_owned()).or_insert(Window {
+                    hits: 0,
+                    expires_at: now + window,
+                });
+
+                if entry.expires_at <= now {
+                    entry.hits = 0;
+                    entry.expires_at = now + window;
+                }
+
+                entry.hits += 1;
+
+                Ok((entry.hits, entry.expires_at))
+            }
+
+            Store::Postgres(db) => {
+                if rand::thread_rng().gen_range(0..POSTGRES_PRUNE_CHANCE) == 0 {
+                    db::rate_limit::prune(db).await?;
+                }
+
+                let (hits, expires_at) = db::rate_limit::hit(db, key, window.num_seconds()).await?;
+
+                Ok((hits as u32, expires_at))
+            }
+        }
+    }
+
+    /// Gets the hits in the current window for `key` and when it expires, if there is one.
+    async fn get(&self, key: &str) -> anyhow::Result<Option<(u32, DateTime<Utc>)>> {
+        match &self.store {
+            Store::Memory(windows) => {
+                let windows = windows.lock().expect("rate limit store poisoned");
+
+                Ok(windows
+                    .get(key)
+                    .filter(|it| it.expires_at > Utc::now())
+                    .map(|it| (it.hits, it.expires_at)))
+            }
+
+            Store::Postgres(db) => Ok(db::rate_limit::get(db, key)
+                .await?
+                .map(|(hits, expires_at)| (hits as u32, expires_at))),
+        }
+    }
+
+    async fn clear(&self, key: &str) -> anyhow::Result<()> {
+        match &self.store {
+            Store::Memory(windows) => {
+                windows
+                    .lock()
+                    .expect("rate limit store poisoned")
+                    .remove(key);
+
+                Ok(())
+            }
+
+            Store::Postgres(db) => Ok(db::rate_limit::clear(db, key).await?),
+        }
+    }
+
+    /// Counts a request against `key`, failing once there have been more than `limit` in the window.
+    async fn check(&self, key: &str, limit: u32) -> Result<(), error::RateLimit> {
+        let (hits, expires_at) = self.hit(key, self.settings.window).await?;
+
+        if hits > limit {
+            return Err(error::RateLimit::TooManyRequests(expires_at - Utc::now()));
+        }
+
+        Ok(())
+    }
+
+    async fn check_request(
+        &self,
+        rule: &Rule,
+        ip_address: Option<&str>,
+        email: Option<&str>,
+    ) -> Result<(), error::RateLimit> {
+        if let Some(ip_address) = ip_address {
+            let key = format!("{} {}:ip:{}", rule.method.as_str(), rule.path, ip_address);
+            self.check(&key, self.settings.ip_limit).await?;
+        }
+
+        if let Some(email) = email {
+            let key = format!("{} {}:account:{}", rule.method.as_str(), rule.path, email);
+            self.check(&key, self.settings.account_limit).await?;
+        }
+
+        Ok(())
+    }
+
+    fn failed_login_key(email: &str) -> String {
+        format!("failed-login:{}", normalize_email(email))
+    }
+
+    /// Fails if `email` (or any other account identifier) has had too many failed logins, until the lockout expires.
+    ///
+    /// The lockout window starts at the first failed login, and is cleared by a successful one.
+    pub async fn check_lockout(&self, email: &str) -> Result<(), error::RateLimit> {
+        if !self.settings.enabled {
+            return Ok(());
+        }
+
+        match self.get(&Self::failed_login_key(email)).await? {
+            Some((hits, expires_at)) if hits >= self.settings.lockout_attempts => {
+                Err(error::RateLimit::TooManyRequests(expires_at - Utc::now()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub async fn record_failed_login(&self, email: &str) -> Result<(), error::RateLimit> {
+        if !self.settings.enabled {
+            return Ok(());
+        }
+
+        let (hits, _) = self
+            .hit(
+                &Self::failed_login_key(email),
+                self.settings.lockout_duration,
+            )
+            .await?;
+
+        if hits == self.settings.lockout_attempts {
+            log::warn!("Locking out login after {} failed attempts", hits);
+        }
+
+        Ok(())
+    }
+
+    pub async fn clear_failed_logins(&self, email: &str) -> Result<(), error::RateLimit> {
+        if !self.settings.enabled {
+            return Ok(());
+        }
+
+        self.clear(&Self::failed_login_key(email)).await?;
+
+        Ok(())
+    }
+}
+
+/// Middleware that applies a [`RateLimiter`] to the endpoints in [`RULES`].
+pub struct RateLimit(RateLimiter);
+
+impl RateLimit {
+    pub fn new(limiter: RateLimiter) -> Self {
+        Self(limiter)
+    }
+}
+
+impl<S> Transform<S, ServiceRequest> for RateLimit
+where
+    S: Service<ServiceRequest, Response = ServiceResponse, Error = actix_web::Error> + 'static,
+{
+    type Response = ServiceResponse;
+    type Error = actix_web::Error;
+    type Transform = RateLimitMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RateLimitMiddleware {
+            service: Rc::new(service),
+            limiter: self.0.clone(),
+        }))
+    }
+}
+
+pub struct RateLimitMiddleware<S> {
+    service: Rc<S>,
+    limiter: RateLimiter,
+}
+
+impl<S> Service<ServiceRequest> for RateLimitMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse, Error = actix_web::Error> + 'static,
+{
+    type Response = ServiceResponse;
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let rule = match self.limiter.settings.enabled {
+            true => RULES
+                .iter()
+                .find(|rule| {
+                    req.method().as_str() == rule.method.as_str()
+                        && req.match_pattern().as_deref() == Some(rule.path)
+                })
+                .copied(),
+            false => None,
+        };
+
+        let rule = match rule {
+            Some(rule) => rule,
+            None => return Box::pin(self.service.call(req)),
+        };
+
+        let service = Rc::clone(&self.service);
+        let limiter = self.limiter.clone();
+
+        Box::pin(async move {
+            let ip_address = req
+                .connection_info()
+                .realip_remote_addr()
+                .map(ToOwned::to_owned);
+
+            let email = match rule.account {
+                AccountKey::None => None,
+                AccountKey::BasicAuth => email_from_basic_auth(&req),
+                AccountKey::JsonEmail => peek_body(&mut req)
+                    .await
+                    .and_then(|body| email_from_json(&body)),
+            };
+
+            if let Err(e) = limiter
+                .check_request(&rule, ip_address.as_deref(), email.as_deref())
+                .await
+            {
+                return Ok(req.error_response(e));
+            }
+
+            service.call(req).await
+        })
+    }
+}
@@ -5,6 +5,9 @@ use image::{
 use shared::domain::{animation::AnimationKind, audio::AudioKind, image::ImageKind};
 use shared::media::MediaKind as SharedMediaKind;
 
+pub mod animation;
+pub mod svg;
+
 /// Kinds of media used with the web media library
 #[repr(i16)]
 #[derive(Copy, Clone, Debug, sqlx::Type)]
@@ -23,6 +26,9 @@ pub enum MediaKind {
 
     /// Media is a Mp3, and Audio
     Mp3Audio = 4,
+
+    /// Media is a Svg, and a Sticker Image
+    SvgStickerImage = 5,
 }
 
 impl MediaKind {
@@ -33,12 +39,17 @@ impl MediaKind {
             Self::GifAnimation => SharedMediaKind::Animation(AnimationKind::Gif),
             Self::SpritesheetAnimation => SharedMediaKind::Animation(AnimationKind::Spritesheet),
             Self::Mp3Audio => SharedMediaKind::Audio(AudioKind::Mp3),
+            Self::SvgStickerImage => SharedMediaKind::Image(ImageKind::VectorSticker),
         }
     }
 }
 
 // todo: use a better method for this
 pub fn detect_image_kind(data: &[u8]) -> anyhow::Result<MediaKind> {
+    if svg::is_svg(data) {
+        return Ok(MediaKind::SvgStickerImage);
+    }
+
     let decoder = GifDecoder::new(&*data);
 
     let frames = match decoder {
@@ -54,37 +65,54 @@ pub fn detect_image_kind(data: &[u8]) -> anyhow::Result<MediaKind> {
     }
 }
 
-pub fn regenerate_images(
-    original: &DynamicImage,
-    kind: ImageKind,
-) -> anyhow::Result<(Vec<u8>, Vec<u8>)> {
-    let resized = {
-        let (width, height) = kind.size();
-        let new_image = match kind {
-            ImageKind::Canvas => original.resize_exact(width, height, FilterType::Nearest),
+/// Quality used for lossy WebP derivatives (0-100).
+const WEBP_QUALITY: f32 = 80.0;
 
-            ImageKind::Sticker if (width >= original.width() && height >= original.height()) => {
-                original.clone()
-            }
+fn resize(original: &DynamicImage, kind: ImageKind) -> DynamicImage {
+    let (width, height) = kind.size();
+    match kind {
+        ImageKind::Canvas => original.resize_exact(width, height, FilterType::Nearest),
 
-            ImageKind::Sticker => original.resize(width, height, FilterType::Nearest),
+        ImageKind::Sticker | ImageKind::VectorSticker
+            if (width >= original.width() && height >= original.height()) =>
+        {
+            original.clone()
+        }
 
-            ImageKind::UserProfile => original.resize(width, height, FilterType::Nearest),
-        };
+        ImageKind::Sticker | ImageKind::VectorSticker => {
+            original.resize(width, height, FilterType::Nearest)
+        }
 
-        let mut buffer = Vec::new();
-        new_image.write_to(&mut buffer, ImageOutputFormat::Png)?;
-        buffer
-    };
+        ImageKind::UserProfile => original.resize(width, height, FilterType::Nearest),
+    }
+}
 
-    let thumbnail = {
-        let mut buffer = Vec::new();
-        let (width, height) = ImageKind::THUMBNAIL_SIZE;
-        original
-            .thumbnail(width, height)
-            .write_to(&mut buffer, ImageOutputFormat::Png)?;
-        buffer
-    };
+pub fn thumbnail(original: &DynamicImage) -> DynamicImage {
+    let (width, height) = ImageKind::THUMBNAIL_SIZE;
+    original.thumbnail(width, height)
+}
+
+pub fn encode_png(image: &DynamicImage) -> anyhow::Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    image.write_to(&mut buffer, ImageOutputFormat::Png)?;
+    Ok(buffer)
+}
+
+pub fn encode_webp(image: &DynamicImage) -> Vec<u8> {
+    let image = image.to_rgba8();
+    let (width, height) = image.dimensions();
+
+    webp::Encoder::from_rgba(&image, width, height)
+        .encode(WEBP_QUALITY)
+        .to_vec()
+}
+
+pub fn regenerate_images(
+    original: &DynamicImage,
+    kind: ImageKind,
+) -> anyhow::Result<(Vec<u8>, Vec<u8>)> {
+    let resized = encode_png(&resize(original, kind))?;
+    let thumbnail = encode_png(&thumbnail(original))?;
 
     Ok((resized, thumbnail))
 }
@@ -95,15 +123,57 @@ pub fn generate_images(
 ) -> anyhow::Result<(Vec<u8>, Vec<u8>, Vec<u8>)> {
     let (resized, thumbnail) = regenerate_images(original, kind)?;
 
-    let original = {
-        let mut buffer = Vec::new();
-        original.write_to(&mut buffer, ImageOutputFormat::Png)?;
-        buffer
-    };
+    let original = encode_png(original)?;
 
     Ok((original, resized, thumbnail))
 }
 
+/// WebP encoded versions of an image's files, served to clients that accept them.
+pub struct WebpImages {
+    pub original: Vec<u8>,
+    pub resized: Vec<u8>,
+    pub thumbnail: Vec<u8>,
+}
+
+pub fn generate_webp_images(original: &DynamicImage, kind: ImageKind) -> WebpImages {
+    WebpImages {
+        original: encode_webp(original),
+        resized: encode_webp(&resize(original, kind)),
+        thumbnail: encode_webp(&thumbnail(original)),
+    }
+}
+
+/// Width of the grid an image is shrunk to for hashing, there's one more column than bits per row
+/// as each bit compares two neighbouring pixels.
+const HASH_WIDTH: u32 = 9;
+const HASH_HEIGHT: u32 = 8;
+
+/// Computes a 64 bit perceptual (difference) hash of an image.
+///
+/// Unlike a cryptographic hash, similar looking images (re-encoded, resized, slightly edited) get similar hashes,
+/// use [`perceptual_hash_distance`] to compare them.
+pub fn perceptual_hash(image: &DynamicImage) -> i64 {
+    let image = image
+        .resize_exact(HASH_WIDTH, HASH_HEIGHT, FilterType::Triangle)
+        .to_luma8();
+
+    let mut hash = 0_u64;
+
+    for y in 0..HASH_HEIGHT {
+        for x in 0..(HASH_WIDTH - 1) {
+            let brighter = image.get_pixel(x, y)[0] < image.get_pixel(x + 1, y)[0];
+            hash = (hash << 1) | (brighter as u64);
+        }
+    }
+
+    hash as i64
+}
+
+/// The number of bits that differ between two perceptual hashes (0 = the same image, 64 = nothing alike).
+pub fn perceptual_hash_distance(a: i64, b: i64) -> u32 {
+    (a ^ b).count_ones()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,4 +211,37 @@ mod tests {
 
         Ok(())
     }
+
+    #[ignore] // slow
+    #[test]
+    fn generate_webp_sticker() -> anyhow::Result<()> {
+        let original = include_bytes!("../fixtures/images/city-wide.png");
+        let original = image::load_from_memory(original)?;
+
+        let webp = generate_webp_images(&original, ImageKind::Sticker);
+
+        let resized = webp::Decoder::new(&webp.resized)
+            .decode()
+            .expect("valid webp")
+            .to_image();
+
+        assert_eq!((1440, 401), resized.dimensions());
+
+        Ok(())
+    }
+
+    #[test]
+    fn perceptual_hash_matches_resized() -> anyhow::Result<()> {
+        let original = image::load_from_memory(include_bytes!("../fixtures/images/city-wide.png"))?;
+        let resized = original.thumbnail(300, 300);
+        let other =
+            image::load_from_memory(include_bytes!("../fixtures/images/large-sticker.png"))?;
+
+        let hash = perceptual_hash(&original);
+
+        assert!(perceptual_hash_distance(hash, perceptual_hash(&resized)) <= 4);
+        assert!(perceptual_hash_distance(hash, perceptual_hash(&other)) > 10);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,365 @@
+//! Animations (GIF, APNG and animated WebP).
+//!
+//! Every animation gets converted into a spritesheet, which is much smaller (and cheaper to decode) than
+//! the GIFs we used to serve as-is.
+
+use std::io::Cursor;
+
+use image::{
+    codecs::{gif::GifDecoder, png::PngDecoder},
+    imageops::{self, FilterType},
+    AnimationDecoder, ColorType, DynamicImage, Frames, ImageDecoder, RgbaImage,
+};
+use shared::domain::animation::{AnimationFormat, AnimationSpritesheet};
+
+/// The largest a spritesheet can be in either direction (frames are scaled down to fit).
+///
+/// Larger textures fail to decode on a lot of mobile devices.
+pub const MAX_SPRITESHEET_SIZE: u32 = 4096;
+
+/// Browsers show frames with a shorter delay than this for [`DEFAULT_FRAME_DURATION_MS`] instead.
+const MIN_FRAME_DURATION_MS: u32 = 20;
+
+const DEFAULT_FRAME_DURATION_MS: u32 = 100;
+
+/// Animations with more pixels than this across all of their frames are rejected, every frame is kept in memory
+/// (as RGBA, so 256 MiB worth) until the spritesheet is made.
+pub const MAX_TOTAL_FRAME_PIXELS: u64 = 64 * 1024 * 1024;
+
+/// A single (fully composited) frame of an animation.
+pub struct AnimationFrame {
+    pub image: RgbaImage,
+    pub duration_ms: u32,
+}
+
+/// Detects the format of an animation from its magic bytes.
+pub fn detect_format(data: &[u8]) -> Option<AnimationFormat> {
+    match data {
+        [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => Some(AnimationFormat::Gif),
+        [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a, ..] => Some(AnimationFormat::Apng),
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => {
+            Some(AnimationFormat::WebP)
+        }
+        _ => None,
+    }
+}
+
+fn frame_duration_ms(duration_ms: u32) -> u32 {
+    if duration_ms < MIN_FRAME_DURATION_MS {
+        DEFAULT_FRAME_DURATION_MS
+    } else {
+        duration_ms
+    }
+}
+
+/// How many frames of `width` x `height` fit in [`MAX_TOTAL_FRAME_PIXELS`].
+fn max_frames(width: u32, height: u32) -> u64 {
+    MAX_TOTAL_FRAME_PIXELS / (u64::from(width) * u64::from(height)).max(1)
+}
+
+/// Decodes frames until there are more than `max_frames` of them, which is an error.
+fn collect_frames(frames: Frames, max_frames: u64) -> anyhow::Result<Vec<AnimationFrame>> {
+    let mut collected = Vec::new();
+
+    for frame in frames {
+        if collected.len() as u64 >= max_frames {
+            anyhow::bail!("animation has too many pixels");
+        }
+
+        let frame = frame?;
+
+        let (numer, denom) = frame.delay().numer_denom_ms();
+        let duration_ms = numer.checked_div(denom).unwrap_or(0);
+
+        collected.push(AnimationFrame {
+            image: frame.into_buffer(),
+            duration_ms: frame_duration_ms(duration_ms),
+        });
+    }
+
+    Ok(collected)
+}
+
+/// Decodes every frame of an animation.
+///
+/// Still images (a PNG without animation control, or a still WebP) decode to a single frame.
+///
+/// Animations over [`MAX_TOTAL_FRAME_PIXELS`] are rejected before the frames that don't fit are decoded.
+pub fn decode(data: &[u8], format: AnimationFormat) -> anyhow::Result<Vec<AnimationFrame>> {
+    let frames = match format {
+        AnimationFormat::Gif => {
+            let decoder = GifDecoder::new(data)?;
+            let (width, height) = decoder.dimensions();
+
+            collect_frames(decoder.into_frames(), max_frames(width, height))?
+        }
+
+        AnimationFormat::Apng => {
+            let decoder = PngDecoder::new(data)?;
+            let (width, height) = decoder.dimensions();
+            let max_frames = max_frames(width, height);
+
+            if max_frames == 0 {
+                anyhow::bail!("animation has too many pixels");
+            }
+
+            // the APNG decoder panics on 16 bit images.
+            if !matches!(
+                decoder.color_type(),
+                ColorType::L8 | ColorType::La8 | ColorType::Rgb8 | ColorType::Rgba8
+            ) {
+                anyhow::bail!("unsupported APNG color type: {:?}", decoder.color_type());
+            }
+
+            if decoder.is_apng() {
+                collect_frames(decoder.apng().into_frames(), max_frames)?
+            } else {
+                vec![AnimationFrame {
+                    image: DynamicImage::from_decoder(decoder)?.into_rgba8(),
+                    duration_ms: DEFAULT_FRAME_DURATION_MS,
+                }]
+            }
+        }
+
+        AnimationFormat::WebP => decode_webp(data)?,
+    };
+
+    if frames.is_empty() {
+        anyhow::bail!("animation has no frames");
+    }
+
+    Ok(frames)
+}
+
+/// The `image` crate can't decode animated WebP, so this uses `image-webp` directly.
+fn decode_webp(data: &[u8]) -> anyhow::Result<Vec<AnimationFrame>> {
+    let mut decoder = image_webp::WebPDecoder::new(Cursor::new(data))?;
+
+    let (width, height) = decoder.dimensions();
+    let has_alpha = decoder.has_alpha();
+    let buffer_size = decoder
+        .output_buffer_size()
+        .ok_or_else(|| anyhow::anyhow!("WebP is too large"))?;
+
+    let frame_count = if decoder.is_animated() {
+        decoder.num_frames()
+    } else {
+        1
+    };
+
+    if u64::from(frame_count) > max_frames(width, height) {
+        anyhow::bail!("animation has too many pixels");
+    }
+
+    let to_rgba = |buffer: Vec<u8>| -> anyhow::Result<RgbaImage> {
+        let image = if has_alpha {
+            RgbaImage::from_raw(width, height, buffer).map(DynamicImage::ImageRgba8)
+        } else {
+            image::RgbImage::from_raw(width, height, buffer).map(DynamicImage::ImageRgb8)
+        };
+
+        image
+            .map(DynamicImage::into_rgba8)
+            .ok_or_else(|| anyhow::anyhow!("WebP frame doesn't match its dimensions"))
+    };
+
+    if !decoder.is_animated() {
+        let mut buffer = vec![0; buffer_size];
+        decoder.read_image(&mut buffer)?;
+
+        return Ok(vec![AnimationFrame {
+            image: to_rgba(buffer)?,
+            duration_ms: DEFAULT_FRAME_DURATION_MS,
+        }]);
+    }
+
+    (0..decoder.num_frames())
+        .map(|_| {
+            let mut buffer = vec![0; buffer_size];
+            let duration_ms = decoder.read_frame(&mut buffer)?;
+
+            Ok(AnimationFrame {
+                image: to_rgba(buffer)?,
+                duration_ms: frame_duration_ms(duration_ms),
+            })
+        })
+        .collect()
+}
+
+/// How many columns a sheet of `frame_count` frames gets, to keep it roughly square.
+fn columns(frame_count: u32) -> u32 {
+    let mut columns = (f64::from(frame_count)).sqrt() as u32;
+
+    while columns * columns < frame_count {
+        columns += 1;
+    }
+
+    columns.max(1)
+}
+
+/// Lays the frames out in a grid, scaling them down if the sheet would be larger than [`MAX_SPRITESHEET_SIZE`].
+///
+/// `frames` must not be empty.
+pub fn spritesheet(frames: &[AnimationFrame]) -> (RgbaImage, AnimationSpritesheet) {
+    let frame_count = frames.len() as u32;
+    let columns = columns(frame_count);
+    let rows = frame_count.div_ceil(columns);
+
+    let (width, height) = frames[0].image.dimensions();
+
+    let scale = f64::min(
+        1.0,
+        f64::min(
+            f64::from(MAX_SPRITESHEET_SIZE) / f64::from(width * columns),
+            f64::from(MAX_SPRITESHEET_SIZE) / f64::from(height * rows),
+        ),
+    );
+
+    let frame_width = ((f64::from(width) * scale) as u32).max(1);
+    let frame_height = ((f64::from(height) * scale) as u32).max(1);
+
+    let mut sheet = RgbaImage::new(frame_width * columns, frame_height * rows);
+
+    for (index, frame) in frames.iter().enumerate() {
+        let index = index as u32;
+        let x = (index % columns) * frame_width;
+        let y = (index / columns) * frame_height;
+
+        if frame.image.dimensions() == (frame_width, frame_height) {
+            imageops::replace(&mut sheet, &frame.image, x, y);
+        } else {
+            let resized = imageops::resize(
+                &frame.image,
+                frame_width,
+                frame_height,
+                FilterType::Triangle,
+            );
+
+            imageops::replace(&mut sheet, &resized, x, y);
+        }
+    }
+
+    let layout = AnimationSpritesheet {
+        frame_width,
+        frame_height,
+        columns,
+        frame_durations_ms: frames.iter().map(|it| it.duration_ms).collect(),
+    };
+
+    (sheet, layout)
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{codecs::gif::GifEncoder, Delay, Frame, Rgba, RgbaImage};
+    use shared::domain::animation::AnimationFormat;
+
+    use super::{decode, detect_format, spritesheet, AnimationFrame, MAX_SPRITESHEET_SIZE};
+
+    fn gif(colors: &[[u8; 4]], delay_ms: u32) -> Vec<u8> {
+        let mut buffer = Vec::new();
+
+        {
+            let mut encoder = GifEncoder::new(&mut buffer);
+
+            let frames = colors.iter().map(|color| {
+                Frame::from_parts(
+                    RgbaImage::from_pixel(4, 3, Rgba(*color)),
+                    0,
+                    0,
+                    Delay::from_numer_denom_ms(delay_ms, 1),
+                )
+            });
+
+            encoder.encode_frames(frames).unwrap();
+        }
+
+        buffer
+    }
+
+    fn frames(count: usize, width: u32, height: u32) -> Vec<AnimationFrame> {
+        (0..count)
+            .map(|it| AnimationFrame {
+                image: RgbaImage::from_pixel(width, height, Rgba([it as u8, 0, 0, 255])),
+                duration_ms: 50,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn detects_formats() {
+        assert_eq!(detect_format(b"GIF89a\x01\x00"), Some(AnimationFormat::Gif));
+        assert_eq!(
+            detect_format(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR"),
+            Some(AnimationFormat::Apng)
+        );
+        assert_eq!(
+            detect_format(b"RIFF\x24\0\0\0WEBPVP8X"),
+            Some(AnimationFormat::WebP)
+        );
+        assert_eq!(detect_format(b"\xff\xd8\xff\xe0"), None);
+    }
+
+    #[test]
+    fn decodes_gif_frames() -> anyhow::Result<()> {
+        let data = gif(&[[255, 0, 0, 255], [0, 255, 0, 255], [0, 0, 255, 255]], 80);
+
+        let frames = decode(&data, AnimationFormat::Gif)?;
+
+        assert_eq!(frames.len(), 3);
+        assert!(frames.iter().all(|it| it.duration_ms == 80));
+        assert_eq!(frames[1].image.get_pixel(0, 0), &Rgba([0, 255, 0, 255]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn zero_delay_frames_get_the_browser_default() -> anyhow::Result<()> {
+        let data = gif(&[[255, 0, 0, 255], [0, 255, 0, 255]], 0);
+
+        let frames = decode(&data, AnimationFormat::Gif)?;
+
+        assert!(frames.iter().all(|it| it.duration_ms == 100));
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_animations_over_the_pixel_budget() {
+        // a single 1x1 frame, on a 16384x16384 screen that every frame is composited onto.
+        let data = [
+            b"GIF89a".as_ref(),
+            &[0x00, 0x40, 0x00, 0x40, 0x80, 0x00, 0x00],
+            &[0x00, 0x00, 0x00, 0xff, 0xff, 0xff],
+            &[0x2c, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00],
+            &[0x02, 0x02, 0x44, 0x01, 0x00, 0x3b],
+        ]
+        .concat();
+
+        assert!(decode(&data, AnimationFormat::Gif).is_err());
+    }
+
+    #[test]
+    fn lays_out_frames_in_a_grid() {
+        let (sheet, layout) = spritesheet(&frames(5, 10, 6));
+
+        assert_eq!((layout.frame_width, layout.frame_height), (10, 6));
+        assert_eq!(layout.columns, 3);
+        assert_eq!(layout.frame_durations_ms, vec![50; 5]);
+        assert_eq!(sheet.dimensions(), (30, 12));
+
+        // the 5th frame is the 2nd one in the 2nd row.
+        assert_eq!(sheet.get_pixel(15, 8), &Rgba([4, 0, 0, 255]));
+        assert_eq!(sheet.get_pixel(25, 8), &Rgba([0, 0, 0, 0]));
+    }
+
+    #[test]
+    fn large_sheets_are_scaled_down() {
+        let (sheet, layout) = spritesheet(&frames(4, 3000, 1000));
+
+        assert_eq!(layout.columns, 2);
+        assert!(sheet.width() <= MAX_SPRITESHEET_SIZE);
+        assert!(sheet.height() <= MAX_SPRITESHEET_SIZE);
+        assert_eq!(sheet.width(), layout.frame_width * 2);
+    }
+}
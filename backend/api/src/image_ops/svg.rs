@@ -0,0 +1,302 @@
+//! Vector (SVG) images.
+//!
+//! SVGs are served as-is from the media bucket, so anything that can run code or load other resources
+//! (scripts, event handlers, external references) is stripped before they are stored.
+
+use std::borrow::Cow;
+
+use image::DynamicImage;
+use quick_xml::{
+    events::{attributes::Attribute, BytesStart, Event},
+    Reader, Writer,
+};
+
+/// Elements that are removed (along with their children).
+const BLOCKED_ELEMENTS: &[&[u8]] = &[
+    b"script",
+    b"foreignobject",
+    b"iframe",
+    b"object",
+    b"embed",
+    b"audio",
+    b"video",
+    b"handler",
+    b"listener",
+];
+
+/// Embedded (`data:`) images that may be referenced, as illustrators' tools like to inline bitmaps.
+const ALLOWED_DATA_URIS: &[&str] = &[
+    "data:image/png;",
+    "data:image/jpeg;",
+    "data:image/gif;",
+    "data:image/webp;",
+];
+
+/// Strips a namespace prefix (`xlink:href` -> `href`).
+fn local_name(name: &[u8]) -> &[u8] {
+    match name.iter().position(|it| *it == b':') {
+        Some(idx) => &name[(idx + 1)..],
+        None => name,
+    }
+}
+
+fn is_allowed_reference(value: &str) -> bool {
+    let value = value.trim().to_ascii_lowercase();
+
+    value.starts_with('#') || ALLOWED_DATA_URIS.iter().any(|it| value.starts_with(it))
+}
+
+/// Does a attribute value or stylesheet reference anything outside of the document?
+fn has_external_reference(value: &str) -> bool {
+    let value = value.to_ascii_lowercase();
+
+    if value.contains("@import") || value.contains("javascript:") {
+        return true;
+    }
+
+    value.match_indices("url(").any(|(idx, _)| {
+        let target = value[(idx + "url(".len())..]
+            .trim_start_matches(|it: char| it.is_ascii_whitespace() || it == '"' || it == '\'');
+
+        !is_allowed_reference(target)
+    })
+}
+
+fn is_allowed_attribute(element: &[u8], key: &[u8], value: &str) -> bool {
+    let name = local_name(key).to_ascii_lowercase();
+
+    if name.starts_with(b"on") || key.eq_ignore_ascii_case(b"xml:base") {
+        return false;
+    }
+
+    if name == b"href" {
+        return is_allowed_reference(value);
+    }
+
+    // `<set attributeName="href" to="javascript:...">` and friends.
+    if name == b"attributename" && (element.starts_with(b"animate") || element == b"set") {
+        let target = value.trim().to_ascii_lowercase();
+        if target.ends_with("href") || target.starts_with("on") {
+            return false;
+        }
+    }
+
+    !has_external_reference(value)
+}
+
+/// Returns `None` if the whole element should be removed.
+fn sanitize_element(element: &BytesStart<'_>) -> anyhow::Result<Option<BytesStart<'static>>> {
+    let name = local_name(element.name()).to_ascii_lowercase();
+
+    if BLOCKED_ELEMENTS.contains(&&*name) {
+        return Ok(None);
+    }
+
+    let mut sanitized = BytesStart::owned_name(element.name().to_vec());
+
+    for attribute in element.attributes() {
+        let attribute = attribute?;
+
+        let value = match attribute.unescaped_value() {
+            Ok(value) => String::from_utf8_lossy(&value).into_owned(),
+            // unknown entities and the like.
+            Err(_) => continue,
+        };
+
+        if is_allowed_attribute(&name, attribute.key, &value) {
+            sanitized.push_attribute(Attribute {
+                key: attribute.key,
+                value: Cow::Borrowed(&*attribute.value),
+            });
+        }
+    }
+
+    Ok(Some(sanitized))
+}
+
+/// Removes scripts, event handlers and external references from an SVG document.
+///
+/// Errors if `data` isn't well formed XML with an `<svg>` root element.
+pub fn sanitize(data: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let mut reader = Reader::from_reader(data);
+    reader.check_end_names(true);
+
+    let mut writer = Writer::new(Vec::with_capacity(data.len()));
+    let mut buf = Vec::new();
+
+    let mut seen_root = false;
+    // depth of the removed element that we're currently inside of (if any).
+    let mut skip_depth = 0_usize;
+    let mut in_style = false;
+
+    loop {
+        let event = reader.read_event(&mut buf)?;
+
+        match event {
+            Event::Eof => break,
+
+            Event::Start(_) if skip_depth > 0 => skip_depth += 1,
+            Event::End(_) if skip_depth > 0 => skip_depth -= 1,
+            _ if skip_depth > 0 => {}
+
+            Event::Start(ref element) | Event::Empty(ref element) => {
+                if !seen_root && !local_name(element.name()).eq_ignore_ascii_case(b"svg") {
+                    anyhow::bail!("root element isn't `<svg>`");
+                }
+
+                seen_root = true;
+
+                let is_start = matches!(event, Event::Start(_));
+
+                match sanitize_element(element)? {
+                    Some(sanitized) if is_start => {
+                        in_style = local_name(sanitized.name()).eq_ignore_ascii_case(b"style");
+                        writer.write_event(Event::Start(sanitized))?;
+                    }
+                    Some(sanitized) => writer.write_event(Event::Empty(sanitized))?,
+                    None if is_start => skip_depth = 1,
+                    None => {}
+                }
+            }
+
+            Event::End(_) => {
+                in_style = false;
+                writer.write_event(event)?;
+            }
+
+            Event::Text(ref text) if in_style => {
+                if !has_external_reference(&String::from_utf8_lossy(text)) {
+                    writer.write_event(event)?;
+                }
+            }
+
+            Event::CData(ref text) if in_style => {
+                if !has_external_reference(&String::from_utf8_lossy(text)) {
+                    writer.write_event(event)?;
+                }
+            }
+
+            // doctypes can declare entities, and processing instructions aren't needed for rendering.
+            Event::DocType(_) | Event::PI(_) => {}
+
+            Event::Decl(_) | Event::Text(_) | Event::CData(_) | Event::Comment(_) => {
+                writer.write_event(event)?;
+            }
+        }
+
+        buf.clear();
+    }
+
+    if !seen_root {
+        anyhow::bail!("missing `<svg>` element");
+    }
+
+    Ok(writer.into_inner())
+}
+
+/// Checks whether `data` looks like an SVG document (as opposed to a bitmap).
+pub fn is_svg(data: &[u8]) -> bool {
+    let data = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+
+    let start = match data.iter().position(|it| !it.is_ascii_whitespace()) {
+        Some(start) => start,
+        None => return false,
+    };
+
+    if data[start] != b'<' {
+        return false;
+    }
+
+    let mut reader = Reader::from_reader(&data[start..]);
+    let mut buf = Vec::new();
+
+    // skip past the xml declaration, comments and doctype.
+    for _ in 0..16 {
+        match reader.read_event(&mut buf) {
+            Ok(Event::Start(element)) | Ok(Event::Empty(element)) => {
+                return local_name(element.name()).eq_ignore_ascii_case(b"svg");
+            }
+            Ok(Event::Eof) | Err(_) => return false,
+            Ok(_) => {}
+        }
+
+        buf.clear();
+    }
+
+    false
+}
+
+/// Renders a (sanitized) SVG to fit within `width` x `height`, keeping its aspect ratio.
+pub fn rasterize(svg: &[u8], (width, height): (u32, u32)) -> anyhow::Result<DynamicImage> {
+    let options = usvg::Options::default();
+    let tree = usvg::Tree::from_data(svg, &options.to_ref())?;
+
+    let fit_to = usvg::FitTo::Size(width, height);
+
+    let size = fit_to
+        .fit_to(tree.svg_node().size.to_screen_size())
+        .ok_or_else(|| anyhow::anyhow!("svg has an invalid size"))?;
+
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+        .ok_or_else(|| anyhow::anyhow!("svg has an invalid size"))?;
+
+    resvg::render(
+        &tree,
+        fit_to,
+        tiny_skia::Transform::default(),
+        pixmap.as_mut(),
+    )
+    .ok_or_else(|| anyhow::anyhow!("failed to render svg"))?;
+
+    Ok(image::load_from_memory(&pixmap.encode_png()?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sanitize_str(svg: &str) -> String {
+        String::from_utf8(sanitize(svg.as_bytes()).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn strips_scripts_and_handlers() {
+        let sanitized = sanitize_str(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" onload="alert(1)"><script>alert(2)</script><rect width="1" height="1" onclick="alert(3)"/></svg>"#,
+        );
+
+        assert_eq!(
+            sanitized,
+            r#"<svg xmlns="http://www.w3.org/2000/svg"><rect width="1" height="1"/></svg>"#
+        );
+    }
+
+    #[test]
+    fn strips_external_references() {
+        let sanitized = sanitize_str(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink"><style>@import url(https://example.com/a.css);</style><use xlink:href="https://example.com/a.svg#b"/><use href="#local"/><rect fill="url(https://example.com/a.svg#c)" stroke="url(#gradient)"/><a href="javascript:alert(1)"/></svg>"##,
+        );
+
+        assert_eq!(
+            sanitized,
+            r##"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink"><style></style><use/><use href="#local"/><rect stroke="url(#gradient)"/><a/></svg>"##
+        );
+    }
+
+    #[test]
+    fn rejects_non_svg() {
+        assert!(sanitize(b"<html><body/></html>").is_err());
+        assert!(sanitize(b"not xml").is_err());
+    }
+
+    #[test]
+    fn detects_svg() {
+        assert!(is_svg(
+            br#"<?xml version="1.0"?><!-- made by hand --><svg xmlns="http://www.w3.org/2000/svg"/>"#
+        ));
+        assert!(!is_svg(include_bytes!(
+            "../../fixtures/images/city-wide.png"
+        )));
+        assert!(!is_svg(b"<html/>"));
+    }
+}
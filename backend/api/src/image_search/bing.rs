@@ -0,0 +1,112 @@
+//! [Bing image search](https://docs.microsoft.com/en-us/bing/search-apis/bing-image-search/overview).
+
+use futures::future::BoxFuture;
+use serde::Deserialize;
+use shared::domain::{
+    media::{License, Provenance},
+    search::{
+        LicenseFilter, SafeSearch, WebImageSearchItem, WebImageSearchQuery, WebImageSearchResponse,
+    },
+};
+
+use super::{Provider, PAGE_SIZE};
+
+const QUERY_TYPE: &str = "imageType";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Image {
+    thumbnail_url: url::Url,
+    content_url: url::Url,
+    host_page_url: Option<url::Url>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ImagesResponse {
+    value: Vec<Image>,
+}
+
+pub struct Bing {
+    key: String,
+}
+
+impl Bing {
+    pub fn new(key: String) -> Self {
+        Self { key }
+    }
+
+    async fn get_images(
+        &self,
+        query: &WebImageSearchQuery,
+    ) -> anyhow::Result<WebImageSearchResponse> {
+        // returns tuple if there is a valid user input,
+        // otherwise empty query for image type field
+        let image_type: (&str, &str) = match query.image_type {
+            Some(image_type) => (QUERY_TYPE, image_type.to_str()),
+            None => ("", ""),
+        };
+
+        let safe_search = match query.safe_search.unwrap_or_default() {
+            SafeSearch::Strict => "Strict",
+            SafeSearch::Moderate => "Moderate",
+        };
+
+        let license = query.license.unwrap_or_default();
+
+        let license_query: (&str, &str) = match license {
+            LicenseFilter::Any => ("", ""),
+            LicenseFilter::CreativeCommons => ("license", "ModifyCommercially"),
+            LicenseFilter::PublicDomain => ("license", "Public"),
+        };
+
+        // https://docs.microsoft.com/en-us/bing/search-apis/bing-image-search/reference/endpoints
+        // https://docs.microsoft.com/en-us/bing/search-apis/bing-image-search/reference/query-parameters
+        let res = reqwest::Client::new()
+            .get("https://api.bing.microsoft.com/v7.0/images/search")
+            .query(&[("q", &*query.q)])
+            .query(&[image_type])
+            .query(&[license_query])
+            .query(&[("safeSearch", safe_search)])
+            .query(&[("count", PAGE_SIZE)])
+            .header("Ocp-Apim-Subscription-Key", &self.key)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<ImagesResponse>()
+            .await?;
+
+        // bing only tells us which license *filter* an image matched, not which license it has.
+        let license = match license {
+            LicenseFilter::PublicDomain => License::PublicDomain,
+            LicenseFilter::Any | LicenseFilter::CreativeCommons => License::Unknown,
+        };
+
+        let res = WebImageSearchResponse {
+            images: res
+                .value
+                .into_iter()
+                .map(|it| WebImageSearchItem {
+                    thumbnail_url: it.thumbnail_url,
+                    url: it.content_url,
+                    provenance: Some(Provenance {
+                        license,
+                        author: None,
+                        source_url: it.host_page_url.map(String::from),
+                    }),
+                })
+                .collect(),
+        };
+
+        Ok(res)
+    }
+}
+
+impl Provider for Bing {
+    fn search<'a>(
+        &'a self,
+        query: &'a WebImageSearchQuery,
+    ) -> BoxFuture<'a, anyhow::Result<WebImageSearchResponse>> {
+        Box::pin(self.get_images(query))
+    }
+}
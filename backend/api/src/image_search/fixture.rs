@@ -0,0 +1,101 @@
+//! A fixed set of results which doesn't need network access, for tests and local development.
+
+use futures::future::{self, BoxFuture};
+use shared::domain::{
+    media::{License, Provenance},
+    search::{
+        LicenseFilter, SafeSearch, WebImageSearchItem, WebImageSearchQuery, WebImageSearchResponse,
+    },
+};
+
+use super::Provider;
+
+struct FixtureImage {
+    name: &'static str,
+    license: License,
+    author: Option<&'static str>,
+    /// Racy, only returned with moderate safe search.
+    mature: bool,
+}
+
+const IMAGES: &[FixtureImage] = &[
+    FixtureImage {
+        name: "cat",
+        license: License::CcBy,
+        author: Some("Jane Doe"),
+        mature: false,
+    },
+    FixtureImage {
+        name: "black-cat",
+        license: License::PublicDomain,
+        author: None,
+        mature: false,
+    },
+    FixtureImage {
+        name: "cat-cartoon",
+        license: License::Unknown,
+        author: None,
+        mature: false,
+    },
+    FixtureImage {
+        name: "cat-fight",
+        license: License::Cc0,
+        author: None,
+        mature: true,
+    },
+    FixtureImage {
+        name: "dog",
+        license: License::CcBySa,
+        author: Some("John Doe"),
+        mature: false,
+    },
+];
+
+fn matches_license(license: License, filter: LicenseFilter) -> bool {
+    match filter {
+        LicenseFilter::Any => true,
+        LicenseFilter::CreativeCommons => matches!(
+            license,
+            License::CcBy | License::CcBySa | License::Cc0 | License::PublicDomain
+        ),
+        LicenseFilter::PublicDomain => matches!(license, License::Cc0 | License::PublicDomain),
+    }
+}
+
+fn url(path: &str) -> url::Url {
+    format!("https://example.com/{}", path)
+        .parse()
+        .expect("fixture urls are valid")
+}
+
+/// Returns the images whose name contains the query.
+pub struct Fixture;
+
+impl Provider for Fixture {
+    fn search<'a>(
+        &'a self,
+        query: &'a WebImageSearchQuery,
+    ) -> BoxFuture<'a, anyhow::Result<WebImageSearchResponse>> {
+        let q = query.q.trim().to_lowercase();
+        let license = query.license.unwrap_or_default();
+        let allow_mature = query.safe_search.unwrap_or_default() == SafeSearch::Moderate;
+
+        let images = IMAGES
+            .iter()
+            .filter(|it| it.name.contains(&q))
+            .filter(|it| matches_license(it.license, license))
+            .filter(|it| allow_mature || !it.mature)
+            .map(|it| WebImageSearchItem {
+                thumbnail_url: url(&format!("thumbnails/{}.png", it.name)),
+                url: url(&format!("images/{}.png", it.name)),
+                provenance: Some(Provenance {
+                    license: it.license,
+                    author: it.author.map(str::to_owned),
+                    source_url: Some(url(it.name).into()),
+                }),
+            })
+            .collect();
+
+        Box::pin(future::ready(Ok(WebImageSearchResponse { images })))
+    }
+}
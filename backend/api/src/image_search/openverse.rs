@@ -0,0 +1,107 @@
+//! [Openverse](https://api.openverse.engineering/v1/), which only indexes openly licensed (or public domain) media.
+
+use futures::future::BoxFuture;
+use serde::Deserialize;
+use shared::domain::{
+    media::{License, Provenance},
+    search::{
+        ImageType, LicenseFilter, WebImageSearchItem, WebImageSearchQuery, WebImageSearchResponse,
+    },
+};
+
+use super::{Provider, PAGE_SIZE};
+
+/// Licenses that we can represent with [`License`].
+const CREATIVE_COMMONS_LICENSES: &str = "by,by-sa,cc0,pdm";
+
+const PUBLIC_DOMAIN_LICENSES: &str = "cc0,pdm";
+
+#[derive(Debug, Deserialize)]
+struct Image {
+    url: url::Url,
+    thumbnail: Option<url::Url>,
+    foreign_landing_url: Option<url::Url>,
+    creator: Option<String>,
+    license: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ImagesResponse {
+    results: Vec<Image>,
+}
+
+fn license(license: &str) -> License {
+    match license {
+        "by" => License::CcBy,
+        "by-sa" => License::CcBySa,
+        "cc0" => License::Cc0,
+        "pdm" => License::PublicDomain,
+        _ => License::Unknown,
+    }
+}
+
+#[derive(Default)]
+pub struct Openverse {
+    client: reqwest::Client,
+}
+
+impl Openverse {
+    async fn get_images(
+        &self,
+        query: &WebImageSearchQuery,
+    ) -> anyhow::Result<WebImageSearchResponse> {
+        let licenses = match query.license.unwrap_or_default() {
+            LicenseFilter::Any | LicenseFilter::CreativeCommons => CREATIVE_COMMONS_LICENSES,
+            LicenseFilter::PublicDomain => PUBLIC_DOMAIN_LICENSES,
+        };
+
+        let image_type: (&str, &str) = match query.image_type {
+            Some(ImageType::Photo) => ("category", "photograph"),
+            Some(ImageType::Clipart) | Some(ImageType::Line) => ("category", "illustration"),
+            Some(ImageType::AnimatedGif) => ("extension", "gif"),
+            Some(ImageType::Transparent) => ("extension", "png,svg"),
+            None => ("", ""),
+        };
+
+        // https://api.openverse.engineering/v1/#operation/image_search
+        // openverse only distinguishes mature content, which is never returned.
+        let res = self
+            .client
+            .get("https://api.openverse.engineering/v1/images/")
+            .query(&[("q", &*query.q), ("license", licenses), ("mature", "false")])
+            .query(&[image_type])
+            .query(&[("page_size", PAGE_SIZE)])
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<ImagesResponse>()
+            .await?;
+
+        let res = WebImageSearchResponse {
+            images: res
+                .results
+                .into_iter()
+                .map(|it| WebImageSearchItem {
+                    thumbnail_url: it.thumbnail.unwrap_or_else(|| it.url.clone()),
+                    url: it.url,
+                    provenance: Some(Provenance {
+                        license: license(&it.license),
+                        author: it.creator,
+                        source_url: it.foreign_landing_url.map(String::from),
+                    }),
+                })
+                .collect(),
+        };
+
+        Ok(res)
+    }
+}
+
+impl Provider for Openverse {
+    fn search<'a>(
+        &'a self,
+        query: &'a WebImageSearchQuery,
+    ) -> BoxFuture<'a, anyhow::Result<WebImageSearchResponse>> {
+        Box::pin(self.get_images(query))
+    }
+}
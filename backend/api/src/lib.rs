@@ -31,6 +31,7 @@
 )]
 
 pub mod algolia;
+mod audio_ops;
 pub mod db;
 mod domain;
 pub mod error;
@@ -42,8 +43,10 @@ pub mod image_search;
 pub mod jwk;
 pub mod logger;
 pub(crate) mod more_futures;
+mod pdf_ops;
 pub mod service;
 pub mod token;
+pub mod totp;
 pub mod translate;
 
 // // todo: make this configurable?
@@ -42,6 +42,7 @@ async fn main() -> anyhow::Result<()> {
         mail_client,
         media_upload_cleaner,
         google_translate,
+        webhook_dispatcher,
         _guard,
     ) = {
         log::trace!("initializing settings and processes");
@@ -123,6 +124,9 @@ async fn main() -> anyhow::Result<()> {
 
         let google_translate = translate::GoogleTranslate::new(db_pool.clone(), &runtime_settings)?;
 
+        let webhook_dispatcher =
+            service::webhook::Dispatcher::new(db_pool.clone(), runtime_settings.is_local())?;
+
         let jwk_verifier =
             jwk::create_verifier(settings.jwk_audience_settings(&runtime_settings).await?);
 
@@ -146,6 +150,7 @@ async fn main() -> anyhow::Result<()> {
             mail_client,
             media_upload_cleaner,
             google_translate,
+            webhook_dispatcher,
             guard,
         )
     };
@@ -164,6 +169,7 @@ async fn main() -> anyhow::Result<()> {
             algolia_manager,
             media_upload_cleaner,
             google_translate,
+            webhook_dispatcher,
         )
     });
 
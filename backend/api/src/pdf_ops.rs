@@ -0,0 +1,235 @@
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use lopdf::{Document, Object};
+use shared::domain::{image::ImageKind, pdf::PdfPreview};
+
+use crate::{error, image_ops};
+
+pub mod printable;
+
+/// How long `pdftoppm` gets to render a thumbnail, before the pdf is considered invalid.
+const RENDER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Reads the page count and title of a pdf.
+pub fn preview(data: &[u8]) -> anyhow::Result<PdfPreview> {
+    let document = Document::load_mem(data)?;
+
+    let page_count = document.get_pages().len() as u32;
+
+    Ok(PdfPreview {
+        page_count,
+        title: title(&document),
+    })
+}
+
+/// The `Title` entry of the document information dictionary, if it has a non-blank one.
+fn title(document: &Document) -> Option<String> {
+    let info = document.trailer.get(b"Info").ok()?;
+    let (_, info) = document.dereference(info).ok()?;
+    let title = info.as_dict().ok()?.get(b"Title").ok()?;
+    let (_, title) = document.dereference(title).ok()?;
+
+    let title = match title {
+        Object::String(bytes, _) => decode_text_string(bytes),
+        _ => return None,
+    };
+
+    let title = title.trim();
+
+    (!title.is_empty()).then(|| title.to_owned())
+}
+
+/// Decodes a pdf "text string", which is either UTF-16BE (with a byte order mark) or `PDFDocEncoding`.
+///
+/// `PDFDocEncoding` is treated as latin-1, which it matches for everything but a few rarely used symbols.
+fn decode_text_string(bytes: &[u8]) -> String {
+    match bytes {
+        [0xfe, 0xff, rest @ ..] => {
+            let units: Vec<u16> = rest
+                .chunks_exact(2)
+                .map(|it| u16::from_be_bytes([it[0], it[1]]))
+                .collect();
+
+            String::from_utf16_lossy(&units)
+        }
+        _ => bytes.iter().copied().map(char::from).collect(),
+    }
+}
+
+/// Renders the first page of a pdf as a PNG thumbnail.
+///
+/// Uses `pdftoppm` (from poppler-utils), which has to be installed. A pdf that it can't render, or takes longer than
+/// [`RENDER_TIMEOUT`] to, is [`InvalidMedia`](error::Upload::InvalidMedia).
+pub fn render_thumbnail(data: &[u8]) -> Result<Vec<u8>, error::Upload> {
+    let (width, height) = ImageKind::THUMBNAIL_SIZE;
+
+    let mut child = Command::new("pdftoppm")
+        .args(&["-png", "-singlefile", "-f", "1", "-l", "1"])
+        .args(&["-scale-to", &width.max(height).to_string()])
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("failed to run `pdftoppm`")?;
+
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let mut stderr = child.stderr.take().expect("stderr is piped");
+
+    // every pipe gets its own thread, so that one filling up can't block the others.
+    let data = data.to_vec();
+    // a broken pipe just means that pdftoppm gave up early, which its exit status reports.
+    let writer = thread::spawn(move || stdin.write_all(&data));
+    let stdout = thread::spawn(move || {
+        let mut buf = Vec::new();
+        stdout.read_to_end(&mut buf).map(|_| buf)
+    });
+    let stderr = thread::spawn(move || {
+        let mut buf = Vec::new();
+        stderr.read_to_end(&mut buf).map(|_| buf)
+    });
+
+    let deadline = Instant::now() + RENDER_TIMEOUT;
+
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+
+        if Instant::now() >= deadline {
+            // the pipes close once it's dead, which ends the threads.
+            child.kill()?;
+            child.wait()?;
+
+            log::info!("`pdftoppm` timed out after {:?}", RENDER_TIMEOUT);
+
+            return Err(error::Upload::InvalidMedia);
+        }
+
+        thread::sleep(Duration::from_millis(50));
+    };
+
+    let _ = writer.join();
+
+    let stdout = stdout
+        .join()
+        .map_err(|_| anyhow::anyhow!("reading `pdftoppm` output panicked"))??;
+    let stderr = stderr
+        .join()
+        .map_err(|_| anyhow::anyhow!("reading `pdftoppm` output panicked"))??;
+
+    if !status.success() {
+        log::info!(
+            "`pdftoppm` failed ({}): {}",
+            status,
+            String::from_utf8_lossy(&stderr).trim()
+        );
+
+        return Err(error::Upload::InvalidMedia);
+    }
+
+    let page = image::load_from_memory(&stdout)?;
+
+    Ok(image_ops::encode_png(&image_ops::thumbnail(&page))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use lopdf::{dictionary, Document, Object, Stream, StringFormat};
+
+    use super::preview;
+
+    fn pdf(pages: usize, title: Option<Object>) -> Vec<u8> {
+        let mut document = Document::with_version("1.5");
+
+        let pages_id = document.new_object_id();
+
+        let kids: Vec<Object> = (0..pages)
+            .map(|_| {
+                let content = document.add_object(Stream::new(dictionary! {}, Vec::new()));
+
+                document
+                    .add_object(dictionary! {
+                        "Type" => "Page",
+                        "Parent" => pages_id,
+                        "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+                        "Contents" => content,
+                    })
+                    .into()
+            })
+            .collect();
+
+        document.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Count" => pages as i64,
+                "Kids" => kids,
+            }),
+        );
+
+        let catalog_id = document.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+
+        document.trailer.set("Root", catalog_id);
+
+        if let Some(title) = title {
+            let info_id = document.add_object(dictionary! { "Title" => title });
+            document.trailer.set("Info", info_id);
+        }
+
+        let mut buffer = Vec::new();
+        document.save_to(&mut buffer).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn counts_pages() {
+        let preview = preview(&pdf(3, None)).unwrap();
+
+        assert_eq!(preview.page_count, 3);
+        assert_eq!(preview.title, None);
+    }
+
+    #[test]
+    fn reads_title() {
+        let title = Object::String(b"  Shapes worksheet ".to_vec(), StringFormat::Literal);
+
+        let preview = preview(&pdf(1, Some(title))).unwrap();
+
+        assert_eq!(preview.title.as_deref(), Some("Shapes worksheet"));
+    }
+
+    #[test]
+    fn reads_utf16_title() {
+        let mut bytes = vec![0xfe, 0xff];
+        bytes.extend("אלף בית".encode_utf16().flat_map(u16::to_be_bytes));
+
+        let title = Object::String(bytes, StringFormat::Hexadecimal);
+
+        let preview = preview(&pdf(1, Some(title))).unwrap();
+
+        assert_eq!(preview.title.as_deref(), Some("אלף בית"));
+    }
+
+    #[test]
+    fn blank_title_is_none() {
+        let title = Object::String(b"   ".to_vec(), StringFormat::Literal);
+
+        let preview = preview(&pdf(2, Some(title))).unwrap();
+
+        assert_eq!(preview.title, None);
+    }
+
+    #[test]
+    fn rejects_garbage() {
+        assert!(preview(b"not a pdf").is_err());
+    }
+}
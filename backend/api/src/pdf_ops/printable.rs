@@ -0,0 +1,1642 @@
+//! Printable worksheets, rendered from module bodies.
+//!
+//! Everything is drawn with plain content stream operations: text uses a subset of the bundled [`font`],
+//! so hebrew prints as well as latin, and images are embedded as compressed RGB with a soft mask for their
+//! alpha channel.
+
+use std::collections::{HashMap, HashSet};
+
+use image::DynamicImage;
+use lopdf::{
+    content::{Content, Operation},
+    dictionary, Dictionary, Document, Object, ObjectId, Stream, StringFormat,
+};
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use serde_json::Value;
+use shared::domain::{
+    image::ImageId,
+    jig::module::{
+        body::{
+            _groups::{
+                cards::{Card, CardContent, CardPair},
+                design::{self, Backgrounds, PathCommand, Sticker, Trace, TraceShape},
+            },
+            drag_drop::{self, ItemKind},
+            tapping_board, Background, Body, Image, Transform,
+        },
+        printable::PrintableLayout,
+    },
+};
+
+mod font;
+
+/// A4 portrait, in points.
+const PORTRAIT: (f64, f64) = (595.0, 842.0);
+
+/// A4 landscape, in points.
+const LANDSCAPE: (f64, f64) = (842.0, 595.0);
+
+const MARGIN: f64 = 36.0;
+
+/// Space taken by the title at the top of every page.
+const HEADER_HEIGHT: f64 = 40.0;
+
+const TITLE_SIZE: f64 = 18.0;
+
+const HEADING_SIZE: f64 = 14.0;
+
+const CARD_TEXT_SIZE: f64 = 20.0;
+
+/// Text is shrunk down to this size before it gets cut off.
+const MIN_TEXT_SIZE: f64 = 8.0;
+
+const LINE_HEIGHT: f64 = 1.2;
+
+const GAP: f64 = 12.0;
+
+const CUT_OUT_CARD_HEIGHT: f64 = 130.0;
+
+const WORKSHEET_ROW_HEIGHT: f64 = 90.0;
+
+const ANSWER_KEY_ROW_HEIGHT: f64 = 60.0;
+
+const LEGEND_ROW_HEIGHT: f64 = 32.0;
+
+/// Width of the column holding row numbers and letters.
+const LABEL_WIDTH: f64 = 28.0;
+
+/// Width of the blank students write their answers on.
+const BLANK_WIDTH: f64 = 56.0;
+
+const BADGE_RADIUS: f64 = 10.0;
+
+/// Width of the stage in pixels, sprites are drawn at their size in pixels relative to it.
+const STAGE_WIDTH_PX: f64 = 1920.0;
+
+/// Font size of text stickers, in pixels of the stage.
+const STICKER_TEXT_SIZE_PX: f64 = 48.0;
+
+const TRACE_COLOR: [f64; 3] = [0.16, 0.35, 0.73];
+
+const BLACK: [f64; 3] = [0.0, 0.0, 0.0];
+
+const WHITE: [f64; 3] = [1.0, 1.0, 1.0];
+
+/// The images printed for a module body, which have to be passed to [`render`].
+pub fn images(body: &Body) -> Vec<Image> {
+    let mut images = Vec::new();
+
+    match Printable::from_body(body) {
+        Some(Printable::Cards(pairs)) => {
+            for CardPair(left, right) in pairs {
+                for card in [left, right] {
+                    if let CardContent::Image(Some(image)) = &card.card_content {
+                        images.push(image.clone());
+                    }
+                }
+            }
+        }
+        Some(Printable::Design(base)) => {
+            design_images(&base.backgrounds, &base.stickers, &mut images);
+        }
+        Some(Printable::TappingBoard(content)) => {
+            design_images(
+                &content.base.backgrounds,
+                &content.base.stickers,
+                &mut images,
+            );
+        }
+        Some(Printable::DragDrop(content)) => {
+            let stickers: Vec<_> = content.items.iter().map(|it| it.sticker.clone()).collect();
+            design_images(&content.backgrounds, &stickers, &mut images);
+        }
+        None => {}
+    }
+
+    let mut seen = HashSet::new();
+    images.retain(|image| seen.insert(image.id));
+
+    images
+}
+
+fn design_images(backgrounds: &Backgrounds, stickers: &[Sticker], images: &mut Vec<Image>) {
+    for background in backgrounds.layer_1.iter().chain(&backgrounds.layer_2) {
+        if let Background::Image(image) = background {
+            images.push(image.clone());
+        }
+    }
+
+    for sticker in stickers {
+        if let Sticker::Sprite(sprite) = sticker {
+            images.push(sprite.image.clone());
+        }
+    }
+}
+
+/// Renders a module body as a pdf titled `title`.
+///
+/// `images` should contain every image returned by [`images`], the ones that are missing are left
+/// out. Returns `None` if the body has nothing to print.
+pub fn render(
+    body: &Body,
+    title: &str,
+    layout: PrintableLayout,
+    answer_key: bool,
+    images: &HashMap<ImageId, DynamicImage>,
+) -> anyhow::Result<Option<Vec<u8>>> {
+    let printable = match Printable::from_body(body) {
+        Some(printable) => printable,
+        None => return Ok(None),
+    };
+
+    let mut printer = Printer::new(title, images);
+
+    match printable {
+        Printable::Cards([]) => return Ok(None),
+        Printable::Cards(pairs) => match layout {
+            PrintableLayout::CutOut => print_cut_out(&mut printer, pairs, answer_key)?,
+            PrintableLayout::Worksheet => print_worksheet(&mut printer, pairs, answer_key)?,
+        },
+        Printable::Design(base) => print_design(&mut printer, base)?,
+        Printable::TappingBoard(content) => print_tapping_board(&mut printer, content, answer_key)?,
+        Printable::DragDrop(content) => print_drag_drop(&mut printer, content, answer_key)?,
+    }
+
+    printer.finish().map(Some)
+}
+
+/// The part of a module body that gets printed.
+enum Printable<'a> {
+    Cards(&'a [CardPair]),
+    Design(&'a design::BaseContent),
+    TappingBoard(&'a tapping_board::Content),
+    DragDrop(&'a drag_drop::Content),
+}
+
+impl<'a> Printable<'a> {
+    fn from_body(body: &'a Body) -> Option<Self> {
+        let printable = match body {
+            Body::MemoryGame(data) => Self::Cards(&data.content.as_ref()?.base.pairs),
+            Body::Matching(data) => Self::Cards(&data.content.as_ref()?.base.pairs),
+            Body::Flashcards(data) => Self::Cards(&data.content.as_ref()?.base.pairs),
+            Body::CardQuiz(data) => Self::Cards(&data.content.as_ref()?.base.pairs),
+            Body::Cover(data) => Self::Design(&data.content.as_ref()?.base),
+            Body::ResourceCover(data) => Self::Design(&data.content.as_ref()?.base),
+            Body::Poster(data) => Self::Design(&data.content.as_ref()?.base),
+            Body::TappingBoard(data) => Self::TappingBoard(data.content.as_ref()?),
+            Body::DragDrop(data) => Self::DragDrop(data.content.as_ref()?),
+            _ => return None,
+        };
+
+        Some(printable)
+    }
+}
+
+fn print_cut_out(
+    printer: &mut Printer<'_>,
+    pairs: &[CardPair],
+    answer_key: bool,
+) -> anyhow::Result<()> {
+    let rows = rows_per_page(printer.body(PORTRAIT).height, CUT_OUT_CARD_HEIGHT);
+
+    for chunk in pairs.chunks(rows) {
+        let (mut page, body) = printer.page(PORTRAIT);
+
+        let card_width = (body.width - GAP) / 2.0;
+
+        for (row, CardPair(left, right)) in chunk.iter().enumerate() {
+            let y = body.y + row as f64 * (CUT_OUT_CARD_HEIGHT + GAP);
+
+            for (column, card) in [left, right].iter().enumerate() {
+                let rect = Rect {
+                    x: body.x + column as f64 * (card_width + GAP),
+                    y,
+                    width: card_width,
+                    height: CUT_OUT_CARD_HEIGHT,
+                };
+
+                page.rect(rect, Paint::Dashed);
+                draw_card(printer, &mut page, card, rect, CARD_TEXT_SIZE);
+            }
+        }
+
+        printer.add_page(page)?;
+    }
+
+    if answer_key {
+        print_cards_answer_key(printer, pairs, None)?;
+    }
+
+    Ok(())
+}
+
+fn print_worksheet(
+    printer: &mut Printer<'_>,
+    pairs: &[CardPair],
+    answer_key: bool,
+) -> anyhow::Result<()> {
+    let rows = rows_per_page(
+        printer.body(PORTRAIT).height - HEADING_SIZE - GAP,
+        WORKSHEET_ROW_HEIGHT,
+    );
+
+    let order = worksheet_order(pairs.len(), rows);
+
+    for (chunk, start) in order.chunks(rows).zip((0..).step_by(rows)) {
+        let (mut page, body) = printer.page(PORTRAIT);
+
+        page.text(
+            body.x,
+            body.y + HEADING_SIZE,
+            HEADING_SIZE,
+            "Match every card on the left with a card on the right.",
+        );
+
+        let top = body.y + HEADING_SIZE + GAP;
+
+        let card_width = (body.width - 2.0 * LABEL_WIDTH - BLANK_WIDTH - 2.0 * GAP) / 2.0;
+
+        for (row, &right) in chunk.iter().enumerate() {
+            let index = start + row;
+
+            let y = top + row as f64 * (WORKSHEET_ROW_HEIGHT + GAP);
+            let baseline = y + WORKSHEET_ROW_HEIGHT / 2.0 + HEADING_SIZE * 0.35;
+
+            page.text(body.x, baseline, HEADING_SIZE, &format!("{}.", index + 1));
+
+            let left_card = Rect {
+                x: body.x + LABEL_WIDTH,
+                y,
+                width: card_width,
+                height: WORKSHEET_ROW_HEIGHT,
+            };
+
+            page.rect(left_card, Paint::Stroke);
+            draw_card(
+                printer,
+                &mut page,
+                &pairs[index].0,
+                left_card,
+                CARD_TEXT_SIZE,
+            );
+
+            let blank_x = left_card.x + card_width + GAP;
+            page.line(
+                (blank_x, baseline + 2.0),
+                (blank_x + BLANK_WIDTH, baseline + 2.0),
+            );
+
+            let letter_x = blank_x + BLANK_WIDTH + GAP;
+            page.text(
+                letter_x,
+                baseline,
+                HEADING_SIZE,
+                &format!("{}.", letter(index)),
+            );
+
+            let right_card = Rect {
+                x: letter_x + LABEL_WIDTH,
+                ..left_card
+            };
+
+            page.rect(right_card, Paint::Stroke);
+            draw_card(
+                printer,
+                &mut page,
+                &pairs[right].1,
+                right_card,
+                CARD_TEXT_SIZE,
+            );
+        }
+
+        printer.add_page(page)?;
+    }
+
+    if answer_key {
+        print_cards_answer_key(printer, pairs, Some(&order))?;
+    }
+
+    Ok(())
+}
+
+/// For every row of a worksheet, the pair whose second card is shown on it.
+///
+/// Pairs are only shuffled within a page, so that matching cards are always on the same page. The
+/// shuffle is seeded, printing the same module twice gives the same worksheet.
+fn worksheet_order(len: usize, rows_per_page: usize) -> Vec<usize> {
+    let mut rng = StdRng::seed_from_u64(len as u64);
+
+    let mut order: Vec<usize> = (0..len).collect();
+
+    for chunk in order.chunks_mut(rows_per_page) {
+        chunk.shuffle(&mut rng);
+
+        // A worksheet where every card is already next to its match isn't much of a worksheet.
+        if chunk.len() > 1 && chunk.windows(2).all(|it| it[0] < it[1]) {
+            chunk.rotate_left(1);
+        }
+    }
+
+    order
+}
+
+/// Lists every pair, with the letter of its match if it was printed as a worksheet with `order`.
+fn print_cards_answer_key(
+    printer: &mut Printer<'_>,
+    pairs: &[CardPair],
+    order: Option<&[usize]>,
+) -> anyhow::Result<()> {
+    let rows = rows_per_page(
+        printer.body(PORTRAIT).height - HEADING_SIZE - GAP,
+        ANSWER_KEY_ROW_HEIGHT,
+    );
+
+    let card_width = (printer.body(PORTRAIT).width - 2.0 * LABEL_WIDTH - 2.0 * GAP) / 2.0;
+
+    for (chunk, start) in pairs.chunks(rows).zip((0..).step_by(rows)) {
+        let (mut page, body) = printer.page(PORTRAIT);
+
+        page.text(body.x, body.y + HEADING_SIZE, HEADING_SIZE, "Answer key");
+
+        let top = body.y + HEADING_SIZE + GAP;
+
+        for (row, CardPair(left, right)) in chunk.iter().enumerate() {
+            let index = start + row;
+
+            let y = top + row as f64 * (ANSWER_KEY_ROW_HEIGHT + GAP);
+            let baseline = y + ANSWER_KEY_ROW_HEIGHT / 2.0 + HEADING_SIZE * 0.35;
+
+            let label = match order {
+                Some(order) => {
+                    let row = order.iter().position(|&it| it == index).unwrap_or(index);
+                    format!("{}. {}", index + 1, letter(row))
+                }
+                None => format!("{}.", index + 1),
+            };
+
+            page.text(body.x, baseline, HEADING_SIZE, &label);
+
+            let left_card = Rect {
+                x: body.x + 2.0 * LABEL_WIDTH + GAP,
+                y,
+                width: card_width,
+                height: ANSWER_KEY_ROW_HEIGHT,
+            };
+
+            let right_card = Rect {
+                x: left_card.x + card_width + GAP,
+                ..left_card
+            };
+
+            for (card, rect) in [(left, left_card), (right, right_card)] {
+                page.rect(rect, Paint::Stroke);
+                draw_card(printer, &mut page, card, rect, HEADING_SIZE);
+            }
+        }
+
+        printer.add_page(page)?;
+    }
+
+    Ok(())
+}
+
+fn draw_card(printer: &mut Printer<'_>, page: &mut Page, card: &Card, rect: Rect, size: f64) {
+    let inner = rect.inset(8.0);
+
+    match &card.card_content {
+        CardContent::Text(text) => page.text_box(inner, size, text),
+        CardContent::Image(Some(image)) => {
+            if let Some(image) = printer.image(image.id) {
+                page.image_fit(&image, inner);
+            }
+        }
+        CardContent::Image(None) => {}
+    }
+}
+
+fn print_design(printer: &mut Printer<'_>, base: &design::BaseContent) -> anyhow::Result<()> {
+    let (mut page, body) = printer.page(LANDSCAPE);
+    let stage = Stage::fit(body);
+
+    draw_backgrounds(printer, &mut page, &stage, &base.backgrounds);
+
+    for sticker in &base.stickers {
+        draw_sticker(printer, &mut page, &stage, sticker, sticker.transform());
+    }
+
+    printer.add_page(page)
+}
+
+/// Prints the board with every trace numbered, followed by a legend.
+///
+/// The legend names every trace, unless there's an answer key, then it's left blank for students to
+/// fill in and the answer key names them instead.
+fn print_tapping_board(
+    printer: &mut Printer<'_>,
+    content: &tapping_board::Content,
+    answer_key: bool,
+) -> anyhow::Result<()> {
+    let (mut page, body) = printer.page(LANDSCAPE);
+    let stage = Stage::fit(body);
+
+    draw_backgrounds(printer, &mut page, &stage, &content.base.backgrounds);
+
+    for sticker in &content.base.stickers {
+        draw_sticker(printer, &mut page, &stage, sticker, sticker.transform());
+    }
+
+    let outlines: Vec<_> = content
+        .traces
+        .iter()
+        .map(|trace| Outline::of(&stage, trace))
+        .collect();
+
+    for outline in &outlines {
+        page.outline(outline, Paint::Trace);
+    }
+
+    for (index, outline) in outlines.iter().enumerate() {
+        page.badge(outline.center(), &(index + 1).to_string());
+    }
+
+    printer.add_page(page)?;
+
+    print_legend(printer, "Labels", &content.traces, !answer_key)?;
+
+    if answer_key {
+        print_legend(printer, "Answer key", &content.traces, true)?;
+    }
+
+    Ok(())
+}
+
+fn print_legend(
+    printer: &mut Printer<'_>,
+    heading: &str,
+    traces: &[Trace],
+    named: bool,
+) -> anyhow::Result<()> {
+    let rows = rows_per_page(
+        printer.body(PORTRAIT).height - HEADING_SIZE - GAP,
+        LEGEND_ROW_HEIGHT - GAP,
+    );
+
+    for (chunk, start) in traces.chunks(rows).zip((0..).step_by(rows)) {
+        let (mut page, body) = printer.page(PORTRAIT);
+
+        page.text(body.x, body.y + HEADING_SIZE, HEADING_SIZE, heading);
+
+        let top = body.y + HEADING_SIZE + GAP;
+
+        for (row, trace) in chunk.iter().enumerate() {
+            let baseline = top + (row + 1) as f64 * LEGEND_ROW_HEIGHT;
+
+            page.text(
+                body.x,
+                baseline,
+                HEADING_SIZE,
+                &format!("{}.", start + row + 1),
+            );
+
+            let text_x = body.x + LABEL_WIDTH;
+
+            match trace.text.as_deref().map(str::trim) {
+                Some(text) if named && !text.is_empty() => {
+                    page.text(text_x, baseline, HEADING_SIZE, text)
+                }
+                _ => page.line((text_x, baseline + 2.0), (body.right(), baseline + 2.0)),
+            }
+        }
+
+        printer.add_page(page)?;
+    }
+
+    Ok(())
+}
+
+/// Prints the board with every item where it starts, and target areas outlined.
+///
+/// The answer key shows every interactive item at its target instead.
+fn print_drag_drop(
+    printer: &mut Printer<'_>,
+    content: &drag_drop::Content,
+    answer_key: bool,
+) -> anyhow::Result<()> {
+    print_drag_drop_page(printer, content, false)?;
+
+    if answer_key {
+        print_drag_drop_page(printer, content, true)?;
+    }
+
+    Ok(())
+}
+
+fn print_drag_drop_page(
+    printer: &mut Printer<'_>,
+    content: &drag_drop::Content,
+    solved: bool,
+) -> anyhow::Result<()> {
+    let (mut page, body) = printer.page(LANDSCAPE);
+
+    if solved {
+        page.text(body.x, body.y + HEADING_SIZE, HEADING_SIZE, "Answer key");
+    }
+
+    let stage = Stage::fit(Rect {
+        y: body.y + HEADING_SIZE + GAP,
+        height: body.height - HEADING_SIZE - GAP,
+        ..body
+    });
+
+    draw_backgrounds(printer, &mut page, &stage, &content.backgrounds);
+
+    for area in &content.target_areas {
+        let outline = Outline::of(&stage, &area.trace);
+
+        page.outline(&outline, Paint::Dashed);
+
+        if let Some(label) = area.label.as_deref() {
+            let (x, y) = outline.center();
+            page.text_centered(x, y, HEADING_SIZE, label);
+        }
+    }
+
+    for item in &content.items {
+        let transform = match &item.kind {
+            ItemKind::Interactive(interactive) if solved => interactive
+                .target_transform
+                .as_ref()
+                .unwrap_or_else(|| item.sticker.transform()),
+            _ => item.sticker.transform(),
+        };
+
+        draw_sticker(printer, &mut page, &stage, &item.sticker, transform);
+    }
+
+    printer.add_page(page)
+}
+
+fn draw_backgrounds(
+    printer: &mut Printer<'_>,
+    page: &mut Page,
+    stage: &Stage,
+    backgrounds: &Backgrounds,
+) {
+    for background in backgrounds.layer_1.iter().chain(&backgrounds.layer_2) {
+        match background {
+            Background::Color(Some(color)) if color.a > 0 => {
+                let color = [color.r, color.g, color.b].map(|it| f64::from(it) / 255.0);
+                page.rect(stage.0, Paint::Fill(color));
+            }
+            Background::Color(_) => {}
+            Background::Image(image) => {
+                if let Some(image) = printer.image(image.id) {
+                    page.image(&image, stage.0);
+                }
+            }
+        }
+    }
+
+    page.rect(stage.0, Paint::Stroke);
+}
+
+/// Draws a sticker as if it had `transform`.
+///
+/// Videos can't be printed, they're drawn as a placeholder.
+fn draw_sticker(
+    printer: &mut Printer<'_>,
+    page: &mut Page,
+    stage: &Stage,
+    sticker: &Sticker,
+    transform: &Transform,
+) {
+    let [x, y, _] = transform.translation.0;
+    let [scale_x, scale_y, _] = transform.scale.0;
+
+    // Stickers are positioned by their center, relative to the center of the stage.
+    let center = stage.point(0.5 + x, 0.5 + y);
+
+    // Rotation around the z axis, the only one stickers have.
+    let [_, _, z, w] = transform.rotation.0;
+    let angle = 2.0 * z.atan2(w);
+
+    let px = stage.0.width / STAGE_WIDTH_PX;
+
+    match sticker {
+        Sticker::Sprite(sprite) => {
+            if let Some(image) = printer.image(sprite.image.id) {
+                let (width, height) = image.size;
+                let rect = Rect::centered(center, width * px * scale_x, height * px * scale_y);
+
+                page.rotated(center, angle, |page| page.image(&image, rect));
+            }
+        }
+        Sticker::Text(text) => {
+            let size = STICKER_TEXT_SIZE_PX * px * scale_y;
+            let lines: Vec<_> = text_value(&text.value).lines().map(str::to_owned).collect();
+
+            let top = center.1 - lines.len() as f64 * size * LINE_HEIGHT / 2.0;
+
+            page.rotated(center, angle, |page| {
+                for (index, line) in lines.iter().enumerate() {
+                    let baseline = top + (index as f64 + 0.5) * size * LINE_HEIGHT + size * 0.35;
+                    page.text_centered(center.0, baseline, size, line);
+                }
+            });
+        }
+        Sticker::Video(_) => {
+            let width = stage.0.width * 0.4 * scale_x;
+            let rect = Rect::centered(center, width, width * 9.0 / 16.0 * scale_y / scale_x);
+
+            page.rotated(center, angle, |page| {
+                page.rect(rect, Paint::Dashed);
+                page.text_box(rect, HEADING_SIZE, "Video");
+            });
+        }
+    }
+}
+
+/// The plain text of a text sticker, whose value is a serialized Slate document.
+fn text_value(value: &str) -> String {
+    let document: Value = match serde_json::from_str(value) {
+        Ok(document) => document,
+        Err(_) => return value.to_owned(),
+    };
+
+    let blocks = match document.get("content").and_then(Value::as_array) {
+        Some(blocks) => blocks,
+        None => return String::new(),
+    };
+
+    blocks
+        .iter()
+        .map(|block| {
+            let mut text = String::new();
+            collect_text(block, &mut text);
+            text
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn collect_text(node: &Value, text: &mut String) {
+    if let Some(value) = node.get("text").and_then(Value::as_str) {
+        text.push_str(value);
+    }
+
+    if let Some(children) = node.get("children").and_then(Value::as_array) {
+        for child in children {
+            collect_text(child, text);
+        }
+    }
+}
+
+/// How many rows of `row_height` (with a [`GAP`] between them) fit in `height`.
+fn rows_per_page(height: f64, row_height: f64) -> usize {
+    (((height + GAP) / (row_height + GAP)).floor() as usize).max(1)
+}
+
+/// `A`, `B`, ..., `Z`, `AA`, `AB`, ...
+fn letter(index: usize) -> String {
+    let mut index = index + 1;
+    let mut letters = Vec::new();
+
+    while index > 0 {
+        index -= 1;
+        letters.push(b'A' + (index % 26) as u8);
+        index /= 26;
+    }
+
+    letters.iter().rev().map(|&it| char::from(it)).collect()
+}
+
+/// A rectangle on a page, with its origin in the top left corner.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Rect {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+impl Rect {
+    fn centered((x, y): (f64, f64), width: f64, height: f64) -> Self {
+        Self {
+            x: x - width / 2.0,
+            y: y - height / 2.0,
+            width,
+            height,
+        }
+    }
+
+    fn inset(self, by: f64) -> Self {
+        Self {
+            x: self.x + by,
+            y: self.y + by,
+            width: (self.width - 2.0 * by).max(0.0),
+            height: (self.height - 2.0 * by).max(0.0),
+        }
+    }
+
+    fn right(self) -> f64 {
+        self.x + self.width
+    }
+
+    fn center(self) -> (f64, f64) {
+        (self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+}
+
+/// Where the stage of a module is drawn, module coordinates are normalized to it.
+struct Stage(Rect);
+
+impl Stage {
+    /// The largest 16:9 stage that fits in `area`, centered horizontally.
+    fn fit(area: Rect) -> Self {
+        let width = area.width.min(area.height * 16.0 / 9.0);
+
+        Self(Rect {
+            x: area.x + (area.width - width) / 2.0,
+            y: area.y,
+            width,
+            height: width * 9.0 / 16.0,
+        })
+    }
+
+    fn point(&self, x: f64, y: f64) -> (f64, f64) {
+        (self.0.x + x * self.0.width, self.0.y + y * self.0.height)
+    }
+
+    fn rect(&self, x: f64, y: f64, width: f64, height: f64) -> Rect {
+        let (x, y) = self.point(x, y);
+
+        Rect {
+            x,
+            y,
+            width: width * self.0.width,
+            height: height * self.0.height,
+        }
+    }
+}
+
+/// The outline of a trace.
+enum Outline {
+    Rect(Rect),
+    Ellipse(Rect),
+    Polygon(Vec<(f64, f64)>),
+}
+
+impl Outline {
+    /// Traces are positioned by the top left corner of their shape, except for paths, which have
+    /// absolute points.
+    ///
+    /// Curves in path commands are printed as straight lines between their end points.
+    fn of(stage: &Stage, trace: &Trace) -> Self {
+        let [x, y, _] = trace.transform.translation.0;
+        let [scale_x, scale_y, _] = trace.transform.scale.0;
+
+        match &trace.shape {
+            TraceShape::Rect(width, height) => {
+                Self::Rect(stage.rect(x, y, width * scale_x, height * scale_y))
+            }
+            TraceShape::Ellipse(radius_x, radius_y) => {
+                Self::Ellipse(stage.rect(x, y, 2.0 * radius_x * scale_x, 2.0 * radius_y * scale_y))
+            }
+            TraceShape::Path(points) => {
+                Self::Polygon(points.iter().map(|&(x, y)| stage.point(x, y)).collect())
+            }
+            TraceShape::PathCommands(commands) => Self::Polygon(
+                path_points(commands)
+                    .into_iter()
+                    .map(|(x, y)| stage.point(x, y))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn center(&self) -> (f64, f64) {
+        match self {
+            Self::Rect(rect) | Self::Ellipse(rect) => rect.center(),
+            Self::Polygon(points) => {
+                let (min_x, max_x) = min_max(points.iter().map(|it| it.0));
+                let (min_y, max_y) = min_max(points.iter().map(|it| it.1));
+
+                ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0)
+            }
+        }
+    }
+}
+
+fn min_max(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), it| {
+        (min.min(it), max.max(it))
+    })
+}
+
+/// The end point of every path command, made absolute.
+fn path_points(commands: &[(PathCommand, bool)]) -> Vec<(f64, f64)> {
+    let mut points = Vec::new();
+
+    let mut current = (0.0, 0.0);
+    let mut start = current;
+
+    for (command, absolute) in commands {
+        let to = |x: f64, y: f64| {
+            if *absolute {
+                (x, y)
+            } else {
+                (current.0 + x, current.1 + y)
+            }
+        };
+
+        current = match *command {
+            PathCommand::MoveTo(x, y) => {
+                start = to(x, y);
+                start
+            }
+            PathCommand::ClosePath => start,
+            PathCommand::LineTo(x, y)
+            | PathCommand::CurveTo(_, _, _, _, x, y)
+            | PathCommand::SmoothCurveTo(_, _, x, y)
+            | PathCommand::QuadCurveTo(_, _, x, y)
+            | PathCommand::SmoothQuadCurveTo(x, y)
+            | PathCommand::ArcTo(_, _, _, _, _, x, y) => to(x, y),
+            PathCommand::HorizontalLineTo(x) if *absolute => (x, current.1),
+            PathCommand::HorizontalLineTo(x) => (current.0 + x, current.1),
+            PathCommand::VerticalLineTo(y) if *absolute => (current.0, y),
+            PathCommand::VerticalLineTo(y) => (current.0, current.1 + y),
+        };
+
+        points.push(current);
+    }
+
+    points
+}
+
+#[derive(Clone, Copy)]
+enum Paint {
+    Stroke,
+    Dashed,
+    Trace,
+    Fill([f64; 3]),
+}
+
+/// An image embedded in the document.
+#[derive(Clone)]
+struct EmbeddedImage {
+    name: String,
+    id: ObjectId,
+    /// In pixels.
+    size: (f64, f64),
+}
+
+/// Builds the document, page by page.
+struct Printer<'a> {
+    document: Document,
+    pages_id: ObjectId,
+    resources_id: ObjectId,
+    pages: Vec<Object>,
+    title: &'a str,
+    images: &'a HashMap<ImageId, DynamicImage>,
+    embedded: HashMap<ImageId, EmbeddedImage>,
+    glyphs: font::Subset,
+}
+
+impl<'a> Printer<'a> {
+    fn new(title: &'a str, images: &'a HashMap<ImageId, DynamicImage>) -> Self {
+        let mut document = Document::with_version("1.5");
+
+        let pages_id = document.new_object_id();
+        let resources_id = document.new_object_id();
+
+        Self {
+            document,
+            pages_id,
+            resources_id,
+            pages: Vec::new(),
+            title,
+            images,
+            embedded: HashMap::new(),
+            glyphs: font::Subset::default(),
+        }
+    }
+
+    /// The area of a page of `size` below its header.
+    fn body(&self, (width, height): (f64, f64)) -> Rect {
+        Rect {
+            x: MARGIN,
+            y: MARGIN + HEADER_HEIGHT,
+            width: width - 2.0 * MARGIN,
+            height: height - 2.0 * MARGIN - HEADER_HEIGHT,
+        }
+    }
+
+    /// Starts a page with the document's title as its header.
+    fn page(&self, size: (f64, f64)) -> (Page, Rect) {
+        let body = self.body(size);
+
+        let mut page = Page {
+            size,
+            operations: Vec::new(),
+            glyphs: font::Subset::default(),
+        };
+
+        page.text(MARGIN, MARGIN + TITLE_SIZE, TITLE_SIZE, self.title);
+        page.line(
+            (MARGIN, MARGIN + TITLE_SIZE + 8.0),
+            (body.right(), MARGIN + TITLE_SIZE + 8.0),
+        );
+
+        (page, body)
+    }
+
+    fn add_page(&mut self, page: Page) -> anyhow::Result<()> {
+        self.glyphs.merge(page.glyphs);
+
+        let content = Content {
+            operations: page.operations,
+        }
+        .encode()?;
+
+        let mut content = Stream::new(dictionary! {}, content);
+        content.compress()?;
+
+        let content_id = self.document.add_object(content);
+
+        let (width, height) = page.size;
+
+        let page_id = self.document.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => self.pages_id,
+            "MediaBox" => vec![0.into(), 0.into(), width.into(), height.into()],
+            "Resources" => self.resources_id,
+            "Contents" => content_id,
+        });
+
+        self.pages.push(page_id.into());
+
+        Ok(())
+    }
+
+    /// Embeds an image the first time it's used, `None` if it wasn't passed in.
+    fn image(&mut self, id: ImageId) -> Option<EmbeddedImage> {
+        if let Some(embedded) = self.embedded.get(&id) {
+            return Some(embedded.clone());
+        }
+
+        let image = self.images.get(&id)?.to_rgba8();
+        let (width, height) = image.dimensions();
+
+        let mut rgb = Vec::with_capacity(image.len() / 4 * 3);
+        let mut alpha = Vec::with_capacity(image.len() / 4);
+
+        for pixel in image.pixels() {
+            rgb.extend_from_slice(&pixel.0[..3]);
+            alpha.push(pixel.0[3]);
+        }
+
+        let image_stream = |color_space: &str, data: Vec<u8>| {
+            let mut stream = Stream::new(
+                dictionary! {
+                    "Type" => "XObject",
+                    "Subtype" => "Image",
+                    "Width" => width as i64,
+                    "Height" => height as i64,
+                    "ColorSpace" => color_space,
+                    "BitsPerComponent" => 8,
+                },
+                data,
+            );
+
+            // compressing is best effort, an uncompressed image is still valid.
+            let _ = stream.compress();
+
+            stream
+        };
+
+        let mask_id = self.document.add_object(image_stream("DeviceGray", alpha));
+
+        let mut image = image_stream("DeviceRGB", rgb);
+        image.dict.set("SMask", mask_id);
+
+        let embedded = EmbeddedImage {
+            name: format!("Im{}", self.embedded.len()),
+            id: self.document.add_object(image),
+            size: (f64::from(width), f64::from(height)),
+        };
+
+        self.embedded.insert(id, embedded.clone());
+
+        Some(embedded)
+    }
+
+    fn finish(mut self) -> anyhow::Result<Vec<u8>> {
+        let font_id = self.glyphs.embed(&mut self.document)?;
+
+        let mut images = Dictionary::new();
+
+        for image in self.embedded.values() {
+            images.set(image.name.clone(), image.id);
+        }
+
+        self.document.objects.insert(
+            self.resources_id,
+            Object::Dictionary(dictionary! {
+                "Font" => dictionary! { "F1" => font_id },
+                "XObject" => images,
+            }),
+        );
+
+        let count = self.pages.len() as i64;
+
+        self.document.objects.insert(
+            self.pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Count" => count,
+                "Kids" => self.pages,
+            }),
+        );
+
+        let catalog_id = self.document.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => self.pages_id,
+        });
+
+        // UTF-16BE (with a byte order mark), so that titles aren't limited to `PDFDocEncoding`.
+        let mut title = vec![0xfe, 0xff];
+        title.extend(self.title.encode_utf16().flat_map(u16::to_be_bytes));
+
+        let info_id = self.document.add_object(dictionary! {
+            "Title" => Object::String(title, StringFormat::Hexadecimal),
+        });
+
+        self.document.trailer.set("Root", catalog_id);
+        self.document.trailer.set("Info", info_id);
+
+        let mut buffer = Vec::new();
+        self.document.save_to(&mut buffer)?;
+
+        Ok(buffer)
+    }
+}
+
+/// The content of a page, drawn with its origin in the top left corner.
+struct Page {
+    size: (f64, f64),
+    operations: Vec<Operation>,
+    glyphs: font::Subset,
+}
+
+impl Page {
+    fn op(&mut self, operator: &str, operands: Vec<Object>) {
+        self.operations.push(Operation::new(operator, operands));
+    }
+
+    /// Flips `y` into pdf coordinates, which start at the bottom.
+    fn y(&self, y: f64) -> f64 {
+        self.size.1 - y
+    }
+
+    fn paint(&mut self, paint: Paint, path: impl FnOnce(&mut Self)) {
+        self.op("q", vec![]);
+
+        match paint {
+            Paint::Stroke => self.op("w", vec![real(1.0)]),
+            Paint::Dashed => {
+                self.op("w", vec![real(0.75)]);
+                self.op("d", vec![vec![real(4.0), real(3.0)].into(), 0.into()]);
+            }
+            Paint::Trace => {
+                self.op("w", vec![real(2.0)]);
+                self.op("RG", TRACE_COLOR.iter().map(|&it| real(it)).collect());
+            }
+            Paint::Fill(color) => self.op("rg", color.iter().map(|&it| real(it)).collect()),
+        }
+
+        path(self);
+
+        match paint {
+            Paint::Fill(_) => self.op("f", vec![]),
+            _ => self.op("S", vec![]),
+        }
+
+        self.op("Q", vec![]);
+    }
+
+    fn rect(&mut self, rect: Rect, paint: Paint) {
+        self.paint(paint, |page| {
+            let y = page.y(rect.y + rect.height);
+            page.op(
+                "re",
+                vec![real(rect.x), real(y), real(rect.width), real(rect.height)],
+            );
+        });
+    }
+
+    fn line(&mut self, from: (f64, f64), to: (f64, f64)) {
+        self.paint(Paint::Stroke, |page| {
+            page.move_to(from);
+            page.line_to(to);
+        });
+    }
+
+    fn move_to(&mut self, (x, y): (f64, f64)) {
+        let y = self.y(y);
+        self.op("m", vec![real(x), real(y)]);
+    }
+
+    fn line_to(&mut self, (x, y): (f64, f64)) {
+        let y = self.y(y);
+        self.op("l", vec![real(x), real(y)]);
+    }
+
+    /// An ellipse filling `rect`, made of four bezier curves.
+    fn ellipse_path(&mut self, rect: Rect) {
+        // Distance of the control points from the ends of each quarter, relative to the radius.
+        const KAPPA: f64 = 0.552_284_75;
+
+        let (cx, cy) = rect.center();
+        let (rx, ry) = (rect.width / 2.0, rect.height / 2.0);
+        let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+
+        let curves = [
+            [(cx + rx, cy + ky), (cx + kx, cy + ry), (cx, cy + ry)],
+            [(cx - kx, cy + ry), (cx - rx, cy + ky), (cx - rx, cy)],
+            [(cx - rx, cy - ky), (cx - kx, cy - ry), (cx, cy - ry)],
+            [(cx + kx, cy - ry), (cx + rx, cy - ky), (cx + rx, cy)],
+        ];
+
+        self.move_to((cx + rx, cy));
+
+        for curve in curves {
+            let operands = curve
+                .iter()
+                .flat_map(|&(x, y)| [real(x), real(self.y(y))])
+                .collect();
+
+            self.op("c", operands);
+        }
+
+        self.op("h", vec![]);
+    }
+
+    fn outline(&mut self, outline: &Outline, paint: Paint) {
+        match outline {
+            Outline::Rect(rect) => self.rect(*rect, paint),
+            Outline::Ellipse(rect) => self.paint(paint, |page| page.ellipse_path(*rect)),
+            Outline::Polygon(points) => self.paint(paint, |page| {
+                if let Some((&first, rest)) = points.split_first() {
+                    page.move_to(first);
+
+                    for &point in rest {
+                        page.line_to(point);
+                    }
+
+                    page.op("h", vec![]);
+                }
+            }),
+        }
+    }
+
+    /// A numbered circle.
+    fn badge(&mut self, center: (f64, f64), label: &str) {
+        let rect = Rect::centered(center, 2.0 * BADGE_RADIUS, 2.0 * BADGE_RADIUS);
+
+        self.paint(Paint::Fill(WHITE), |page| page.ellipse_path(rect));
+        self.paint(Paint::Trace, |page| page.ellipse_path(rect));
+        self.text_centered(center.0, center.1 + 4.0, 11.0, label);
+    }
+
+    fn text(&mut self, x: f64, baseline: f64, size: f64, text: &str) {
+        let y = self.y(baseline);
+        let glyphs = font::layout(text);
+
+        self.op("BT", vec![]);
+        self.op("rg", BLACK.iter().map(|&it| real(it)).collect());
+        self.op("Tf", vec!["F1".into(), real(size)]);
+        self.op("Td", vec![real(x), real(y)]);
+        self.op("Tj", vec![font::encode(&glyphs)]);
+        self.op("ET", vec![]);
+
+        self.glyphs.add(&glyphs);
+    }
+
+    fn text_centered(&mut self, center_x: f64, baseline: f64, size: f64, text: &str) {
+        self.text(
+            center_x - font::width(text, size) / 2.0,
+            baseline,
+            size,
+            text,
+        );
+    }
+
+    /// Wraps text to fit in `rect` and centers it in it.
+    ///
+    /// Text that doesn't fit is shrunk down to [`MIN_TEXT_SIZE`], and then cut off.
+    fn text_box(&mut self, rect: Rect, size: f64, text: &str) {
+        let mut size = size;
+
+        let lines = loop {
+            let lines = wrap(text, size, rect.width);
+
+            if size <= MIN_TEXT_SIZE || lines.len() as f64 * size * LINE_HEIGHT <= rect.height {
+                break lines;
+            }
+
+            size = (size - 2.0).max(MIN_TEXT_SIZE);
+        };
+
+        let line_height = size * LINE_HEIGHT;
+
+        let max_lines = ((rect.height / line_height).floor() as usize).max(1);
+        let lines = &lines[..lines.len().min(max_lines)];
+
+        let top = rect.y + (rect.height - lines.len() as f64 * line_height) / 2.0;
+        let center_x = rect.x + rect.width / 2.0;
+
+        for (index, line) in lines.iter().enumerate() {
+            let baseline = top + (index as f64 + 0.5) * line_height + size * 0.35;
+            self.text_centered(center_x, baseline, size, line);
+        }
+    }
+
+    /// Draws an image stretched over `rect`.
+    fn image(&mut self, image: &EmbeddedImage, rect: Rect) {
+        let y = self.y(rect.y + rect.height);
+
+        self.op("q", vec![]);
+        self.op(
+            "cm",
+            vec![
+                real(rect.width),
+                0.into(),
+                0.into(),
+                real(rect.height),
+                real(rect.x),
+                real(y),
+            ],
+        );
+        self.op("Do", vec![Object::Name(image.name.clone().into_bytes())]);
+        self.op("Q", vec![]);
+    }
+
+    /// Draws an image as large as it fits in `rect` (keeping its aspect ratio), centered in it.
+    fn image_fit(&mut self, image: &EmbeddedImage, rect: Rect) {
+        let (width, height) = image.size;
+        let scale = (rect.width / width).min(rect.height / height);
+
+        self.image(
+            image,
+            Rect::centered(rect.center(), width * scale, height * scale),
+        );
+    }
+
+    /// Draws with everything rotated clockwise by `angle` (in radians) around `center`.
+    fn rotated(&mut self, center: (f64, f64), angle: f64, draw: impl FnOnce(&mut Self)) {
+        if angle.abs() < f64::EPSILON {
+            draw(self);
+            return;
+        }
+
+        let (x, y) = (center.0, self.y(center.1));
+        let (sin, cos) = angle.sin_cos();
+
+        self.op("q", vec![]);
+        self.op(
+            "cm",
+            vec![1.into(), 0.into(), 0.into(), 1.into(), real(x), real(y)],
+        );
+        // pdf's y axis points up, so clockwise is a negative angle.
+        self.op(
+            "cm",
+            vec![
+                real(cos),
+                real(-sin),
+                real(sin),
+                real(cos),
+                0.into(),
+                0.into(),
+            ],
+        );
+        self.op(
+            "cm",
+            vec![1.into(), 0.into(), 0.into(), 1.into(), real(-x), real(-y)],
+        );
+
+        draw(self);
+
+        self.op("Q", vec![]);
+    }
+}
+
+fn real(value: f64) -> Object {
+    Object::Real((value * 1000.0).round() / 1000.0)
+}
+
+/// Greedily wraps text into lines no wider than `width`, only breaking between words.
+fn wrap(text: &str, size: f64, width: f64) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for paragraph in text.lines() {
+        let mut line = String::new();
+
+        for word in paragraph.split_whitespace() {
+            if line.is_empty() {
+                line.push_str(word);
+                continue;
+            }
+
+            let candidate = format!("{} {}", line, word);
+
+            if font::width(&candidate, size) <= width {
+                line = candidate;
+            } else {
+                lines.push(std::mem::replace(&mut line, word.to_owned()));
+            }
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use image::{DynamicImage, RgbaImage};
+    use lopdf::{Document, Object};
+    use shared::domain::{
+        image::ImageId,
+        jig::module::{
+            body::{
+                _groups::{
+                    cards::{Card, CardContent, CardPair},
+                    design::{Sprite, Sticker, Text, Trace, TraceKind, TraceShape},
+                },
+                flashcards, poster, tapping_board, Body, Image, Transform,
+            },
+            printable::PrintableLayout,
+        },
+    };
+    use shared::media::MediaLibrary;
+    use uuid::Uuid;
+
+    use super::{font, images, letter, render, text_value, worksheet_order};
+
+    fn text_card(text: &str) -> Card {
+        Card {
+            audio: None,
+            card_content: CardContent::Text(text.to_owned()),
+        }
+    }
+
+    fn flashcards(pairs: usize) -> Body {
+        let mut content = flashcards::Content::default();
+
+        content.base.pairs = (0..pairs)
+            .map(|it| CardPair(text_card(&format!("word {}", it)), text_card("translation")))
+            .collect();
+
+        Body::Flashcards(flashcards::ModuleData {
+            content: Some(content),
+        })
+    }
+
+    fn page_count(pdf: &[u8]) -> usize {
+        Document::load_mem(pdf).unwrap().get_pages().len()
+    }
+
+    fn print(body: &Body, layout: PrintableLayout, answer_key: bool) -> Option<Vec<u8>> {
+        render(body, "Printable", layout, answer_key, &HashMap::new()).unwrap()
+    }
+
+    #[test]
+    fn cut_out_fits_five_pairs_per_page() {
+        let pdf = print(&flashcards(7), PrintableLayout::CutOut, false).unwrap();
+        assert_eq!(page_count(&pdf), 2);
+
+        let pdf = print(&flashcards(7), PrintableLayout::CutOut, true).unwrap();
+        assert_eq!(page_count(&pdf), 3);
+    }
+
+    #[test]
+    fn worksheet_has_answer_key() {
+        let pdf = print(&flashcards(3), PrintableLayout::Worksheet, false).unwrap();
+        assert_eq!(page_count(&pdf), 1);
+
+        let pdf = print(&flashcards(3), PrintableLayout::Worksheet, true).unwrap();
+        assert_eq!(page_count(&pdf), 2);
+    }
+
+    #[test]
+    fn worksheet_only_shuffles_within_pages() {
+        let order = worksheet_order(11, 5);
+
+        assert_eq!(order, worksheet_order(11, 5));
+
+        for (page, chunk) in order.chunks(5).enumerate() {
+            let mut sorted = chunk.to_vec();
+            sorted.sort_unstable();
+
+            let expected: Vec<_> = (page * 5..(page * 5 + 5).min(11)).collect();
+
+            assert_eq!(sorted, expected);
+        }
+
+        assert_ne!(&order[..5], &[0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn nothing_to_print() {
+        let empty = Body::Flashcards(flashcards::ModuleData { content: None });
+        assert!(print(&empty, PrintableLayout::CutOut, false).is_none());
+
+        assert!(print(&flashcards(0), PrintableLayout::CutOut, false).is_none());
+    }
+
+    #[test]
+    fn design_with_stickers() {
+        let id = ImageId(Uuid::from_u128(1));
+
+        let mut content = poster::Content::default();
+
+        content.base.stickers = vec![
+            Sticker::Sprite(Sprite {
+                image: Image {
+                    id,
+                    lib: MediaLibrary::User,
+                },
+                transform: Transform::identity(),
+                effects: Vec::new(),
+                flip_horizontal: false,
+                flip_vertical: false,
+            }),
+            Sticker::Text(Text::from_str("Hello (world)")),
+        ];
+
+        let body = Body::Poster(poster::ModuleData {
+            content: Some(content),
+        });
+
+        assert_eq!(images(&body).len(), 1);
+
+        let images: HashMap<_, _> = vec![(
+            id,
+            DynamicImage::ImageRgba8(RgbaImage::from_pixel(4, 2, image::Rgba([255, 0, 0, 128]))),
+        )]
+        .into_iter()
+        .collect();
+
+        let pdf = render(&body, "Poster", PrintableLayout::default(), true, &images)
+            .unwrap()
+            .unwrap();
+
+        let document = Document::load_mem(&pdf).unwrap();
+
+        assert_eq!(document.get_pages().len(), 1);
+
+        let preview = super::super::preview(&pdf).unwrap();
+        assert_eq!(preview.title.as_deref(), Some("Poster"));
+    }
+
+    #[test]
+    fn tapping_board_legend_and_answer_key() {
+        let content = tapping_board::Content {
+            traces: vec![
+                Trace {
+                    transform: Transform::identity(),
+                    shape: TraceShape::Rect(0.2, 0.1),
+                    kind: TraceKind::Regular,
+                    audio: None,
+                    text: Some("Head".to_owned()),
+                },
+                Trace {
+                    transform: Transform::identity(),
+                    shape: TraceShape::Path(vec![(0.1, 0.1), (0.3, 0.2), (0.2, 0.4)]),
+                    kind: TraceKind::Regular,
+                    audio: None,
+                    text: None,
+                },
+            ],
+            ..tapping_board::Content::default()
+        };
+
+        let body = Body::TappingBoard(tapping_board::ModuleData {
+            content: Some(content),
+        });
+
+        let pdf = print(&body, PrintableLayout::default(), false).unwrap();
+        assert_eq!(page_count(&pdf), 2);
+
+        let pdf = print(&body, PrintableLayout::default(), true).unwrap();
+        assert_eq!(page_count(&pdf), 3);
+    }
+
+    #[test]
+    fn letters() {
+        assert_eq!(letter(0), "A");
+        assert_eq!(letter(25), "Z");
+        assert_eq!(letter(26), "AA");
+        assert_eq!(letter(27), "AB");
+    }
+
+    /// The glyph IDs drawn on every page, one `Vec` per `Tj`.
+    fn drawn_glyphs(document: &Document) -> Vec<Vec<u16>> {
+        document
+            .get_pages()
+            .values()
+            .flat_map(|page| {
+                document
+                    .get_and_decode_page_content(*page)
+                    .unwrap()
+                    .operations
+            })
+            .filter(|operation| operation.operator == "Tj")
+            .map(|operation| match &operation.operands[..] {
+                [Object::String(bytes, _)] => bytes
+                    .chunks_exact(2)
+                    .map(|it| u16::from_be_bytes([it[0], it[1]]))
+                    .collect(),
+                operands => panic!("unexpected Tj operands: {:?}", operands),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn prints_hebrew() {
+        let mut content = flashcards::Content::default();
+        content.base.pairs = vec![CardPair(text_card("שָׁלוֹם"), text_card("hello"))];
+
+        let body = Body::Flashcards(flashcards::ModuleData {
+            content: Some(content),
+        });
+
+        let pdf = render(
+            &body,
+            "שלום עולם",
+            PrintableLayout::Worksheet,
+            true,
+            &HashMap::new(),
+        )
+        .unwrap()
+        .unwrap();
+
+        let document = Document::load_mem(&pdf).unwrap();
+        let drawn = drawn_glyphs(&document);
+
+        let question_mark = font::layout("?")[0].0 .0;
+        assert!(drawn
+            .iter()
+            .flatten()
+            .all(|glyph| *glyph != 0 && *glyph != question_mark));
+
+        // the title and the card are drawn right to left, so in reverse.
+        for text in &["שלום עולם", "שָׁלוֹם"] {
+            let glyphs: Vec<u16> = font::layout(text)
+                .iter()
+                .map(|(glyph, _)| glyph.0)
+                .collect();
+            assert!(drawn.contains(&glyphs), "{} isn't drawn", text);
+
+            let first = font::layout(text).first().unwrap().1;
+            assert_eq!(
+                first,
+                text.chars().rev().find(|c| c.is_alphabetic()).unwrap()
+            );
+        }
+
+        // and can be copied back out as text.
+        let to_unicode = document
+            .objects
+            .values()
+            .find_map(
+                |object| match object.as_dict().ok()?.get(b"ToUnicode").ok()? {
+                    Object::Reference(id) => document.get_object(*id).ok()?.as_stream().ok(),
+                    _ => None,
+                },
+            )
+            .unwrap()
+            .decompressed_content()
+            .unwrap();
+
+        let to_unicode = String::from_utf8(to_unicode).unwrap();
+        for c in "שלוםעhello".chars() {
+            assert!(
+                to_unicode.contains(&format!("<{:04X}>", u32::from(c))),
+                "{}",
+                c
+            );
+        }
+    }
+
+    #[test]
+    fn reads_slate_text() {
+        let value = r#"{"version":"0.1.0","content":[{"children":[{"text":"Hello "},{"text":"there","bold":true}]},{"children":[{"text":"again"}]}]}"#;
+
+        assert_eq!(text_value(value), "Hello there\nagain");
+        assert_eq!(text_value("plain"), "plain");
+    }
+}
@@ -0,0 +1,320 @@
+//! The font printables are drawn in.
+//!
+//! The bundled font is `DejaVuSans.ttf` (see `fonts/LICENSE`), it covers latin and hebrew among others. Each document embeds a
+//! subset with only the glyphs it uses, addressed by glyph ID (`Identity-H`), with a `ToUnicode` map so the text can
+//! still be copied and searched.
+//!
+//! There's no shaping: every character is drawn with its own glyph, after right to left runs are reordered for
+//! display. That's enough for hebrew, including points.
+
+use std::collections::BTreeMap;
+
+use lopdf::{dictionary, Document, Object, ObjectId, Stream, StringFormat};
+use once_cell::sync::Lazy;
+use ttf_parser::{Face, GlyphId};
+use unicode_bidi::BidiInfo;
+
+const DATA: &[u8] = include_bytes!("../../../fonts/DejaVuSans.ttf");
+
+const NAME: &str = "DejaVuSans";
+
+static FACE: Lazy<Face<'static>> =
+    Lazy::new(|| Face::from_slice(DATA, 0).expect("the bundled font is valid"));
+
+/// Glyph IDs are 2 bytes in the content stream, and in the `ToUnicode` map.
+const CODE_SPACE: &str = "<0000> <FFFF>";
+
+/// `bfchar` blocks can't have more entries than this.
+const MAX_BFCHAR_ENTRIES: usize = 100;
+
+/// The glyphs to draw `text` with, left to right.
+pub(super) fn layout(text: &str) -> Vec<(GlyphId, char)> {
+    visual(text)
+        .into_iter()
+        .map(|c| match c {
+            '\t' | '\n' | '\r' => ' ',
+            c => c,
+        })
+        .map(|c| (FACE.glyph_index(c).unwrap_or(GlyphId(0)), c))
+        .collect()
+}
+
+/// Width of `text` at `size`.
+pub(super) fn width(text: &str, size: f64) -> f64 {
+    let units: u32 = layout(text)
+        .into_iter()
+        .map(|(glyph, _)| u32::from(advance(glyph)))
+        .sum();
+
+    f64::from(units) * size / f64::from(FACE.units_per_em())
+}
+
+/// Encodes glyphs for a `Tj` operand.
+pub(super) fn encode(glyphs: &[(GlyphId, char)]) -> Object {
+    let bytes = glyphs
+        .iter()
+        .flat_map(|(glyph, _)| glyph.0.to_be_bytes())
+        .collect();
+
+    Object::String(bytes, StringFormat::Hexadecimal)
+}
+
+fn advance(glyph: GlyphId) -> u16 {
+    FACE.glyph_hor_advance(glyph).unwrap_or(0)
+}
+
+/// The characters of `text` in the order they're drawn, left to right.
+///
+/// Right to left runs are reversed with their brackets mirrored, combining marks (which have no width) stay after
+/// the letter they go on, since that's where the font positions them.
+fn visual(text: &str) -> Vec<char> {
+    let bidi = BidiInfo::new(text, None);
+
+    let mut chars = Vec::with_capacity(text.len());
+
+    for paragraph in &bidi.paragraphs {
+        let (levels, runs) = bidi.visual_runs(paragraph, paragraph.range.clone());
+
+        for run in runs {
+            let run_text = &text[run.clone()];
+
+            if !levels[run.start].is_rtl() {
+                chars.extend(run_text.chars());
+                continue;
+            }
+
+            let mut clusters: Vec<Vec<char>> = Vec::new();
+
+            for c in run_text.chars() {
+                match clusters.last_mut() {
+                    Some(cluster) if is_mark(c) => cluster.push(c),
+                    _ => clusters.push(vec![mirror(c)]),
+                }
+            }
+
+            chars.extend(clusters.into_iter().rev().flatten());
+        }
+    }
+
+    chars
+}
+
+fn is_mark(c: char) -> bool {
+    matches!(FACE.glyph_index(c), Some(glyph) if advance(glyph) == 0)
+}
+
+const fn mirror(c: char) -> char {
+    match c {
+        '(' => ')',
+        ')' => '(',
+        '[' => ']',
+        ']' => '[',
+        '{' => '}',
+        '}' => '{',
+        '<' => '>',
+        '>' => '<',
+        c => c,
+    }
+}
+
+/// The glyphs used in a document, and the characters they were used for.
+#[derive(Default)]
+pub(super) struct Subset(BTreeMap<u16, char>);
+
+impl Subset {
+    pub(super) fn add(&mut self, glyphs: &[(GlyphId, char)]) {
+        for (glyph, c) in glyphs {
+            self.0.entry(glyph.0).or_insert(*c);
+        }
+    }
+
+    pub(super) fn merge(&mut self, other: Self) {
+        for (glyph, c) in other.0 {
+            self.0.entry(glyph).or_insert(c);
+        }
+    }
+
+    /// Embeds the font with the used glyphs, returning the ID of its font dictionary.
+    pub(super) fn embed(&self, document: &mut Document) -> anyhow::Result<ObjectId> {
+        let face = &*FACE;
+
+        // everything is scaled to the 1000 units per em pdf uses for glyph space.
+        let scale = |value: f64| (value * 1000.0 / f64::from(face.units_per_em())).round() as i64;
+
+        // `.notdef` has to stay, it's drawn for anything the font doesn't have.
+        let glyphs: Vec<u16> = std::iter::once(0).chain(self.0.keys().copied()).collect();
+
+        let data = subsetter::subset(DATA, 0, subsetter::Profile::pdf(&glyphs))?;
+
+        let base_font = format!("{}+{}", self.tag(), NAME);
+
+        let mut font_file = Stream::new(dictionary! { "Length1" => data.len() as i64 }, data);
+        font_file.compress()?;
+        let font_file_id = document.add_object(font_file);
+
+        let bbox = face.global_bounding_box();
+
+        let descriptor_id = document.add_object(dictionary! {
+            "Type" => "FontDescriptor",
+            "FontName" => Object::Name(base_font.clone().into_bytes()),
+            // nonsymbolic
+            "Flags" => 32,
+            "FontBBox" => vec![
+                scale(bbox.x_min.into()).into(),
+                scale(bbox.y_min.into()).into(),
+                scale(bbox.x_max.into()).into(),
+                scale(bbox.y_max.into()).into(),
+            ],
+            "ItalicAngle" => 0,
+            "Ascent" => scale(face.ascender().into()),
+            "Descent" => scale(face.descender().into()),
+            "CapHeight" => scale(face.capital_height().unwrap_or_else(|| face.ascender()).into()),
+            "StemV" => 80,
+            "FontFile2" => font_file_id,
+        });
+
+        let widths: Vec<Object> = glyphs
+            .iter()
+            .flat_map(|&glyph| {
+                let width = scale(advance(GlyphId(glyph)).into());
+                vec![i64::from(glyph).into(), vec![width.into()].into()]
+            })
+            .collect();
+
+        let cid_font_id = document.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "CIDFontType2",
+            "BaseFont" => Object::Name(base_font.clone().into_bytes()),
+            "CIDSystemInfo" => dictionary! {
+                "Registry" => Object::string_literal("Adobe"),
+                "Ordering" => Object::string_literal("Identity"),
+                "Supplement" => 0,
+            },
+            "FontDescriptor" => descriptor_id,
+            "W" => widths,
+            "CIDToGIDMap" => "Identity",
+        });
+
+        let mut to_unicode = Stream::new(dictionary! {}, self.to_unicode().into_bytes());
+        to_unicode.compress()?;
+        let to_unicode_id = document.add_object(to_unicode);
+
+        Ok(document.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type0",
+            "BaseFont" => Object::Name(base_font.into_bytes()),
+            "Encoding" => "Identity-H",
+            "DescendantFonts" => vec![cid_font_id.into()],
+            "ToUnicode" => to_unicode_id,
+        }))
+    }
+
+    /// Subset fonts are named with a tag of six capital letters, which has to be different for different subsets.
+    fn tag(&self) -> String {
+        // 64 bit FNV-1a of the glyph IDs.
+        let mut hash = self
+            .0
+            .keys()
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, glyph| {
+                glyph.to_be_bytes().iter().fold(hash, |hash, byte| {
+                    (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+                })
+            });
+
+        (0..6)
+            .map(|_| {
+                let letter = b'A' + (hash % 26) as u8;
+                hash /= 26;
+                char::from(letter)
+            })
+            .collect()
+    }
+
+    fn to_unicode(&self) -> String {
+        let mut lines = vec![
+            "/CIDInit /ProcSet findresource begin".to_owned(),
+            "12 dict begin".to_owned(),
+            "begincmap".to_owned(),
+            "/CIDSystemInfo << /Registry (Adobe) /Ordering (UCS) /Supplement 0 >> def".to_owned(),
+            "/CMapName /Adobe-Identity-UCS def".to_owned(),
+            "/CMapType 2 def".to_owned(),
+            "1 begincodespacerange".to_owned(),
+            CODE_SPACE.to_owned(),
+            "endcodespacerange".to_owned(),
+        ];
+
+        let entries: Vec<_> = self.0.iter().collect();
+
+        for chunk in entries.chunks(MAX_BFCHAR_ENTRIES) {
+            lines.push(format!("{} beginbfchar", chunk.len()));
+
+            for (glyph, c) in chunk {
+                let mut units = [0; 2];
+                let units: Vec<_> = c
+                    .encode_utf16(&mut units)
+                    .iter()
+                    .map(|unit| format!("{unit:04X}"))
+                    .collect();
+
+                lines.push(format!("<{:04X}> <{}>", glyph, units.concat()));
+            }
+
+            lines.push("endbfchar".to_owned());
+        }
+
+        lines.extend(
+            [
+                "endcmap",
+                "CMapName currentdict /CMap defineresource pop",
+                "end",
+                "end",
+            ]
+            .iter()
+            .map(|&line| line.to_owned()),
+        );
+
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{layout, visual, width, FACE};
+
+    fn visual_str(text: &str) -> String {
+        visual(text).into_iter().collect()
+    }
+
+    #[test]
+    fn reorders_right_to_left_runs() {
+        assert_eq!(visual_str("hello"), "hello");
+        assert_eq!(visual_str("שלום"), "םולש");
+        assert_eq!(visual_str("abc שלום def"), "abc םולש def");
+        assert_eq!(visual_str("שלום (עולם)"), "(םלוע) םולש");
+
+        // numbers keep reading left to right.
+        assert_eq!(visual_str("שלום 123"), "123 םולש");
+    }
+
+    #[test]
+    fn points_stay_on_their_letter() {
+        // shin with a shin dot and qamats, then lamed.
+        assert_eq!(
+            visual_str("\u{5e9}\u{5c1}\u{5b8}\u{5dc}"),
+            "\u{5dc}\u{5e9}\u{5c1}\u{5b8}"
+        );
+    }
+
+    #[test]
+    fn hebrew_has_glyphs() {
+        let question_mark = FACE.glyph_index('?').unwrap();
+
+        for (glyph, c) in layout("שָׁלוֹם עולם") {
+            assert_ne!(glyph.0, 0, "{c:?}");
+            assert_ne!(glyph, question_mark, "{c:?}");
+        }
+
+        assert!(width("שלום", 10.0) > 0.0);
+        assert!(width("\u{5b8}", 10.0).abs() < f64::EPSILON);
+    }
+}
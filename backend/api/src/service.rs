@@ -13,6 +13,7 @@ use core::google::GoogleAccessTokenResponse;
 
 use self::translate::GoogleTranslate;
 use self::upload::cleaner::UploadCleaner;
+use self::webhook::Dispatcher as WebhookDispatcher;
 
 pub mod event_arc;
 pub mod mail;
@@ -20,6 +21,7 @@ pub mod notifications;
 pub mod s3;
 pub mod storage;
 pub mod upload;
+pub mod webhook;
 
 pub trait Service {
     const DISABLED_ERROR: error::ServiceKind;
@@ -66,6 +68,10 @@ impl Service for UploadCleaner {
 impl Service for GoogleTranslate {
     const DISABLED_ERROR: ServiceKind = error::ServiceKind::GoogleTranslate;
 }
+
+impl Service for WebhookDispatcher {
+    const DISABLED_ERROR: ServiceKind = error::ServiceKind::WebhookDispatcher;
+}
 #[derive(Debug)]
 pub struct ServiceData<T: ?Sized>(Arc<T>);
 
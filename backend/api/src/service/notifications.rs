@@ -80,6 +80,7 @@ impl Client {
         let status = ProcessingStatus {
             processing: Some(Value::BooleanValue(true)),
             ready: Some(Value::BooleanValue(false)),
+            similar: None,
         };
         let update_mask = &["processing", "ready"];
 
@@ -89,17 +90,24 @@ impl Client {
         Ok(())
     }
 
+    /// `similar` is set for images, to whether a similar image already exists in the library.
     pub async fn signal_status_ready(
         &self,
         access_token: &str,
         library: &MediaLibrary,
         id: &Uuid,
+        similar: Option<bool>,
     ) -> anyhow::Result<()> {
         let status = ProcessingStatus {
             processing: None,
             ready: Some(Value::BooleanValue(true)),
+            similar: similar.map(Value::BooleanValue),
+        };
+
+        let update_mask: &[&str] = match similar {
+            Some(_) => &["ready", "similar"],
+            None => &["ready"],
         };
-        let update_mask = &["ready"];
 
         self.signal_status(access_token, library, id, status, update_mask)
             .await?;
@@ -114,6 +122,8 @@ pub struct ProcessingStatus {
     ready: Option<Value>, // bool
     #[serde(skip_serializing_if = "Option::is_none")]
     processing: Option<Value>, // bool
+    #[serde(skip_serializing_if = "Option::is_none")]
+    similar: Option<Value>, // bool
 }
 
 // Types for bindings to Firestore
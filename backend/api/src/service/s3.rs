@@ -12,6 +12,8 @@ use tokio::io::AsyncReadExt;
 use tracing::instrument;
 use uuid::Uuid;
 
+use crate::image_ops::WebpImages;
+
 #[derive(Clone)]
 pub struct Client {
     media_bucket: String,
@@ -114,6 +116,41 @@ impl Client {
         Ok(())
     }
 
+    #[instrument(skip(self, library, images))]
+    pub async fn upload_webp_images(
+        &self,
+        library: MediaLibrary,
+        image: Uuid,
+        images: WebpImages,
+    ) -> anyhow::Result<()> {
+        let upload =
+            |data, file| self.upload_media(data, library, image, FileKind::ImageWebp(file));
+
+        let original = upload(images.original, PngImageFile::Original);
+        let resized = upload(images.resized, PngImageFile::Resized);
+        let thumbnail = upload(images.thumbnail, PngImageFile::Thumbnail);
+
+        futures::future::try_join3(original, resized, thumbnail).await?;
+
+        Ok(())
+    }
+
+    /// Deletes all the files (in every format) stored for an image.
+    pub async fn delete_image_files(&self, library: MediaLibrary, image: Uuid) {
+        let files = [
+            PngImageFile::Original,
+            PngImageFile::Resized,
+            PngImageFile::Thumbnail,
+        ];
+
+        let files = files
+            .iter()
+            .flat_map(|file| vec![FileKind::ImagePng(*file), FileKind::ImageWebp(*file)])
+            .chain(std::iter::once(FileKind::ImageSvg));
+
+        futures::future::join_all(files.map(|file| self.delete_media(library, file, image))).await;
+    }
+
     pub async fn delete_media(&self, library: MediaLibrary, file: FileKind, id: Uuid) {
         let key = media_key(library, id, file);
         if let Err(err) = self.try_delete(key.clone()).await {
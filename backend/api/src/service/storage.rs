@@ -146,7 +146,9 @@ impl Client {
     pub fn file_size_limit(&self, file_kind: &FileKind) -> Option<usize> {
         match file_kind {
             FileKind::AnimationGif => Some(ANIMATION_BODY_SIZE_LIMIT),
-            FileKind::ImagePng(PngImageFile::Original) => Some(IMAGE_BODY_SIZE_LIMIT),
+            FileKind::ImagePng(PngImageFile::Original) | FileKind::ImageSvg => {
+                Some(IMAGE_BODY_SIZE_LIMIT)
+            }
             FileKind::AudioMp3 => Some(AUDIO_BODY_SIZE_LIMIT),
             FileKind::DocumentPdf => Some(PDF_BODY_SIZE_LIMIT),
             _ => unimplemented!("File type size limit undefined!"),
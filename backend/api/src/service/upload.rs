@@ -1,14 +1,170 @@
+use image::DynamicImage;
 use shared::{
-    domain::{animation::AnimationKind, image::ImageKind},
+    domain::{
+        animation::{AnimationFormat, AnimationKind, AnimationSpritesheet},
+        audio::AudioFormat,
+        image::{ImageId, ImageKind},
+        pdf::PdfPreview,
+    },
     media::{FileKind, MediaLibrary, PngImageFile},
 };
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use crate::{error, image_ops::MediaKind, service};
+use crate::{
+    audio_ops, db, error,
+    image_ops::{self, MediaKind, WebpImages},
+    pdf_ops, service,
+};
 
 pub mod cleaner;
 
+/// How an image's original file is stored.
+enum ProcessedOriginal {
+    /// The uploaded (raster) file is stored as-is.
+    Uploaded,
+
+    /// The upload is vector art, which gets sanitized, and rasterized for clients that need a PNG.
+    Vector { svg: Vec<u8>, png: Vec<u8> },
+}
+
+/// Files generated from an uploaded image.
+struct ProcessedImage {
+    original: ProcessedOriginal,
+    resized: Vec<u8>,
+    thumbnail: Vec<u8>,
+    webp: WebpImages,
+    perceptual_hash: i64,
+}
+
+fn process_image_file(file: &[u8], kind: ImageKind) -> Result<ProcessedImage, error::Upload> {
+    if kind.is_vector() {
+        let svg = image_ops::svg::sanitize(file).map_err(|_| error::Upload::InvalidMedia)?;
+
+        let original = image_ops::svg::rasterize(&svg, kind.size())
+            .map_err(|_| error::Upload::InvalidMedia)?;
+
+        let (png, resized, thumbnail) = image_ops::generate_images(&original, kind)?;
+
+        Ok(ProcessedImage {
+            original: ProcessedOriginal::Vector { svg, png },
+            resized,
+            thumbnail,
+            webp: image_ops::generate_webp_images(&original, kind),
+            perceptual_hash: image_ops::perceptual_hash(&original),
+        })
+    } else {
+        let original = image::load_from_memory(file).map_err(|_| error::Upload::InvalidMedia)?;
+
+        let (resized, thumbnail) = image_ops::regenerate_images(&original, kind)?;
+
+        Ok(ProcessedImage {
+            original: ProcessedOriginal::Uploaded,
+            resized,
+            thumbnail,
+            webp: image_ops::generate_webp_images(&original, kind),
+            perceptual_hash: image_ops::perceptual_hash(&original),
+        })
+    }
+}
+
+/// Files generated from uploaded audio.
+struct ProcessedAudio {
+    original_format: AudioFormat,
+    mp3: Vec<u8>,
+    waveform: Vec<u8>,
+}
+
+/// Transcodes the audio to mp3, trimming silence from the start and end of it and normalizing its loudness.
+fn process_audio_file(file: &[u8]) -> Result<ProcessedAudio, error::Upload> {
+    let original_format = audio_ops::detect_format(file).ok_or(error::Upload::InvalidMedia)?;
+
+    let mut audio = audio_ops::decode(file).map_err(|_| error::Upload::InvalidMedia)?;
+
+    audio_ops::trim_silence(&mut audio);
+    audio_ops::normalize_loudness(&mut audio);
+
+    let waveform = serde_json::to_vec(&audio_ops::waveform(&audio))?;
+
+    Ok(ProcessedAudio {
+        original_format,
+        mp3: audio_ops::encode_mp3(&audio)?,
+        waveform,
+    })
+}
+
+/// Files generated from an uploaded animation.
+struct ProcessedAnimation {
+    original_format: AnimationFormat,
+    spritesheet: Vec<u8>,
+    layout: AnimationSpritesheet,
+}
+
+/// Converts the animation's frames into a (WebP) spritesheet.
+fn process_animation_file(file: &[u8]) -> Result<ProcessedAnimation, error::Upload> {
+    let original_format =
+        image_ops::animation::detect_format(file).ok_or(error::Upload::InvalidMedia)?;
+
+    let frames = image_ops::animation::decode(file, original_format)
+        .map_err(|_| error::Upload::InvalidMedia)?;
+
+    let (spritesheet, layout) = image_ops::animation::spritesheet(&frames);
+
+    Ok(ProcessedAnimation {
+        original_format,
+        spritesheet: image_ops::encode_webp(&DynamicImage::ImageRgba8(spritesheet)),
+        layout,
+    })
+}
+
+/// What's extracted from an uploaded pdf.
+struct ProcessedPdf {
+    preview: PdfPreview,
+    thumbnail: Vec<u8>,
+}
+
+/// Reads the pdf's metadata and renders its first page.
+fn process_pdf_file(file: &[u8]) -> Result<ProcessedPdf, error::Upload> {
+    let preview = pdf_ops::preview(file).map_err(|_| error::Upload::InvalidMedia)?;
+
+    Ok(ProcessedPdf {
+        preview,
+        thumbnail: pdf_ops::render_thumbnail(file)?,
+    })
+}
+
+async fn upload_processed_image(
+    s3: &service::s3::Client,
+    library: MediaLibrary,
+    id: Uuid,
+    processed: ProcessedImage,
+) -> anyhow::Result<()> {
+    let ProcessedImage {
+        original,
+        resized,
+        thumbnail,
+        webp,
+        perceptual_hash: _,
+    } = processed;
+
+    match original {
+        ProcessedOriginal::Uploaded => {
+            s3.upload_png_images_copy_original(library, id, resized, thumbnail)
+                .await?
+        }
+
+        ProcessedOriginal::Vector { svg, png } => {
+            s3.upload_media(svg, library, id, FileKind::ImageSvg)
+                .await?;
+
+            s3.upload_png_images(library, id, png, resized, thumbnail)
+                .await?;
+        }
+    }
+
+    s3.upload_webp_images(library, id, webp).await
+}
+
 pub async fn process_image(
     db: &PgPool,
     s3: &service::s3::Client,
@@ -45,11 +201,7 @@ skip locked
     };
 
     let file = s3
-        .download_media_for_processing(
-            MediaLibrary::Global,
-            id,
-            FileKind::ImagePng(PngImageFile::Original),
-        )
+        .download_media_for_processing(MediaLibrary::Global, id, FileKind::original_image(kind))
         .await?;
 
     let file = match file {
@@ -65,14 +217,11 @@ skip locked
         }
     };
 
-    let processed = tokio::task::spawn_blocking(move || -> Result<_, error::Upload> {
-        let original = image::load_from_memory(&file).map_err(|_| error::Upload::InvalidMedia)?;
-        Ok(crate::image_ops::regenerate_images(&original, kind)?)
-    })
-    .await
-    .unwrap();
+    let processed = tokio::task::spawn_blocking(move || process_image_file(&file, kind))
+        .await
+        .unwrap();
 
-    let (resized, thumbnail) = match processed {
+    let processed = match processed {
         Ok(it) => it,
         Err(error::Upload::InvalidMedia) => {
             log::info!("invalid media");
@@ -87,10 +236,11 @@ skip locked
         Err(_) => unreachable!(),
     };
 
-    s3.upload_png_images_copy_original(MediaLibrary::Global, id, resized, thumbnail)
-        .await?;
+    let perceptual_hash = processed.perceptual_hash;
 
-    sqlx::query!("update image_upload set processed_at = now(), processing_result = true where image_id = $1", id)
+    upload_processed_image(s3, MediaLibrary::Global, id, processed).await?;
+
+    sqlx::query!("update image_upload set processed_at = now(), processing_result = true, has_webp = true, perceptual_hash = $2 where image_id = $1", id, perceptual_hash)
         .execute(&mut txn)
         .await?;
 
@@ -134,11 +284,7 @@ skip locked
     };
 
     let file = s3
-        .download_media_for_processing(
-            MediaLibrary::User,
-            id,
-            FileKind::ImagePng(PngImageFile::Original),
-        )
+        .download_media_for_processing(MediaLibrary::User, id, FileKind::original_image(kind))
         .await?;
 
     let file = match file {
@@ -154,14 +300,13 @@ skip locked
         }
     };
 
-    let processed = tokio::task::spawn_blocking(move || -> Result<_, error::Upload> {
-        let original = image::load_from_memory(&file).map_err(|_| error::Upload::InvalidMedia)?;
-        Ok(crate::image_ops::regenerate_images(&original, kind)?)
-    })
-    .await
-    .unwrap();
+    let file_size = file.len() as i64;
 
-    let (resized, thumbnail) = match processed {
+    let processed = tokio::task::spawn_blocking(move || process_image_file(&file, kind))
+        .await
+        .unwrap();
+
+    let processed = match processed {
         Ok(it) => it,
         Err(error::Upload::InvalidMedia) => {
             sqlx::query!("update user_image_upload set processed_at = now(), processing_result = false where image_id = $1", id)
@@ -175,10 +320,11 @@ skip locked
         Err(_) => unreachable!(),
     };
 
-    s3.upload_png_images_copy_original(MediaLibrary::User, id, resized, thumbnail)
-        .await?;
+    let perceptual_hash = processed.perceptual_hash;
 
-    sqlx::query!("update user_image_upload set processed_at = now(), processing_result = true where image_id = $1", id).execute(&mut txn).await?;
+    upload_processed_image(s3, MediaLibrary::User, id, processed).await?;
+
+    sqlx::query!("update user_image_upload set processed_at = now(), processing_result = true, has_webp = true, perceptual_hash = $2, file_size = $3 where image_id = $1", id, perceptual_hash, file_size).execute(&mut txn).await?;
 
     txn.commit().await?;
 
@@ -233,24 +379,43 @@ skip locked
             }
         }
 
-        MediaKind::PngStickerImage => {
+        MediaKind::PngStickerImage | MediaKind::SvgStickerImage => {
+            let image_kind = match kind {
+                MediaKind::SvgStickerImage => ImageKind::VectorSticker,
+                _ => ImageKind::Sticker,
+            };
+
             let file = s3
                 .download_media_for_processing(
                     MediaLibrary::Web,
                     id,
-                    FileKind::ImagePng(PngImageFile::Original),
+                    FileKind::original_image(image_kind),
                 )
                 .await?;
 
             if let Some(data) = file {
-                let (_original, resized, thumbnail) = actix_web::web::block(move || {
-                    let original = image::load_from_memory(&data)?;
-                    crate::image_ops::generate_images(&original, ImageKind::Sticker)
-                })
-                .await??;
-
-                s3.upload_png_images_copy_original(MediaLibrary::Web, id, resized, thumbnail)
-                    .await?;
+                let processed =
+                    tokio::task::spawn_blocking(move || process_image_file(&data, image_kind))
+                        .await
+                        .unwrap();
+
+                let processed = match processed {
+                    Ok(it) => it,
+                    Err(error::Upload::InvalidMedia) => {
+                        sqlx::query!("update web_media_upload set processed_at = now(), processing_result = false where media_id = $1", id)
+                            .execute(&mut txn)
+                            .await?;
+
+                        txn.commit().await?;
+                        return Ok(true);
+                    }
+                    Err(error::Upload::InternalServerError(e)) => return Err(e),
+                    Err(_) => unreachable!(),
+                };
+
+                upload_processed_image(s3, MediaLibrary::Web, id, processed).await?;
+
+                crate::db::media::set_has_webp(&mut txn, MediaLibrary::Web, id).await?;
             }
         }
 
@@ -286,18 +451,12 @@ skip locked
     .fetch_optional(&mut txn)
     .await?;
 
-    let row = match row {
-        Some(row) => row,
-        None => {
-            txn.rollback().await?;
-            return Ok(false);
-        }
-    };
-
-    if !matches!(row.kind, AnimationKind::Gif) {
-        return Err(anyhow::anyhow!("Unimplemented Animation Kind: {:?}", row.kind).into());
+    if row.is_none() {
+        txn.rollback().await?;
+        return Ok(false);
     }
 
+    // the upload is always stored as a gif, even if it's actually an APNG or WebP.
     let file = s3
         .download_media_for_processing(MediaLibrary::Global, id, FileKind::AnimationGif)
         .await?;
@@ -315,16 +474,12 @@ skip locked
         }
     };
 
-    let res = tokio::task::spawn_blocking(move || -> Result<_, error::Upload> {
-        let _ = image::load_from_memory_with_format(&file, image::ImageFormat::Gif)
-            .or(Err(error::Upload::InvalidMedia))?;
-        Ok(())
-    })
-    .await
-    .unwrap();
+    let processed = tokio::task::spawn_blocking(move || process_animation_file(&file))
+        .await
+        .unwrap();
 
-    match res {
-        Ok(()) => {}
+    let processed = match processed {
+        Ok(it) => it,
         Err(error::Upload::InvalidMedia) => {
             sqlx::query!("update global_animation_upload set processed_at = now(), processing_result = false where animation_id = $1", id)
                 .execute(&mut txn)
@@ -337,8 +492,36 @@ skip locked
         Err(_) => unreachable!(),
     };
 
-    s3.copy_processed_file(MediaLibrary::Global, id, FileKind::AnimationGif)
-        .await?;
+    // keep serving gifs as-is for clients that don't use the spritesheet yet.
+    if processed.original_format == AnimationFormat::Gif {
+        s3.copy_processed_file(MediaLibrary::Global, id, FileKind::AnimationGif)
+            .await?;
+    }
+
+    s3.upload_media(
+        processed.spritesheet,
+        MediaLibrary::Global,
+        id,
+        FileKind::AnimationSpritesheet,
+    )
+    .await?;
+
+    s3.upload_media(
+        serde_json::to_vec(&processed.layout)?,
+        MediaLibrary::Global,
+        id,
+        FileKind::AnimationSpritesheetJson,
+    )
+    .await?;
+
+    sqlx::query!(
+        "update animation_metadata set original_format = $2, spritesheet = $3 where id = $1",
+        id,
+        processed.original_format as i16,
+        serde_json::to_value(&processed.layout)?,
+    )
+    .execute(&mut txn)
+    .await?;
 
     sqlx::query!("update global_animation_upload set processed_at = now(), processing_result = true where animation_id = $1", id).execute(&mut txn).await?;
 
@@ -381,16 +564,6 @@ skip locked
         .download_media_for_processing(MediaLibrary::User, id, FileKind::AudioMp3)
         .await?;
 
-    // todo: use the duration
-    // let _duration = {
-    //     let bytes = bytes.clone();
-    //     tokio::task::spawn_blocking(move || {
-    //         mp3_metadata::read_from_slice(&bytes).map_err(|_it| error::Upload::InvalidMedia)
-    //     })
-    //         .await
-    //         .unwrap()?
-    // };
-
     let file = match file {
         Some(it) => it,
         None => {
@@ -404,12 +577,46 @@ skip locked
         }
     };
 
-    // todo: processing
+    let file_size = file.len() as i64;
 
-    s3.upload_media(file, MediaLibrary::User, id, FileKind::AudioMp3)
+    let processed = tokio::task::spawn_blocking(move || process_audio_file(&file))
+        .await
+        .unwrap();
+
+    let processed = match processed {
+        Ok(it) => it,
+        Err(error::Upload::InvalidMedia) => {
+            sqlx::query!("update user_audio_upload set processed_at = now(), processing_result = false where audio_id = $1", id)
+                .execute(&mut txn)
+                .await?;
+
+            txn.commit().await?;
+            return Ok(true);
+        }
+        Err(error::Upload::InternalServerError(e)) => return Err(e),
+        Err(_) => unreachable!(),
+    };
+
+    s3.upload_media(processed.mp3, MediaLibrary::User, id, FileKind::AudioMp3)
         .await?;
 
-    sqlx::query!("update user_audio_upload set processed_at = now(), processing_result = true where audio_id = $1", id).execute(&mut txn).await?;
+    s3.upload_media(
+        processed.waveform,
+        MediaLibrary::User,
+        id,
+        FileKind::AudioWaveform,
+    )
+    .await?;
+
+    sqlx::query!(
+        "update user_audio_library set original_format = $2 where id = $1",
+        id,
+        processed.original_format as i16,
+    )
+    .execute(&mut txn)
+    .await?;
+
+    sqlx::query!("update user_audio_upload set processed_at = now(), processing_result = true, file_size = $2 where audio_id = $1", id, file_size).execute(&mut txn).await?;
 
     txn.commit().await?;
 
@@ -450,16 +657,6 @@ skip locked
         .download_media_for_processing(MediaLibrary::User, id, FileKind::DocumentPdf)
         .await?;
 
-    // todo: use the duration
-    // let _duration = {
-    //     let bytes = bytes.clone();
-    //     tokio::task::spawn_blocking(move || {
-    //         mp3_metadata::read_from_slice(&bytes).map_err(|_it| error::Upload::InvalidMedia)
-    //     })
-    //         .await
-    //         .unwrap()?
-    // };
-
     let file = match file {
         Some(it) => it,
         None => {
@@ -473,26 +670,84 @@ skip locked
         }
     };
 
-    // todo: processing
+    let processed = {
+        let file = file.clone();
+        tokio::task::spawn_blocking(move || process_pdf_file(&file))
+            .await
+            .unwrap()
+    };
+
+    let processed = match processed {
+        Ok(it) => it,
+        Err(error::Upload::InvalidMedia) => {
+            log::info!("invalid media");
+            sqlx::query!("update user_pdf_upload set processed_at = now(), processing_result = false where pdf_id = $1", id)
+                .execute(&mut txn)
+                .await?;
+
+            txn.commit().await?;
+            return Ok(true);
+        }
+        Err(error::Upload::InternalServerError(e)) => return Err(e),
+        _ => unreachable!(),
+    };
+
+    let file_size = file.len() as i64;
 
     s3.upload_media(file, MediaLibrary::User, id, FileKind::DocumentPdf)
         .await?;
 
-    sqlx::query!("update user_pdf_upload set processed_at = now(), processing_result = true where pdf_id = $1", id).execute(&mut txn).await?;
+    s3.upload_media(
+        processed.thumbnail,
+        MediaLibrary::User,
+        id,
+        FileKind::DocumentThumbnail,
+    )
+    .await?;
+
+    sqlx::query!(
+        "update user_pdf_library set page_count = $2, title = $3 where id = $1",
+        id,
+        processed.preview.page_count as i32,
+        processed.preview.title,
+    )
+    .execute(&mut txn)
+    .await?;
+
+    sqlx::query!("update user_pdf_upload set processed_at = now(), processing_result = true, file_size = $2 where pdf_id = $1", id, file_size).execute(&mut txn).await?;
 
     txn.commit().await?;
 
     Ok(true)
 }
 
+/// Signals that the upload is ready, along with whether a similar image already exists for uploaded images.
 pub async fn finalize_upload(
     access_token: &str,
+    db: &PgPool,
     notifications: &service::notifications::Client,
     library: &MediaLibrary,
     id: &Uuid,
+    file_kind: &FileKind,
 ) -> anyhow::Result<()> {
+    let is_image = matches!(
+        file_kind,
+        FileKind::ImagePng(PngImageFile::Original) | FileKind::ImageSvg
+    );
+
+    let similar = match library {
+        MediaLibrary::Global if is_image => Some(
+            db::image::has_similar(db, ImageId(*id), db::image::DEFAULT_SIMILAR_DISTANCE).await?,
+        ),
+        MediaLibrary::User if is_image => Some(
+            db::image::user::has_similar(db, ImageId(*id), db::image::DEFAULT_SIMILAR_DISTANCE)
+                .await?,
+        ),
+        _ => None,
+    };
+
     notifications
-        .signal_status_ready(access_token, library, id)
+        .signal_status_ready(access_token, library, id, similar)
         .await?;
     Ok(())
 }
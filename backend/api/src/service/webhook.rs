@@ -0,0 +1,278 @@
+//! Delivers queued webhook notifications, see [`db::webhook`](crate::db::webhook).
+
+use anyhow::Context;
+use chrono::{DateTime, Duration, Utc};
+use futures::future::join_all;
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha256;
+use sqlx::PgPool;
+use std::net::{IpAddr, SocketAddr};
+use url::{Host, Url};
+
+use crate::db::{self, webhook::PendingDelivery};
+
+/// How many deliveries are claimed (and sent concurrently) at once.
+const BATCH_SIZE: i64 = 50;
+
+/// How many batches a single run sends at most, so that a run can't go on forever.
+const MAX_BATCHES: usize = 20;
+
+/// Attempts after which a delivery is given up on.
+const MAX_ATTEMPTS: i16 = 10;
+
+/// How long a claimed delivery is hidden from other dispatchers, should be comfortably longer than [`REQUEST_TIMEOUT_SECS`].
+const LEASE_SECS: i64 = 300;
+
+const REQUEST_TIMEOUT_SECS: u64 = 10;
+
+/// The signature for a delivery's `X-Jigzi-Signature` header.
+#[must_use]
+pub fn sign(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+
+    format!("sha256={:x}", mac.finalize().into_bytes())
+}
+
+/// Whether `ip` is on a private network (or is this machine), which deliveries must never reach.
+#[must_use]
+pub fn is_private_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, ..] = ip.octets();
+
+            ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                // "this network" (0.0.0.0/8) and shared address space (100.64.0.0/10).
+                || a == 0
+                || (a == 100 && (b & 0xc0) == 64)
+        }
+        IpAddr::V6(ip) => {
+            // unique local (fc00::/7) and link local (fe80::/10) addresses.
+            ip.is_loopback()
+                || ip.is_unspecified()
+                || ip.is_multicast()
+                || (ip.segments()[0] & 0xfe00) == 0xfc00
+                || (ip.segments()[0] & 0xffc0) == 0xfe80
+                // IPv4 addresses written as IPv6 ones (`::ffff:10.0.0.1`).
+                || ip.to_ipv4().map_or(false, |ip| is_private_ip(ip.into()))
+        }
+    }
+}
+
+/// Exponential backoff starting at a minute, capped at six hours.
+fn retry_at(attempts: i16, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    if attempts >= MAX_ATTEMPTS {
+        return None;
+    }
+
+    let minutes = 1_i64 << (attempts - 1).clamp(0, 16);
+
+    Some(now + Duration::minutes(minutes.min(6 * 60)))
+}
+
+fn client_builder() -> reqwest::ClientBuilder {
+    reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .redirect(reqwest::redirect::Policy::none())
+}
+
+#[derive(Clone)]
+pub struct Dispatcher {
+    db: PgPool,
+    client: reqwest::Client,
+    /// Local development delivers to local servers, so private addresses are allowed.
+    allow_private: bool,
+}
+
+impl Dispatcher {
+    pub fn new(db: PgPool, allow_private: bool) -> anyhow::Result<Option<Self>> {
+        let client = client_builder().build()?;
+
+        Ok(Some(Self {
+            db,
+            client,
+            allow_private,
+        }))
+    }
+
+    /// A client that can only reach `url`'s host at a public address.
+    ///
+    /// URLs are checked when a webhook is created, but a public name can resolve (or later be
+    /// pointed) to an internal address. So the host is resolved here, every address is checked,
+    /// and the request is pinned to a checked address so it can't be re-resolved in between.
+    async fn client_for(&self, url: &str) -> anyhow::Result<reqwest::Client> {
+        if self.allow_private {
+            return Ok(self.client.clone());
+        }
+
+        let url = Url::parse(url)?;
+
+        let port = url
+            .port_or_known_default()
+            .context("webhook URL has no port")?;
+
+        let domain = match url.host() {
+            Some(Host::Domain(domain)) => domain,
+            Some(Host::Ipv4(ip)) if !is_private_ip(ip.into()) => return Ok(self.client.clone()),
+            Some(Host::Ipv6(ip)) if !is_private_ip(ip.into()) => return Ok(self.client.clone()),
+            _ => anyhow::bail!("webhook URL must point to a public host"),
+        };
+
+        let addrs: Vec<SocketAddr> = tokio::net::lookup_host((domain, port))
+            .await
+            .with_context(|| format!("couldn't resolve {}", domain))?
+            .collect();
+
+        let addr = match addrs.first() {
+            Some(addr) if !addrs.iter().any(|addr| is_private_ip(addr.ip())) => *addr,
+            Some(_) => anyhow::bail!("{} resolves to a private address", domain),
+            None => anyhow::bail!("{} doesn't resolve to any address", domain),
+        };
+
+        Ok(client_builder().resolve(domain, addr).build()?)
+    }
+
+    /// Sends every delivery that's due (up to a limit).
+    pub async fn spawn_cron_jobs(&self) -> anyhow::Result<()> {
+        log::debug!("reached webhook dispatcher for spawning jobs");
+
+        for _ in 0..MAX_BATCHES {
+            let deliveries = db::webhook::claim_due(
+                &self.db,
+                BATCH_SIZE,
+                Utc::now() + Duration::seconds(LEASE_SECS),
+            )
+            .await?;
+
+            if deliveries.is_empty() {
+                break;
+            }
+
+            let done = deliveries.len() < BATCH_SIZE as usize;
+
+            for res in join_all(deliveries.into_iter().map(|it| self.deliver(it))).await {
+                if let Err(e) = res {
+                    log::error!("{:?}", e);
+                    sentry::integrations::anyhow::capture_anyhow(&e);
+                }
+            }
+
+            if done {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn deliver(&self, delivery: PendingDelivery) -> anyhow::Result<()> {
+        let body = serde_json::to_vec(&delivery.payload)?;
+        let now = Utc::now();
+        let attempts = delivery.attempts + 1;
+
+        let client = match self.client_for(&delivery.url).await {
+            Ok(client) => client,
+            Err(e) => {
+                db::webhook::mark_attempt_failed(
+                    &self.db,
+                    delivery.id,
+                    None,
+                    &e.to_string(),
+                    retry_at(attempts, now),
+                )
+                .await?;
+
+                return Ok(());
+            }
+        };
+
+        let resp = client
+            .post(&delivery.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header("X-Jigzi-Event", delivery.event.as_str())
+            .header("X-Jigzi-Delivery", delivery.id.to_string())
+            .header("X-Jigzi-Timestamp", now.timestamp().to_string())
+            .header(
+                "X-Jigzi-Signature",
+                sign(&delivery.secret, now.timestamp(), &body),
+            )
+            .body(body)
+            .send()
+            .await;
+
+        match resp {
+            Ok(resp) if resp.status().is_success() => {
+                db::webhook::mark_delivered(&self.db, delivery.id, resp.status().as_u16() as i16)
+                    .await?;
+            }
+
+            Ok(resp) => {
+                db::webhook::mark_attempt_failed(
+                    &self.db,
+                    delivery.id,
+                    Some(resp.status().as_u16() as i16),
+                    &format!("unexpected status: {}", resp.status()),
+                    retry_at(attempts, now),
+                )
+                .await?;
+            }
+
+            Err(e) => {
+                db::webhook::mark_attempt_failed(
+                    &self.db,
+                    delivery.id,
+                    None,
+                    &e.to_string(),
+                    retry_at(attempts, now),
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_private_ip;
+
+    #[test]
+    fn private_addresses() {
+        for ip in [
+            "10.0.0.1",
+            "172.16.0.1",
+            "192.168.1.1",
+            "127.0.0.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:10.0.0.1",
+        ] {
+            assert!(is_private_ip(ip.parse().unwrap()), "{}", ip);
+        }
+    }
+
+    #[test]
+    fn public_addresses() {
+        for ip in [
+            "8.8.8.8",
+            "100.128.0.1",
+            "2606:4700::1111",
+            "::ffff:8.8.8.8",
+        ] {
+            assert!(!is_private_ip(ip.parse().unwrap()), "{}", ip);
+        }
+    }
+}
@@ -4,6 +4,7 @@ use http::StatusCode;
 use paseto::{PasetoBuilder, TimeBackend};
 use rand::Rng;
 use serde_json::json;
+use sha2::{Digest, Sha256};
 use shared::domain::{session::AUTH_COOKIE_NAME, user::UserScope};
 use sqlx::PgPool;
 use tracing::instrument;
@@ -13,8 +14,13 @@ use crate::error::{self, BasicError};
 
 const AUTHORIZED_FOOTER: &str = "authorized";
 
+/// Prefix of personal access tokens, so they can be told apart from session tokens (and recognized if leaked).
+pub const PERSONAL_ACCESS_TOKEN_PREFIX: &str = "jigzi_pat_";
+
 pub struct SessionClaims {
     pub user_id: Uuid,
+
+    /// The session's token, or the token's id for personal access tokens.
     pub token: String,
 }
 
@@ -38,6 +44,8 @@ bitflags::bitflags! {
         const DELETE_ACCOUNT = 0b0000_0000_0001_0000;
         const CHANGE_EMAIL = 0b0000_0000_0010_0000;
         const REPORT_EMAIL = 0b0000_0000_0100_0000;
+        /// The session has passed a second factor (TOTP or a recovery code).
+        const SECOND_FACTOR = 0b0000_0000_1000_0000;
 
         const GENERAL = Self::GENERAL_API.bits | Self::DELETE_ACCOUNT.bits;
         const ONE_TIME = Self::CHANGE_PASSWORD.bits | Self::VERIFY_EMAIL.bits;
@@ -136,6 +144,67 @@ where
     })
 }
 
+#[must_use]
+pub fn is_personal_access_token(token_string: &str) -> bool {
+    token_string.starts_with(PERSONAL_ACCESS_TOKEN_PREFIX)
+}
+
+#[must_use]
+pub fn generate_personal_access_token() -> String {
+    let mut bytes = [0_u8; 32];
+    rand::thread_rng().fill(&mut bytes[..]);
+    format!(
+        "{}{}",
+        PERSONAL_ACCESS_TOKEN_PREFIX,
+        base64::encode_config(&bytes, base64::URL_SAFE_NO_PAD)
+    )
+}
+
+/// Personal access tokens are random enough that they don't need a slow hash.
+#[must_use]
+pub fn hash_personal_access_token(token_string: &str) -> String {
+    format!("{:x}", Sha256::digest(token_string.as_bytes()))
+}
+
+pub struct PersonalAccessTokenClaims {
+    pub claims: SessionClaims,
+    pub scopes: Vec<i16>,
+}
+
+/// Checks that a personal access token exists, and hasn't been revoked or expired.
+///
+/// Unlike sessions, these don't have a CSRF token.
+pub async fn check_personal_access_token(
+    db: &PgPool,
+    token_string: &str,
+) -> Result<PersonalAccessTokenClaims, actix_web::Error> {
+    let token = sqlx::query!(
+        r#"
+update personal_access_token
+set last_used_at = now()
+where
+    token_hash = $1 and
+    revoked_at is null and
+    expires_at > now()
+returning id, user_id, scopes
+"#,
+        hash_personal_access_token(token_string)
+    )
+    .fetch_optional(db)
+    .await
+    .map_err(anyhow::Error::from)
+    .map_err(error::ise)?
+    .ok_or_else(|| BasicError::new(StatusCode::UNAUTHORIZED))?;
+
+    Ok(PersonalAccessTokenClaims {
+        claims: SessionClaims {
+            user_id: token.user_id,
+            token: token.id.to_string(),
+        },
+        scopes: token.scopes,
+    })
+}
+
 pub fn create_auth_token(
     token_secret: &[u8; 32],
     local_insecure: bool,
@@ -0,0 +1,141 @@
+//! Time-based one-time passwords ([RFC 6238](https://tools.ietf.org/html/rfc6238)) and recovery codes,
+//! used as a second factor for admin-scoped sessions.
+
+use chrono::{DateTime, Utc};
+use data_encoding::BASE32_NOPAD;
+use hmac::{Hmac, Mac, NewMac};
+use rand::Rng;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+/// Length of a TOTP secret in bytes (160 bits, as recommended by RFC 4226).
+const SECRET_LEN: usize = 20;
+
+/// How long each code is valid for, in seconds.
+const STEP_SECS: i64 = 30;
+
+/// How many steps either side of the current one are accepted, to allow for clock drift.
+const ALLOWED_DRIFT: i64 = 1;
+
+const DIGITS: u32 = 6;
+
+const ISSUER: &str = "Jigzi";
+
+/// How many recovery codes are generated at once.
+pub const RECOVERY_CODE_COUNT: usize = 10;
+
+/// Characters used in recovery codes, without easily confused ones (`0`/`o`, `1`/`l`/`i`).
+const RECOVERY_CODE_ALPHABET: &[u8] = b"23456789abcdefghjkmnpqrstuvwxyz";
+
+const RECOVERY_CODE_LEN: usize = 10;
+
+#[must_use]
+pub fn generate_secret() -> Vec<u8> {
+    let mut secret = vec![0_u8; SECRET_LEN];
+    rand::thread_rng().fill(&mut secret[..]);
+    secret
+}
+
+#[must_use]
+pub fn encode_secret(secret: &[u8]) -> String {
+    BASE32_NOPAD.encode(secret)
+}
+
+/// Creates an `otpauth://` URI for authenticator apps (usually shown as a QR code).
+#[must_use]
+pub fn provisioning_uri(secret: &[u8], account: &str) -> String {
+    let label = format!("{}:{}", ISSUER, account);
+
+    format!(
+        "otpauth://totp/{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+        percent_encoding::utf8_percent_encode(&label, percent_encoding::NON_ALPHANUMERIC),
+        encode_secret(secret),
+        ISSUER,
+        DIGITS,
+        STEP_SECS
+    )
+}
+
+fn step_at(time: DateTime<Utc>) -> i64 {
+    time.timestamp().div_euclid(STEP_SECS)
+}
+
+/// HOTP ([RFC 4226](https://tools.ietf.org/html/rfc4226)) for the given counter.
+fn hotp(secret: &[u8], counter: u64) -> u32 {
+    let mut mac = Hmac::<Sha1>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(&counter.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+
+    let offset = (hash[hash.len() - 1] & 0xf) as usize;
+
+    let code = u32::from_be_bytes([
+        hash[offset] & 0x7f,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+
+    code % 10_u32.pow(DIGITS)
+}
+
+/// The code for the step containing `time`.
+#[must_use]
+pub fn code_at(secret: &[u8], time: DateTime<Utc>) -> String {
+    format!(
+        "{:0width$}",
+        hotp(secret, step_at(time) as u64),
+        width = DIGITS as usize
+    )
+}
+
+/// Checks `code` against the steps around `now`.
+///
+/// Returns the step that matched, which must be greater than `last_used_step` so that a code can't be replayed.
+#[must_use]
+pub fn verify(
+    secret: &[u8],
+    code: &str,
+    now: DateTime<Utc>,
+    last_used_step: Option<i64>,
+) -> Option<i64> {
+    let code = code.trim();
+
+    if code.len() != DIGITS as usize || !code.bytes().all(|it| it.is_ascii_digit()) {
+        return None;
+    }
+
+    let code: u32 = code.parse().ok()?;
+
+    let current = step_at(now);
+
+    ((current - ALLOWED_DRIFT)..=(current + ALLOWED_DRIFT))
+        .filter(|step| last_used_step.map_or(true, |last| *step > last))
+        .find(|step| hotp(secret, *step as u64) == code)
+}
+
+#[must_use]
+pub fn generate_recovery_codes() -> Vec<String> {
+    let mut rng = rand::thread_rng();
+
+    (0..RECOVERY_CODE_COUNT)
+        .map(|_| {
+            (0..RECOVERY_CODE_LEN)
+                .map(|_| {
+                    RECOVERY_CODE_ALPHABET[rng.gen_range(0..RECOVERY_CODE_ALPHABET.len())] as char
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Recovery codes are random enough that they don't need a slow hash.
+#[must_use]
+pub fn hash_recovery_code(code: &str) -> String {
+    let code: String = code
+        .chars()
+        .filter(|it| it.is_ascii_alphanumeric())
+        .map(|it| it.to_ascii_lowercase())
+        .collect();
+
+    format!("{:x}", Sha256::digest(code.as_bytes()))
+}
@@ -0,0 +1,143 @@
+use http::StatusCode;
+use serde_json::json;
+use shared::domain::admin::{AuditAction, AuditLogResponse, StorageConsumersResponse};
+use uuid::Uuid;
+
+use crate::{
+    fixture::Fixture,
+    helpers::{initialize_server, LoginExt},
+};
+
+#[actix_rt::test]
+async fn audit_log_jig_admin_data() -> anyhow::Result<()> {
+    let app = initialize_server(&[Fixture::User, Fixture::Jig], &[]).await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .patch(&format!(
+            "http://0.0.0.0:{}/v1/jig/0cc084bc-7c83-11eb-9f77-e3218dffb008/admin",
+            port
+        ))
+        .json(&json!({ "blocked": true }))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    let resp = client
+        .get(&format!(
+            "http://0.0.0.0:{}/v1/admin/audit-log?action=updateJigAdminData",
+            port
+        ))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let body: AuditLogResponse = resp.json().await?;
+
+    assert_eq!(body.total_count, 1);
+
+    let entry = &body.entries[0];
+
+    assert_eq!(entry.action, AuditAction::UpdateJigAdminData);
+    assert_eq!(
+        entry.actor_id,
+        Some("1f241e1b-b537-493f-a230-075cb16315be".parse::<Uuid>()?)
+    );
+    assert_eq!(
+        entry.target_id,
+        Some("0cc084bc-7c83-11eb-9f77-e3218dffb008".parse::<Uuid>()?)
+    );
+    assert_eq!(
+        entry.before.as_ref().and_then(|it| it.get("blocked")),
+        Some(&json!(false))
+    );
+    assert_eq!(
+        entry.after.as_ref().and_then(|it| it.get("blocked")),
+        Some(&json!(true))
+    );
+
+    let resp = client
+        .get(&format!(
+            "http://0.0.0.0:{}/v1/admin/audit-log/export?action=updateJigAdminData",
+            port
+        ))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let body = resp.text().await?;
+
+    // header + one entry
+    assert_eq!(body.lines().count(), 2);
+    assert!(body.contains("updateJigAdminData"));
+
+    app.stop(false).await;
+
+    Ok(())
+}
+
+#[actix_rt::test]
+async fn audit_log_forbidden() -> anyhow::Result<()> {
+    let app = initialize_server(&[Fixture::UserNoPerms], &[]).await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .get(&format!("http://0.0.0.0:{}/v1/admin/audit-log", port))
+        .login()
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    app.stop(false).await;
+
+    Ok(())
+}
+
+#[actix_rt::test]
+async fn storage_consumers() -> anyhow::Result<()> {
+    let app = initialize_server(&[Fixture::User, Fixture::Image], &[]).await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .get(&format!(
+            "http://0.0.0.0:{}/v1/admin/storage-usage?group=image&limit=5",
+            port
+        ))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let body: StorageConsumersResponse = resp.json().await?;
+
+    app.stop(false).await;
+
+    assert_eq!(body.users.len(), 1);
+
+    let user = &body.users[0];
+
+    assert_eq!(
+        user.user_id,
+        "1f241e1b-b537-493f-a230-075cb16315be".parse::<Uuid>()?
+    );
+    assert_eq!(user.username.as_deref(), Some("test"));
+    assert_eq!(user.image_bytes, 400000);
+    assert_eq!(user.used_bytes, 400000);
+
+    Ok(())
+}
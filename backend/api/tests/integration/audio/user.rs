@@ -1,4 +1,5 @@
 use http::StatusCode;
+use serde_json::json;
 use shared::domain::{audio::AudioId, CreateResponse};
 
 use crate::{
@@ -31,3 +32,78 @@ async fn create_returns_created() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[actix_rt::test]
+async fn get_unprocessed() -> anyhow::Result<()> {
+    let app = initialize_server(&[Fixture::User], &[]).await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::new();
+
+    let CreateResponse { id } = client
+        .post(&format!("http://0.0.0.0:{}/v1/user/me/audio", port))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<CreateResponse<AudioId>>()
+        .await?;
+
+    let body: serde_json::Value = client
+        .get(&format!(
+            "http://0.0.0.0:{}/v1/user/me/audio/{}",
+            port, id.0
+        ))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    app.stop(false).await;
+
+    // the format isn't known until the upload has been processed.
+    assert_eq!(
+        body,
+        json!({ "metadata": { "id": id.0, "original_format": null } })
+    );
+
+    Ok(())
+}
+
+#[actix_rt::test]
+async fn upload_opus_is_rejected() -> anyhow::Result<()> {
+    let app = initialize_server(&[Fixture::User], &[]).await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::new();
+
+    let CreateResponse { id } = client
+        .post(&format!("http://0.0.0.0:{}/v1/user/me/audio", port))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?
+        .json::<CreateResponse<AudioId>>()
+        .await?;
+
+    let resp = client
+        .put(&format!(
+            "http://0.0.0.0:{}/v1/user/me/audio/{}/raw",
+            port, id.0
+        ))
+        .login()
+        .json(&json!({ "file_size": 1024, "content_type": "audio/webm;codecs=opus" }))
+        .send()
+        .await?;
+
+    app.stop(false).await;
+
+    // rejected before any upload url is handed out.
+    assert_eq!(resp.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+    Ok(())
+}
@@ -1,8 +1,17 @@
 use std::{collections::HashSet, sync::Mutex};
 
 use chrono::{Duration, Utc};
-use core::settings::{JwkAudiences, RuntimeSettings};
-use ji_cloud_api::http::Application;
+use core::{
+    google::GoogleAccessTokenResponse,
+    settings::{
+        GoogleCloudStorageSettings, ImageSearchProvider, JwkAudiences, RuntimeSettings,
+        StorageQuotaSettings,
+    },
+};
+use ji_cloud_api::{
+    http::Application,
+    service::{storage, GcpAccessKeyStore},
+};
 use rand::Rng;
 use shared::config::RemoteTarget;
 use sqlx::{Connection, Executor, PgPool};
@@ -144,10 +153,30 @@ pub async fn initialize_server(fixtures: &[Fixture], services: &[Service]) -> Ap
     app
 }
 
+/// Starts a server which enforces `storage_quota`.
+///
+/// Uploads get as far as checking the quota, the storage services they need after that are placeholders which can't
+/// be reached.
+pub async fn initialize_server_with_storage_quota(
+    fixtures: &[Fixture],
+    storage_quota: StorageQuotaSettings,
+) -> Application {
+    let (app, _) = build_server(fixtures, &[], Some(storage_quota)).await;
+    app
+}
+
 // FIXME: is there a cleaner way to get a db connection from the application?
 pub async fn initialize_server_and_get_db(
     fixtures: &[Fixture],
     services: &[Service],
+) -> (Application, PgPool) {
+    build_server(fixtures, services, None).await
+}
+
+async fn build_server(
+    fixtures: &[Fixture],
+    services: &[Service],
+    storage_quota: Option<StorageQuotaSettings>,
 ) -> (Application, PgPool) {
     let _ = dotenv::dotenv().ok();
 
@@ -189,7 +218,7 @@ pub async fn initialize_server_and_get_db(
     };
 
     // todo: cache this.
-    let settings = RuntimeSettings::new(
+    let mut settings = RuntimeSettings::new(
         RemoteTarget::Local,
         0,
         0,
@@ -201,11 +230,39 @@ pub async fn initialize_server_and_get_db(
         None,
     );
 
+    settings.image_search_provider = Some(ImageSearchProvider::Fixture);
+
+    let gcp_key_store = match storage_quota {
+        Some(storage_quota) => {
+            settings.storage_quota = storage_quota;
+
+            Some(
+                GcpAccessKeyStore::new(GoogleAccessTokenResponse {
+                    access_token: Some("placeholder".to_owned()),
+                    expires_at: Some(Utc::now() + Duration::hours(1)),
+                })
+                .expect("failed to create key store"),
+            )
+        }
+        None => None, // TODO add test
+    };
+
+    let gcs = match (gcs, &gcp_key_store) {
+        (None, Some(_)) => Some(
+            storage::Client::new(GoogleCloudStorageSettings {
+                media_bucket: "placeholder-media".to_owned(),
+                processing_bucket: "placeholder-processing".to_owned(),
+            })
+            .expect("failed to create storage client"),
+        ),
+        (gcs, _) => gcs,
+    };
+
     let app = ji_cloud_api::http::build(
         db.clone(),
         settings,
         s3,
-        None, // TODO add test
+        gcp_key_store,
         gcs,
         algolia,
         None,
@@ -214,6 +271,7 @@ pub async fn initialize_server_and_get_db(
         None,
         None,
         None,
+        None,
     )
     .expect("failed to initialize server");
 
@@ -4,12 +4,15 @@ mod user;
 
 use http::StatusCode;
 use serde_json::json;
-use shared::domain::{image::ImageId, CreateResponse};
+use shared::domain::{
+    image::{ImageId, ImageKind},
+    CreateResponse,
+};
 use uuid::Uuid;
 
 use crate::{
     fixture::Fixture,
-    helpers::{initialize_server, LoginExt},
+    helpers::{initialize_server, initialize_server_and_get_db, LoginExt},
     service::Service,
 };
 
@@ -40,6 +43,7 @@ async fn create(
             "categories": categories,
             "tags": tags,
             "kind": "Canvas",
+            "provenance": {"license": "Owned"},
         }))
         .send()
         .await?
@@ -109,6 +113,7 @@ async fn create_error(kind: &str, id: &str) -> anyhow::Result<()> {
             "categories": [],
             "tags": [],
             "kind": "Canvas",
+            "provenance": {"license": "Owned"},
             kind: [id],
         }))
         .send()
@@ -146,6 +151,7 @@ async fn create_error_tag(kind: &str, id: &i16) -> anyhow::Result<()> {
             "categories": [],
             "tags": [],
             "kind": "Canvas",
+            "provenance": {"license": "Owned"},
             kind: [id],
         }))
         .send()
@@ -291,6 +297,51 @@ async fn update_tags() -> anyhow::Result<()> {
     update(&json!({"tags": [0, 2]})).await
 }
 
+#[actix_rt::test]
+async fn update_provenance() -> anyhow::Result<()> {
+    update(&json!({"provenance": {
+        "license": "CcBy",
+        "author": "Jane Doe",
+        "sourceUrl": "https://example.com/cat.png",
+    }}))
+    .await
+}
+
+#[actix_rt::test]
+async fn create_provenance_error() -> anyhow::Result<()> {
+    let app = initialize_server(&[Fixture::User], &[]).await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::new();
+
+    // `CcBy` requires crediting the author.
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/image", port))
+        .login()
+        .json(&json!({
+            "name": "test",
+            "description": "testest",
+            "is_premium": false,
+            "publish_at": null,
+            "styles": [],
+            "age_ranges": [],
+            "affiliations": [],
+            "categories": [],
+            "tags": [],
+            "kind": "Canvas",
+            "provenance": {"license": "CcBy", "sourceUrl": "https://example.com/cat.png"},
+        }))
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    app.stop(false).await;
+
+    Ok(())
+}
+
 #[actix_rt::test]
 async fn browse() -> anyhow::Result<()> {
     let app = initialize_server(&[Fixture::User, Fixture::Image], &[]).await;
@@ -386,6 +437,7 @@ async fn create_media_and_upload_with_url() -> anyhow::Result<()> {
             "tags": [],
             "categories": [],
             "kind": "Canvas",
+            "provenance": {"license": "Owned"},
         }))
         .login()
         .send()
@@ -422,3 +474,182 @@ async fn create_media_and_upload_with_url() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[actix_rt::test]
+async fn get_file_by_accept() -> anyhow::Result<()> {
+    let (app, db) = initialize_server_and_get_db(&[Fixture::User, Fixture::Image], &[]).await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()?;
+
+    let id = "3095d05e-f2c7-11ea-89c3-3b621dd74a1f";
+
+    let location = |accept: &'static str| {
+        let client = client.clone();
+        async move {
+            let resp = client
+                .get(&format!(
+                    "http://0.0.0.0:{}/v1/media/image/Global/{}/Resized",
+                    port, id
+                ))
+                .header(http::header::ACCEPT, accept)
+                .send()
+                .await?;
+
+            assert_eq!(resp.status(), StatusCode::TEMPORARY_REDIRECT);
+            assert_eq!(resp.headers()[http::header::VARY], "Accept");
+
+            Ok::<_, anyhow::Error>(resp.headers()[http::header::LOCATION].to_str()?.to_owned())
+        }
+    };
+
+    const BROWSER_ACCEPT: &str = "image/avif,image/webp,image/apng,image/*,*/*;q=0.8";
+
+    // the image hasn't been given WebP files yet.
+    let png = format!("media/global/{}/resized.png", id);
+    assert!(location(BROWSER_ACCEPT).await?.ends_with(&png));
+
+    sqlx::query!(
+        "update image_upload set has_webp = true where image_id = $1",
+        id.parse::<Uuid>()?
+    )
+    .execute(&db)
+    .await?;
+
+    let webp = format!("media/global/{}/resized.webp", id);
+    assert!(location(BROWSER_ACCEPT).await?.ends_with(&webp));
+    assert!(location("image/png,*/*").await?.ends_with(&png));
+    assert!(location("image/webp;q=0,*/*").await?.ends_with(&png));
+
+    // vector images are served as the (scalable) original.
+    sqlx::query!(
+        "update image_metadata set kind = $2 where id = $1",
+        id.parse::<Uuid>()?,
+        ImageKind::VectorSticker as i16,
+    )
+    .execute(&db)
+    .await?;
+
+    let svg = format!("media/global/{}/original.svg", id);
+    assert!(location(BROWSER_ACCEPT).await?.ends_with(&svg));
+    assert!(location("image/png").await?.ends_with(&png));
+
+    // never uploaded
+    let resp = client
+        .get(&format!(
+            "http://0.0.0.0:{}/v1/media/image/Global/{}/Resized",
+            port, "8cca720a-c4bb-11eb-8edf-63da1d86939c"
+        ))
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    app.stop(false).await;
+
+    Ok(())
+}
+
+#[actix_rt::test]
+async fn similar_and_duplicates() -> anyhow::Result<()> {
+    let (app, db) = initialize_server_and_get_db(&[Fixture::User, Fixture::Image], &[]).await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::new();
+
+    let original: Uuid = "3095d05e-f2c7-11ea-89c3-3b621dd74a1f".parse()?;
+    let copy: Uuid = "8cca6f3a-c4bb-11eb-8edf-13c75672da8f".parse()?;
+    let unrelated: Uuid = "8cca7124-c4bb-11eb-8edf-7b42383ed8f5".parse()?;
+    let user_image: Uuid = "89125d88-ffaa-11eb-86a5-9fd50ab8d8df".parse()?;
+
+    // `copy` differs from `original` by two bits, `unrelated` by all of them.
+    sqlx::query!(
+        r#"
+update image_upload
+set perceptual_hash = case image_id when $1 then 0 when $2 then 3 else -1 end
+where image_id = any(array[$1, $2, $3]::uuid[])
+"#,
+        original,
+        copy,
+        unrelated,
+    )
+    .execute(&db)
+    .await?;
+
+    sqlx::query!(
+        "update user_image_upload set perceptual_hash = 1 where image_id = $1",
+        user_image
+    )
+    .execute(&db)
+    .await?;
+
+    let body: serde_json::Value = client
+        .get(&format!(
+            "http://0.0.0.0:{}/v1/image/{}/similar",
+            port, original
+        ))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    assert_eq!(
+        body,
+        json!({ "images": [{ "id": copy, "library": "Global", "distance": 2 }] })
+    );
+
+    let body: serde_json::Value = client
+        .get(&format!(
+            "http://0.0.0.0:{}/v1/user/me/image/{}/similar?maxDistance=1",
+            port, user_image
+        ))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    assert_eq!(
+        body,
+        json!({ "images": [
+            { "id": original, "library": "Global", "distance": 1 },
+            { "id": copy, "library": "Global", "distance": 1 },
+        ] })
+    );
+
+    let body: serde_json::Value = client
+        .get(&format!("http://0.0.0.0:{}/v1/image/duplicates", port))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    let clusters: Vec<Vec<&str>> = body["clusters"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|cluster| {
+            cluster["images"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|it| it["metadata"]["id"].as_str().unwrap())
+                .collect()
+        })
+        .collect();
+
+    assert_eq!(clusters, vec![vec![original.to_string(), copy.to_string()]]);
+
+    app.stop(false).await;
+
+    Ok(())
+}
@@ -1,14 +1,16 @@
 use http::StatusCode;
 use serde_json::json;
 use shared::domain::{jig::JigId, CreateResponse};
+use uuid::Uuid;
 
 use crate::{
     fixture::Fixture,
-    helpers::{initialize_server, LoginExt},
+    helpers::{initialize_server, initialize_server_and_get_db, LoginExt},
 };
 
 mod additional_resource;
 mod cover;
+mod flashcard_review;
 mod module;
 mod player;
 
@@ -537,3 +539,97 @@ async fn update_and_publish_incomplete_modules() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[actix_rt::test]
+async fn attribution() -> anyhow::Result<()> {
+    let (app, db) = initialize_server_and_get_db(
+        &[
+            Fixture::MetaKinds,
+            Fixture::User,
+            Fixture::Jig,
+            Fixture::Image,
+        ],
+        &[],
+    )
+    .await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::new();
+
+    let jig_id: Uuid = "0cc084bc-7c83-11eb-9f77-e3218dffb008".parse()?;
+    let image_id: Uuid = "3095d05e-f2c7-11ea-89c3-3b621dd74a1f".parse()?;
+
+    sqlx::query!(
+        r#"
+update image_metadata
+set license    = 4,
+    author     = 'Jane Doe',
+    source_url = 'https://example.com/cat.png'
+where id = $1
+"#,
+        image_id
+    )
+    .execute(&db)
+    .await?;
+
+    // the same image used twice, plus a user library image (which isn't listed).
+    sqlx::query!(
+        r#"
+update jig_data_module
+set contents = jsonb_build_object(
+    'content', jsonb_build_object(
+        'backgrounds', jsonb_build_object('layer_1', jsonb_build_object('image', jsonb_build_object('id', $2::uuid, 'lib', 'Global'))),
+        'stickers', jsonb_build_array(
+            jsonb_build_object('image', jsonb_build_object('id', $2::uuid, 'lib', 'Global')),
+            jsonb_build_object('image', jsonb_build_object('id', $2::uuid, 'lib', 'User'))
+        )
+    )
+)
+where jig_data_id = (select live_id from jig where id = $1)
+"#,
+        jig_id,
+        image_id
+    )
+    .execute(&db)
+    .await?;
+
+    let body: serde_json::Value = client
+        .get(&format!(
+            "http://0.0.0.0:{}/v1/jig/{}/live/attribution",
+            port, jig_id
+        ))
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    assert_eq!(
+        body,
+        json!({ "media": [{
+            "id": image_id,
+            "library": "Global",
+            "provenance": {
+                "license": "CcBy",
+                "author": "Jane Doe",
+                "sourceUrl": "https://example.com/cat.png",
+            },
+        }] })
+    );
+
+    let resp = client
+        .get(&format!(
+            "http://0.0.0.0:{}/v1/jig/{}/live/attribution",
+            port,
+            Uuid::nil()
+        ))
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    app.stop(false).await;
+
+    Ok(())
+}
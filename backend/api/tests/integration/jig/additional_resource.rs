@@ -1,12 +1,14 @@
 use crate::{
     fixture::Fixture,
-    helpers::{initialize_server, LoginExt},
+    helpers::{initialize_server, initialize_server_and_get_db, LoginExt},
 };
 use http::StatusCode;
+use serde_json::json;
 use shared::domain::{
     image::ImageId,
     jig::additional_resource::{AdditionalResourceCreateRequest, ResourceContent},
     meta::ResourceTypeId,
+    pdf::PdfId,
 };
 use std::str::FromStr;
 use uuid::Uuid;
@@ -127,3 +129,65 @@ async fn delete() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[actix_rt::test]
+async fn pdf_preview() -> anyhow::Result<()> {
+    let (app, db) =
+        initialize_server_and_get_db(&[Fixture::MetaKinds, Fixture::User, Fixture::Jig], &[]).await;
+
+    let port: u16 = app.port();
+
+    // a pdf that's already been processed.
+    let pdf_id = sqlx::query!(
+        r#"
+insert into user_pdf_library (user_id, page_count, title)
+values ('1f241e1b-b537-493f-a230-075cb16315be', 4, 'Shapes worksheet')
+returning id as "id: PdfId"
+"#
+    )
+    .fetch_one(&db)
+    .await?
+    .id;
+
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(&format!(
+            "http://0.0.0.0:{}/v1/jig/0cc084bc-7c83-11eb-9f77-e3218dffb008/draft/additional-resource",
+            port
+        ))
+        .login()
+        .json(&AdditionalResourceCreateRequest {
+            display_name: "worksheet".to_string(),
+            resource_type_id: ResourceTypeId(Uuid::from_str("a939f454-519e-11ec-ab46-2fa68cd3a8c7").unwrap()),
+            resource_content: ResourceContent::PdfId(pdf_id),
+        })
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let body: serde_json::Value = resp.json().await?;
+
+    let resp = client
+        .get(&format!(
+            "http://0.0.0.0:{}/v1/jig/0cc084bc-7c83-11eb-9f77-e3218dffb008/draft/additional-resource/{}",
+            port,
+            body["id"].as_str().unwrap()
+        ))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let body: serde_json::Value = resp.json().await?;
+
+    app.stop(false).await;
+
+    assert_eq!(body["pdfId"], json!(pdf_id.0));
+    assert_eq!(
+        body["pdfPreview"],
+        json!({ "pageCount": 4, "title": "Shapes worksheet" })
+    );
+
+    Ok(())
+}
@@ -0,0 +1,197 @@
+use http::StatusCode;
+use shared::domain::jig::{
+    module::body::{
+        _groups::cards::{Card, CardContent, CardPair},
+        flashcards::review::{pair_key, CardReview, FlashcardReviewResponse},
+    },
+    player::instance::PlayerSessionInstanceResponse,
+};
+
+use crate::{
+    fixture::Fixture,
+    helpers::{initialize_server_and_get_db, LoginExt},
+};
+
+const JIG_ID: &str = "0cc084bc-7c83-11eb-9f77-e3218dffb008";
+
+/// Stable ID of the fixture jig's flashcards module.
+const FLASHCARDS_ID: &str = "0cc03a02-7c83-11eb-9f77-f77f9ad65e9a";
+
+/// Stable ID of the fixture jig's cover module.
+const COVER_ID: &str = "0cbfdd82-7c83-11eb-9f77-d7d86264c3bc";
+
+fn pair(word: &str) -> CardPair {
+    let card = |text: &str| Card {
+        audio: None,
+        card_content: CardContent::Text(text.to_owned()),
+    };
+
+    CardPair(card(word), card(&word.to_uppercase()))
+}
+
+/// Sets the pairs of the fixture jig's flashcards module, the rest of its content doesn't matter here.
+async fn set_pairs(db: &sqlx::PgPool, pairs: &[CardPair]) -> anyhow::Result<()> {
+    sqlx::query!(
+        r#"update jig_data_module set contents = jsonb_build_object('content', jsonb_build_object('base', jsonb_build_object('pairs', $2::jsonb))) where stable_id = $1"#,
+        FLASHCARDS_ID.parse::<uuid::Uuid>()?,
+        serde_json::to_value(pairs)?,
+    )
+    .execute(db)
+    .await?;
+
+    Ok(())
+}
+
+#[actix_rt::test]
+async fn review_flow() -> anyhow::Result<()> {
+    let (app, db) =
+        initialize_server_and_get_db(&[Fixture::MetaKinds, Fixture::User, Fixture::Jig], &[]).await;
+
+    let port = app.port();
+
+    let pairs = vec![pair("a"), pair("b"), pair("c")];
+    let keys: Vec<_> = pairs.iter().map(pair_key).collect();
+
+    set_pairs(&db, &pairs).await?;
+
+    let client = reqwest::Client::builder()
+        .user_agent("mocked user agent")
+        .build()?;
+
+    let review_url = |module_id: &str| {
+        format!(
+            "http://0.0.0.0:{}/v1/jig/{}/module/{}/flashcards/review",
+            port, JIG_ID, module_id
+        )
+    };
+
+    let resp = client
+        .get(&review_url(FLASHCARDS_ID))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let body: FlashcardReviewResponse = resp.json().await?;
+    assert!(body.reviews.is_empty());
+
+    let mut last = None;
+    for (pair_index, known) in &[(1, true), (1, true), (0, false)] {
+        let resp = client
+            .put(&review_url(FLASHCARDS_ID))
+            .json(&serde_json::json!({
+                "pairKey": keys[*pair_index],
+                "known": known,
+            }))
+            .login()
+            .send()
+            .await?
+            .error_for_status()?;
+
+        let review: CardReview = resp.json().await?;
+        last = Some(review);
+    }
+
+    let unknown = last.unwrap();
+    assert_eq!(unknown.leitner_box, 0);
+    assert_eq!(unknown.due_at, unknown.reviewed_at);
+
+    let resp = client
+        .get(&review_url(FLASHCARDS_ID))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let body: FlashcardReviewResponse = resp.json().await?;
+    let mut boxes: Vec<_> = body
+        .reviews
+        .iter()
+        .map(|it| (it.pair_key.clone(), it.leitner_box))
+        .collect();
+    boxes.sort_by_key(|(_, leitner_box)| *leitner_box);
+    assert_eq!(boxes, vec![(keys[0].clone(), 0), (keys[1].clone(), 2)]);
+
+    let resp = client
+        .put(&review_url(FLASHCARDS_ID))
+        .json(&serde_json::json!({ "pairKey": pair_key(&pair("d")), "known": true }))
+        .login()
+        .send()
+        .await?;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    // the state follows "b" when "a" is deleted and the pairs are reordered.
+    set_pairs(&db, &[pair("c"), pair("b")]).await?;
+
+    let resp = client
+        .put(&review_url(FLASHCARDS_ID))
+        .json(&serde_json::json!({ "pairKey": keys[1], "known": true }))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let review: CardReview = resp.json().await?;
+    assert_eq!(review.leitner_box, 3);
+
+    let resp = client
+        .put(&review_url(FLASHCARDS_ID))
+        .json(&serde_json::json!({ "pairKey": keys[0], "known": true }))
+        .login()
+        .send()
+        .await?;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let resp = client.get(&review_url(COVER_ID)).login().send().await?;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    let resp = client.get(&review_url(FLASHCARDS_ID)).send().await?;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    // players without an account keep their state with their session instance.
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/jig/player/instance", port))
+        .json(&serde_json::json!({ "index": 1234 }))
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let instance: PlayerSessionInstanceResponse = resp.json().await?;
+
+    client
+        .put(&review_url(FLASHCARDS_ID))
+        .json(&serde_json::json!({
+            "token": instance.token,
+            "pairKey": keys[2],
+            "known": true,
+        }))
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let resp = client
+        .get(&review_url(FLASHCARDS_ID))
+        .query(&[("token", &instance.token)])
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let body: FlashcardReviewResponse = resp.json().await?;
+    let boxes: Vec<_> = body
+        .reviews
+        .iter()
+        .map(|it| (it.pair_key.clone(), it.leitner_box))
+        .collect();
+    assert_eq!(boxes, vec![(keys[2].clone(), 1)]);
+
+    let resp = client
+        .get(&review_url(FLASHCARDS_ID))
+        .query(&[("token", "not a token")])
+        .send()
+        .await?;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    app.stop(false).await;
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+mod admin;
 mod animation;
 mod audio;
 mod auth;
@@ -8,9 +9,11 @@ mod image;
 mod jig;
 mod locale;
 mod meta;
+mod search;
 mod service;
 mod session;
 mod user;
+mod webhook;
 
 #[actix_rt::test]
 async fn pass() -> anyhow::Result<()> {
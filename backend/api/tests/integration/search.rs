@@ -0,0 +1,106 @@
+use http::StatusCode;
+use serde_json::json;
+
+use crate::{
+    fixture::Fixture,
+    helpers::{initialize_server, LoginExt},
+};
+
+async fn search_web(port: u16, query: &[(&str, &str)]) -> anyhow::Result<Vec<String>> {
+    let resp = reqwest::Client::new()
+        .get(&format!("http://0.0.0.0:{}/v1/search/web/image", port))
+        .query(query)
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let body: serde_json::Value = resp.json().await?;
+
+    Ok(body["images"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|it| it["url"].as_str().unwrap().to_owned())
+        .collect())
+}
+
+#[actix_rt::test]
+async fn web_image_filters() -> anyhow::Result<()> {
+    let app = initialize_server(&[Fixture::User], &[]).await;
+
+    let port = app.port();
+
+    // mature results are left out by default.
+    assert_eq!(
+        search_web(port, &[("q", "cat")]).await?,
+        vec![
+            "https://example.com/images/cat.png",
+            "https://example.com/images/black-cat.png",
+            "https://example.com/images/cat-cartoon.png",
+        ]
+    );
+
+    assert_eq!(
+        search_web(port, &[("q", "cat"), ("license", "creativeCommons")]).await?,
+        vec![
+            "https://example.com/images/cat.png",
+            "https://example.com/images/black-cat.png",
+        ]
+    );
+
+    assert_eq!(
+        search_web(
+            port,
+            &[
+                ("q", "cat"),
+                ("license", "publicDomain"),
+                ("safeSearch", "moderate")
+            ]
+        )
+        .await?,
+        vec![
+            "https://example.com/images/black-cat.png",
+            "https://example.com/images/cat-fight.png",
+        ]
+    );
+
+    // adult content can't be let through.
+    let resp = reqwest::Client::new()
+        .get(&format!("http://0.0.0.0:{}/v1/search/web/image", port))
+        .query(&[("q", "cat"), ("safeSearch", "off")])
+        .login()
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let resp = reqwest::Client::new()
+        .get(&format!("http://0.0.0.0:{}/v1/search/web/image", port))
+        .query(&[("q", "dog")])
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let body: serde_json::Value = resp.json().await?;
+
+    app.stop(false).await;
+
+    assert_eq!(
+        body,
+        json!({ "images": [{
+            "thumbnail_url": "https://example.com/thumbnails/dog.png",
+            "url": "https://example.com/images/dog.png",
+            "provenance": {
+                "license": "CcBySa",
+                "author": "John Doe",
+                "sourceUrl": "https://example.com/dog",
+            },
+        }] })
+    );
+
+    Ok(())
+}
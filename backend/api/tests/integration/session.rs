@@ -1,6 +1,13 @@
+use chrono::Utc;
 use http::StatusCode;
+use serde_json::json;
 
-use crate::{fixture::Fixture, helpers::initialize_server};
+use crate::{
+    fixture::Fixture,
+    helpers::{initialize_server, LoginExt},
+};
+
+const TOTP_SECRET: &[u8] = b"12345678901234567890";
 
 #[actix_rt::test]
 async fn create_401_no_auth() -> anyhow::Result<()> {
@@ -69,3 +76,119 @@ async fn create_basic_bad_password() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[actix_rt::test]
+async fn create_basic_too_many_requests() -> anyhow::Result<()> {
+    let app = initialize_server(&[Fixture::User], &[]).await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::new();
+
+    let limit = core::settings::RateLimitSettings::default().account_limit;
+
+    for _ in 0..limit {
+        let resp = client
+            .post(&format!("http://0.0.0.0:{}/v1/session", port))
+            .basic_auth("test@test.test", Some("wrongPAssword"))
+            .send()
+            .await?;
+
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/session", port))
+        .basic_auth("test@test.test", Some("password1"))
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(resp.headers().contains_key(http::header::RETRY_AFTER));
+
+    app.stop(false).await;
+
+    Ok(())
+}
+
+#[actix_rt::test]
+async fn second_factor_required_for_admin() -> anyhow::Result<()> {
+    let app = initialize_server(&[Fixture::User, Fixture::UserTotp], &[]).await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::new();
+
+    let impersonate_url = format!(
+        "http://0.0.0.0:{}/v1/admin/session/user/1f241e1b-b537-493f-a230-075cb16315be",
+        port
+    );
+
+    let resp = client.post(&impersonate_url).login().send().await?;
+
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/session/second-factor", port))
+        .json(&json!({ "totp": { "code": "000000" } }))
+        .login()
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/session/second-factor", port))
+        .json(&json!({
+            "totp": { "code": ji_cloud_api::totp::code_at(TOTP_SECRET, Utc::now()) }
+        }))
+        .login()
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    let resp = client
+        .post(&impersonate_url)
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    app.stop(false).await;
+
+    Ok(())
+}
+
+#[actix_rt::test]
+async fn second_factor_recovery_code_single_use() -> anyhow::Result<()> {
+    let app = initialize_server(&[Fixture::User, Fixture::UserTotp], &[]).await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/session/second-factor", port))
+        .json(&json!({ "recoveryCode": { "code": "TEST-RECOVERY" } }))
+        .login()
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/session/second-factor", port))
+        .json(&json!({ "recoveryCode": { "code": "testrecovery" } }))
+        .login()
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    app.stop(false).await;
+
+    Ok(())
+}
@@ -16,6 +16,9 @@ use sqlx::PgPool;
 
 mod color;
 mod font;
+mod storage;
+mod token;
+mod totp;
 
 #[actix_rt::test]
 async fn get_profile() -> anyhow::Result<()> {
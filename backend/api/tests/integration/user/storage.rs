@@ -0,0 +1,110 @@
+use core::settings::StorageQuotaSettings;
+use http::StatusCode;
+use serde_json::json;
+
+use crate::{
+    fixture::Fixture,
+    helpers::{initialize_server, initialize_server_with_storage_quota, LoginExt},
+};
+
+#[actix_rt::test]
+async fn usage() -> anyhow::Result<()> {
+    let app = initialize_server(&[Fixture::User, Fixture::Image], &[]).await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::new();
+
+    // not uploaded yet, so it doesn't count.
+    client
+        .post(&format!("http://0.0.0.0:{}/v1/user/me/pdf", port))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let resp = client
+        .get(&format!("http://0.0.0.0:{}/v1/user/me/usage", port))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let body: serde_json::Value = resp.json().await?;
+
+    app.stop(false).await;
+
+    assert_eq!(
+        body,
+        json!({
+            "usedBytes": 400000,
+            "groups": [
+                { "group": "image", "usedBytes": 400000, "fileCount": 4, "quotaBytes": null },
+                { "group": "audio", "usedBytes": 0, "fileCount": 0, "quotaBytes": null },
+                { "group": "pdf", "usedBytes": 0, "fileCount": 0, "quotaBytes": null },
+            ]
+        })
+    );
+
+    Ok(())
+}
+
+#[actix_rt::test]
+async fn upload_over_quota() -> anyhow::Result<()> {
+    // the fixture's user already has 400000 bytes of images.
+    let app = initialize_server_with_storage_quota(
+        &[Fixture::User, Fixture::Image],
+        StorageQuotaSettings {
+            image: Some(450_000),
+            audio: None,
+            pdf: None,
+        },
+    )
+    .await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/user/me/image", port))
+        .json(&json!({ "kind": "Sticker" }))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let body: serde_json::Value = resp.json().await?;
+    let id = body["id"].as_str().expect("created image has an id");
+
+    let resp = client
+        .put(&format!(
+            "http://0.0.0.0:{}/v1/user/me/image/{}/raw",
+            port, id
+        ))
+        .json(&json!({ "file_size": 50_001 }))
+        .login()
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::INSUFFICIENT_STORAGE);
+
+    // the rejected upload doesn't count towards the usage.
+    let resp = client
+        .get(&format!("http://0.0.0.0:{}/v1/user/me/usage", port))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let body: serde_json::Value = resp.json().await?;
+
+    app.stop(false).await;
+
+    assert_eq!(body["groups"][0]["usedBytes"], json!(400000));
+    assert_eq!(body["groups"][0]["quotaBytes"], json!(450000));
+
+    Ok(())
+}
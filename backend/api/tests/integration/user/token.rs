@@ -0,0 +1,145 @@
+use chrono::{Duration, Utc};
+use http::StatusCode;
+use shared::domain::{
+    category::CreateCategoryRequest,
+    user::{CreateTokenRequest, CreateTokenResponse, ListTokensResponse, UserScope},
+};
+
+use crate::{
+    fixture::Fixture,
+    helpers::{initialize_server, LoginExt},
+};
+
+#[actix_rt::test]
+async fn create_use_revoke() -> anyhow::Result<()> {
+    let app = initialize_server(&[Fixture::User], &[]).await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::new();
+
+    let expires_at = Utc::now() + Duration::days(30);
+
+    // the user only has `Admin`, so they can't hand out scopes they don't have explicitly.
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/user/me/token", port))
+        .json(&CreateTokenRequest {
+            name: "batch-tag".to_owned(),
+            scopes: vec![UserScope::ManageImage],
+            expires_at,
+        })
+        .login()
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/user/me/token", port))
+        .json(&CreateTokenRequest {
+            name: "batch-tag".to_owned(),
+            scopes: vec![UserScope::Admin],
+            expires_at,
+        })
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    let admin_token: CreateTokenResponse = resp.json().await?;
+
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/user/me/token", port))
+        .json(&CreateTokenRequest {
+            name: "read only".to_owned(),
+            scopes: vec![],
+            expires_at,
+        })
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let plain_token: CreateTokenResponse = resp.json().await?;
+
+    // no cookie and no CSRF header.
+    let resp = client
+        .get(&format!("http://0.0.0.0:{}/v1/user/me/profile", port))
+        .bearer_auth(&plain_token.token)
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/category", port))
+        .bearer_auth(&plain_token.token)
+        .json(&CreateCategoryRequest {
+            name: "test".to_owned(),
+            parent_id: None,
+        })
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/category", port))
+        .bearer_auth(&admin_token.token)
+        .json(&CreateCategoryRequest {
+            name: "test".to_owned(),
+            parent_id: None,
+        })
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    // tokens can't be used to manage tokens.
+    let resp = client
+        .get(&format!("http://0.0.0.0:{}/v1/user/me/token", port))
+        .bearer_auth(&admin_token.token)
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    let resp = client
+        .get(&format!("http://0.0.0.0:{}/v1/user/me/token", port))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let body: ListTokensResponse = resp.json().await?;
+
+    assert_eq!(body.tokens.len(), 2);
+    assert!(body.tokens.iter().any(|it| it.id == admin_token.id
+        && it.scopes == [UserScope::Admin]
+        && it.last_used_at.is_some()));
+
+    let resp = client
+        .delete(&format!(
+            "http://0.0.0.0:{}/v1/user/me/token/{}",
+            port, admin_token.id
+        ))
+        .login()
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    let resp = client
+        .get(&format!("http://0.0.0.0:{}/v1/user/me/profile", port))
+        .bearer_auth(&admin_token.token)
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    app.stop(false).await;
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use chrono::{Duration, Utc};
+use http::StatusCode;
+use serde_json::json;
+use shared::domain::user::{
+    CreateTokenRequest, CreateTokenResponse, RecoveryCodesResponse, TotpEnrollResponse,
+    TotpStatusResponse, UserScope,
+};
+
+use crate::{
+    fixture::Fixture,
+    helpers::{initialize_server, LoginExt},
+};
+
+#[actix_rt::test]
+async fn enroll_and_confirm() -> anyhow::Result<()> {
+    let app = initialize_server(&[Fixture::User], &[]).await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/user/me/totp", port))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    let body: TotpEnrollResponse = resp.json().await?;
+
+    assert!(body.uri.starts_with("otpauth://totp/"));
+
+    let secret = data_encoding::BASE32_NOPAD.decode(body.secret.as_bytes())?;
+
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/user/me/totp/confirm", port))
+        .json(&json!({ "code": "000000" }))
+        .login()
+        .send()
+        .await?;
+
+    // there's a one in a million chance of this being the right code.
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/user/me/totp/confirm", port))
+        .json(&json!({ "code": ji_cloud_api::totp::code_at(&secret, Utc::now()) }))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let body: RecoveryCodesResponse = resp.json().await?;
+
+    assert_eq!(body.recovery_codes.len(), 10);
+
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/user/me/totp", port))
+        .login()
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+    let resp = client
+        .get(&format!("http://0.0.0.0:{}/v1/user/me/totp", port))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let body: TotpStatusResponse = resp.json().await?;
+
+    assert!(body.enabled);
+    assert_eq!(body.recovery_codes_remaining, 10);
+
+    app.stop(false).await;
+
+    Ok(())
+}
+
+#[actix_rt::test]
+async fn personal_access_token_cant_enroll() -> anyhow::Result<()> {
+    let app = initialize_server(&[Fixture::User], &[]).await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::new();
+
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/user/me/token", port))
+        .json(&CreateTokenRequest {
+            name: "script".to_owned(),
+            scopes: vec![UserScope::Admin],
+            expires_at: Utc::now() + Duration::days(30),
+        })
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let token: CreateTokenResponse = resp.json().await?;
+
+    // a leaked token mustn't be able to add a second factor to the account.
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/user/me/totp", port))
+        .bearer_auth(&token.token)
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    let resp = client
+        .post(&format!("http://0.0.0.0:{}/v1/user/me/totp/confirm", port))
+        .json(&json!({ "code": "000000" }))
+        .bearer_auth(&token.token)
+        .send()
+        .await?;
+
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    let resp = client
+        .get(&format!("http://0.0.0.0:{}/v1/user/me/totp", port))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    let body: TotpStatusResponse = resp.json().await?;
+
+    assert!(!body.enabled);
+
+    app.stop(false).await;
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use std::{
+    net::TcpListener,
+    sync::{Arc, Mutex},
+};
+
+use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer};
+use http::StatusCode;
+use serde_json::json;
+use shared::domain::{
+    jig::JigId,
+    webhook::{CreateWebhookRequest, WebhookEvent, WebhookId, WebhookPayload},
+    CreateResponse,
+};
+
+use crate::{
+    fixture::Fixture,
+    helpers::{initialize_server_and_get_db, LoginExt},
+};
+
+struct Received {
+    event: String,
+    timestamp: String,
+    signature: String,
+    body: web::Bytes,
+}
+
+type Receiver = Arc<Mutex<Vec<Received>>>;
+
+async fn receive(
+    req: HttpRequest,
+    body: web::Bytes,
+    received: web::Data<Receiver>,
+) -> HttpResponse {
+    let header = |name: &str| {
+        req.headers()
+            .get(name)
+            .and_then(|it| it.to_str().ok())
+            .unwrap_or_default()
+            .to_owned()
+    };
+
+    received.lock().unwrap().push(Received {
+        event: header("X-Jigzi-Event"),
+        timestamp: header("X-Jigzi-Timestamp"),
+        signature: header("X-Jigzi-Signature"),
+        body,
+    });
+
+    HttpResponse::NoContent().finish()
+}
+
+#[actix_rt::test]
+async fn deliveries_are_signed_and_retried() -> anyhow::Result<()> {
+    let received: Receiver = Arc::default();
+
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let receiver_port = listener.local_addr()?.port();
+
+    let receiver = {
+        let received = received.clone();
+        HttpServer::new(move || {
+            App::new()
+                .app_data(web::Data::new(received.clone()))
+                .route("/ok", web::post().to(receive))
+                .route(
+                    "/fail",
+                    web::post().to(|| async { HttpResponse::InternalServerError().finish() }),
+                )
+        })
+        .listen(listener)?
+        .run()
+    };
+
+    let (app, db) = initialize_server_and_get_db(&[Fixture::User, Fixture::Jig], &[]).await;
+
+    let port = app.port();
+
+    let client = reqwest::Client::new();
+
+    const SECRET: &str = "0123456789abcdef";
+
+    for (path, events) in [
+        (
+            "ok",
+            vec![WebhookEvent::JigPublished, WebhookEvent::JigBlocked],
+        ),
+        ("fail", vec![WebhookEvent::JigPublished]),
+    ] {
+        let resp = client
+            .post(&format!("http://0.0.0.0:{}/v1/webhook", port))
+            .json(&CreateWebhookRequest {
+                url: format!("http://127.0.0.1:{}/{}", receiver_port, path),
+                secret: SECRET.to_owned(),
+                events,
+                organization: None,
+            })
+            .login()
+            .send()
+            .await?
+            .error_for_status()?;
+
+        assert_eq!(resp.status(), StatusCode::CREATED);
+
+        let _body: CreateResponse<WebhookId> = resp.json().await?;
+    }
+
+    let resp = client
+        .put(&format!(
+            "http://0.0.0.0:{}/v1/jig/0cc084bc-7c83-11eb-9f77-e3218dffb008/draft/publish",
+            port
+        ))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    let resp = client
+        .patch(&format!(
+            "http://0.0.0.0:{}/v1/jig/0cc084bc-7c83-11eb-9f77-e3218dffb008/admin",
+            port
+        ))
+        .json(&json!({ "blocked": true }))
+        .login()
+        .send()
+        .await?
+        .error_for_status()?;
+
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    // the receiver is on localhost.
+    ji_cloud_api::service::webhook::Dispatcher::new(db.clone(), true)?
+        .expect("dispatcher is always enabled")
+        .spawn_cron_jobs()
+        .await?;
+
+    {
+        let received = received.lock().unwrap();
+
+        let events: Vec<_> = received.iter().map(|it| it.event.as_str()).collect();
+        assert_eq!(events.len(), 2);
+        assert!(events.contains(&"jigPublished"));
+        assert!(events.contains(&"jigBlocked"));
+
+        for it in received.iter() {
+            assert_eq!(
+                it.signature,
+                ji_cloud_api::service::webhook::sign(SECRET, it.timestamp.parse()?, &it.body)
+            );
+
+            let payload: WebhookPayload = serde_json::from_slice(&it.body)?;
+            assert_eq!(
+                payload.jig_id,
+                JigId("0cc084bc-7c83-11eb-9f77-e3218dffb008".parse()?)
+            );
+        }
+    }
+
+    // the failed delivery stays queued for a retry.
+    let delivery = sqlx::query!(
+        r#"
+select attempts,
+       last_status,
+       failed_at is null and next_attempt_at > now() as "pending!"
+from webhook_delivery
+where delivered_at is null
+"#
+    )
+    .fetch_one(&db)
+    .await?;
+
+    assert_eq!(delivery.attempts, 1);
+    assert_eq!(delivery.last_status, Some(500));
+    assert!(delivery.pending);
+
+    receiver.stop(false).await;
+    app.stop(false).await;
+
+    Ok(())
+}
@@ -129,6 +129,51 @@ pub mod event_arc {
     pub const MEDIA_PROCESSED_TOPIC: &str = "GOOGLE_EVENTARC_MEDIA_PROCESSED_TOPIC";
 }
 
+pub mod rate_limit {
+    /// Disable rate limiting entirely.
+    /// If specified in a way that maps to `true` (currently "true", "1", "y"), auth and kids-code
+    /// endpoints will not be throttled.
+    pub const DISABLE: &str = "RATE_LIMIT_DISABLE";
+
+    /// Where rate limit counters are stored, either `memory` or `postgres`.
+    /// Is optional. If missing, counters are kept in memory (per instance).
+    pub const STORE: &str = "RATE_LIMIT_STORE";
+
+    /// Length of a rate limit window (measured in seconds).
+    /// Is optional. If missing, the compiled default will be used.
+    pub const WINDOW: &str = "RATE_LIMIT_WINDOW";
+
+    /// Maximum number of requests to a throttled endpoint per IP address per window.
+    /// Is optional. If missing, the compiled default will be used.
+    pub const IP_LIMIT: &str = "RATE_LIMIT_IP_LIMIT";
+
+    /// Maximum number of requests to a throttled endpoint per account per window.
+    /// Is optional. If missing, the compiled default will be used.
+    pub const ACCOUNT_LIMIT: &str = "RATE_LIMIT_ACCOUNT_LIMIT";
+
+    /// Number of failed logins after which an account is locked out.
+    /// Is optional. If missing, the compiled default will be used.
+    pub const LOCKOUT_ATTEMPTS: &str = "RATE_LIMIT_LOCKOUT_ATTEMPTS";
+
+    /// How long an account stays locked out after too many failed logins (measured in seconds).
+    /// Is optional. If missing, the compiled default will be used.
+    pub const LOCKOUT_DURATION: &str = "RATE_LIMIT_LOCKOUT_DURATION";
+}
+
+pub mod storage_quota {
+    /// The most bytes a user can upload to their image library.
+    /// Is optional. If missing, image uploads are only limited by file size.
+    pub const IMAGE: &str = "STORAGE_QUOTA_IMAGE_BYTES";
+
+    /// The most bytes a user can upload to their audio library.
+    /// Is optional. If missing, audio uploads are only limited by file size.
+    pub const AUDIO: &str = "STORAGE_QUOTA_AUDIO_BYTES";
+
+    /// The most bytes a user can upload to their pdf library.
+    /// Is optional. If missing, pdf uploads are only limited by file size.
+    pub const PDF: &str = "STORAGE_QUOTA_PDF_BYTES";
+}
+
 /// Must be 32 bytes of hex
 pub const TOKEN_SECRET: &str = "TOKEN_SECRET";
 
@@ -137,12 +182,20 @@ pub const TOKEN_SECRET: &str = "TOKEN_SECRET";
 /// This environment variable is optional, if missing it will use the server's compiled default (an indeterminate but reasonable amount of time)
 pub const LOGIN_TOKEN_VALID_DURATION: &str = "LOGIN_TOKEN_VALID_DURATION";
 
+/// Comma separated list of scope ids (see `UserScope`) that can only be used after passing a second factor.
+/// Is optional. If missing, only users who have enrolled a second factor need to pass it.
+pub const SECOND_FACTOR_REQUIRED_SCOPES: &str = "SECOND_FACTOR_REQUIRED_SCOPES";
+
 pub const SENTRY_DSN_API: &str = "SENTRY_DSN_API";
 pub const SENTRY_DSN_PAGES: &str = "SENTRY_DSN_PAGES";
 pub const SENTRY_SAMPLE_RATE: &str = "SENTRY_SAMPLE_RATE";
 
 pub const BING_SEARCH_KEY: &str = "BING_SEARCH_KEY";
 
+/// Which provider to use for web image search (`bing`, `openverse` or `fixture`).
+/// Is optional. If missing, `bing` is used if `BING_SEARCH_KEY` is set, otherwise web image search is disabled.
+pub const IMAGE_SEARCH_PROVIDER: &str = "IMAGE_SEARCH_PROVIDER";
+
 /// ID of the google oauth client.
 /// Is optional. If missing, all google-oauth related services will be disabled,
 /// all related routes will return "501 - Not Implemented" and a warning will be emitted.
@@ -16,9 +16,12 @@ use crate::{
 
 use anyhow::Context;
 use chrono::{DateTime, Utc};
-use shared::config::RemoteTarget;
+use shared::{
+    config::RemoteTarget,
+    domain::user::{UserMediaGroup, UserScope},
+};
 use std::{
-    convert::TryInto,
+    convert::{TryFrom, TryInto},
     env::VarError,
     str::FromStr,
     time::{SystemTime, UNIX_EPOCH},
@@ -68,6 +71,122 @@ impl GoogleOAuth {
     }
 }
 
+/// Where rate limiting counters are kept.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RateLimitStore {
+    /// Counters live in process memory, so each instance limits independently.
+    Memory,
+
+    /// Counters live in the `rate_limit` table, so they are shared between instances.
+    Postgres,
+}
+
+impl FromStr for RateLimitStore {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "memory" => Ok(Self::Memory),
+            "postgres" => Ok(Self::Postgres),
+            s => anyhow::bail!("Unknown rate limit store: {} (expected memory|postgres)", s),
+        }
+    }
+}
+
+/// Where web image search results come from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageSearchProvider {
+    /// The bing image search api, requires `bing_search_key`.
+    Bing,
+
+    /// Openverse, which only has openly licensed images (and doesn't need a key).
+    Openverse,
+
+    /// A small set of built in results, for tests.
+    Fixture,
+}
+
+impl FromStr for ImageSearchProvider {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bing" => Ok(Self::Bing),
+            "openverse" => Ok(Self::Openverse),
+            "fixture" => Ok(Self::Fixture),
+            s => anyhow::bail!(
+                "Unknown image search provider: {} (expected bing|openverse|fixture)",
+                s
+            ),
+        }
+    }
+}
+
+/// Settings for throttling the auth and kids-code endpoints.
+#[derive(Clone, Debug)]
+pub struct RateLimitSettings {
+    /// Whether or not rate limiting is enabled at all.
+    pub enabled: bool,
+
+    /// Where the counters are stored.
+    pub store: RateLimitStore,
+
+    /// Length of a rate limit window.
+    pub window: chrono::Duration,
+
+    /// Maximum number of requests per IP address per window, per endpoint.
+    pub ip_limit: u32,
+
+    /// Maximum number of requests per account (email) per window, per endpoint.
+    pub account_limit: u32,
+
+    /// Number of failed logins after which an account gets locked out.
+    pub lockout_attempts: u32,
+
+    /// How long an account stays locked out for.
+    pub lockout_duration: chrono::Duration,
+}
+
+impl Default for RateLimitSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            store: RateLimitStore::Memory,
+            window: chrono::Duration::minutes(1),
+            ip_limit: 20,
+            account_limit: 5,
+            lockout_attempts: 10,
+            lockout_duration: chrono::Duration::minutes(15),
+        }
+    }
+}
+
+/// How many bytes each user can upload to each of their media libraries.
+///
+/// `None` means the library isn't limited (besides the per-file size limit).
+#[derive(Clone, Debug, Default)]
+pub struct StorageQuotaSettings {
+    /// Quota for the user image library.
+    pub image: Option<u64>,
+
+    /// Quota for the user audio library.
+    pub audio: Option<u64>,
+
+    /// Quota for the user pdf library.
+    pub pdf: Option<u64>,
+}
+
+impl StorageQuotaSettings {
+    /// The quota for `group`, if it has one.
+    pub fn for_group(&self, group: UserMediaGroup) -> Option<u64> {
+        match group {
+            UserMediaGroup::Image => self.image,
+            UserMediaGroup::Audio => self.audio,
+            UserMediaGroup::Pdf => self.pdf,
+        }
+    }
+}
+
 /// Settings that are accessed at runtime (as compared to startup time)
 #[derive(Clone)]
 pub struct RuntimeSettings {
@@ -89,6 +208,11 @@ pub struct RuntimeSettings {
     // todo: move this and make it runtime reloadable somehow (bing suggests rotating keys)
     pub bing_search_key: Option<String>,
 
+    /// Where web image search results come from.
+    ///
+    /// If missing, web image search returns no results.
+    pub image_search_provider: Option<ImageSearchProvider>,
+
     remote_target: RemoteTarget,
 
     /// Settings for google OAuth
@@ -106,6 +230,16 @@ pub struct RuntimeSettings {
     /// * can only be set on `local`
     /// * optional, if missing it will use the server's compiled default (an indeterminate but reasonable amount of time)
     pub login_token_valid_duration: Option<chrono::Duration>,
+
+    /// Settings for throttling the auth and kids-code endpoints.
+    pub rate_limit: RateLimitSettings,
+
+    /// Scopes which can only be used from a session that has passed a second factor (TOTP),
+    /// even by users who haven't enrolled one.
+    pub second_factor_required_scopes: Vec<UserScope>,
+
+    /// How much each user can upload to their media libraries.
+    pub storage_quota: StorageQuotaSettings,
 }
 
 impl RuntimeSettings {
@@ -129,21 +263,29 @@ impl RuntimeSettings {
             media_watch_port,
             epoch: get_epoch(),
             remote_target,
+            image_search_provider: bing_search_key.as_ref().map(|_| ImageSearchProvider::Bing),
             bing_search_key,
             google_oauth,
             google_api_key,
             token_secret,
             login_token_valid_duration,
+            rate_limit: RateLimitSettings::default(),
+            second_factor_required_scopes: Vec::new(),
+            storage_quota: StorageQuotaSettings::default(),
         }
     }
 
     pub(crate) fn with_env(
         remote_target: RemoteTarget,
         bing_search_key: Option<String>,
+        image_search_provider: Option<ImageSearchProvider>,
         google_api_key: Option<String>,
         google_oauth: Option<GoogleOAuth>,
         token_secret: Box<[u8; 32]>,
         login_token_valid_duration: Option<chrono::Duration>,
+        rate_limit: RateLimitSettings,
+        second_factor_required_scopes: Vec<UserScope>,
+        storage_quota: StorageQuotaSettings,
     ) -> anyhow::Result<Self> {
         let (api_port, pages_port, media_watch_port) = match remote_target {
             RemoteTarget::Local => (
@@ -164,10 +306,14 @@ impl RuntimeSettings {
             epoch: get_epoch(),
             remote_target,
             bing_search_key,
+            image_search_provider,
             google_oauth,
             google_api_key,
             token_secret,
             login_token_valid_duration,
+            rate_limit,
+            second_factor_required_scopes,
+            storage_quota,
         })
     }
 
@@ -791,6 +937,115 @@ impl SettingsManager {
         })
     }
 
+    /// Load the settings for rate limiting, falling back to the compiled defaults.
+    pub async fn rate_limit_settings(&self) -> anyhow::Result<RateLimitSettings> {
+        let defaults = RateLimitSettings::default();
+
+        let enabled = !crate::env::env_bool(keys::rate_limit::DISABLE);
+
+        let store = self
+            .get_optional_secret(keys::rate_limit::STORE)
+            .await?
+            .as_deref()
+            .map(RateLimitStore::from_str)
+            .transpose()?
+            .unwrap_or(defaults.store);
+
+        let window = self
+            .get_optional_secret(keys::rate_limit::WINDOW)
+            .await?
+            .as_deref()
+            .map(i64::from_str)
+            .transpose()?
+            .map_or(defaults.window, chrono::Duration::seconds);
+
+        let ip_limit = self
+            .get_optional_secret(keys::rate_limit::IP_LIMIT)
+            .await?
+            .as_deref()
+            .map(u32::from_str)
+            .transpose()?
+            .unwrap_or(defaults.ip_limit);
+
+        let account_limit = self
+            .get_optional_secret(keys::rate_limit::ACCOUNT_LIMIT)
+            .await?
+            .as_deref()
+            .map(u32::from_str)
+            .transpose()?
+            .unwrap_or(defaults.account_limit);
+
+        let lockout_attempts = self
+            .get_optional_secret(keys::rate_limit::LOCKOUT_ATTEMPTS)
+            .await?
+            .as_deref()
+            .map(u32::from_str)
+            .transpose()?
+            .unwrap_or(defaults.lockout_attempts);
+
+        let lockout_duration = self
+            .get_optional_secret(keys::rate_limit::LOCKOUT_DURATION)
+            .await?
+            .as_deref()
+            .map(i64::from_str)
+            .transpose()?
+            .map_or(defaults.lockout_duration, chrono::Duration::seconds);
+
+        Ok(RateLimitSettings {
+            enabled,
+            store,
+            window,
+            ip_limit,
+            account_limit,
+            lockout_attempts,
+            lockout_duration,
+        })
+    }
+
+    /// Load the per-user storage quotas, missing quotas mean unlimited storage.
+    pub async fn storage_quota_settings(&self) -> anyhow::Result<StorageQuotaSettings> {
+        let image = self
+            .get_optional_secret(keys::storage_quota::IMAGE)
+            .await?
+            .as_deref()
+            .map(u64::from_str)
+            .transpose()?;
+
+        let audio = self
+            .get_optional_secret(keys::storage_quota::AUDIO)
+            .await?
+            .as_deref()
+            .map(u64::from_str)
+            .transpose()?;
+
+        let pdf = self
+            .get_optional_secret(keys::storage_quota::PDF)
+            .await?
+            .as_deref()
+            .map(u64::from_str)
+            .transpose()?;
+
+        Ok(StorageQuotaSettings { image, audio, pdf })
+    }
+
+    /// Load the scopes that require a second factor, as a comma separated list of scope ids.
+    pub async fn second_factor_required_scopes(&self) -> anyhow::Result<Vec<UserScope>> {
+        let scopes = match self
+            .get_optional_secret(keys::SECOND_FACTOR_REQUIRED_SCOPES)
+            .await?
+        {
+            Some(scopes) => scopes,
+            None => return Ok(Vec::new()),
+        };
+
+        scopes
+            .split(',')
+            .map(str::trim)
+            .filter(|it| !it.is_empty())
+            .map(|it| UserScope::try_from(i16::from_str(it)?))
+            .collect()
+    }
+
     /// Load the `RuntimeSettings`.
     pub async fn runtime_settings(&self) -> anyhow::Result<RuntimeSettings> {
         let token_secret = self
@@ -811,6 +1066,14 @@ impl SettingsManager {
 
         let bing_search_key = self.get_optional_secret(keys::BING_SEARCH_KEY).await?;
 
+        let image_search_provider = match self
+            .get_optional_secret(keys::IMAGE_SEARCH_PROVIDER)
+            .await?
+        {
+            Some(provider) => Some(ImageSearchProvider::from_str(&provider)?),
+            None => bing_search_key.as_ref().map(|_| ImageSearchProvider::Bing),
+        };
+
         let google_api_key = self.get_optional_secret(keys::GOOGLE_API_KEY).await?;
 
         let login_token_valid_duration = match self.remote_target {
@@ -829,13 +1092,23 @@ impl SettingsManager {
             self.get_varying_secret(keys::GOOGLE_OAUTH_SECRET).await?,
         );
 
+        let rate_limit = self.rate_limit_settings().await?;
+
+        let second_factor_required_scopes = self.second_factor_required_scopes().await?;
+
+        let storage_quota = self.storage_quota_settings().await?;
+
         RuntimeSettings::with_env(
             self.remote_target,
             bing_search_key,
+            image_search_provider,
             google_api_key,
             google_oauth,
             token_secret,
             login_token_valid_duration,
+            rate_limit,
+            second_factor_required_scopes,
+            storage_quota,
         )
     }
 }
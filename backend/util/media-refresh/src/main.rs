@@ -82,6 +82,19 @@ enum Command {
         #[clap(long, default_value = "5")]
         max_tasks: usize,
     },
+
+    /// Generate WebP files for images that were processed before they existed.
+    /// Takes the output of `download` as its input.
+    BackfillWebp {
+        input_file: PathBuf,
+
+        #[clap(long, default_value = "record.csv")]
+        record_file: PathBuf,
+
+        /// Controls the maximum amount of tasks that will be processed at once
+        #[clap(long, default_value = "5")]
+        max_tasks: usize,
+    },
 }
 
 #[tokio::main]
@@ -113,6 +126,24 @@ async fn main() -> anyhow::Result<()> {
             max_tasks,
         } => {
             refresh::run(
+                refresh::Action::Refresh,
+                input_file,
+                record_file,
+                max_tasks,
+                opts.endpoint,
+                opts.token,
+                opts.csrf,
+                opts.show_progress,
+            )
+            .await
+        }
+        Command::BackfillWebp {
+            input_file,
+            record_file,
+            max_tasks,
+        } => {
+            refresh::run(
+                refresh::Action::BackfillWebp,
                 input_file,
                 record_file,
                 max_tasks,
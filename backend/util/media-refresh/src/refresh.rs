@@ -22,6 +22,16 @@ enum MediaResolution {
     NotFound,
 }
 
+/// What to do with each item of media.
+#[derive(Copy, Clone)]
+pub enum Action {
+    /// Reprocess the item (as if it was just uploaded), if it hasn't been updated since it was downloaded.
+    Refresh,
+
+    /// Generate the WebP files of an item that was processed before they existed.
+    BackfillWebp,
+}
+
 #[derive(serde::Deserialize)]
 struct MediaItem {
     id: Uuid,
@@ -69,7 +79,9 @@ async fn load(input_file: PathBuf, show_progress: bool) -> anyhow::Result<Vec<Me
     Ok(data.media)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
+    action: Action,
     input_file: PathBuf,
     record_file: PathBuf,
     max_tasks: usize,
@@ -141,7 +153,7 @@ pub async fn run(
             {
                 let pb = mp.add(ProgressBar::new_spinner());
                 pb.set_message(&format!("handling item: {}", item.id));
-                refresh_item(pb.clone(), item, client, &endpoint, tx).await;
+                refresh_item(pb.clone(), action, item, client, &endpoint, tx).await;
                 pb.finish_and_clear();
             }
             pb.inc(1);
@@ -163,31 +175,35 @@ pub async fn run(
 
 async fn refresh_item(
     pb: ProgressBar,
+    action: Action,
     item: MediaItem,
     client: reqwest::Client,
     endpoint: &Url,
     tx: Sender<MediaRecord>,
 ) {
     let id = item.id;
-    if let Err(e) = refresh_item_inner(item, client, endpoint, tx).await {
+    if let Err(e) = refresh_item_inner(action, item, client, endpoint, tx).await {
         log::error!("Failed to refresh item `{}`: {}", id, e);
         pb.println(format!("Failed to refresh item: {}", id));
     }
 }
 
 async fn refresh_item_inner(
+    action: Action,
     item: MediaItem,
     client: reqwest::Client,
     endpoint: &Url,
     tx: Sender<MediaRecord>,
 ) -> anyhow::Result<()> {
-    let path = media_refresh_path(item.library, item.id);
+    let path = media_path(action, item.library, item.id);
 
     let request = client.post(endpoint.join(&path)?);
 
-    let request = match item.etag {
-        None => request.header(header::IF_NONE_MATCH, "*"),
-        Some(etag) => request.header(header::IF_MATCH, &*etag),
+    let request = match (action, item.etag) {
+        (Action::Refresh, None) => request.header(header::IF_NONE_MATCH, "*"),
+        (Action::Refresh, Some(etag)) => request.header(header::IF_MATCH, &*etag),
+        // already generated WebP files are reported as `AlreadyUpdated`.
+        (Action::BackfillWebp, _) => request,
     };
 
     let response = request.send().await?;
@@ -219,12 +235,17 @@ async fn refresh_item_inner(
     Ok(())
 }
 
-fn media_refresh_path(library: MediaLibrary, id: Uuid) -> String {
+fn media_path(action: Action, library: MediaLibrary, id: Uuid) -> String {
     let library = match library {
         MediaLibrary::Web => "Web",
         MediaLibrary::User => "User",
         MediaLibrary::Global => "Global",
     };
 
-    format!("v0/admin/media/refresh/{}/image/{}", library, id)
+    let action = match action {
+        Action::Refresh => "refresh",
+        Action::BackfillWebp => "webp",
+    };
+
+    format!("v0/admin/media/{}/{}/image/{}", action, library, id)
 }
@@ -25,7 +25,7 @@ impl Context {
 
         let token = {
             if !opts.token.is_empty() {
-                log::info!("TOKEN: {}", opts.token);
+                log::info!("using token from opts");
                 opts.token.clone()
             } else {
                 log::info!("no token set in opts, using env");
@@ -9,6 +9,8 @@ pub struct Opts {
     #[structopt(long, default_value = "local")]
     pub remote_target: String,
 
+    /// personal access token, created with `POST /v1/user/me/token`
+    /// if empty, `LOCAL_API_AUTH_OVERRIDE` is used
     #[structopt(long, default_value = "")]
     pub token: String,
 
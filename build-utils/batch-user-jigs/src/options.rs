@@ -33,6 +33,8 @@ pub struct Opts {
     #[structopt(long, default_value = "release")]
     pub remote_target: String,
 
+    /// personal access token, created with `POST /v1/user/me/token`
+    /// if empty, `LOCAL_API_AUTH_OVERRIDE` is used
     #[structopt(long, default_value = "")]
     pub token: String,
 }
@@ -234,8 +234,7 @@ async fn upload_image(opts:Arc<Opts>, credentials:Arc<Credentials>, album_name:A
 
     let request = reqwest::Client::new()
         .patch(&upload_url)
-        .header("X-CSRF", &credentials.csrf)
-        .header("Cookie", &format!("X-JWT={}", credentials.token))
+        .header("AUTHORIZATION", &format!("Bearer {}", &credentials.token))
         .header("Content-Type", content_type)
         .header("Content-Length", file_size) 
         .body(body);
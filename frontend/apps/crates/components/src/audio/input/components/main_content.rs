@@ -27,6 +27,9 @@ fn render_start(state: Rc<AudioInput>, add_method: AudioInputAddMethod) -> Dom {
         AudioInputAddMethod::Upload => {
             html!("input-file", {
                 .property("slot", "main-content")
+                // anything the server can transcode, see `AudioFormat`. Ogg and WebM files are
+                // usually Opus, which it can't.
+                .property("accept", "audio/mpeg,audio/wav,audio/mp4,audio/x-m4a,audio/flac,.mp3,.wav,.m4a,.flac")
                 .child(html!("audio-input-icon", {
                     .property("kind", "upload")
                 }))
@@ -13,6 +13,8 @@ use utils::prelude::*;
 use web_sys::File;
 
 const STR_AUDIO_IS_TOO_LARGE: &str = "Audio is too large, limit is 30MB";
+const STR_AUDIO_IS_UNSUPPORTED: &str =
+    "Opus audio isn't supported, please upload an mp3, wav, m4a or flac file";
 
 #[derive(Debug, Error)]
 pub enum UploadError {
@@ -20,6 +22,8 @@ pub enum UploadError {
     Aborted,
     #[error("TooLarge")]
     TooLarge,
+    #[error("Unsupported")]
+    Unsupported,
     #[error("awsm_web error")]
     Other(awsm_web::errors::Error),
 }
@@ -66,6 +70,7 @@ pub async fn upload_audio(
             MediaLibrary::User => {
                 let req = UserAudioUploadRequest {
                     file_size: file.size() as usize,
+                    content_type: Some(file.type_()).filter(|it| !it.is_empty()),
                 };
 
                 let path = endpoints::audio::user::Upload::PATH.replace("{id}", &id.0.to_string());
@@ -91,6 +96,11 @@ pub async fn upload_audio(
                                 .unwrap_ji()
                                 .alert_with_message(STR_AUDIO_IS_TOO_LARGE);
                             Err(UploadError::TooLarge)
+                        } else if status == 415 {
+                            let _ = web_sys::window()
+                                .unwrap_ji()
+                                .alert_with_message(STR_AUDIO_IS_UNSUPPORTED);
+                            Err(UploadError::Unsupported)
                         } else {
                             side_effect_status_code(status);
                             resp.map_err(|_| UploadError::Other(awsm_web::errors::Error::Empty))
@@ -15,7 +15,7 @@ use shared::domain::image::{
 };
 use shared::domain::media::WebMediaUrlCreateRequest;
 use shared::domain::meta::ImageTagIndex;
-use shared::domain::search::WebImageSearchQuery;
+use shared::domain::search::{WebImageSearchItem, WebImageSearchQuery};
 use shared::media::MediaKind;
 use shared::{
     api::{endpoints, ApiEndpoint},
@@ -28,12 +28,14 @@ use shared::{
     media::MediaLibrary,
 };
 use std::rc::Rc;
-use url::Url;
 use utils::prelude::*;
 use web_sys::File;
 
-pub async fn web_to_image(url: Url) -> Result<Image, ()> {
-    let req = WebMediaUrlCreateRequest { url };
+pub async fn web_to_image(image: WebImageSearchItem) -> Result<Image, ()> {
+    let req = WebMediaUrlCreateRequest {
+        url: image.url,
+        provenance: image.provenance,
+    };
 
     let res = endpoints::media::Create::api_with_auth(Some(req))
         .await
@@ -60,10 +62,10 @@ impl State {
     }
 }
 
-pub fn on_web_image_click(state: Rc<State>, url: Url) {
+pub fn on_web_image_click(state: Rc<State>, image: WebImageSearchItem) {
     state.loader.load(clone!(state => async move {
 
-        let image = web_to_image(url).await.expect_ji("Couldn't upload image");
+        let image = web_to_image(image).await.expect_ji("Couldn't upload image");
         state.set_selected(image);
 
     }));
@@ -307,10 +309,15 @@ pub fn add_recent(state: &State, image: &Image) {
 }
 
 pub async fn upload_file(state: Rc<State>, file: File) {
-    let req = UserImageCreateRequest {
-        kind: ImageKind::Sticker,
+    // vector art is stored as-is (so that it can be scaled without blurring), rather than being rasterized.
+    let kind = if file.type_() == "image/svg+xml" {
+        ImageKind::VectorSticker
+    } else {
+        ImageKind::Sticker
     };
 
+    let req = UserImageCreateRequest { kind };
+
     match api_with_auth::<CreateResponse, EmptyError, _>(
         endpoints::image::user::Create::PATH,
         endpoints::image::user::Create::METHOD,
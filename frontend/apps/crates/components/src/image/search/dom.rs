@@ -155,11 +155,11 @@ fn render_web_image(state: Rc<State>, image: WebImageSearchItem, slot: &str) ->
         .property("src", &image.thumbnail_url.to_string())
         .property("loading", "lazy")
         .event(clone!(state, image => move |_: events::Click| {
-            actions::on_web_image_click(Rc::clone(&state), image.url.clone());
+            actions::on_web_image_click(Rc::clone(&state), image.clone());
         }))
         .event(clone!(image => move |evt: events::DragStart| {
             if let Some(data_transfer) = evt.data_transfer() {
-                let data = ImageDataTransfer::Web(image.url.clone());
+                let data = ImageDataTransfer::Web(image.clone());
                 let json = serde_json::to_string(&data).unwrap_ji();
                 let _ = data_transfer.set_data(IMAGE_SEARCH_DATA_TRANSFER, &json);
                 data_transfer.set_drop_effect("all");
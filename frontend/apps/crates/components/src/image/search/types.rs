@@ -1,6 +1,5 @@
 use serde::{Deserialize, Serialize};
-use shared::domain::jig::module::body::Image;
-use url::Url;
+use shared::domain::{jig::module::body::Image, search::WebImageSearchItem};
 use utils::unwrap::UnwrapJiExt;
 
 use super::actions;
@@ -9,7 +8,7 @@ pub const IMAGE_SEARCH_DATA_TRANSFER: &str = "image-search";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ImageDataTransfer {
-    Web(Url),
+    Web(WebImageSearchItem),
     Image(Image),
 }
 
@@ -17,7 +16,7 @@ impl ImageDataTransfer {
     pub async fn to_image(self) -> Image {
         match self {
             Self::Image(image) => image,
-            Self::Web(url) => actions::web_to_image(url).await.unwrap_ji(),
+            Self::Web(image) => actions::web_to_image(image).await.unwrap_ji(),
         }
     }
 }
@@ -127,6 +127,11 @@ fn get_input_kind(kind: SettingsButtonKind) -> Option<InputKind> {
         SettingsButtonKind::TimeLimit => Some(InputKind::Field),
         SettingsButtonKind::ContinueSome => Some(InputKind::Field),
         SettingsButtonKind::Rounds => Some(InputKind::Field),
+        SettingsButtonKind::GridRows => Some(InputKind::Field),
+        SettingsButtonKind::GridColumns => Some(InputKind::Field),
+        SettingsButtonKind::TypeAnswer => Some(InputKind::Field),
+        SettingsButtonKind::Players => Some(InputKind::Field),
+        SettingsButtonKind::AllowTypos => Some(InputKind::Field),
         _ => None,
     }
 }
@@ -114,59 +114,117 @@ impl<T: Copy + Display + Default + Eq + FromStr + 'static> SettingsValueExt for
 // These must match the typescript / custom element variants
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SettingsButtonKind {
+    AllowTypos,
     Attempts,
     Autoplay,
+    Backward,
     CardDouble,
     CardSingle,
+    ChooseAnswer,
     ContinueAll,
     ContinueClick,
     ContinueAutomatically,
     ContinueSome,
+    Crossword,
+    Diagonal,
+    ExactSpelling,
+    Explore,
+    FlipThrough,
+    FlyBack,
+    GridColumns,
+    GridRows,
     Highlight,
     HighlightOff,
+    LetterHint,
+    LetterHintOff,
     Loop,
     Mute,
     NoLimit,
     NumChoices,
     NumPairs,
     Order,
+    Players,
+    Preview,
+    PreviewOff,
+    PutInOrder,
     Randomize,
+    Reshuffle,
+    RetryLater,
     Rounds,
+    Rtl,
     Score,
     ScoreOff,
+    ShowAnswer,
+    SinglePlayer,
+    SnapClose,
+    SnapExact,
+    SnapLoose,
+    SpacedRepetition,
+    StayPut,
     Swap,
     TimeLimit,
     TimeLimitOff,
+    TypeAnswer,
     VideoCaptions,
+    WordSearch,
 }
 
 impl SettingsButtonKind {
     pub fn as_str_id(&self) -> &'static str {
         match self {
+            Self::AllowTypos => "allow-typos",
             Self::Attempts => "attempts",
             Self::Autoplay => "autoplay",
+            Self::Backward => "backward",
             Self::CardDouble => "card-double",
             Self::CardSingle => "card-single",
+            Self::ChooseAnswer => "choose-answer",
             Self::ContinueAll => "continue-all",
             Self::ContinueAutomatically => "continue-automatically",
             Self::ContinueClick => "continue-click",
             Self::ContinueSome => "continue-some",
+            Self::Crossword => "crossword",
+            Self::Diagonal => "diagonal",
+            Self::ExactSpelling => "exact-spelling",
+            Self::Explore => "explore",
+            Self::FlipThrough => "flip-through",
+            Self::FlyBack => "fly-back",
+            Self::GridColumns => "grid-columns",
+            Self::GridRows => "grid-rows",
             Self::Highlight => "highlight",
             Self::HighlightOff => "highlight-off",
+            Self::LetterHint => "letter-hint",
+            Self::LetterHintOff => "letter-hint-off",
             Self::Loop => "loop",
             Self::Mute => "mute",
             Self::NoLimit => "no-limit",
             Self::NumChoices => "n_choices",
             Self::NumPairs => "n_pairs",
             Self::Order => "order",
+            Self::Players => "players",
+            Self::Preview => "preview",
+            Self::PreviewOff => "preview-off",
+            Self::PutInOrder => "put-in-order",
             Self::Randomize => "randomize",
+            Self::Reshuffle => "reshuffle",
+            Self::RetryLater => "retry-later",
             Self::Rounds => "rounds",
+            Self::Rtl => "rtl",
             Self::Score => "score",
             Self::ScoreOff => "score-off",
+            Self::ShowAnswer => "show-answer",
+            Self::SinglePlayer => "single-player",
+            Self::SnapClose => "snap-close",
+            Self::SnapExact => "snap-exact",
+            Self::SnapLoose => "snap-loose",
+            Self::SpacedRepetition => "spaced-repetition",
+            Self::StayPut => "stay-put",
             Self::Swap => "swap",
             Self::TimeLimit => "time-limit",
             Self::TimeLimitOff => "time-limit-off",
+            Self::TypeAnswer => "type-answer",
             Self::VideoCaptions => "video-captions",
+            Self::WordSearch => "word-search",
         }
     }
 }
@@ -9,9 +9,23 @@ pub enum LineKind {
     Rounds,
     TimeLimit,
     Attempts,
+    AfterAttempts,
     Score,
     VideoPlay,
     VideoFeatures,
+    GridKind,
+    GridSize,
+    WordDirections,
+    Layout,
+    Review,
+    Answer,
+    Players,
+    Timeline,
+    Preview,
+    Snap,
+    WrongDrop,
+    LetterHints,
+    Spelling,
 }
 
 impl LineKind {
@@ -24,9 +38,23 @@ impl LineKind {
             Self::Next => "next",
             Self::TimeLimit => "time-limit",
             Self::Attempts => "attempts",
+            Self::AfterAttempts => "after-attempts",
             Self::Score => "score",
             Self::VideoPlay => "video-play",
             Self::VideoFeatures => "video-features",
+            Self::GridKind => "grid-kind",
+            Self::GridSize => "grid-size",
+            Self::WordDirections => "word-directions",
+            Self::Layout => "layout",
+            Self::Review => "review",
+            Self::Answer => "answer",
+            Self::Players => "players",
+            Self::Timeline => "timeline",
+            Self::Preview => "preview",
+            Self::Snap => "snap",
+            Self::WrongDrop => "wrong-drop",
+            Self::LetterHints => "letter-hints",
+            Self::Spelling => "spelling",
         }
     }
 }
@@ -155,9 +155,9 @@ where
                 })))
         }))
 
-        .apply_if(jig_player, |dom| {
+        .apply_if(jig_player, clone!(base => move |dom| {
             dom
-                .global_event(|evt:dominator_helpers::events::Message| {
+                .global_event(clone!(base => move |evt:dominator_helpers::events::Message| {
                     if let Ok(msg) = evt.try_serde_data::<IframeAction<JigToModulePlayerMessage>>() {
                         match msg.data {
                             JigToModulePlayerMessage::Play => {
@@ -167,19 +167,20 @@ where
                                 AUDIO_MIXER.with(|mixer| mixer.pause_all());
                             },
                             JigToModulePlayerMessage::TimerDone => {
+                                Base::time_up(base.clone());
                             }
                         }
                     } else {
                         log::info!("hmmm got other iframe message...");
                     }
-                })
+                }))
                 .after_inserted(|_elem| {
                     //On mount - send an empty IframeInit message to let the player know we're ready
                     IframeInit::empty()
                         .try_post_message_to_player()
                         .unwrap_ji();
                 })
-        })
+        }))
 
         .apply_if(!is_screenshot, |dom| {
             dom.child_signal(base.play_phase().signal().map(clone!(base => move |curr_play_phase| {
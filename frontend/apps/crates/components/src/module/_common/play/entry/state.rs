@@ -2,7 +2,7 @@ use crate::audio::mixer::AUDIO_MIXER;
 use dominator::{clone, Dom, DomHandle};
 use dominator_helpers::futures::AsyncLoader;
 use futures_signals::signal::Mutable;
-use shared::domain::jig::module::body::Instructions;
+use shared::domain::jig::module::body::{_groups::assessment::PlaySettings, Instructions};
 use shared::{
     api::endpoints::{self, jig::module::*, ApiEndpoint},
     domain::jig::{
@@ -359,9 +359,18 @@ pub trait BaseExt: DomRenderable {
     fn get_instructions(&self) -> Option<Instructions> {
         None
     }
-    fn get_timer_minutes(&self) -> Option<u32> {
+    /// Time limit and attempts, for modules the student can get wrong
+    fn get_play_settings(&self) -> Option<&PlaySettings> {
         None
     }
+    fn get_timer_minutes(&self) -> Option<u32> {
+        self.get_play_settings()
+            .and_then(|settings| settings.time_limit)
+    }
+    /// Called when the jig player's timer runs out
+    fn time_up(state: Rc<Self>) {
+        state.set_play_phase(ModulePlayPhase::Ending(Some(ModuleEnding::Negative)));
+    }
 
     fn set_play_phase(&self, phase: ModulePlayPhase) {
         self.play_phase().set_neq(phase);
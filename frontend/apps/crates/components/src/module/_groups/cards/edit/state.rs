@@ -60,6 +60,14 @@ impl RawDataExt for shared::domain::jig::module::body::card_quiz::ModuleData {
         self.content.as_mut().map(|content| &mut content.base)
     }
 }
+impl RawDataExt for shared::domain::jig::module::body::word_grid::ModuleData {
+    fn get_content(&self) -> Option<&BaseContent> {
+        self.content.as_ref().map(|content| &content.base)
+    }
+    fn get_content_mut(&mut self) -> Option<&mut BaseContent> {
+        self.content.as_mut().map(|content| &mut content.base)
+    }
+}
 
 pub trait ExtraExt: 'static {}
 
@@ -0,0 +1,34 @@
+use super::strings::{STR_BCE, STR_MONTHS};
+use shared::domain::jig::module::body::tapping_board::timeline::{EventDate, TimelineEvent};
+
+/// The name of a month, `1..=12`
+pub fn month_name(month: u8) -> Option<&'static str> {
+    STR_MONTHS.get(usize::from(month).checked_sub(1)?).copied()
+}
+
+/// A timeline event's date, as precise as it's known
+pub fn format_date(date: &EventDate) -> String {
+    let year = if date.year < 0 {
+        format!("{} {STR_BCE}", date.year.unsigned_abs())
+    } else {
+        date.year.to_string()
+    };
+
+    // a day is only shown together with its month
+    match (date.month.and_then(month_name), date.day) {
+        (Some(month), Some(day)) => format!("{month} {day}, {year}"),
+        (Some(month), None) => format!("{month} {year}"),
+        _ => year,
+    }
+}
+
+/// The date and text of a timeline event, as its trace is labelled
+pub fn event_label(event: &TimelineEvent) -> String {
+    let date = format_date(&event.date);
+
+    if event.text.is_empty() {
+        date
+    } else {
+        format!("{date}: {}", event.text)
+    }
+}
@@ -0,0 +1,64 @@
+use dominator::{html, svg, Dom};
+use futures_signals::signal::SignalExt;
+use std::rc::Rc;
+
+use super::state::*;
+
+pub fn render(state: Rc<Cards>) -> Dom {
+    html!("tapping-board-cards", {
+        .child(svg!("svg", {
+            .property("slot", "links")
+            // normalized stage coordinates, stretched over the stage
+            .attribute("viewBox", "0 0 1 1")
+            .attribute("preserveAspectRatio", "none")
+            .children(state.links.iter().map(|link| {
+                let ((x1, y1), (x2, y2)) = link.line();
+
+                svg!("line", {
+                    .attribute("x1", &x1.to_string())
+                    .attribute("y1", &y1.to_string())
+                    .attribute("x2", &x2.to_string())
+                    .attribute("y2", &y2.to_string())
+                })
+            }))
+        }))
+        .children(state.list.iter().map(render_card))
+    })
+}
+
+fn render_card(card: &Card) -> Dom {
+    let bounds = card.bounds;
+    let title = card.title.clone();
+
+    html!("tapping-board-card", {
+        .property("slot", "cards")
+        .style("left", percent(bounds.x))
+        .style("top", percent(bounds.y))
+        .style("width", percent(bounds.width))
+        .style("height", percent(bounds.height))
+        .property_signal("heading", card.revealed.signal().map(move |revealed| {
+            if revealed {
+                title.clone()
+            } else {
+                String::new()
+            }
+        }))
+        .apply(|dom| match card.text.as_ref() {
+            Some(text) => dom.property("text", text),
+            None => dom,
+        })
+        .apply(|dom| match card.image.as_ref() {
+            Some(image) => dom.child(html!("img-ji", {
+                .property("slot", "image")
+                .property("size", "full")
+                .property("id", image.id.0.to_string())
+                .property("lib", image.lib.to_str())
+            })),
+            None => dom,
+        })
+    })
+}
+
+fn percent(value: f64) -> String {
+    format!("{}%", value * 100.0)
+}
@@ -0,0 +1,4 @@
+pub mod date;
+pub mod dom;
+pub mod state;
+pub mod strings;
@@ -0,0 +1,111 @@
+use futures_signals::signal::Mutable;
+use shared::domain::jig::module::body::{
+    tapping_board::{
+        family_tree::{FamilyTree, RelationshipKind},
+        timeline::Timeline,
+        CardBounds,
+    },
+    Image,
+};
+use std::rc::Rc;
+
+use super::date::format_date;
+
+/// The cards of a timeline or family tree, one per trace
+pub struct Cards {
+    pub list: Vec<Card>,
+    pub links: Vec<Link>,
+}
+
+pub struct Card {
+    pub bounds: CardBounds,
+    pub image: Option<Image>,
+    /// The event's date or the person's name
+    pub title: String,
+    pub text: Option<String>,
+    /// Whether the title is showing
+    pub revealed: Mutable<bool>,
+}
+
+/// A line between two related people
+pub struct Link {
+    pub from: CardBounds,
+    pub to: CardBounds,
+    pub kind: RelationshipKind,
+}
+
+impl Cards {
+    pub fn timeline(timeline: &Timeline, order: &[usize], revealed: bool) -> Rc<Self> {
+        let list = timeline
+            .events
+            .iter()
+            .zip(timeline.layout_bounds(order))
+            .map(|(event, bounds)| Card {
+                bounds,
+                image: event.image.clone(),
+                title: format_date(&event.date),
+                text: Some(event.text.clone()).filter(|text| !text.is_empty()),
+                revealed: Mutable::new(revealed),
+            })
+            .collect();
+
+        Rc::new(Self {
+            list,
+            links: Vec::new(),
+        })
+    }
+
+    pub fn family_tree(family_tree: &FamilyTree) -> Rc<Self> {
+        let bounds = family_tree.layout_bounds();
+
+        let list = family_tree
+            .people
+            .iter()
+            .zip(bounds.iter())
+            .map(|(person, bounds)| Card {
+                bounds: *bounds,
+                image: person.image.clone(),
+                title: person.name.clone(),
+                text: None,
+                revealed: Mutable::new(true),
+            })
+            .collect();
+
+        let links = family_tree
+            .relationships
+            .iter()
+            .filter_map(|rel| {
+                Some(Link {
+                    from: *bounds.get(rel.from)?,
+                    to: *bounds.get(rel.to)?,
+                    kind: rel.kind,
+                })
+            })
+            .collect();
+
+        Rc::new(Self { list, links })
+    }
+
+    pub fn reveal(&self, index: usize) {
+        if let Some(card) = self.list.get(index) {
+            card.revealed.set_neq(true);
+        }
+    }
+}
+
+impl Link {
+    /// Endpoints in normalized stage coordinates. Parents connect to the top of their child,
+    /// spouses side by side.
+    pub fn line(&self) -> ((f64, f64), (f64, f64)) {
+        match self.kind {
+            RelationshipKind::Parent => (
+                (self.from.center_x(), self.from.bottom()),
+                (self.to.center_x(), self.to.y),
+            ),
+            RelationshipKind::Spouse => {
+                let middle = self.from.y + self.from.height / 2.0;
+                ((self.from.center_x(), middle), (self.to.center_x(), middle))
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+pub const STR_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// After the year of dates before the common era
+pub const STR_BCE: &str = "BCE";
@@ -3,7 +3,11 @@ use components::image::upload::upload_image;
 use dominator::clone;
 use shared::{
     api::{endpoints, ApiEndpoint},
-    domain::{image::*, meta::*},
+    domain::{
+        image::*,
+        media::{License, Provenance},
+        meta::*,
+    },
     error::*,
     media::MediaLibrary,
 };
@@ -39,6 +43,12 @@ pub fn on_file(state: Rc<State>, file: File) {
             .map(|x| x.id)
             .collect();
 
+        // vector art is stored as-is (so that it can be scaled without blurring), rather than being rasterized.
+        let kind = match *state.kind.borrow() {
+            ImageKind::Sticker if file.type_() == "image/svg+xml" => ImageKind::VectorSticker,
+            kind => kind,
+        };
+
         let req = ImageCreateRequest {
             name: "".to_string(),
             description: "".to_string(),
@@ -49,7 +59,13 @@ pub fn on_file(state: Rc<State>, file: File) {
             age_ranges,
             affiliations,
             categories: Vec::new(),
-            kind: *state.kind.borrow()
+            kind,
+            // images uploaded through the admin are our own, the license can be changed afterwards.
+            provenance: Provenance {
+                license: License::Owned,
+                author: None,
+                source_url: None,
+            },
         };
 
         match api_with_auth::<CreateResponse, MetadataNotFound, _>(endpoints::image::Create::PATH, endpoints::image::Create::METHOD, Some(req)).await {
@@ -43,6 +43,8 @@ impl AddAdditionalResource {
                     display_name,
                     resource_type_id,
                     resource_content,
+                    // filled in once the pdf has been processed and the jig is reloaded.
+                    pdf_preview: None,
                 };
                 state
                     .publish_state
@@ -14,12 +14,14 @@ static MODULE_KINDS: &[ModuleKind] = &[
     ModuleKind::Matching,
     ModuleKind::Memory,
     ModuleKind::CardQuiz,
+    ModuleKind::WordGrid,
     ModuleKind::Poster,
     ModuleKind::TappingBoard,
     ModuleKind::DragDrop,
+    ModuleKind::Puzzle,
+    ModuleKind::TalkType,
     //ModuleKind::Tracing,
     ModuleKind::Video,
-    //ModuleKind::VisualQuiz,
 ];
 
 const STR_TOOLTIP_TITLE: &str = "Let's build your JIG!";
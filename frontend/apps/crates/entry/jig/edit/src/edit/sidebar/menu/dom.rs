@@ -18,11 +18,12 @@ const STR_PASTE: &'static str = "Paste from another JIG";
 const STR_DUPLICATE_AS: &'static str = "Duplicate content as:";
 // const STR_EDIT_SETTINGS: &'static str = "Edit setting";
 
-const CARD_KINDS: [ModuleKind; 4] = [
+const CARD_KINDS: [ModuleKind; 5] = [
     ModuleKind::Memory,
     ModuleKind::Flashcards,
     ModuleKind::Matching,
     ModuleKind::CardQuiz,
+    ModuleKind::WordGrid,
 ];
 
 pub fn render(module_state: &Rc<ModuleState>) -> Dom {
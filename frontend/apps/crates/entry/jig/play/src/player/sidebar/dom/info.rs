@@ -5,11 +5,15 @@ use futures_signals::{
     map_ref,
     signal::{Signal, SignalExt},
 };
-use shared::domain::jig::JigResponse;
+use shared::{
+    domain::jig::{additional_resource::ResourceContent, JigResponse},
+    media::MediaLibrary,
+};
 use utils::{
     ages::AgeRangeVecExt,
     events,
     jig::{published_at_string, ResourceContentExt},
+    path::pdf_thumbnail_url,
 };
 
 use super::{super::state::State, report};
@@ -92,11 +96,22 @@ fn render_jig_info(state: Rc<State>, jig: &JigResponse) -> Dom {
                 .property("slot", "additional-resources")
                 .property("target", "_BLANK")
                 .property("href", resource.resource_content.get_link())
-                .child(html!("fa-icon", {
-                    .property("icon", "fa-light fa-file")
-                }))
+                .child(match (&resource.resource_content, &resource.pdf_preview) {
+                    (ResourceContent::PdfId(pdf_id), Some(_)) => html!("img", {
+                        .property("src", pdf_thumbnail_url(MediaLibrary::User, *pdf_id))
+                        .property("alt", "")
+                        .style("height", "1em")
+                    }),
+                    _ => html!("fa-icon", {
+                        .property("icon", "fa-light fa-file")
+                    }),
+                })
                 .text(" ")
                 .text(&resource.display_name)
+                .apply(|dom| match &resource.pdf_preview {
+                    Some(preview) => dom.text(&format!(" ({} pages)", preview.page_count)),
+                    None => dom,
+                })
             })
         }))
         .child(html!("button-rect", {
@@ -8,7 +8,7 @@ use shared::{
         JigPlayerSessionIndex,
     },
 };
-use utils::prelude::*;
+use utils::{prelude::*, storage::save_player_session_token};
 
 use super::state::State;
 
@@ -17,6 +17,7 @@ pub fn submit_code(state: Rc<State>, number: String) {
         match code_to_jig_id(number).await {
             Err(_) => {}
             Ok(res) => {
+                save_player_session_token(&res.token);
                 state.error.set_neq(false);
                 state.play_jig.set(Some((res.jig_id, res.settings)));
             }
@@ -1,4 +1,5 @@
 use super::state::*;
+use shared::domain::jig::module::body::{_groups::assessment::RetryPolicy, card_quiz::AnswerMode};
 
 impl SidebarSettings {
     pub fn set_n_choices(&self, n_choices: u8) {
@@ -39,10 +40,10 @@ impl SidebarSettings {
         self.base.history.push_modify(|raw| {
             if let Some(content) = &mut raw.content {
                 if !flag {
-                    content.player_settings.time_limit = None;
+                    content.player_settings.assessment.time_limit = None;
                 } else {
                     let value = self.base.extra.settings.time_limit.get();
-                    content.player_settings.time_limit = Some(value);
+                    content.player_settings.assessment.time_limit = Some(value);
                 }
             }
         })
@@ -53,7 +54,7 @@ impl SidebarSettings {
         if self.base.extra.settings.has_time_limit.get() {
             self.base.history.push_modify(|raw| {
                 if let Some(content) = &mut raw.content {
-                    content.player_settings.time_limit = Some(time_limit);
+                    content.player_settings.assessment.time_limit = Some(time_limit);
                 }
             })
         }
@@ -64,10 +65,10 @@ impl SidebarSettings {
         self.base.history.push_modify(|raw| {
             if let Some(content) = &mut raw.content {
                 if !flag {
-                    content.player_settings.n_attempts = None;
+                    content.player_settings.assessment.attempts = None;
                 } else {
                     let value = self.base.extra.settings.attempts_limit.get();
-                    content.player_settings.n_attempts = Some(value);
+                    content.player_settings.assessment.attempts = Some(value);
                 }
             }
         })
@@ -78,9 +79,53 @@ impl SidebarSettings {
         if self.base.extra.settings.has_attempts_limit.get() {
             self.base.history.push_modify(|raw| {
                 if let Some(content) = &mut raw.content {
-                    content.player_settings.n_attempts = Some(n_attempts);
+                    content.player_settings.assessment.attempts = Some(n_attempts);
                 }
             })
         }
     }
+    pub fn toggle_show_answer(&self) {
+        let show_answer = !self.base.extra.settings.show_answer.get();
+
+        self.base.extra.settings.show_answer.set_neq(show_answer);
+
+        self.base.history.push_modify(|raw| {
+            if let Some(content) = &mut raw.content {
+                content.player_settings.assessment.show_answer = show_answer;
+            }
+        })
+    }
+    pub fn toggle_retry(&self) {
+        let retry = match self.base.extra.settings.retry.get() {
+            RetryPolicy::Never => RetryPolicy::Later,
+            RetryPolicy::Later => RetryPolicy::Never,
+        };
+
+        self.base.extra.settings.retry.set_neq(retry);
+
+        self.base.history.push_modify(|raw| {
+            if let Some(content) = &mut raw.content {
+                content.player_settings.assessment.retry = retry;
+            }
+        })
+    }
+
+    pub fn set_answer_mode(&self, answer_mode: AnswerMode) {
+        self.base.extra.settings.answer_mode.set_neq(answer_mode);
+
+        self.base.history.push_modify(|raw| {
+            if let Some(content) = &mut raw.content {
+                content.player_settings.answer_mode = answer_mode;
+            }
+        })
+    }
+    pub fn set_max_typos(&self, max_typos: u8) {
+        self.base.extra.settings.max_typos.set_neq(max_typos);
+
+        self.base.history.push_modify(|raw| {
+            if let Some(content) = &mut raw.content {
+                content.player_settings.max_typos = max_typos;
+            }
+        })
+    }
 }
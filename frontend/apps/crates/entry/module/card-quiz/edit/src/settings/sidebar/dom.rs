@@ -5,6 +5,7 @@ use super::state::*;
 use futures_signals::signal::{always, SignalExt};
 
 use components::module::_common::edit::settings::prelude::*;
+use shared::domain::jig::module::body::{_groups::assessment::RetryPolicy, card_quiz::AnswerMode};
 
 pub fn render(state: Rc<SidebarSettings>) -> Dom {
     render_settings(Rc::new(ModuleSettings {
@@ -110,6 +111,64 @@ pub fn render(state: Rc<SidebarSettings>) -> Dom {
                     )),
                 ],
             ),
+            (
+                LineKind::AfterAttempts,
+                vec![
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::ShowAnswer,
+                        clone!(state => move || {
+                            state.base.extra.settings.show_answer.signal()
+                        }),
+                        clone!(state => move || {
+                            state.toggle_show_answer();
+                        }),
+                    )),
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::RetryLater,
+                        clone!(state => move || {
+                            state.base.extra.settings.retry
+                                .signal()
+                                .map(|retry| retry == RetryPolicy::Later)
+                        }),
+                        clone!(state => move || {
+                            state.toggle_retry();
+                        }),
+                    )),
+                ],
+            ),
+            (
+                LineKind::Answer,
+                vec![
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::ChooseAnswer,
+                        clone!(state => move || {
+                            state.base.extra.settings.answer_mode
+                                .signal()
+                                .map(|mode| mode == AnswerMode::Choose)
+                        }),
+                        clone!(state => move || {
+                            state.set_answer_mode(AnswerMode::Choose);
+                        }),
+                    )),
+                    Some(SettingsButton::new_value_click(
+                        SettingsButtonKind::TypeAnswer,
+                        clone!(state => move || {
+                            state.base.extra.settings.answer_mode
+                                .signal()
+                                .map(|mode| mode == AnswerMode::Type)
+                        }),
+                        SettingsValue::new(
+                            state.settings().max_typos.get(),
+                            clone!(state => move |value| {
+                                state.set_max_typos(value);
+                            }),
+                        ),
+                        clone!(state => move || {
+                            state.set_answer_mode(AnswerMode::Type);
+                        }),
+                    )),
+                ],
+            ),
             // NOTE - not including score until player/jig story is resolved
         ],
     }))
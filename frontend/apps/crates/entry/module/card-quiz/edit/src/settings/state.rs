@@ -1,6 +1,9 @@
 use futures_signals::signal::Mutable;
 use rand::prelude::*;
-use shared::domain::jig::module::body::card_quiz::Content;
+use shared::domain::jig::module::body::{
+    _groups::assessment::RetryPolicy,
+    card_quiz::{AnswerMode, Content},
+};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -13,6 +16,10 @@ pub struct Settings {
     pub has_attempts_limit: Mutable<bool>,
     pub time_limit: Mutable<u32>,
     pub has_time_limit: Mutable<bool>,
+    pub show_answer: Mutable<bool>,
+    pub retry: Mutable<RetryPolicy>,
+    pub answer_mode: Mutable<AnswerMode>,
+    pub max_typos: Mutable<u8>,
 }
 
 impl Settings {
@@ -26,16 +33,22 @@ impl Settings {
             n_rounds: Mutable::new(settings.n_rounds),
             attempts_limit: Mutable::new(
                 settings
-                    .n_attempts
+                    .assessment
+                    .attempts
                     .unwrap_or(crate::config::DEFAULT_ATTEMPTS_LIMIT),
             ),
-            has_attempts_limit: Mutable::new(settings.n_attempts.is_some()),
+            has_attempts_limit: Mutable::new(settings.assessment.attempts.is_some()),
             time_limit: Mutable::new(
                 settings
+                    .assessment
                     .time_limit
                     .unwrap_or(crate::config::DEFAULT_TIME_LIMIT),
             ),
-            has_time_limit: Mutable::new(settings.time_limit.is_some()),
+            has_time_limit: Mutable::new(settings.assessment.time_limit.is_some()),
+            show_answer: Mutable::new(settings.assessment.show_answer),
+            retry: Mutable::new(settings.assessment.retry),
+            answer_mode: Mutable::new(settings.answer_mode),
+            max_typos: Mutable::new(settings.max_typos),
         }
     }
 }
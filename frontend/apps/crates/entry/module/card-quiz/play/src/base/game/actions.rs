@@ -13,6 +13,9 @@ use dominator::clone;
 use futures_signals::signal::Mutable;
 use gloo_timers::future::TimeoutFuture;
 use rand::prelude::*;
+use shared::domain::jig::module::body::{
+    _groups::assessment::AfterMistake, card_quiz::answer::is_correct_answer,
+};
 use std::convert::TryInto;
 use std::rc::Rc;
 use utils::prelude::*;
@@ -22,20 +25,20 @@ impl Game {
     pub fn next(state: Rc<Self>) {
         let rounds_played = state.rounds_played.load(Ordering::SeqCst);
 
-        let has_ended = {
-            if rounds_played >= state.base.settings.n_rounds.try_into().unwrap_ji() {
-                true
-            } else if state.remaining.borrow().len() == 0 {
+        let all_rounds_played =
+            rounds_played >= state.base.settings.n_rounds.try_into().unwrap_ji();
+
+        if !all_rounds_played {
+            if state.remaining.borrow().len() == 0 {
                 log::info!("deck finished, re-shuffling!");
                 Self::reset_deck(state.clone());
-                false
-            } else {
-                false
             }
-        };
 
-        if !has_ended {
-            state.current.set(Some(Current::new(state.clone())));
+            //remove our target from the deck, and add it to the used buffer
+            let pair = state.remaining.borrow_mut().pop().unwrap_ji();
+            state.used.borrow_mut().push(pair.clone());
+
+            state.current.set(Some(Current::new(state.clone(), pair)));
             state
                 .rounds_played
                 .store(rounds_played + 1, Ordering::SeqCst);
@@ -44,6 +47,9 @@ impl Game {
                 rounds_played + 1,
                 state.base.settings.n_rounds
             );
+        } else if let Some(pair) = Self::next_retry(&state) {
+            log::info!("asking a missed question again");
+            state.current.set(Some(Current::new(state.clone(), pair)));
         } else {
             log::info!("GAME OVER!");
             state.base.phase.set(Phase::Ending);
@@ -53,6 +59,11 @@ impl Game {
         }
     }
 
+    // Separate from `next` so that the queue isn't borrowed while the round starts
+    fn next_retry(state: &Self) -> Option<CardPairId> {
+        state.retry.borrow_mut().pop_front()
+    }
+
     pub fn reset_deck(state: Rc<Self>) {
         let mut remaining: Vec<CardPairId> = state
             .base
@@ -132,13 +143,89 @@ impl Game {
                     phase.set(CurrentPhase::Wrong(pair_id));
                     // We should be able to safely assume that current is Some(_), but
                     // double-check here anyway because assumptions are bad.
-                    if let Some(current) = &*state.current.lock_ref() {
+                    if let Some(current) = state.current.get_cloned() {
                         current.incorrect_choices.borrow_mut().push(pair_id);
+                        if Self::record_mistake(state.clone(), current) {
+                            phase.set(CurrentPhase::Waiting);
+                        }
                     }
-                    phase.set(CurrentPhase::Waiting);
                 }
 
             }));
         }
     }
+
+    pub fn evaluate_answer(state: Rc<Self>) {
+        let current = match state.current.get_cloned() {
+            Some(current) => current,
+            None => return,
+        };
+
+        if current.phase.get() != CurrentPhase::Waiting {
+            return;
+        }
+
+        let expected = match &current.answer {
+            Some(expected) => expected,
+            None => return,
+        };
+
+        let pair_id = current.target.pair_id;
+        let typed = current.typed.get_cloned();
+
+        if is_correct_answer(&typed, expected, state.base.settings.max_typos) {
+            spawn_local(clone!(state, current => async move {
+                AUDIO_MIXER.with(|mixer| {
+                    let audio_path: AudioPath<'_> = mixer.get_random_positive().into();
+
+                    mixer.play_oneshot(audio_path);
+                });
+
+                current.phase.set(CurrentPhase::Correct(pair_id));
+                TimeoutFuture::new(crate::config::SUCCESS_TIME).await;
+                Self::next(state);
+            }));
+        } else {
+            AUDIO_MIXER.with(|mixer| {
+                mixer.play_oneshot(AudioPath::new_cdn(FLIPPED_AUDIO_EFFECT.to_string()));
+            });
+            current.phase.set(CurrentPhase::Wrong(pair_id));
+            Self::record_mistake(state, current);
+        }
+    }
+
+    /// Counts a wrong answer, and moves on once the student is out of attempts.
+    ///
+    /// Returns whether the student can try again.
+    fn record_mistake(state: Rc<Self>, current: Rc<Current>) -> bool {
+        let mistakes = current.mistakes.get() + 1;
+        current.mistakes.set(mistakes);
+
+        match state.base.settings.assessment.after_mistake(mistakes) {
+            AfterMistake::TryAgain => true,
+            AfterMistake::OutOfAttempts {
+                show_answer,
+                retry_later,
+            } => {
+                if retry_later {
+                    state.retry.borrow_mut().push_back(current.pair.clone());
+                }
+
+                spawn_local(async move {
+                    if show_answer {
+                        if let Some(answer) = &current.answer {
+                            current.typed.set(answer.clone());
+                        }
+                        current
+                            .phase
+                            .set(CurrentPhase::Revealed(current.target.pair_id));
+                        TimeoutFuture::new(crate::config::SUCCESS_TIME).await;
+                    }
+                    Self::next(state);
+                });
+
+                false
+            }
+        }
+    }
 }
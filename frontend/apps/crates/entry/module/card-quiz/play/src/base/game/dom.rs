@@ -6,9 +6,12 @@ use components::module::_groups::cards::play::card::dom::{
 };
 use futures_signals::signal::SignalExt;
 use std::rc::Rc;
+use web_sys::HtmlInputElement;
 
 use utils::prelude::*;
 
+const STR_SUBMIT: &str = "Check";
+
 impl Game {
     pub fn render(state: Rc<Self>) -> Dom {
         html!("card-quiz-main", {
@@ -31,6 +34,11 @@ impl Game {
 
                             children.push(render_card(options));
 
+                            if current.answer.is_some() {
+                                children.extend(render_answer(state.clone(), current.clone()));
+                                return children;
+                            }
+
                             for other in others.iter() {
 
                                 let mut options = CardOptions::new(&other.card, theme_id, mode, side.negate(), Size::QuizOption);
@@ -46,7 +54,7 @@ impl Game {
                                             "effect",
                                             phase.signal().map(move |phase| {
                                                 match phase {
-                                                    CurrentPhase::Correct(id) if id == pair_id => Effect::Positive,
+                                                    CurrentPhase::Correct(id) | CurrentPhase::Revealed(id) if id == pair_id => Effect::Positive,
                                                     _ => Effect::None,
                                                 }
                                             })
@@ -56,7 +64,7 @@ impl Game {
                                                 false
                                             } else {
                                                 match phase {
-                                                    CurrentPhase::Correct(id) => id == pair_id,
+                                                    CurrentPhase::Correct(id) | CurrentPhase::Revealed(id) => id == pair_id,
                                                     _ => true,
                                                 }
                                             }
@@ -77,6 +85,46 @@ impl Game {
     }
 }
 
+fn render_answer(state: Rc<Game>, current: Rc<Current>) -> Vec<Dom> {
+    vec![
+        html!("input" => HtmlInputElement, {
+            .property("slot", "answer")
+            .property_signal("value", current.typed.signal_cloned())
+            .class_signal("wrong", current.phase.signal().map(|phase| {
+                matches!(phase, CurrentPhase::Wrong(_))
+            }))
+            .class_signal("correct", current.phase.signal().map(|phase| {
+                matches!(phase, CurrentPhase::Correct(_))
+            }))
+            .class_signal("revealed", current.phase.signal().map(|phase| {
+                matches!(phase, CurrentPhase::Revealed(_))
+            }))
+            .with_node!(elem => {
+                .event(clone!(current => move |_evt:events::Input| {
+                    current.typed.set(elem.value());
+                    if let CurrentPhase::Wrong(_) = current.phase.get() {
+                        current.phase.set(CurrentPhase::Waiting);
+                    }
+                }))
+            })
+            .event(clone!(state => move |evt:events::KeyDown| {
+                if evt.key() == "Enter" {
+                    Game::evaluate_answer(state.clone());
+                }
+            }))
+        }),
+        html!("button-rect", {
+            .property("slot", "answer")
+            .property("color", "blue")
+            .property("bold", true)
+            .text(STR_SUBMIT)
+            .event(clone!(state => move |_evt:events::Click| {
+                Game::evaluate_answer(state.clone());
+            }))
+        }),
+    ]
+}
+
 fn is_incorrect_choice(state: &Rc<Game>, pair_id: &usize) -> bool {
     state
         .current
@@ -2,9 +2,10 @@ use crate::base::state::Base;
 use components::module::_groups::cards::lookup::Side;
 use futures_signals::signal::Mutable;
 use rand::prelude::*;
-use shared::domain::jig::module::body::_groups::cards::Card;
-use shared::domain::jig::module::body::card_quiz::PlayerSettings;
-use std::cell::RefCell;
+use shared::domain::jig::module::body::_groups::cards::{Card, CardContent};
+use shared::domain::jig::module::body::card_quiz::{AnswerMode, PlayerSettings};
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
 use std::rc::Rc;
 use std::sync::atomic::AtomicUsize;
 use utils::prelude::*;
@@ -14,6 +15,8 @@ pub struct Game {
     pub rng: RefCell<ThreadRng>,
     pub remaining: RefCell<Vec<CardPairId>>,
     pub used: RefCell<Vec<CardPairId>>,
+    /// Missed questions, asked again after the others
+    pub retry: RefCell<VecDeque<CardPairId>>,
     pub current: Mutable<Option<Rc<Current>>>,
     pub rounds_played: AtomicUsize,
 }
@@ -28,11 +31,16 @@ pub struct CardId {
 }
 
 pub struct Current {
+    pub pair: CardPairId,
     pub target: CardId,
     pub others: Vec<CardId>,
     pub incorrect_choices: RefCell<Vec<usize>>,
     pub side: Side,
     pub phase: Mutable<CurrentPhase>,
+    /// Expected text when the student types the answer instead of choosing it
+    pub answer: Option<String>,
+    pub typed: Mutable<String>,
+    pub mistakes: Cell<u32>,
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -40,6 +48,8 @@ pub enum CurrentPhase {
     Waiting,
     Correct(usize),
     Wrong(usize),
+    /// Out of attempts, the correct answer is shown
+    Revealed(usize),
 }
 
 impl Game {
@@ -48,6 +58,7 @@ impl Game {
             base,
             remaining: RefCell::new(Vec::new()),
             used: RefCell::new(Vec::new()),
+            retry: RefCell::new(VecDeque::new()),
             rng: RefCell::new(thread_rng()),
             current: Mutable::new(None),
             rounds_played: AtomicUsize::new(0),
@@ -60,13 +71,16 @@ impl Game {
 }
 
 impl Current {
-    pub fn new(game: Rc<Game>) -> Rc<Self> {
+    pub fn new(game: Rc<Game>, pair: CardPairId) -> Rc<Self> {
         let PlayerSettings {
-            n_choices, swap, ..
+            n_choices,
+            swap,
+            answer_mode,
+            ..
         } = game.base.settings;
 
-        let remaining = &mut *game.remaining.borrow_mut();
-        let used = &mut *game.used.borrow_mut();
+        let remaining = &*game.remaining.borrow();
+        let used = &*game.used.borrow();
         let rng = &mut *game.rng.borrow_mut();
 
         let _deck_len = remaining.len() + used.len();
@@ -79,11 +93,12 @@ impl Current {
         // which can be useful for educational purposes
         // even though it technically isn't in play settings yet
 
-        //remove our target from potential choices
-        let target = remaining.pop().unwrap_ji();
-
-        //first get all the potential choices
-        let mut others: Vec<&CardPairId> = remaining.iter().chain(used.iter()).collect();
+        //first get all the potential choices, without our target
+        let mut others: Vec<&CardPairId> = remaining
+            .iter()
+            .chain(used.iter())
+            .filter(|other| other.0.pair_id != pair.0.pair_id)
+            .collect();
         //shuffle them up
         others.shuffle(rng);
 
@@ -91,29 +106,38 @@ impl Current {
         let mut others: Vec<CardPairId> = others.into_iter().take(amount - 1).cloned().collect();
 
         //add in our target
-        others.push(target.clone());
+        others.push(pair.clone());
 
         //re-shuffle to move it
         others.shuffle(rng);
 
-        //add the target to the used buffer
-        used.push(target.clone());
-
         //reduce it down to just the sides
 
         let target = {
             if !swap {
-                target.0
+                pair.0.clone()
             } else {
-                target.1
+                pair.1.clone()
             }
         };
 
-        let others = others
+        let others: Vec<CardId> = others
             .into_iter()
             .map(|pair| if !swap { pair.1 } else { pair.0 })
             .collect();
 
+        // Typing only makes sense for text cards, image pairs fall back to choosing
+        let answer = match answer_mode {
+            AnswerMode::Choose => None,
+            AnswerMode::Type => others
+                .iter()
+                .find(|other| other.pair_id == target.pair_id)
+                .and_then(|other| match &other.card.card_content {
+                    CardContent::Text(text) => Some(text.clone()),
+                    CardContent::Image(_) => None,
+                }),
+        };
+
         //needed for styling
         let side = {
             if !swap {
@@ -123,11 +147,15 @@ impl Current {
             }
         };
         Rc::new(Self {
+            pair,
             target,
             others,
             incorrect_choices: RefCell::new(Vec::new()),
             side,
             phase: Mutable::new(CurrentPhase::Waiting),
+            answer,
+            typed: Mutable::new(String::new()),
+            mistakes: Cell::new(0),
         })
     }
 }
@@ -1,9 +1,12 @@
 use shared::domain::jig::{
     module::{
         body::{
-            Background, Instructions,
-            _groups::cards::{CardPair, Mode, Step},
+            _groups::{
+                assessment::PlaySettings,
+                cards::{CardPair, Mode, Step},
+            },
             card_quiz::{ModuleData as RawData, PlayerSettings},
+            Background, Instructions,
         },
         ModuleId,
     },
@@ -74,8 +77,13 @@ impl BaseExt for Base {
         Some(self.instructions.clone())
     }
 
-    fn get_timer_minutes(&self) -> Option<u32> {
-        self.settings.time_limit
+    fn get_play_settings(&self) -> Option<&PlaySettings> {
+        Some(&self.settings.assessment)
+    }
+
+    fn time_up(state: Rc<Self>) {
+        state.phase.set(Phase::Ending);
+        state.set_play_phase(ModulePlayPhase::Ending(Some(ModuleEnding::Negative)));
     }
 
     fn play_phase(&self) -> Mutable<ModulePlayPhase> {
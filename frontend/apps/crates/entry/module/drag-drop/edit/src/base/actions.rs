@@ -4,12 +4,12 @@ use super::{
 };
 use components::module::_common::edit::prelude::*;
 use shared::domain::jig::module::body::{
-    Audio, Transform,
     _groups::design::Trace as RawTrace,
     drag_drop::{
         Interactive as RawInteractive, ItemKind as RawItemKind, Mode, ModuleData as RawData, Step,
         TargetArea,
     },
+    Audio, Transform,
 };
 use std::rc::Rc;
 
@@ -49,17 +49,39 @@ impl Base {
     pub fn on_trace_added(&self, raw_trace: RawTrace) {
         self.history.push_modify(move |raw| {
             if let Some(content) = &mut raw.content {
-                content.target_areas.push(TargetArea { trace: raw_trace })
+                content.target_areas.push(TargetArea {
+                    trace: raw_trace,
+                    label: None,
+                })
             }
         });
+
+        self.bucket_labels.lock_mut().push_cloned(None);
+        self.update_items_missing_bucket();
     }
 
     pub fn on_trace_deleted(&self, index: usize) {
         self.history.push_modify(move |raw| {
             if let Some(content) = &mut raw.content {
-                content.target_areas.remove(index);
+                content.remove_target_area(index);
             }
         });
+
+        self.bucket_labels.lock_mut().remove(index);
+
+        for item in self.stickers.list.lock_ref().iter() {
+            if let ItemKind::Interactive(data) = &*item.kind.lock_ref() {
+                data.target_areas.replace_with(|target_areas| {
+                    target_areas
+                        .iter()
+                        .filter(|area| **area != index)
+                        .map(|area| if *area > index { area - 1 } else { *area })
+                        .collect()
+                });
+            }
+        }
+
+        self.update_items_missing_bucket();
     }
 
     pub fn on_trace_changed(&self, index: usize, raw_trace: RawTrace) {
@@ -78,6 +100,7 @@ impl Base {
             let data = RawInteractive {
                 audio: None,
                 target_transform: None,
+                target_areas: Vec::new(),
             };
 
             kind.set(ItemKind::Interactive(Interactive::new(data.clone())));
@@ -87,6 +110,8 @@ impl Base {
                     content.items[index].kind = RawItemKind::Interactive(data);
                 }
             });
+
+            self.update_items_missing_bucket();
         }
 
         self.drag_item_selected_index.set(Some(index));
@@ -103,6 +128,8 @@ impl Base {
                     content.items[index].kind = RawItemKind::Static;
                 }
             });
+
+            self.update_items_missing_bucket();
         }
         self.drag_item_selected_index.set(None);
     }
@@ -147,4 +174,64 @@ impl Base {
             }
         });
     }
+
+    pub fn set_bucket_label(&self, index: usize, label: String) {
+        let label = Some(label).filter(|label| !label.trim().is_empty());
+
+        self.bucket_labels
+            .lock_mut()
+            .set_cloned(index, label.clone());
+
+        self.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                content.target_areas[index].label = label;
+            }
+        });
+    }
+
+    pub fn toggle_drag_item_bucket(&self, index: usize, bucket: usize) {
+        let list = &*self.stickers.list.lock_ref();
+        let item = &list[index];
+        let data = item.get_interactive_unchecked();
+
+        let target_areas = {
+            let mut target_areas = data.target_areas.lock_mut();
+            match target_areas.iter().position(|area| *area == bucket) {
+                Some(position) => {
+                    target_areas.remove(position);
+                }
+                None => {
+                    target_areas.push(bucket);
+                    target_areas.sort_unstable();
+                }
+            }
+            target_areas.clone()
+        };
+
+        self.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                match &mut content.items[index].kind {
+                    RawItemKind::Interactive(data) => {
+                        data.target_areas = target_areas;
+                    }
+                    RawItemKind::Static => {
+                        panic!("saving buckets on static item!?");
+                    }
+                }
+            }
+        });
+
+        self.update_items_missing_bucket();
+    }
+
+    pub fn update_items_missing_bucket(&self) {
+        let items_missing_bucket = self
+            .history
+            .get_current()
+            .content
+            .map(|content| content.items_missing_bucket())
+            .unwrap_or_default();
+
+        self.items_missing_bucket.set_neq(items_missing_bucket);
+    }
 }
@@ -1,4 +1,5 @@
 use super::state::*;
+use crate::base::state::{Base, ItemKind};
 use crate::base::{
     main::{drag::MainDrag, select::MainSelect},
     sidebar::state::{StickerPhase, TracePhase},
@@ -8,8 +9,12 @@ use components::{
     tabs::{MenuTab, MenuTabKind},
 };
 use dominator::{clone, html, Dom};
-use futures_signals::signal::{Signal, SignalExt};
+use futures_signals::{
+    signal::{Signal, SignalExt},
+    signal_vec::SignalVecExt,
+};
 use std::rc::Rc;
+use web_sys::HtmlInputElement;
 
 pub fn render_step_3(state: Rc<Step3>) -> Dom {
     html!("menu-tabs", {
@@ -17,6 +22,7 @@ pub fn render_step_3(state: Rc<Step3>) -> Dom {
             let (sticker_phase, trace_phase) = match tab {
                 Tab::Select | Tab::Audio(_) => (StickerPhase::Select(MainSelect::new(state.sidebar.base.clone())), None),
                 Tab::Trace => (StickerPhase::Static, Some(TracePhase::Edit)),
+                Tab::Place if state.sidebar.base.mode.uses_buckets() => (StickerPhase::Select(MainSelect::new(state.sidebar.base.clone())), Some(TracePhase::Show)),
                 Tab::Place => (StickerPhase::Drag(MainDrag::new(state.sidebar.base.clone())), Some(TracePhase::Show)),
             };
 
@@ -49,6 +55,9 @@ pub fn render_step_3(state: Rc<Step3>) -> Dom {
                                 .property("imagePath", "module/_common/edit/sidebar/illustration-trace-area.svg")
                             }))
                         },
+                        Tab::Place if state.sidebar.base.mode.uses_buckets() => {
+                            Some(render_buckets(state.clone()))
+                        },
                         Tab::Place => {
                             Some(html!("module-sidebar-drag-prompt"))
                         },
@@ -93,3 +102,58 @@ fn render_audio(
         })))
     })
 }
+
+fn render_buckets(state: Rc<Step3>) -> Dom {
+    let base = state.sidebar.base.clone();
+
+    html!("div", {
+        .style("display", "flex")
+        .style("flex-direction", "column")
+        .style("gap", "16px")
+        .child(html!("div", {
+            .text(crate::strings::STR_SIDEBAR_BUCKETS)
+        }))
+        .children_signal_vec(base.bucket_labels.signal_vec_cloned().to_signal_cloned().map(clone!(base => move |labels| {
+            labels
+                .into_iter()
+                .enumerate()
+                .map(|(index, label)| render_bucket(base.clone(), index, label))
+                .collect()
+        })).to_signal_vec())
+        .child_signal(base.items_missing_bucket.signal_ref(|items| {
+            (!items.is_empty()).then(|| html!("div", {
+                .text(crate::strings::STR_BUCKETS_MISSING)
+            }))
+        }))
+    })
+}
+
+fn render_bucket(base: Rc<Base>, index: usize, label: Option<String>) -> Dom {
+    html!("empty-fragment", {
+        .child(html!("input-wrapper", {
+            .property("label", format!("{} {}", crate::strings::STR_BUCKET_LABEL, index + 1))
+            .child(html!("input" => HtmlInputElement, {
+                .property("value", label.unwrap_or_default())
+                .with_node!(elem => {
+                    .event(clone!(base => move |_:events::Change| {
+                        base.set_bucket_label(index, elem.value());
+                    }))
+                })
+            }))
+        }))
+        .child_signal(base.selected_item_kind_signal().map(clone!(base => move |index_item_kind| {
+            index_item_kind.and_then(|(item_index, item_kind)| match item_kind {
+                ItemKind::Static => None,
+                ItemKind::Interactive(data) => Some(html!("input-checkbox", {
+                    .property("label", crate::strings::STR_BUCKET_BELONGS)
+                    .property_signal("checked", data.target_areas.signal_ref(move |target_areas| {
+                        target_areas.contains(&index)
+                    }))
+                    .event(clone!(base => move |_evt:events::CustomToggle| {
+                        base.toggle_drag_item_bucket(item_index, index);
+                    }))
+                })),
+            })
+        })))
+    })
+}
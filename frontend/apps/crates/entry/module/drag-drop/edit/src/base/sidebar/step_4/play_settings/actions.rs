@@ -1,5 +1,8 @@
 use super::state::PlaySettingsState;
-use shared::domain::jig::module::body::drag_drop::{Hint, Next};
+use shared::domain::jig::module::body::{
+    _groups::assessment::RetryPolicy,
+    drag_drop::{Hint, Next},
+};
 
 impl PlaySettingsState {
     pub fn set_hint(&self, hint: Hint) {
@@ -27,10 +30,10 @@ impl PlaySettingsState {
         self.base.history.push_modify(|raw| {
             if let Some(content) = &mut raw.content {
                 if !flag {
-                    content.play_settings.time_limit = None;
+                    content.play_settings.assessment.time_limit = None;
                 } else {
                     let value = self.base.play_settings.time_limit.get();
-                    content.play_settings.time_limit = Some(value);
+                    content.play_settings.assessment.time_limit = Some(value);
                 }
             }
         })
@@ -41,9 +44,59 @@ impl PlaySettingsState {
         if self.base.play_settings.has_time_limit.get() {
             self.base.history.push_modify(|raw| {
                 if let Some(content) = &mut raw.content {
-                    content.play_settings.time_limit = Some(time_limit);
+                    content.play_settings.assessment.time_limit = Some(time_limit);
                 }
             })
         }
     }
+    pub fn set_has_attempts_limit(&self, flag: bool) {
+        self.base.play_settings.has_attempts_limit.set_neq(flag);
+
+        self.base.history.push_modify(|raw| {
+            if let Some(content) = &mut raw.content {
+                if !flag {
+                    content.play_settings.assessment.attempts = None;
+                } else {
+                    let value = self.base.play_settings.attempts_limit.get();
+                    content.play_settings.assessment.attempts = Some(value);
+                }
+            }
+        })
+    }
+    pub fn set_attempts_limit(&self, n_attempts: u8) {
+        self.base.play_settings.attempts_limit.set_neq(n_attempts);
+
+        if self.base.play_settings.has_attempts_limit.get() {
+            self.base.history.push_modify(|raw| {
+                if let Some(content) = &mut raw.content {
+                    content.play_settings.assessment.attempts = Some(n_attempts);
+                }
+            })
+        }
+    }
+    pub fn toggle_show_answer(&self) {
+        let show_answer = !self.base.play_settings.show_answer.get();
+
+        self.base.play_settings.show_answer.set_neq(show_answer);
+
+        self.base.history.push_modify(|raw| {
+            if let Some(content) = &mut raw.content {
+                content.play_settings.assessment.show_answer = show_answer;
+            }
+        })
+    }
+    pub fn toggle_retry(&self) {
+        let retry = match self.base.play_settings.retry.get() {
+            RetryPolicy::Never => RetryPolicy::Later,
+            RetryPolicy::Later => RetryPolicy::Never,
+        };
+
+        self.base.play_settings.retry.set_neq(retry);
+
+        self.base.history.push_modify(|raw| {
+            if let Some(content) = &mut raw.content {
+                content.play_settings.assessment.retry = retry;
+            }
+        })
+    }
 }
@@ -3,7 +3,10 @@ use futures_signals::signal::SignalExt;
 use std::rc::Rc;
 
 use super::state::PlaySettingsState;
-use shared::domain::jig::module::body::drag_drop::{Hint, Next};
+use shared::domain::jig::module::body::{
+    _groups::assessment::RetryPolicy,
+    drag_drop::{Hint, Next},
+};
 
 use components::module::_common::edit::settings::prelude::*;
 
@@ -69,6 +72,63 @@ pub fn render(state: Rc<PlaySettingsState>) -> Dom {
                     )),
                 ],
             ),
+            (
+                LineKind::Attempts,
+                vec![
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::NoLimit,
+                        clone!(state => move || {
+                            state.base.play_settings.has_attempts_limit
+                                .signal()
+                                .map(|flag| !flag)
+                        }),
+                        clone!(state => move || {
+                            state.set_has_attempts_limit(false);
+                        }),
+                    )),
+                    Some(SettingsButton::new_value_click(
+                        SettingsButtonKind::Attempts,
+                        clone!(state => move || {
+                            state.base.play_settings.has_attempts_limit
+                                .signal()
+                        }),
+                        SettingsValue::new(
+                            state.base.play_settings.attempts_limit.get(),
+                            clone!(state => move |value| {
+                                state.set_attempts_limit(value);
+                            }),
+                        ),
+                        clone!(state => move || {
+                            state.set_has_attempts_limit(true);
+                        }),
+                    )),
+                ],
+            ),
+            (
+                LineKind::AfterAttempts,
+                vec![
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::ShowAnswer,
+                        clone!(state => move || {
+                            state.base.play_settings.show_answer.signal()
+                        }),
+                        clone!(state => move || {
+                            state.toggle_show_answer();
+                        }),
+                    )),
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::RetryLater,
+                        clone!(state => move || {
+                            state.base.play_settings.retry
+                                .signal()
+                                .map(|retry| retry == RetryPolicy::Later)
+                        }),
+                        clone!(state => move || {
+                            state.toggle_retry();
+                        }),
+                    )),
+                ],
+            ),
             (
                 LineKind::Hint,
                 vec![
@@ -17,14 +17,17 @@ use components::{
 use dominator::clone;
 use futures_signals::{
     map_ref,
-    signal::{self, Mutable, ReadOnlyMutable, Signal, SignalExt},
-    signal_vec::SignalVecExt,
+    signal::{Mutable, ReadOnlyMutable, Signal, SignalExt},
+    signal_vec::{MutableVec, SignalVecExt},
 };
 use shared::domain::jig::{
     module::{
         body::{
             Audio, Instructions, Transform,
-            _groups::design::{Trace as RawTrace, TraceKind},
+            _groups::{
+                assessment::RetryPolicy,
+                design::{Trace as RawTrace, TraceKind},
+            },
             drag_drop::{
                 Hint, Interactive as RawInteractive, Item as RawItem, ItemKind as RawItemKind,
                 Mode, ModuleData as RawData, Next, PlaySettings as RawPlaySettings, Step,
@@ -54,13 +57,21 @@ pub struct Base {
 
     pub drag_item_selected_index: Mutable<Option<usize>>,
     pub feedback: Mutable<Instructions>,
+
+    pub mode: Mode,
+    pub bucket_labels: MutableVec<Option<String>>,
+    pub items_missing_bucket: Mutable<Vec<usize>>,
 }
 
 pub struct PlaySettings {
     pub hint: Mutable<Hint>,
     pub next: Mutable<Next>,
+    pub attempts_limit: Mutable<u8>,
+    pub has_attempts_limit: Mutable<bool>,
     pub time_limit: Mutable<u32>,
     pub has_time_limit: Mutable<bool>,
+    pub show_answer: Mutable<bool>,
+    pub retry: Mutable<RetryPolicy>,
 }
 
 impl PlaySettings {
@@ -68,12 +79,22 @@ impl PlaySettings {
         Self {
             hint: Mutable::new(settings.hint),
             next: Mutable::new(settings.next),
+            attempts_limit: Mutable::new(
+                settings
+                    .assessment
+                    .attempts
+                    .unwrap_or(crate::config::DEFAULT_ATTEMPTS_LIMIT),
+            ),
+            has_attempts_limit: Mutable::new(settings.assessment.attempts.is_some()),
             time_limit: Mutable::new(
                 settings
+                    .assessment
                     .time_limit
                     .unwrap_or(crate::config::DEFAULT_TIME_LIMIT),
             ),
-            has_time_limit: Mutable::new(settings.time_limit.is_some()),
+            has_time_limit: Mutable::new(settings.assessment.time_limit.is_some()),
+            show_answer: Mutable::new(settings.assessment.show_answer),
+            retry: Mutable::new(settings.assessment.retry),
         }
     }
 }
@@ -103,6 +124,7 @@ impl Item {
                 ItemKind::Interactive(data) => RawItemKind::Interactive(RawInteractive {
                     audio: data.audio.get_cloned(),
                     target_transform: data.target_transform.get_cloned(),
+                    target_areas: data.target_areas.get_cloned(),
                 }),
             },
         }
@@ -149,6 +171,7 @@ pub enum ItemKind {
 pub struct Interactive {
     pub audio: Mutable<Option<Audio>>,
     pub target_transform: Mutable<Option<Transform>>,
+    pub target_areas: Mutable<Vec<usize>>,
 }
 
 impl Interactive {
@@ -156,6 +179,7 @@ impl Interactive {
         Self {
             audio: Mutable::new(raw.audio),
             target_transform: Mutable::new(raw.target_transform),
+            target_areas: Mutable::new(raw.target_areas),
         }
     }
 }
@@ -174,6 +198,13 @@ impl Base {
 
         let content = raw.content.unwrap_ji();
 
+        let items_missing_bucket = content.items_missing_bucket();
+        let bucket_labels: Vec<Option<String>> = content
+            .target_areas
+            .iter()
+            .map(|target_area| target_area.label.clone())
+            .collect();
+
         let _self_ref: Rc<RefCell<Option<Rc<Self>>>> = Rc::new(RefCell::new(None));
 
         let instructions = Mutable::new(content.instructions);
@@ -220,7 +251,7 @@ impl Base {
 
         let stickers = Stickers::new(
             text_editor.clone(),
-            StickersCallbacks::new(Some(clone!(history => move |items:&[Item]| {
+            StickersCallbacks::new(Some(clone!(history, _self_ref => move |items:&[Item]| {
                 history.push_modify(|raw| {
                     if let Some(content) = &mut raw.content {
                         content.items = items
@@ -231,6 +262,10 @@ impl Base {
                             .collect();
                     }
                 });
+
+                if let Some(_self) = _self_ref.borrow().as_ref() {
+                    _self.update_items_missing_bucket();
+                }
             }))),
         );
 
@@ -286,6 +321,9 @@ impl Base {
             traces,
             play_settings: Rc::new(PlaySettings::new(content.play_settings)),
             drag_item_selected_index: Mutable::new(None),
+            mode: content.mode,
+            bucket_labels: MutableVec::new_with_values(bucket_labels),
+            items_missing_bucket: Mutable::new(items_missing_bucket),
         });
 
         *_self_ref.borrow_mut() = Some(_self.clone());
@@ -328,7 +366,16 @@ impl BaseExt<Step> for Base {
     }
 
     fn next_step_allowed_signal(&self) -> Self::NextStepAllowedSignal {
-        signal::always(true)
+        let uses_buckets = self.mode.uses_buckets();
+
+        map_ref! {
+            let step = self.step.signal(),
+            let items_missing_bucket = self.items_missing_bucket.signal_ref(|items| !items.is_empty())
+                => {
+                    // every draggable item needs somewhere to be sorted before moving on to settings
+                    !(uses_buckets && *step == Step::Three && *items_missing_bucket)
+                }
+        }
     }
 
     fn get_jig_id(&self) -> JigId {
@@ -90,7 +90,7 @@ impl DebugSettings {
                                     }
                                 };
 
-                                TargetArea { trace }
+                                TargetArea { trace, label: None }
                             })
                             .collect(),
                         items: init_data
@@ -175,6 +175,7 @@ pub fn init(jig_id: JigId, _module_id: ModuleId) {
                         ItemKind::Interactive(Interactive {
                             audio: None,
                             target_transform: None,
+                            target_areas: Vec::new(),
                         }),
                         (-0.3, -0.3),
                     ),
@@ -183,6 +184,7 @@ pub fn init(jig_id: JigId, _module_id: ModuleId) {
                         ItemKind::Interactive(Interactive {
                             audio: None,
                             target_transform: None,
+                            target_areas: Vec::new(),
                         }),
                         (-0.3, 0.1),
                     ),
@@ -1,3 +1,12 @@
 pub const STR_SIDEBAR_SELECT: &str = "Select the items that can be dragged";
 
 pub const STR_SIDEBAR_TRACE: &str = "Trace the areas where items can be dropped.";
+
+pub const STR_SIDEBAR_BUCKETS: &str =
+    "Select an item, then check every area it can be sorted into.";
+
+pub const STR_BUCKET_LABEL: &str = "Area";
+
+pub const STR_BUCKET_BELONGS: &str = "Selected item belongs here";
+
+pub const STR_BUCKETS_MISSING: &str = "Every item needs at least one area before continuing.";
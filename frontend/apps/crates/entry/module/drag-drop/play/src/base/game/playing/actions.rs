@@ -13,7 +13,7 @@ use components::instructions::player::InstructionsPlayer;
 use wasm_bindgen_futures::spawn_local;
 
 use components::module::_common::play::prelude::*;
-use shared::domain::jig::module::body::drag_drop::Next;
+use shared::domain::jig::module::body::{_groups::assessment::AfterMistake, drag_drop::Next};
 
 impl PlayState {
     pub async fn set_targets(&self) {
@@ -31,6 +31,16 @@ impl PlayState {
             .collect();
 
         for item in items {
+            if self.game.base.mode.uses_buckets() {
+                // Sorted items can go in any of their buckets, the first one is used for hints
+                *item.target_index.borrow_mut() = item
+                    .buckets
+                    .iter()
+                    .copied()
+                    .find(|index| *index < traces.len());
+                continue;
+            }
+
             let hit_source = item
                 .get_hit_source(Some(SourceTransformOverride::Target))
                 .unwrap_ji();
@@ -41,30 +51,75 @@ impl PlayState {
         }
     }
 
-    pub fn evaluate_all_completed(state: Rc<Self>) -> bool {
-        let all_completed = state
-            .items
-            .iter()
-            .filter_map(|item| {
-                match item {
-                    PlayItem::Interactive(item) => {
-                        // Only return items which are interactive _and_ have a target trace so
-                        // that we can end the game correctly when there are items which aren't
-                        // meant to be placed anywhere.
-                        item.target_index.borrow().as_ref().map(|_| item.clone())
-                    }
-                    _ => None,
+    fn placeable_items(&self) -> impl Iterator<Item = &Rc<InteractiveItem>> {
+        self.items.iter().filter_map(|item| {
+            match item {
+                PlayItem::Interactive(item) => {
+                    // Only return items which are interactive _and_ have a target trace so
+                    // that we can end the game correctly when there are items which aren't
+                    // meant to be placed anywhere.
+                    item.target_index.borrow().as_ref().map(|_| item)
                 }
-            })
-            .all(|item| item.completed.get());
+                _ => None,
+            }
+        })
+    }
+
+    pub fn evaluate_all_completed(state: Rc<Self>) -> bool {
+        let all_completed = state.placeable_items().all(|item| item.completed.get());
 
         all_completed
     }
 
+    /// Items which ran out of attempts get another go once everything else is placed
+    fn release_set_aside(&self) {
+        if self
+            .placeable_items()
+            .all(|item| item.completed.get() || item.set_aside.get())
+        {
+            for item in self.placeable_items() {
+                item.set_aside.set_neq(false);
+            }
+        }
+    }
+
+    fn record_mistake(state: Rc<Self>, item: &InteractiveItem) {
+        item.mistakes.set(item.mistakes.get() + 1);
+
+        if let AfterMistake::OutOfAttempts {
+            show_answer,
+            retry_later,
+        } = state
+            .game
+            .base
+            .settings
+            .assessment
+            .after_mistake(item.mistakes.get())
+        {
+            if retry_later {
+                // Placing it for the student would give the retry away
+                item.mistakes.set(0);
+                item.set_aside.set_neq(true);
+            } else {
+                if show_answer {
+                    item.curr_transform.set(item.target_transform.clone());
+                }
+                item.completed.set_neq(true);
+            }
+
+            state.release_set_aside();
+            if Self::evaluate_all_completed(state.clone()) {
+                Self::finish(state);
+            }
+        }
+    }
+
     pub fn evaluate(state: Rc<Self>, item: Rc<InteractiveItem>) {
         spawn_local(async move {
             let mut move_back = true;
             let mut is_correct = false;
+            // dropped on an area at all, as opposed to somewhere on the background
+            let mut is_answer = false;
             if let Some(target_index) = item.target_index.borrow().as_ref() {
                 let target_index = *target_index;
 
@@ -85,7 +140,11 @@ impl PlayState {
                         {
                             debug_render_hit_trace(index, &traces);
                         }
-                        if index == target_index {
+                        is_answer = true;
+                        if state.game.base.mode.uses_buckets() {
+                            is_correct = item.buckets.contains(&index);
+                            state.bucket_scores.borrow_mut()[index].record(is_correct);
+                        } else if index == target_index {
                             is_correct = true;
                         }
                     }
@@ -100,35 +159,49 @@ impl PlayState {
 
             if is_correct {
                 item.completed.set_neq(true);
+                state.release_set_aside();
                 if !Self::evaluate_all_completed(state.clone()) {
                     item.play_audio_effect(AudioEffect::Correct);
                 } else {
-                    // Play JIG positive feedback sound
-                    AUDIO_MIXER.with(|mixer| {
-                        let positive_audio: AudioPath<'_> = mixer.get_random_positive().into();
-                        mixer.play_oneshot_on_ended(positive_audio, move || {
-                            // Once the positive feedback effect has played, we can show/play the
-                            // feedback for the activity. If we played this at the same time, it
-                            // we could have two audio clips playing simultaneously which would be
-                            // noisy and distracting from the intent of the feedbacks.
-                            state.feedback_player.set(Some(
-                                InstructionsPlayer::new(
-                                    state.game.base.feedback.clone(),
-                                    Some(clone!(state => move || {
-                                        if matches!(state.game.base.settings.next, Next::PlaceAll) {
-                                            state.game.base.set_play_phase(ModulePlayPhase::Ending(Some(ModuleEnding::Next)));
-                                        }
-                                    }))
-                                )
-                            ));
-                        });
-                    });
+                    Self::finish(state);
                 }
             } else {
                 item.play_audio_effect(AudioEffect::Wrong);
+                if is_answer {
+                    Self::record_mistake(state, &item);
+                }
             }
         });
     }
+
+    fn finish(state: Rc<Self>) {
+        if state.game.base.mode.uses_buckets() {
+            state
+                .bucket_report
+                .set(Some(state.bucket_scores.borrow().clone()));
+        }
+
+        // Play JIG positive feedback sound
+        AUDIO_MIXER.with(|mixer| {
+            let positive_audio: AudioPath<'_> = mixer.get_random_positive().into();
+            mixer.play_oneshot_on_ended(positive_audio, move || {
+                // Once the positive feedback effect has played, we can show/play the
+                // feedback for the activity. If we played this at the same time, it
+                // we could have two audio clips playing simultaneously which would be
+                // noisy and distracting from the intent of the feedbacks.
+                state.feedback_player.set(Some(
+                    InstructionsPlayer::new(
+                        state.game.base.feedback.clone(),
+                        Some(clone!(state => move || {
+                            if matches!(state.game.base.settings.next, Next::PlaceAll) {
+                                state.game.base.set_play_phase(ModulePlayPhase::Ending(Some(ModuleEnding::Next)));
+                            }
+                        }))
+                    )
+                ));
+            });
+        });
+    }
 }
 
 pub enum AudioEffect {
@@ -164,7 +237,7 @@ impl InteractiveItem {
     }
 
     pub fn start_drag(&self, x: i32, y: i32) {
-        if !self.completed.get() {
+        if !self.completed.get() && !self.set_aside.get() {
             self.try_play_user_audio();
 
             self.drag
@@ -8,11 +8,14 @@ use components::{
 };
 use dominator::{apply_methods, clone, html, Dom};
 use futures_signals::signal::{Mutable, SignalExt};
+use shared::domain::jig::module::body::drag_drop::BucketScore;
 use std::rc::Rc;
 use utils::prelude::*;
 
 use super::state::*;
 
+const STR_BUCKET_LABEL: &str = "Area";
+
 pub fn render(state: Rc<PlayState>) -> Dom {
     let theme_id = state.game.base.theme_id;
 
@@ -33,6 +36,9 @@ pub fn render(state: Rc<PlayState>) -> Dom {
         .child_signal(state.feedback_player.signal_cloned().map(|feedback| {
             feedback.map(InstructionsPlayer::render)
         }))
+        .child_signal(state.bucket_report.signal_cloned().map(clone!(state => move |scores| {
+            scores.map(|scores| render_bucket_report(&state, &scores))
+        })))
         .child(TracesShow::render(TracesShow::new(
                 state.game.base.target_areas
                     .iter()
@@ -65,6 +71,9 @@ pub fn render(state: Rc<PlayState>) -> Dom {
                                                 .style_signal("display", targets_ready.signal().map(|ready| {
                                                     if ready { "block" } else { "none" }
                                                 }))
+                                                .style_signal("opacity", item.set_aside.signal().map(|set_aside| {
+                                                    if set_aside { "0.5" } else { "1" }
+                                                }))
                                         })
                                         .event(clone!(item => move |evt:events::PointerDown| {
                                             item.start_drag(evt.x() as i32, evt.y() as i32);
@@ -96,3 +105,26 @@ pub fn render(state: Rc<PlayState>) -> Dom {
         })
     })
 }
+
+fn render_bucket_report(state: &PlayState, scores: &[BucketScore]) -> Dom {
+    html!("drag-drop-bucket-report", {
+        .children(state.game.base.target_areas
+            .iter()
+            .zip(scores.iter())
+            .enumerate()
+            .map(|(index, (target_area, score))| {
+                let label = target_area
+                    .label
+                    .clone()
+                    .unwrap_or_else(|| format!("{} {}", STR_BUCKET_LABEL, index + 1));
+
+                html!("drag-drop-bucket-score", {
+                    .property("label", label)
+                    .property("correct", score.correct)
+                    .property("incorrect", score.incorrect)
+                })
+            })
+            .collect::<Vec<Dom>>()
+        )
+    })
+}
@@ -5,11 +5,14 @@ use futures_signals::{
     signal_vec::{self, SignalVecExt},
 };
 use shared::domain::jig::module::body::{
-    Audio, Transform,
     _groups::design::Sticker,
-    drag_drop::{Interactive, ItemKind},
+    drag_drop::{BucketScore, Interactive, ItemKind},
+    Audio, Transform,
+};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
 };
-use std::{cell::RefCell, rc::Rc};
 use utils::drag::Drag;
 
 use components::collision::stickers_traces::pixels::{StickerBoundsKind, StickerHitSource};
@@ -19,6 +22,8 @@ pub struct PlayState {
     pub game: Rc<Game>,
     pub items: Vec<PlayItem>,
     pub feedback_player: Mutable<Option<Rc<InstructionsPlayer>>>,
+    pub bucket_scores: RefCell<Vec<BucketScore>>,
+    pub bucket_report: Mutable<Option<Vec<BucketScore>>>,
 }
 
 impl PlayState {
@@ -39,10 +44,14 @@ impl PlayState {
             })
             .collect();
 
+        let bucket_scores = vec![BucketScore::default(); game.base.target_areas.len()];
+
         Rc::new(Self {
             game,
             items,
             feedback_player: Mutable::new(None),
+            bucket_scores: RefCell::new(bucket_scores),
+            bucket_report: Mutable::new(None),
         })
     }
 
@@ -70,12 +79,17 @@ pub enum PlayItem {
 pub struct InteractiveItem {
     pub sticker: Sticker,
     pub completed: Mutable<bool>,
+    /// wrong drops since the item was last (re)started
+    pub mistakes: Cell<u32>,
+    /// out of attempts, waiting for the other items before it's retried
+    pub set_aside: Mutable<bool>,
     pub audio: Option<Audio>,
     pub target_transform: Transform,
     pub curr_transform: Mutable<Transform>,
     pub drag: Mutable<Option<Rc<Drag>>>,
     pub size: Mutable<Option<(f64, f64)>>,
     pub target_index: RefCell<Option<usize>>,
+    pub buckets: Vec<usize>,
 }
 
 pub enum SourceTransformOverride {
@@ -89,6 +103,8 @@ impl InteractiveItem {
         Rc::new(Self {
             sticker,
             completed: Mutable::new(false),
+            mistakes: Cell::new(0),
+            set_aside: Mutable::new(false),
             audio: data.audio,
             target_transform: data
                 .target_transform
@@ -97,6 +113,7 @@ impl InteractiveItem {
             drag: Mutable::new(None),
             size: Mutable::new(None),
             target_index: RefCell::new(None),
+            buckets: data.target_areas,
         })
     }
 
@@ -2,9 +2,9 @@ use components::module::_common::play::prelude::*;
 use shared::domain::jig::{
     module::{
         body::{
-            Instructions,
-            _groups::design::Backgrounds,
+            _groups::{assessment, design::Backgrounds},
             drag_drop::{Item, Mode, ModuleData as RawData, PlaySettings, Step, TargetArea},
+            Instructions,
         },
         ModuleId,
     },
@@ -26,6 +26,7 @@ pub struct Base {
     pub backgrounds: Backgrounds,
     pub items: Vec<Item>,
     pub target_areas: Vec<TargetArea>,
+    pub mode: Mode,
     pub module_phase: Mutable<ModulePlayPhase>,
 }
 
@@ -53,6 +54,7 @@ impl Base {
             backgrounds: content.backgrounds,
             items: content.items,
             target_areas: content.target_areas,
+            mode: content.mode,
             module_phase: init_args.play_phase,
         })
     }
@@ -63,8 +65,8 @@ impl BaseExt for Base {
         Some(self.instructions.clone())
     }
 
-    fn get_timer_minutes(&self) -> Option<u32> {
-        self.settings.time_limit
+    fn get_play_settings(&self) -> Option<&assessment::PlaySettings> {
+        Some(&self.settings.assessment)
     }
 
     fn play_phase(&self) -> Mutable<ModulePlayPhase> {
@@ -134,7 +134,7 @@ impl DebugSettings {
                                     }
                                 };
 
-                                TargetArea { trace }
+                                TargetArea { trace, label: None }
                             })
                             .collect(),
                         items: init_data
@@ -245,6 +245,7 @@ pub fn init(jig_id: JigId, _module_id: ModuleId) {
                                 let t = Transform::identity();
                                 Some(t)
                             },
+                            target_areas: Vec::new(),
                         }),
                         (-0.3, 0.3),
                     ),
@@ -46,6 +46,7 @@ impl DebugSettings {
                             display_mode:
                                 shared::domain::jig::module::body::flashcards::DisplayMode::Single,
                             swap: false,
+                            spaced_repetition: false,
                         },
                         base: BaseContent {
                             mode,
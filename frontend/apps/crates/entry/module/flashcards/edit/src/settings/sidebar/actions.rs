@@ -24,4 +24,18 @@ impl SidebarSettings {
             }
         })
     }
+
+    pub fn set_spaced_repetition(&self, spaced_repetition: bool) {
+        self.base
+            .extra
+            .settings
+            .spaced_repetition
+            .set_neq(spaced_repetition);
+
+        self.base.history.push_modify(|raw| {
+            if let Some(content) = &mut raw.content {
+                content.player_settings.spaced_repetition = spaced_repetition;
+            }
+        })
+    }
 }
@@ -8,22 +8,31 @@ use shared::domain::jig::module::body::flashcards::DisplayMode;
 
 pub fn render(state: Rc<SidebarSettings>) -> Dom {
     render_settings(Rc::new(ModuleSettings {
-        lines: vec![(
-            LineKind::CardView,
-            vec![
-                Some(make_button(state.clone(), DisplayMode::Single)),
-                Some(make_button(state.clone(), DisplayMode::Double)),
-                Some(SettingsButton::new_click(
-                    SettingsButtonKind::Swap,
-                    clone!(state => move || {
-                        state.base.extra.settings.swap.signal()
-                    }),
-                    clone!(state => move || {
-                        state.toggle_swap();
-                    }),
-                )),
-            ],
-        )],
+        lines: vec![
+            (
+                LineKind::CardView,
+                vec![
+                    Some(make_button(state.clone(), DisplayMode::Single)),
+                    Some(make_button(state.clone(), DisplayMode::Double)),
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::Swap,
+                        clone!(state => move || {
+                            state.base.extra.settings.swap.signal()
+                        }),
+                        clone!(state => move || {
+                            state.toggle_swap();
+                        }),
+                    )),
+                ],
+            ),
+            (
+                LineKind::Review,
+                vec![
+                    Some(make_review_button(state.clone(), false)),
+                    Some(make_review_button(state.clone(), true)),
+                ],
+            ),
+        ],
     }))
 }
 
@@ -44,3 +53,24 @@ pub fn make_button(state: Rc<SidebarSettings>, display_mode: DisplayMode) -> Rc<
         }),
     )
 }
+
+pub fn make_review_button(
+    state: Rc<SidebarSettings>,
+    spaced_repetition: bool,
+) -> Rc<SettingsButton> {
+    SettingsButton::new_click(
+        if spaced_repetition {
+            SettingsButtonKind::SpacedRepetition
+        } else {
+            SettingsButtonKind::FlipThrough
+        },
+        clone!(state => move || {
+            state.base.extra.settings.spaced_repetition.signal_ref(move |curr| {
+                *curr == spaced_repetition
+            })
+        }),
+        clone!(state => move || {
+            state.set_spaced_repetition(spaced_repetition);
+        }),
+    )
+}
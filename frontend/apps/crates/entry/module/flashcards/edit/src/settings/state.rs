@@ -6,6 +6,7 @@ use std::cell::RefCell;
 pub struct Settings {
     pub display_mode: Mutable<DisplayMode>,
     pub swap: Mutable<bool>,
+    pub spaced_repetition: Mutable<bool>,
     pub rng: RefCell<ThreadRng>,
 }
 
@@ -16,6 +17,7 @@ impl Settings {
         Self {
             display_mode: Mutable::new(player_settings.display_mode),
             swap: Mutable::new(player_settings.swap),
+            spaced_repetition: Mutable::new(player_settings.spaced_repetition),
             rng: RefCell::new(thread_rng()),
         }
     }
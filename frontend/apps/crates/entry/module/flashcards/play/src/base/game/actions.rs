@@ -12,6 +12,7 @@ use dominator::clone;
 use rand::prelude::*;
 use std::rc::Rc;
 use utils::prelude::*;
+use wasm_bindgen_futures::spawn_local;
 
 impl Game {
     pub fn next(&self) {
@@ -53,6 +54,19 @@ impl Game {
         }
     }
 
+    /// Marks the current card known or unknown and moves on.
+    pub fn mark(state: Rc<Self>, known: bool) {
+        let pair_index = state.current.lock_ref().pair_index;
+
+        spawn_local(clone!(state => async move {
+            if let Some(review) = &state.base.review {
+                review.mark(&state.base.raw_pairs[pair_index], known).await;
+            }
+        }));
+
+        state.next();
+    }
+
     fn reset_deck(&self) {
         let mut rng = self.rng.borrow_mut();
 
@@ -65,24 +79,38 @@ impl Game {
     }
 }
 
-pub(super) fn get_fresh_deck(base: &Base, rng: &mut ThreadRng) -> Vec<CardPair> {
-    let mut deck = base.raw_pairs.clone();
-
-    deck.shuffle(rng);
-
-    deck
+pub(super) fn get_fresh_deck(base: &Base, rng: &mut ThreadRng) -> Vec<(usize, CardPair)> {
+    match &base.review {
+        // cards are taken from the end of the deck, so the first one to review goes last
+        Some(review) => review
+            .order(&base.raw_pairs)
+            .into_iter()
+            .rev()
+            .map(|index| (index, base.raw_pairs[index].clone()))
+            .collect(),
+        None => {
+            let mut deck: Vec<(usize, CardPair)> =
+                base.raw_pairs.iter().cloned().enumerate().collect();
+
+            deck.shuffle(rng);
+
+            deck
+        }
+    }
 }
 
-pub(super) fn get_current(base: &Base, deck: &mut Vec<CardPair>) -> Option<Current> {
-    deck.pop().map(|pair| {
+pub(super) fn get_current(base: &Base, deck: &mut Vec<(usize, CardPair)>) -> Option<Current> {
+    deck.pop().map(|(pair_index, pair)| {
         let current = if base.settings.swap {
             Current {
+                pair_index,
                 card: pair.0,
                 other: pair.1,
                 side: Side::Left,
             }
         } else {
             Current {
+                pair_index,
                 card: pair.1,
                 other: pair.0,
                 side: Side::Right,
@@ -12,6 +12,9 @@ use shared::domain::jig::module::body::flashcards::DisplayMode;
 
 use utils::prelude::*;
 
+const STR_KNOWN: &str = "I knew it";
+const STR_UNKNOWN: &str = "Still learning";
+
 pub fn render(state: Rc<Game>) -> Dom {
     html!("flashcards-main", {
         .property("slot", "main")
@@ -24,7 +27,7 @@ pub fn render(state: Rc<Game>) -> Dom {
                     let mode = state.base.mode;
 
 
-                    let Current { card, other, side } = current;
+                    let Current { card, other, side, .. } = current;
 
                     if state.base.settings.display_mode == DisplayMode::Single {
 
@@ -48,12 +51,33 @@ pub fn render(state: Rc<Game>) -> Dom {
                 }))
                 .to_signal_vec()
         )
-        .child(html!("button-icon", {
-            .property("icon", "white-circle-blue-arrow")
-            .property("slot", "next")
-            .event(clone!(state => move |_evt:events::Click| {
-                state.next();
-            }))
+        .apply(|dom| {
+            if state.base.review.is_some() {
+                dom.child(html!("div", {
+                    .property("slot", "review")
+                    .child(render_mark_button(state.clone(), false))
+                    .child(render_mark_button(state.clone(), true))
+                }))
+            } else {
+                dom.child(html!("button-icon", {
+                    .property("icon", "white-circle-blue-arrow")
+                    .property("slot", "next")
+                    .event(clone!(state => move |_evt:events::Click| {
+                        state.next();
+                    }))
+                }))
+            }
+        })
+    })
+}
+
+fn render_mark_button(state: Rc<Game>, known: bool) -> Dom {
+    html!("button-rect", {
+        .property("color", if known { "green" } else { "red" })
+        .property("bold", true)
+        .text(if known { STR_KNOWN } else { STR_UNKNOWN })
+        .event(clone!(state => move |_evt:events::Click| {
+            Game::mark(state.clone(), known);
         }))
     })
 }
@@ -12,7 +12,7 @@ use utils::prelude::*;
 pub struct Game {
     pub base: Rc<Base>,
     pub rng: RefCell<ThreadRng>,
-    pub deck: RefCell<Vec<CardPair>>,
+    pub deck: RefCell<Vec<(usize, CardPair)>>,
     pub current: Mutable<Current>,
     pub gate: Mutable<Gate>,
     pub animation_loader: AsyncLoader,
@@ -20,6 +20,7 @@ pub struct Game {
 
 #[derive(Clone)]
 pub struct Current {
+    pub pair_index: usize,
     pub card: Card,
     pub other: Card,
     pub side: Side,
@@ -1,3 +1,4 @@
 pub mod dom;
 pub mod game;
+pub mod review;
 pub mod state;
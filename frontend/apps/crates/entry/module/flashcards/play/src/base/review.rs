@@ -0,0 +1,124 @@
+use chrono::Utc;
+use shared::{
+    api::endpoints::jig::module::{self, flashcards},
+    domain::jig::{
+        module::{
+            body::{
+                _groups::cards::CardPair,
+                flashcards::review::{
+                    pair_key, review_order, CardReview, FlashcardReviewQuery,
+                    FlashcardReviewRequest, FlashcardReviewResponse,
+                },
+            },
+            ModuleId, ModuleResponse, StableModuleId,
+        },
+        JigId,
+    },
+    error::EmptyError,
+};
+use std::cell::RefCell;
+use utils::{prelude::*, storage::load_player_session_token};
+
+/// The student's spaced repetition state, saved for the player session they joined or for their account.
+pub struct Review {
+    path: String,
+    token: Option<String>,
+    reviews: RefCell<Vec<CardReview>>,
+}
+
+impl Review {
+    /// Loads the student's review state, or `None` if it can't be saved for them (e.g. they aren't logged in).
+    pub async fn load(jig_id: JigId, module_id: ModuleId) -> Option<Self> {
+        let stable_id = stable_id(jig_id, module_id).await?;
+
+        let path = flashcards::GetReviews::PATH
+            .replace("{id}", &jig_id.0.to_string())
+            .replace("{module_id}", &stable_id.0.to_string());
+
+        let token = load_player_session_token();
+
+        let query = FlashcardReviewQuery {
+            token: token.clone(),
+        };
+
+        let (resp, _status) = match token {
+            Some(_) => {
+                api_no_auth_status::<FlashcardReviewResponse, EmptyError, _>(
+                    &path,
+                    flashcards::GetReviews::METHOD,
+                    Some(query),
+                )
+                .await
+            }
+            None => {
+                api_with_auth_status::<FlashcardReviewResponse, EmptyError, _>(
+                    &path,
+                    flashcards::GetReviews::METHOD,
+                    Some(query),
+                )
+                .await
+            }
+        };
+
+        resp.ok().map(|resp| Self {
+            path,
+            token,
+            reviews: RefCell::new(resp.reviews),
+        })
+    }
+
+    /// The order to show `pairs` in, as indexes into `pairs`, due cards first.
+    pub fn order(&self, pairs: &[CardPair]) -> Vec<usize> {
+        review_order(pairs, &self.reviews.borrow(), Utc::now())
+    }
+
+    /// Marks a pair known or unknown.
+    pub async fn mark(&self, pair: &CardPair, known: bool) {
+        let req = FlashcardReviewRequest {
+            token: self.token.clone(),
+            pair_key: pair_key(pair),
+            known,
+        };
+
+        let (resp, _status) = match &self.token {
+            Some(_) => {
+                api_no_auth_status::<CardReview, EmptyError, _>(
+                    &self.path,
+                    flashcards::Review::METHOD,
+                    Some(req),
+                )
+                .await
+            }
+            None => {
+                api_with_auth_status::<CardReview, EmptyError, _>(
+                    &self.path,
+                    flashcards::Review::METHOD,
+                    Some(req),
+                )
+                .await
+            }
+        };
+
+        match resp {
+            Ok(review) => {
+                let mut reviews = self.reviews.borrow_mut();
+                reviews.retain(|it| it.pair_key != review.pair_key);
+                reviews.push(review);
+            }
+            Err(_) => log::warn!("couldn't save flashcard review"),
+        }
+    }
+}
+
+/// Review state is kept per stable ID, so it carries over when the jig is published.
+async fn stable_id(jig_id: JigId, module_id: ModuleId) -> Option<StableModuleId> {
+    // modules are looked up by their unique ID here, which works for draft modules too.
+    let path = module::GetLive::PATH
+        .replace("{id}", &jig_id.0.to_string())
+        .replace("{module_id}", &module_id.0.to_string());
+
+    api_no_auth::<ModuleResponse, EmptyError, ()>(&path, module::GetLive::METHOD, None)
+        .await
+        .ok()
+        .map(|resp| resp.module.stable_id)
+}
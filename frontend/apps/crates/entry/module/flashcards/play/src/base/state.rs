@@ -16,7 +16,7 @@ use std::rc::Rc;
 use components::module::_common::play::prelude::*;
 use utils::prelude::*;
 
-use super::game::state::Game;
+use super::{game::state::Game, review::Review};
 
 pub struct Base {
     pub jig_id: JigId,
@@ -27,6 +27,7 @@ pub struct Base {
     pub instructions: Instructions,
     pub settings: PlayerSettings,
     pub raw_pairs: Vec<CardPair>,
+    pub review: Option<Review>,
     pub phase: Mutable<Phase>,
     pub module_phase: Mutable<ModulePlayPhase>,
 }
@@ -50,6 +51,12 @@ impl Base {
 
         let content = raw.content.unwrap_ji();
 
+        let review = if content.player_settings.spaced_repetition {
+            Review::load(jig_id, module_id).await
+        } else {
+            None
+        };
+
         let _self = Rc::new(Self {
             jig_id,
             module_id,
@@ -59,6 +66,7 @@ impl Base {
             instructions: content.base.instructions,
             settings: content.player_settings,
             raw_pairs: content.base.pairs,
+            review,
             phase: Mutable::new(Phase::Init),
             module_phase: init_args.play_phase,
         });
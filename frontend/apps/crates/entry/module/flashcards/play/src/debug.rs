@@ -53,6 +53,7 @@ impl DebugSettings {
                             display_mode:
                                 shared::domain::jig::module::body::flashcards::DisplayMode::Double,
                             swap: false,
+                            spaced_repetition: false,
                         },
                         base: BaseContent {
                             mode,
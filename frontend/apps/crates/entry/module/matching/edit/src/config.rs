@@ -1 +1,2 @@
 pub const DEFAULT_TIME_LIMIT: u32 = 3;
+pub const DEFAULT_ATTEMPTS_LIMIT: u8 = 2;
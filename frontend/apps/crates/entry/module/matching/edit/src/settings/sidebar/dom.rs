@@ -5,6 +5,7 @@ use super::state::*;
 use futures_signals::signal::{always, SignalExt};
 
 use components::module::_common::edit::settings::prelude::*;
+use shared::domain::jig::module::body::_groups::assessment::RetryPolicy;
 
 pub fn render(state: Rc<SidebarSettings>) -> Dom {
     render_settings(Rc::new(ModuleSettings {
@@ -78,6 +79,63 @@ pub fn render(state: Rc<SidebarSettings>) -> Dom {
                     )),
                 ],
             ),
+            (
+                LineKind::Attempts,
+                vec![
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::NoLimit,
+                        clone!(state => move || {
+                            state.base.extra.settings.has_attempts_limit
+                                .signal()
+                                .map(|flag| !flag)
+                        }),
+                        clone!(state => move || {
+                            state.set_has_attempts_limit(false);
+                        }),
+                    )),
+                    Some(SettingsButton::new_value_click(
+                        SettingsButtonKind::Attempts,
+                        clone!(state => move || {
+                            state.base.extra.settings.has_attempts_limit
+                                .signal()
+                        }),
+                        SettingsValue::new(
+                            state.base.extra.settings.attempts_limit.get(),
+                            clone!(state => move |value| {
+                                state.set_attempts_limit(value);
+                            }),
+                        ),
+                        clone!(state => move || {
+                            state.set_has_attempts_limit(true);
+                        }),
+                    )),
+                ],
+            ),
+            (
+                LineKind::AfterAttempts,
+                vec![
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::ShowAnswer,
+                        clone!(state => move || {
+                            state.base.extra.settings.show_answer.signal()
+                        }),
+                        clone!(state => move || {
+                            state.toggle_show_answer();
+                        }),
+                    )),
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::RetryLater,
+                        clone!(state => move || {
+                            state.base.extra.settings.retry
+                                .signal()
+                                .map(|retry| retry == RetryPolicy::Later)
+                        }),
+                        clone!(state => move || {
+                            state.toggle_retry();
+                        }),
+                    )),
+                ],
+            ),
             // NOTE - not including score until player/jig story is resolved
         ],
     }))
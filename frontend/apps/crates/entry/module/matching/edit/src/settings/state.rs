@@ -1,6 +1,6 @@
 use futures_signals::signal::Mutable;
 use rand::prelude::*;
-use shared::domain::jig::module::body::matching::Content;
+use shared::domain::jig::module::body::{_groups::assessment::RetryPolicy, matching::Content};
 use std::cell::RefCell;
 use std::rc::Rc;
 
@@ -9,8 +9,12 @@ pub struct Settings {
     pub n_choices: Mutable<u8>,
     pub swap: Mutable<bool>,
     pub n_rounds: Mutable<u32>,
+    pub attempts_limit: Mutable<u8>,
+    pub has_attempts_limit: Mutable<bool>,
     pub time_limit: Mutable<u32>,
     pub has_time_limit: Mutable<bool>,
+    pub show_answer: Mutable<bool>,
+    pub retry: Mutable<RetryPolicy>,
 }
 
 impl Settings {
@@ -22,12 +26,22 @@ impl Settings {
             n_choices: Mutable::new(settings.n_choices),
             swap: Mutable::new(settings.swap),
             n_rounds: Mutable::new(settings.n_rounds),
+            attempts_limit: Mutable::new(
+                settings
+                    .assessment
+                    .attempts
+                    .unwrap_or(crate::config::DEFAULT_ATTEMPTS_LIMIT),
+            ),
+            has_attempts_limit: Mutable::new(settings.assessment.attempts.is_some()),
             time_limit: Mutable::new(
                 settings
+                    .assessment
                     .time_limit
                     .unwrap_or(crate::config::DEFAULT_TIME_LIMIT),
             ),
-            has_time_limit: Mutable::new(settings.time_limit.is_some()),
+            has_time_limit: Mutable::new(settings.assessment.time_limit.is_some()),
+            show_answer: Mutable::new(settings.assessment.show_answer),
+            retry: Mutable::new(settings.assessment.retry),
         }
     }
 }
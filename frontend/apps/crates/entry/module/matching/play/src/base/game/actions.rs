@@ -1,10 +1,11 @@
-use super::state::*;
+use super::{card::state::TopPhase, state::*};
 
 use std::sync::atomic::Ordering;
 
 use crate::base::state::Phase;
 use components::module::_common::play::prelude::*;
 use rand::prelude::*;
+use shared::domain::jig::module::body::_groups::assessment::AfterMistake;
 use std::convert::TryInto;
 use std::rc::Rc;
 use utils::prelude::*;
@@ -13,20 +14,17 @@ impl Game {
     pub fn next(state: Rc<Self>) {
         let rounds_played = state.rounds_played.load(Ordering::SeqCst);
 
-        let has_ended = {
-            if rounds_played >= state.base.settings.n_rounds.try_into().unwrap_ji() {
-                true
-            } else if state.remaining.borrow().len() == 0 {
+        let all_rounds_played =
+            rounds_played >= state.base.settings.n_rounds.try_into().unwrap_ji();
+
+        if !all_rounds_played {
+            if state.remaining.borrow().len() == 0 {
                 log::info!("deck finished, re-shuffling!");
                 Self::reset_deck(state.clone());
-                false
-            } else {
-                false
             }
-        };
 
-        if !has_ended {
-            state.current.set(Some(Current::new(state.clone())));
+            let pairs = Self::deal(&state);
+            state.current.set(Some(Current::new(state.clone(), pairs)));
             state
                 .rounds_played
                 .store(rounds_played + 1, Ordering::SeqCst);
@@ -35,6 +33,9 @@ impl Game {
                 rounds_played + 1,
                 state.base.settings.n_rounds
             );
+        } else if let Some(pairs) = Self::deal_retry(&state) {
+            log::info!("matching missed pairs again");
+            state.current.set(Some(Current::new(state.clone(), pairs)));
         } else {
             log::info!("GAME OVER!");
             state.base.phase.set(Phase::Ending);
@@ -44,6 +45,97 @@ impl Game {
         }
     }
 
+    /// The pairs for the next round, a target from the deck and others to choose from.
+    fn deal(state: &Self) -> Vec<CardPairId> {
+        let remaining = &mut *state.remaining.borrow_mut();
+        let used = &mut *state.used.borrow_mut();
+        let rng = &mut *state.rng.borrow_mut();
+
+        let amount: usize = state.base.settings.n_choices.into();
+        let amount: usize = amount.min(state.base.raw_pairs.len());
+
+        // Remaining and used is split so that we can detect
+        // when the entire deck has been looped through
+        // which can be useful for educational purposes
+        // even though it technically isn't in play settings yet
+
+        //remove our target from potential choices
+        let target = remaining.pop().unwrap_ji();
+
+        //first get all the potential choices
+        let mut others: Vec<&CardPairId> = remaining.iter().chain(used.iter()).collect();
+        //shuffle them up
+        others.shuffle(rng);
+
+        //take just what we need
+        let mut others: Vec<CardPairId> = others.into_iter().take(amount - 1).cloned().collect();
+
+        //add in our target
+        others.push(target.clone());
+
+        //re-shuffle to move it
+        others.shuffle(rng);
+
+        //add the target to the used buffer
+        used.push(target);
+
+        others
+    }
+
+    /// The pairs for a round of missed pairs, `None` once there are none left.
+    fn deal_retry(state: &Self) -> Option<Vec<CardPairId>> {
+        let retry = &mut *state.retry.borrow_mut();
+
+        if retry.is_empty() {
+            None
+        } else {
+            let amount: usize = state.base.settings.n_choices.into();
+            let amount = amount.min(retry.len());
+
+            Some(retry.drain(..amount).collect())
+        }
+    }
+
+    /// Counts a wrong drop of the card `pair_id`, and settles it once the student is out of
+    /// attempts.
+    ///
+    /// Returns whether the student can try again.
+    pub fn record_mistake(state: &Self, current: &Current, pair_id: usize) -> bool {
+        let mistakes = {
+            let mut mistakes = current.mistakes.borrow_mut();
+            let mistakes = mistakes.entry(pair_id).or_default();
+            *mistakes += 1;
+            *mistakes
+        };
+
+        match state.base.settings.assessment.after_mistake(mistakes) {
+            AfterMistake::TryAgain => true,
+            AfterMistake::OutOfAttempts {
+                show_answer,
+                retry_later,
+            } => {
+                if retry_later {
+                    let pair = &state.base.raw_pairs[pair_id];
+                    state.retry.borrow_mut().push_back(CardPairId(
+                        pair.0.clone(),
+                        pair.1.clone(),
+                        pair_id,
+                    ));
+                }
+
+                if let Some(top) = current.top.iter().find(|top| top.pair_id == pair_id) {
+                    top.phase.set(if show_answer {
+                        TopPhase::Landed
+                    } else {
+                        TopPhase::Missed
+                    });
+                }
+
+                false
+            }
+        }
+    }
+
     pub fn reset_deck(state: Rc<Self>) {
         let mut remaining: Vec<CardPairId> = state
             .base
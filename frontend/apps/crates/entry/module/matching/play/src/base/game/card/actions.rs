@@ -11,24 +11,26 @@ impl CardDrag {
         if let Some(current) = self.game.get_current() {
             let choice = current.top.iter().find(|choice| choice.is_drag_over());
 
-            let mut found_match = false;
+            // whether the card is done with, either matched or out of attempts
+            let mut settled = false;
 
             if let Some(choice) = choice {
                 if choice.pair_id == self.pair_id {
                     play_random_positive();
-                    found_match = true;
+                    settled = true;
                     choice.phase.set(TopPhase::Landed);
                 } else {
                     // Only play the negative effect if they've dropped the card over a target. If
                     // they drop the card over nothing, it could be for something like releasing
                     // the card to select a new card.
                     play_random_negative();
+                    settled = !Game::record_mistake(&self.game, &current, self.pair_id);
                 }
             } else {
                 //empty area
             }
 
-            if !found_match {
+            if !settled {
                 if let Some(target) = current
                     .bottom
                     .iter()
@@ -36,7 +38,7 @@ impl CardDrag {
                 {
                     target.phase.set(BottomPhase::Show);
                 }
-            } else if current.top.iter().all(|choice| choice.is_landed()) {
+            } else if current.top.iter().all(|choice| choice.is_done()) {
                 Game::next(self.game.clone());
             }
 
@@ -47,6 +47,10 @@ pub fn render_top(state: Rc<CardTop>) -> Dom {
                     let mut options = CardOptions::new(card, theme_id, mode, side.negate(), Size::Matching);
                     options.flipped = true;
                     Some(render_card(options))
+                },
+                TopPhase::Missed => {
+                    let options = EmptyCardOptions::new(EmptyKind::Translucent, theme_id, Size::Matching);
+                    Some(render_empty_card(options))
                 }
             }
         })))
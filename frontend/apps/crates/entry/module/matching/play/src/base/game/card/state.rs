@@ -2,8 +2,8 @@ use crate::base::game::state::{CardPairId, Game};
 use components::module::_groups::cards::lookup::Side;
 use futures_signals::signal::Mutable;
 use shared::domain::jig::module::body::{
-    ThemeId,
     _groups::cards::{Card, Mode},
+    ThemeId,
 };
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -32,6 +32,8 @@ type IsDragOver = bool;
 pub enum TopPhase {
     Empty(Mutable<IsDragOver>),
     Landed,
+    /// Out of attempts, and the answer isn't shown
+    Missed,
 }
 
 #[derive(Clone)]
@@ -93,9 +95,9 @@ impl CardChoice<TopPhase> {
             _ => false,
         }
     }
-    pub fn is_landed(&self) -> bool {
+    pub fn is_done(&self) -> bool {
         match self.phase.get_cloned() {
-            TopPhase::Landed => true,
+            TopPhase::Landed | TopPhase::Missed => true,
             _ => false,
         }
     }
@@ -4,6 +4,7 @@ use futures_signals::signal::Mutable;
 use rand::prelude::*;
 use shared::domain::jig::module::body::_groups::cards::Card;
 use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
 use std::rc::Rc;
 use std::sync::atomic::AtomicUsize;
 use utils::prelude::*;
@@ -13,6 +14,8 @@ pub struct Game {
     pub rng: RefCell<ThreadRng>,
     pub remaining: RefCell<Vec<CardPairId>>,
     pub used: RefCell<Vec<CardPairId>>,
+    /// Missed pairs, matched again after the others
+    pub retry: RefCell<VecDeque<CardPairId>>,
     pub current: Mutable<Option<Rc<Current>>>,
     pub rounds_played: AtomicUsize,
 }
@@ -22,6 +25,7 @@ impl Game {
             base,
             remaining: RefCell::new(Vec::new()),
             used: RefCell::new(Vec::new()),
+            retry: RefCell::new(VecDeque::new()),
             rng: RefCell::new(thread_rng()),
             current: Mutable::new(None),
             rounds_played: AtomicUsize::new(0),
@@ -45,51 +49,21 @@ pub struct Current {
     pub top: Vec<Rc<CardTop>>,
     pub bottom: Vec<Rc<CardBottom>>,
     pub drag: Mutable<Option<Rc<CardDrag>>>,
+    /// Wrong drops of each card, by pair id
+    pub mistakes: RefCell<HashMap<usize, u32>>,
 }
 
 impl Current {
-    pub fn new(game: Rc<Game>) -> Rc<Self> {
-        let remaining = &mut *game.remaining.borrow_mut();
-        let used = &mut *game.used.borrow_mut();
+    pub fn new(game: Rc<Game>, pairs: Vec<CardPairId>) -> Rc<Self> {
         let rng = &mut *game.rng.borrow_mut();
 
-        let _deck_len = remaining.len() + used.len();
-
-        let amount: usize = game.base.settings.n_choices.into();
-        let amount: usize = amount.min(game.base.raw_pairs.len());
-
-        // Remaining and used is split so that we can detect
-        // when the entire deck has been looped through
-        // which can be useful for educational purposes
-        // even though it technically isn't in play settings yet
-
-        //remove our target from potential choices
-        let target = remaining.pop().unwrap_ji();
-
-        //first get all the potential choices
-        let mut others: Vec<&CardPairId> = remaining.iter().chain(used.iter()).collect();
-        //shuffle them up
-        others.shuffle(rng);
-
-        //take just what we need
-        let mut others: Vec<CardPairId> = others.into_iter().take(amount - 1).cloned().collect();
-
-        //add in our target
-        others.push(target.clone());
-
-        //re-shuffle to move it
-        others.shuffle(rng);
-
-        //add the target to the used buffer
-        used.push(target);
-
         // clone into top/bottom
-        let mut top: Vec<Rc<CardTop>> = others
+        let mut top: Vec<Rc<CardTop>> = pairs
             .iter()
             .map(|choice| Rc::new(CardChoice::<TopPhase>::new(game.clone(), choice.clone())))
             .collect();
 
-        let mut bottom: Vec<Rc<CardBottom>> = others
+        let mut bottom: Vec<Rc<CardBottom>> = pairs
             .iter()
             .map(|choice| Rc::new(CardChoice::<BottomPhase>::new(game.clone(), choice.clone())))
             .collect();
@@ -102,6 +76,7 @@ impl Current {
             top,
             bottom,
             drag: Mutable::new(None),
+            mistakes: RefCell::new(HashMap::new()),
         })
     }
 }
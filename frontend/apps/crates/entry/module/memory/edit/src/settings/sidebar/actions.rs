@@ -1,4 +1,7 @@
-use shared::config::{MAX_LIST_WORDS, MIN_LIST_WORDS};
+use shared::{
+    config::{MAX_LIST_WORDS, MIN_LIST_WORDS},
+    domain::jig::module::body::memory::turns::clamp_players,
+};
 
 use super::state::*;
 
@@ -9,10 +12,10 @@ impl SidebarSettings {
         self.base.history.push_modify(|raw| {
             if let Some(content) = &mut raw.content {
                 if !flag {
-                    content.player_settings.time_limit = None;
+                    content.player_settings.assessment.time_limit = None;
                 } else {
                     let value = self.base.extra.settings.time_limit.get();
-                    content.player_settings.time_limit = Some(value);
+                    content.player_settings.assessment.time_limit = Some(value);
                 }
             }
         })
@@ -23,7 +26,7 @@ impl SidebarSettings {
         if self.base.extra.settings.has_time_limit.get() {
             self.base.history.push_modify(|raw| {
                 if let Some(content) = &mut raw.content {
-                    content.player_settings.time_limit = Some(time_limit);
+                    content.player_settings.assessment.time_limit = Some(time_limit);
                 }
             })
         }
@@ -108,4 +111,32 @@ impl SidebarSettings {
             }
         })
     }
+
+    pub fn set_has_players(&self, flag: bool) {
+        self.base.extra.settings.has_players.set_neq(flag);
+
+        self.base.history.push_modify(|raw| {
+            if let Some(content) = &mut raw.content {
+                content.player_settings.players = if flag {
+                    Some(self.base.extra.settings.players.get())
+                } else {
+                    None
+                };
+            }
+        })
+    }
+
+    pub fn set_players(&self, players: u8) {
+        let players = clamp_players(players);
+
+        self.base.extra.settings.players.set(players);
+
+        if self.base.extra.settings.has_players.get() {
+            self.base.history.push_modify(|raw| {
+                if let Some(content) = &mut raw.content {
+                    content.player_settings.players = Some(players);
+                }
+            })
+        }
+    }
 }
@@ -62,6 +62,38 @@ pub fn render(state: Rc<SidebarSettings>) -> Dom {
                     )),
                 ],
             ),
+            (
+                LineKind::Players,
+                vec![
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::SinglePlayer,
+                        clone!(state => move || {
+                            state.base.extra.settings.has_players
+                                .signal()
+                                .map(|flag| !flag)
+                        }),
+                        clone!(state => move || {
+                            state.set_has_players(false);
+                        }),
+                    )),
+                    Some(SettingsButton::new_value_click(
+                        SettingsButtonKind::Players,
+                        clone!(state => move || {
+                            state.base.extra.settings.has_players
+                                .signal()
+                        }),
+                        SettingsValue::new_mutable(
+                            state.settings().players.clone(),
+                            clone!(state => move |value| {
+                                state.set_players(value);
+                            }),
+                        ),
+                        clone!(state => move || {
+                            state.set_has_players(true);
+                        }),
+                    )),
+                ],
+            ),
             // NOTE - not including score until player/jig story is resolved
         ],
     }))
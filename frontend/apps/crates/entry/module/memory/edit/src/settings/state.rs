@@ -1,11 +1,13 @@
 use futures_signals::signal::Mutable;
-use shared::domain::jig::module::body::memory::Content;
+use shared::domain::jig::module::body::memory::{turns::MIN_PLAYERS, Content};
 
 pub struct Settings {
     pub time_limit: Mutable<u32>,
     pub has_time_limit: Mutable<bool>,
     pub use_default_pairs: Mutable<bool>,
     pub pairs_to_display: Mutable<u32>,
+    pub players: Mutable<u8>,
+    pub has_players: Mutable<bool>,
 }
 
 impl Settings {
@@ -15,12 +17,15 @@ impl Settings {
         Self {
             time_limit: Mutable::new(
                 settings
+                    .assessment
                     .time_limit
                     .unwrap_or(crate::config::DEFAULT_TIME_LIMIT),
             ),
-            has_time_limit: Mutable::new(settings.time_limit.is_some()),
+            has_time_limit: Mutable::new(settings.assessment.time_limit.is_some()),
             use_default_pairs: Mutable::new(settings.pairs_to_display.is_none()),
             pairs_to_display: Mutable::new(settings.pairs_to_display.unwrap_or_default()),
+            players: Mutable::new(settings.players.unwrap_or(MIN_PLAYERS)),
+            has_players: Mutable::new(settings.players.is_some()),
         }
     }
 }
@@ -1,7 +1,10 @@
-use super::game::dom::render as render_game;
+use super::{
+    game::dom::render as render_game,
+    players::dom::{render_scoreboard, render_setup},
+};
 use components::module::_common::play::prelude::*;
 use dominator::{clone, html, Dom};
-use futures_signals::signal::SignalExt;
+use futures_signals::{map_ref, signal::SignalExt};
 use std::rc::Rc;
 
 use crate::base::state::*;
@@ -15,15 +18,33 @@ pub fn render(state: Rc<Base>) -> Dom {
             async {}
         })))
         .property("slot", "main")
-        .child_signal(
-            state.all_cards_ended_signal()
-                .map(clone!(state => move |ended| {
-                    if ended {
-                        None
+        .children_signal_vec(
+            map_ref! {
+                let ended = state.all_cards_ended_signal(),
+                let started = state.turns.signal_ref(Option::is_some).dedupe()
+                    => (*ended, *started)
+            }
+            .map(clone!(state => move |(ended, started)| {
+                let turn_based = state.is_turn_based();
+
+                if turn_based && !started {
+                    vec![render_setup(state.clone())]
+                } else if ended {
+                    // A turn-based game ends with everyone's score
+                    if turn_based {
+                        vec![render_scoreboard(state.clone(), true)]
                     } else {
-                        Some(render_game(state.clone()))
+                        Vec::new()
+                    }
+                } else {
+                    let mut children = vec![render_game(state.clone())];
+                    if turn_based {
+                        children.push(render_scoreboard(state.clone(), false));
                     }
-                }))
+                    children
+                }
+            }))
+            .to_signal_vec()
         )
     })
 }
@@ -1,4 +1,4 @@
-use crate::base::state::*;
+use crate::base::{stage::players::actions::finish_turn, state::*};
 
 use components::{
     audio::mixer::{AudioMixer, AudioPath, AudioSourceExt, AUDIO_MIXER},
@@ -84,9 +84,11 @@ pub fn evaluate(state: Rc<Base>, id_1: usize, id_2: usize) {
             }
 
             play_effect(true);
+            finish_turn(&state, true);
         } else {
             play_effect(false);
             TimeoutFuture::new(2_000).await;
+            finish_turn(&state, false);
         }
 
         state.flip_state.set(FlipState::None);
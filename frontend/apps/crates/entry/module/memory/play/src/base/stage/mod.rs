@@ -1,2 +1,3 @@
 pub mod dom;
 pub mod game;
+pub mod players;
@@ -0,0 +1,20 @@
+use crate::base::state::*;
+
+use shared::domain::jig::module::body::memory::turns::Turns;
+use std::rc::Rc;
+
+pub fn start(state: Rc<Base>) {
+    let names: Vec<String> = state
+        .player_names
+        .iter()
+        .map(|name| name.get_cloned())
+        .collect();
+
+    state.turns.set(Some(Turns::new(&names)));
+}
+
+pub fn finish_turn(state: &Base, matched: bool) {
+    if let Some(turns) = state.turns.lock_mut().as_mut() {
+        turns.finish_turn(matched);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::base::state::*;
+use dominator::{clone, html, Dom};
+use futures_signals::signal::SignalExt;
+use shared::domain::jig::module::body::memory::turns::default_player_name;
+use std::rc::Rc;
+use web_sys::HtmlInputElement;
+
+use utils::prelude::*;
+
+const STR_START: &str = "Start";
+
+pub fn render_setup(state: Rc<Base>) -> Dom {
+    html!("memory-players-setup", {
+        .children(
+            state.player_names
+                .iter()
+                .enumerate()
+                .map(|(index, name)| {
+                    html!("input" => HtmlInputElement, {
+                        .property("slot", "names")
+                        .property("placeholder", default_player_name(index))
+                        .property_signal("value", name.signal_cloned())
+                        .with_node!(elem => {
+                            .event(clone!(name => move |_evt:events::Input| {
+                                name.set(elem.value());
+                            }))
+                        })
+                    })
+                })
+        )
+        .child(html!("button-rect", {
+            .property("slot", "start")
+            .property("color", "blue")
+            .property("bold", true)
+            .text(STR_START)
+            .event(clone!(state => move |_evt:events::Click| {
+                super::actions::start(state.clone());
+            }))
+        }))
+    })
+}
+
+pub fn render_scoreboard(state: Rc<Base>, ended: bool) -> Dom {
+    html!("memory-scoreboard", {
+        .property("ended", ended)
+        .children_signal_vec(
+            state.turns.signal_cloned()
+                .map(move |turns| {
+                    turns.map_or_else(Vec::new, |turns| {
+                        let leaders = turns.leaders();
+
+                        turns.players()
+                            .iter()
+                            .enumerate()
+                            .map(|(index, player)| {
+                                html!("memory-player-score", {
+                                    .property("name", &player.name)
+                                    .property("score", player.score)
+                                    .property("current", !ended && index == turns.current())
+                                    .property("winner", ended && leaders.contains(&index))
+                                })
+                            })
+                            .collect()
+                    })
+                })
+                .to_signal_vec()
+        )
+    })
+}
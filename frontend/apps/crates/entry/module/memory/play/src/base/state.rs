@@ -3,9 +3,15 @@ use shared::{
     domain::jig::{
         module::{
             body::{
+                _groups::{
+                    assessment::PlaySettings,
+                    cards::{CardPair as RawCardPair, Mode, Step},
+                },
+                memory::{
+                    turns::{clamp_players, Turns},
+                    ModuleData as RawData, PlayerSettings,
+                },
                 Background, Instructions,
-                _groups::cards::{CardPair as RawCardPair, Mode, Step},
-                memory::{ModuleData as RawData, PlayerSettings},
             },
             ModuleId,
         },
@@ -37,6 +43,10 @@ pub struct Base {
     pub instructions: Instructions,
     pub settings: PlayerSettings,
     pub module_phase: Mutable<ModulePlayPhase>,
+    /// Names entered before a turn-based game starts, empty for a single player game
+    pub player_names: Vec<Mutable<String>>,
+    /// `None` until a turn-based game starts
+    pub turns: Mutable<Option<Turns>>,
 }
 
 #[derive(Debug, Clone)]
@@ -119,6 +129,11 @@ impl Base {
             cards.shuffle(&mut rng);
         }
 
+        let n_players = content.player_settings.players.map_or(0, clamp_players);
+        let player_names = (0..n_players)
+            .map(|_| Mutable::new(String::new()))
+            .collect();
+
         Rc::new(Self {
             jig_id,
             module_id,
@@ -133,9 +148,15 @@ impl Base {
             instructions: content.base.instructions,
             settings: content.player_settings,
             module_phase: init_args.play_phase,
+            player_names,
+            turns: Mutable::new(None),
         })
     }
 
+    pub fn is_turn_based(&self) -> bool {
+        !self.player_names.is_empty()
+    }
+
     pub fn all_cards_ended_future(&self) -> impl Future<Output = bool> {
         let fut = join_all(
             self.cards
@@ -159,8 +180,8 @@ impl BaseExt for Base {
         Some(self.instructions.clone())
     }
 
-    fn get_timer_minutes(&self) -> Option<u32> {
-        self.settings.time_limit
+    fn get_play_settings(&self) -> Option<&PlaySettings> {
+        Some(&self.settings.assessment)
     }
 
     fn play_phase(&self) -> Mutable<ModulePlayPhase> {
@@ -0,0 +1,66 @@
+use super::{
+    footer::state::Footer, header::state::Header, main::state::Main, overlay::state::Overlay,
+    sidebar::state::Sidebar, state::Base,
+};
+use components::module::_common::edit::prelude::*;
+use shared::domain::jig::module::body::{
+    _groups::design::Trace,
+    puzzle::{Mode, ModuleData as RawData, PuzzleItem, Step},
+};
+use std::rc::Rc;
+
+pub async fn init_from_raw(
+    init_args: BaseInitFromRawArgs<RawData, Mode, Step>,
+) -> BaseInit<Step, Base, Main, Sidebar, Header, Footer, Overlay> {
+    let force_step = {
+        if init_args.source == InitSource::ForceRaw {
+            crate::debug::settings().step
+        } else {
+            None
+        }
+    };
+
+    let base = Base::new(init_args).await;
+
+    BaseInit {
+        force_step,
+        force_theme: None,
+        base: base.clone(),
+        main: Rc::new(Main::new(base.clone())),
+        sidebar: Rc::new(Sidebar::new(base.clone())),
+        header: Rc::new(Header::new(base.clone())),
+        footer: Rc::new(Footer::new(base.clone())),
+        overlay: Rc::new(Overlay::new(base)),
+    }
+}
+
+impl Base {
+    /*
+     * The traces themselves are managed by the component
+     * Callbacks here are fired from there and need only to manage
+     * history
+     */
+    pub fn on_trace_added(&self, trace: Trace) {
+        self.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                content.items.push(PuzzleItem { trace });
+            }
+        });
+    }
+
+    pub fn on_trace_deleted(&self, index: usize) {
+        self.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                content.items.remove(index);
+            }
+        });
+    }
+
+    pub fn on_trace_changed(&self, index: usize, raw_trace: Trace) {
+        self.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                content.items[index].trace = raw_trace;
+            }
+        });
+    }
+}
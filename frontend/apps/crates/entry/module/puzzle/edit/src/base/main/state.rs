@@ -0,0 +1,40 @@
+use crate::base::state::Base;
+use components::module::_common::edit::prelude::*;
+use std::rc::Rc;
+
+use futures_signals::signal::{Signal, SignalExt};
+
+use shared::domain::jig::module::body::puzzle::Step;
+
+pub struct Main {
+    pub base: Rc<Base>,
+}
+
+impl Main {
+    pub fn new(base: Rc<Base>) -> Self {
+        Self { base }
+    }
+
+    pub fn phase_signal(&self) -> impl Signal<Item = Phase> {
+        self.base
+            .step
+            .signal()
+            .map(|step| step == Step::Three)
+            .dedupe()
+            .map(|is_step_three| {
+                if is_step_three {
+                    Phase::Trace
+                } else {
+                    Phase::Layout
+                }
+            })
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Phase {
+    Layout,
+    Trace,
+}
+
+impl MainExt for Main {}
@@ -0,0 +1,6 @@
+pub mod dom;
+pub mod state;
+pub mod step_1;
+pub mod step_2;
+pub mod step_3;
+pub mod step_4;
@@ -0,0 +1,3 @@
+pub mod dom;
+pub mod play_settings;
+pub mod state;
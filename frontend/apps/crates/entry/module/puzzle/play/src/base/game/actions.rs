@@ -0,0 +1,345 @@
+use super::state::*;
+use crate::config::{HOLE_COLOR, PIECE_FALLBACK_COLOR, PIECE_OUTLINE_COLOR};
+use awsm_web::canvas::get_2d_context;
+use components::{
+    audio::mixer::{AudioPath, AudioSourceExt, AUDIO_MIXER},
+    module::_common::play::prelude::*,
+    traces::{canvas::apply_transform, utils::TraceShapeExt},
+};
+use dominator::{animation::Percentage, clone};
+use futures_signals::signal::SignalExt;
+use gloo_timers::future::TimeoutFuture;
+use shared::domain::jig::module::body::_groups::assessment::AfterMistake;
+use std::rc::Rc;
+use utils::{
+    drag::Drag,
+    image_effects::ImageEffect,
+    math::vec2,
+    prelude::*,
+    resize::{get_resize_info, ResizeInfo},
+};
+use wasm_bindgen::prelude::*;
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, Path2d};
+
+impl Game {
+    pub async fn load(self: Rc<Self>, canvas: HtmlCanvasElement) {
+        let state = self;
+
+        let ctx = get_2d_context(&canvas, None).unwrap_ji();
+        *state.canvas.borrow_mut() = Some((canvas, ctx));
+
+        if let Some(image) = state.base.image.clone() {
+            *state.image.borrow_mut() = Some(ImageEffect::new(image, None).await);
+        }
+
+        if state.base.settings.show_preview {
+            state.phase.set(Phase::Preview);
+            TimeoutFuture::new(crate::config::PREVIEW_DELAY).await;
+            state.start_preview();
+        } else {
+            state.phase.set(Phase::Playing);
+        }
+    }
+
+    fn start_preview(self: Rc<Self>) {
+        let state = self;
+
+        state
+            .loader
+            .load(state.preview.signal().for_each(clone!(state => move |t| {
+                let t_f64 = t.into_f64();
+                for piece in state.pieces.iter() {
+                    let (x, y) = piece.scattered;
+                    piece.offset.set((x * t_f64, y * t_f64));
+                }
+
+                state.draw(&get_resize_info());
+
+                if t == Percentage::END {
+                    state.phase.set_neq(Phase::Playing);
+                }
+
+                async {}
+            })));
+
+        state.preview.animate_to(Percentage::END);
+    }
+
+    /// Free pieces, with the one being dragged last so that it's drawn on top
+    fn free_pieces(&self) -> Vec<Rc<Piece>> {
+        let drag_index = self.drag_index.get();
+
+        let mut pieces: Vec<(usize, Rc<Piece>)> = self
+            .pieces
+            .iter()
+            .cloned()
+            .enumerate()
+            .filter(|(_, piece)| !piece.locked.get() && !piece.missed.get())
+            .collect();
+
+        pieces.sort_by_key(|(index, _)| Some(*index) == drag_index);
+
+        pieces.into_iter().map(|(_, piece)| piece).collect()
+    }
+
+    pub fn draw(&self, resize_info: &ResizeInfo) {
+        if let Some((canvas, ctx)) = self.canvas.borrow().as_ref() {
+            // setting the size also clears it
+            canvas.set_width(resize_info.width as u32);
+            canvas.set_height(resize_info.height as u32);
+
+            let free_pieces = self.free_pieces();
+
+            // the holes the free and missed pieces belong in
+            ctx.set_fill_style(&JsValue::from_str(HOLE_COLOR));
+            for piece in self.pieces.iter().filter(|piece| !piece.locked.get()) {
+                piece.with_transform(ctx, resize_info, (0.0, 0.0), |ctx, path| {
+                    ctx.fill_with_path_2d(path);
+                });
+            }
+
+            // locked pieces aren't drawn, the background shows through in their place
+            let image = self.image.borrow();
+            for piece in free_pieces.iter() {
+                // set aside pieces are faded out until they can be retried
+                ctx.set_global_alpha(if piece.set_aside.get() { 0.5 } else { 1.0 });
+                piece.draw(ctx, resize_info, image.as_ref());
+            }
+            ctx.set_global_alpha(1.0);
+        }
+    }
+
+    pub fn start_drag(&self, x: i32, y: i32) {
+        if self.phase.get() != Phase::Playing {
+            return;
+        }
+
+        let resize_info = get_resize_info();
+        let (canvas_x, canvas_y) = resize_info.get_pos_px(x as f64, y as f64);
+
+        if let Some((_, ctx)) = self.canvas.borrow().as_ref() {
+            // the top-most piece under the pointer
+            let hit = self.free_pieces().into_iter().rev().find(|piece| {
+                !piece.set_aside.get() && piece.hit_test(ctx, &resize_info, canvas_x, canvas_y)
+            });
+
+            if let Some(piece) = hit {
+                let index = self
+                    .pieces
+                    .iter()
+                    .position(|other| Rc::ptr_eq(other, &piece))
+                    .unwrap_ji();
+
+                piece.start_drag(x, y);
+                self.drag_index.set(Some(index));
+            }
+        }
+    }
+
+    pub fn try_move_drag(&self, x: i32, y: i32) {
+        if let Some(index) = self.drag_index.get() {
+            self.pieces[index].try_move_drag(x, y);
+            self.draw(&get_resize_info());
+        }
+    }
+
+    pub fn try_end_drag(&self) {
+        if let Some(index) = self.drag_index.take() {
+            let piece = &self.pieces[index];
+
+            if piece.drag.borrow_mut().take().is_some() {
+                let is_correct = piece.evaluate(
+                    self.base.settings.snap_tolerance.distance(),
+                    self.base.settings.fly_back_to_origin,
+                );
+
+                if is_correct {
+                    self.release_set_aside();
+                } else {
+                    self.record_mistake(piece);
+                }
+            }
+
+            self.draw(&get_resize_info());
+            self.evaluate_end();
+        }
+    }
+
+    /// Pieces which ran out of attempts get another go once everything else is placed
+    fn release_set_aside(&self) {
+        if self
+            .pieces
+            .iter()
+            .all(|piece| piece.locked.get() || piece.missed.get() || piece.set_aside.get())
+        {
+            for piece in self.pieces.iter() {
+                piece.set_aside.set(false);
+            }
+        }
+    }
+
+    fn record_mistake(&self, piece: &Piece) {
+        piece.mistakes.set(piece.mistakes.get() + 1);
+
+        if let AfterMistake::OutOfAttempts {
+            show_answer,
+            retry_later,
+        } = self
+            .base
+            .settings
+            .assessment
+            .after_mistake(piece.mistakes.get())
+        {
+            if retry_later {
+                // Snapping it in for the student would give the retry away
+                piece.mistakes.set(0);
+                piece.set_aside.set(true);
+            } else if show_answer {
+                piece.offset.set((0.0, 0.0));
+                piece.locked.set(true);
+            } else {
+                piece.missed.set(true);
+            }
+
+            self.release_set_aside();
+        }
+    }
+
+    fn evaluate_end(&self) {
+        if self
+            .pieces
+            .iter()
+            .all(|piece| piece.locked.get() || piece.missed.get())
+        {
+            self.phase.set_neq(Phase::Finished);
+            self.base
+                .set_play_phase(ModulePlayPhase::Ending(Some(ModuleEnding::Positive)));
+        }
+    }
+}
+
+impl Piece {
+    /// Sets the canvas up so that the piece's shape is drawn at the offset from its place
+    fn with_transform(
+        &self,
+        ctx: &CanvasRenderingContext2d,
+        resize_info: &ResizeInfo,
+        offset: (f64, f64),
+        f: impl FnOnce(&CanvasRenderingContext2d, &Path2d),
+    ) {
+        ctx.save();
+
+        let (x, y) = resize_info.get_pos_denormalized(offset.0, offset.1);
+        let _ = ctx.translate(x, y);
+        apply_transform(ctx, resize_info, &self.trace.transform, &self.bounds);
+
+        f(ctx, &self.trace.shape.as_path2d(resize_info));
+
+        ctx.restore();
+    }
+
+    fn draw(
+        &self,
+        ctx: &CanvasRenderingContext2d,
+        resize_info: &ResizeInfo,
+        image: Option<&ImageEffect>,
+    ) {
+        let offset = self.offset.get();
+        let (x, y) = resize_info.get_pos_denormalized(offset.0, offset.1);
+
+        self.with_transform(ctx, resize_info, offset, |ctx, path| {
+            ctx.clip_with_path_2d(path);
+
+            // the clip stays in place, draw the picture as if the piece were where it belongs
+            let _ = ctx.set_transform(1.0, 0.0, 0.0, 1.0, x, y);
+
+            match image {
+                Some(image) => {
+                    let _ = ctx.draw_image_with_html_image_element_and_dw_and_dh(
+                        &image.image_element,
+                        0.0,
+                        0.0,
+                        resize_info.width,
+                        resize_info.height,
+                    );
+                }
+                None => {
+                    ctx.set_fill_style(&JsValue::from_str(PIECE_FALLBACK_COLOR));
+                    ctx.fill_rect(0.0, 0.0, resize_info.width, resize_info.height);
+                }
+            }
+        });
+
+        self.with_transform(ctx, resize_info, offset, |ctx, path| {
+            ctx.set_stroke_style(&JsValue::from_str(PIECE_OUTLINE_COLOR));
+            ctx.stroke_with_path(path);
+        });
+    }
+
+    fn hit_test(
+        &self,
+        ctx: &CanvasRenderingContext2d,
+        resize_info: &ResizeInfo,
+        x: f64,
+        y: f64,
+    ) -> bool {
+        let mut hit = false;
+
+        self.with_transform(ctx, resize_info, self.offset.get(), |ctx, path| {
+            hit = ctx.is_point_in_path_with_path_2d_and_f64(path, x, y);
+        });
+
+        hit
+    }
+
+    fn start_drag(&self, x: i32, y: i32) {
+        self.picked_from.set(self.offset.get());
+        *self.drag.borrow_mut() = Some(Rc::new(Drag::new(x, y, 0.0, 0.0, true)));
+
+        if let Some(audio) = self.trace.audio.as_ref() {
+            AUDIO_MIXER.with(|mixer| {
+                mixer.play_oneshot(audio.as_source());
+            });
+        }
+    }
+
+    fn try_move_drag(&self, x: i32, y: i32) {
+        if let Some(drag) = self.drag.borrow().as_ref() {
+            if let Some((_, diff)) = drag.update(x, y) {
+                let resize_info = get_resize_info();
+                let (diff_x, diff_y) = resize_info.get_px_normalized(diff.x as f64, diff.y as f64);
+
+                let (x, y) = self.offset.get();
+                self.offset.set((x - diff_x, y - diff_y));
+            }
+        }
+    }
+
+    /// Snaps the piece in if it was dropped close enough to its place, returns whether it was
+    fn evaluate(&self, tolerance: f64, fly_back_to_origin: bool) -> bool {
+        let (x, y) = self.offset.get();
+
+        if vec2::distance(&[x, y], &[0.0, 0.0]) <= tolerance {
+            self.offset.set((0.0, 0.0));
+            self.locked.set(true);
+
+            AUDIO_MIXER.with(|mixer| {
+                let audio_path: AudioPath<'_> = mixer.get_random_positive().into();
+                mixer.play_oneshot(audio_path);
+            });
+
+            true
+        } else {
+            if fly_back_to_origin {
+                self.offset.set(self.picked_from.get());
+            }
+
+            AUDIO_MIXER.with(|mixer| {
+                let audio_path: AudioPath<'_> = mixer.get_random_negative().into();
+                mixer.play_oneshot(audio_path);
+            });
+
+            false
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use super::state::*;
+use dominator::{clone, html, with_node, Dom};
+use futures_signals::signal::SignalExt;
+use std::rc::Rc;
+use utils::prelude::*;
+use web_sys::HtmlCanvasElement;
+
+pub fn render(state: Rc<Game>) -> Dom {
+    html!("canvas" => HtmlCanvasElement, {
+        .style("position", "absolute")
+        .style("top", "0")
+        .style("left", "0")
+        .style("width", "100%")
+        .style("height", "100%")
+        .style("touch-action", "none")
+        .with_node!(canvas => {
+            .future(clone!(state => async move {
+                state.load(canvas).await;
+            }))
+        })
+        .future(state.draw_signal().for_each(clone!(state => move |(phase, resize_info)| {
+            if phase != Phase::Loading {
+                state.draw(&resize_info);
+            }
+            async {}
+        })))
+        .event(clone!(state => move |evt:events::PointerDown| {
+            state.start_drag(evt.x(), evt.y());
+        }))
+        .global_event(clone!(state => move |evt:events::PointerMove| {
+            state.try_move_drag(evt.x(), evt.y());
+        }))
+        .global_event(clone!(state => move |_evt:events::PointerUp| {
+            state.try_end_drag();
+        }))
+        .global_event(clone!(state => move |_evt:events::PointerCancel| {
+            state.try_end_drag();
+        }))
+    })
+}
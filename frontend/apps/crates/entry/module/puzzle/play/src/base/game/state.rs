@@ -0,0 +1,143 @@
+use crate::base::state::*;
+use components::traces::utils::TraceExt;
+use dominator::animation::MutableAnimation;
+use dominator_helpers::futures::AsyncLoader;
+use futures_signals::{
+    map_ref,
+    signal::{Mutable, Signal},
+};
+use rand::prelude::*;
+use shared::domain::jig::module::body::_groups::design::Trace;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use utils::{
+    drag::Drag,
+    image_effects::ImageEffect,
+    math::{vec2, BoundsF64},
+    resize::{resize_info_signal, ResizeInfo},
+};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
+
+pub struct Game {
+    pub base: Rc<Base>,
+    pub phase: Mutable<Phase>,
+    pub pieces: Vec<Rc<Piece>>,
+    pub canvas: RefCell<Option<(HtmlCanvasElement, CanvasRenderingContext2d)>>,
+    pub image: RefCell<Option<ImageEffect>>,
+    pub drag_index: Cell<Option<usize>>,
+    pub preview: MutableAnimation,
+    pub loader: AsyncLoader,
+}
+
+impl Game {
+    pub fn new(base: Rc<Base>) -> Rc<Self> {
+        let tolerance = base.settings.snap_tolerance.distance();
+        let show_preview = base.settings.show_preview;
+
+        let pieces = base
+            .items
+            .iter()
+            .filter_map(|item| Piece::new(item.trace.clone(), tolerance, show_preview))
+            .collect();
+
+        Rc::new(Self {
+            base,
+            phase: Mutable::new(Phase::Loading),
+            pieces,
+            canvas: RefCell::new(None),
+            image: RefCell::new(None),
+            drag_index: Cell::new(None),
+            preview: MutableAnimation::new(crate::config::PREVIEW_DURATION),
+            loader: AsyncLoader::new(),
+        })
+    }
+
+    pub fn draw_signal(&self) -> impl Signal<Item = (Phase, ResizeInfo)> {
+        map_ref! {
+            let phase = self.phase.signal(),
+            let resize_info = resize_info_signal()
+            => {
+                (*phase, resize_info.clone())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Loading,
+    Preview,
+    Playing,
+    Finished,
+}
+
+pub struct Piece {
+    pub trace: Trace,
+    /// The bounds of the trace shape, prior to its transform
+    pub bounds: BoundsF64,
+    /// Where the piece starts out, relative to its place
+    pub scattered: (f64, f64),
+    /// Where the piece currently is, relative to its place
+    pub offset: Cell<(f64, f64)>,
+    /// Where the piece was picked up from, for flying back
+    pub picked_from: Cell<(f64, f64)>,
+    pub drag: RefCell<Option<Rc<Drag>>>,
+    pub locked: Cell<bool>,
+    /// wrong drops since the piece was last (re)started
+    pub mistakes: Cell<u32>,
+    /// out of attempts, waiting for the other pieces before it's retried
+    pub set_aside: Cell<bool>,
+    /// out of attempts without the answer shown, its hole stays empty
+    pub missed: Cell<bool>,
+}
+
+impl Piece {
+    pub fn new(trace: Trace, tolerance: f64, show_preview: bool) -> Option<Rc<Self>> {
+        let bounds = trace.calc_bounds(false)?;
+        let placed = trace.calc_bounds(true)?;
+
+        let scattered = scatter(&placed, tolerance);
+
+        Some(Rc::new(Self {
+            trace,
+            bounds,
+            scattered,
+            offset: Cell::new(if show_preview { (0.0, 0.0) } else { scattered }),
+            picked_from: Cell::new(scattered),
+            drag: RefCell::new(None),
+            locked: Cell::new(false),
+            mistakes: Cell::new(0),
+            set_aside: Cell::new(false),
+            missed: Cell::new(false),
+        }))
+    }
+}
+
+// Moves the piece somewhere random on the stage, but not so close to its place that it would
+// already snap in
+fn scatter(placed: &BoundsF64, tolerance: f64) -> (f64, f64) {
+    let mut rng = thread_rng();
+
+    let mut offset = (0.0, 0.0);
+
+    for _ in 0..crate::config::SCATTER_ATTEMPTS {
+        offset = (
+            random_in(&mut rng, -placed.x, 1.0 - (placed.x + placed.width)),
+            random_in(&mut rng, -placed.y, 1.0 - (placed.y + placed.height)),
+        );
+
+        if vec2::distance(&[offset.0, offset.1], &[0.0, 0.0]) > tolerance {
+            break;
+        }
+    }
+
+    offset
+}
+
+fn random_in(rng: &mut ThreadRng, min: f64, max: f64) -> f64 {
+    if min < max {
+        rng.gen_range(min..max)
+    } else {
+        0.0
+    }
+}
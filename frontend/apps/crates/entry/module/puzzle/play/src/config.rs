@@ -0,0 +1,11 @@
+/// How long the finished picture takes to break apart
+pub const PREVIEW_DURATION: f64 = 1000.0;
+/// How long the finished picture is shown before it breaks apart
+pub const PREVIEW_DELAY: u32 = 500;
+/// How many times a piece is re-scattered if it lands where it would already snap in
+pub const SCATTER_ATTEMPTS: usize = 10;
+
+pub const HOLE_COLOR: &str = "rgba(0, 0, 0, 0.5)";
+pub const PIECE_OUTLINE_COLOR: &str = "white";
+/// Piece fill when there's no image background to cut them from
+pub const PIECE_FALLBACK_COLOR: &str = "#d8e7fa";
@@ -0,0 +1,121 @@
+use super::{
+    footer::state::Footer,
+    header::state::Header,
+    main::state::Main,
+    overlay::state::Overlay,
+    sidebar::state::Sidebar,
+    state::{Base, Item},
+};
+use components::module::_common::edit::prelude::*;
+use shared::domain::jig::module::body::{
+    Audio,
+    _groups::design::Trace,
+    talk_type::{AnswerKind, Mode, ModuleData as RawData, Step, TalkTypeItem},
+};
+use std::rc::Rc;
+
+pub async fn init_from_raw(
+    init_args: BaseInitFromRawArgs<RawData, Mode, Step>,
+) -> BaseInit<Step, Base, Main, Sidebar, Header, Footer, Overlay> {
+    let force_step = {
+        if init_args.source == InitSource::ForceRaw {
+            crate::debug::settings().step
+        } else {
+            None
+        }
+    };
+
+    let base = Base::new(init_args).await;
+
+    BaseInit {
+        force_step,
+        force_theme: None,
+        base: base.clone(),
+        main: Rc::new(Main::new(base.clone())),
+        sidebar: Rc::new(Sidebar::new(base.clone())),
+        header: Rc::new(Header::new(base.clone())),
+        footer: Rc::new(Footer::new(base.clone())),
+        overlay: Rc::new(Overlay::new(base)),
+    }
+}
+
+impl Base {
+    /*
+     * The traces themselves are managed by the component
+     * Callbacks here are fired from there and need only to manage
+     * the answers and history
+     */
+    pub fn on_trace_added(&self, trace: Trace) {
+        let item = TalkTypeItem::new(trace);
+
+        self.items.lock_mut().push_cloned(Item::new(&item));
+
+        self.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                content.items.push(item);
+            }
+        });
+    }
+
+    pub fn on_trace_deleted(&self, index: usize) {
+        self.items.lock_mut().remove(index);
+
+        self.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                content.items.remove(index);
+            }
+        });
+    }
+
+    pub fn on_trace_changed(&self, index: usize, raw_trace: Trace) {
+        self.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                content.items[index].trace = raw_trace;
+            }
+        });
+    }
+
+    pub fn set_answer_kind(&self, index: usize, answer_kind: AnswerKind) {
+        if let Some(item) = self.items.lock_ref().get(index) {
+            item.answer_kind.set_neq(answer_kind);
+        }
+
+        self.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                content.items[index].answer_kind = answer_kind;
+            }
+        });
+    }
+
+    /// Answers are edited as one per line, empty lines are dropped
+    pub fn set_answers(&self, index: usize, value: &str) {
+        let answers: Vec<String> = value
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| line.to_string())
+            .collect();
+
+        if let Some(item) = self.items.lock_ref().get(index) {
+            item.answers.set(answers.clone());
+        }
+
+        self.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                content.items[index].answers = answers;
+            }
+        });
+    }
+
+    pub fn set_correct_audio(&self, index: usize, audio: Option<Audio>) {
+        if let Some(item) = self.items.lock_ref().get(index) {
+            item.correct_audio.set(audio.clone());
+        }
+
+        self.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                content.items[index].correct_audio = audio;
+            }
+        });
+    }
+}
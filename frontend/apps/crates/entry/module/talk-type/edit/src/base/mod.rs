@@ -0,0 +1,7 @@
+pub mod actions;
+pub mod footer;
+pub mod header;
+pub mod main;
+pub mod overlay;
+pub mod sidebar;
+pub mod state;
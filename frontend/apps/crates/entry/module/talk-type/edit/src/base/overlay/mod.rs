@@ -0,0 +1,2 @@
+pub mod dom;
+pub mod state;
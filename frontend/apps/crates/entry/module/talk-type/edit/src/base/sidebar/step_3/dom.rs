@@ -0,0 +1,134 @@
+use super::state::*;
+use std::rc::Rc;
+
+use components::{
+    audio::input::AudioInput,
+    tabs::{MenuTab, MenuTabKind},
+};
+use dominator::{clone, html, with_node, Dom};
+use futures_signals::signal::{Mutable, SignalExt};
+use shared::domain::jig::module::body::talk_type::AnswerKind;
+use utils::prelude::*;
+use web_sys::HtmlTextAreaElement;
+
+const STR_EMPTY_SELECTION: &str = "Trace an area and select it to add a question and answers";
+const STR_SPOKEN: &str = "The student says the answer out loud";
+const STR_ANSWERS_LABEL: &str = "Correct answers";
+const STR_ANSWERS_PLACEHOLDER: &str = "One answer per line";
+const STR_CORRECT_AUDIO: &str = "Play when answered correctly";
+
+pub fn render(state: Rc<Step3>) -> Dom {
+    html!("empty-fragment", {
+        .style("display", "contents")
+        .child_signal(
+            //we need both an ability to change tabs, and to know if we should show tabs
+            //so get a Mutable<Option<MenuTabKind>>
+            state.selected_tab_signal().map(clone!(state => move |selected_tab| {
+                selected_tab.signal_cloned().map(clone!(selected_tab, state => move |kind| {
+                    //from selected_tab kind is a None, no trace is selected - don't show anything
+                    //TODO- empty-fragment so we can set tab_index?
+
+                    match kind {
+                        Some(_) => {
+                            //otherwise, it means a trace is selected
+                            Some(html!("menu-tabs", {
+                                .future(selected_tab.signal_cloned().dedupe().for_each(clone!(state => move |kind| {
+                                    state.sidebar.tab_kind.set(kind);
+                                    async move {}
+                                })))
+                                .children(&mut [
+                                    //pass down our mutable so that we can switch tabs
+                                    render_tab(state.clone(), MenuTabKind::Audio, selected_tab.clone()),
+                                    render_tab(state.clone(), MenuTabKind::Answer, selected_tab.clone()),
+                                    html!("module-sidebar-body", {
+                                        .property("slot", "body")
+                                        .child_signal(
+                                            //based on the selected tab kind, create and render the tab state
+                                            state
+                                                .tab_signal(selected_tab.signal())
+                                                .map(clone!(state => move |tab| {
+                                                    tab.map(|tab| {
+                                                        render_tab_body(state.clone(), tab)
+                                                    })
+                                                }))
+                                        )
+                                    })
+                                ])
+                            }))
+                        }
+                        None => {
+                            Some(html!("sidebar-empty", {
+                                .property("label", STR_EMPTY_SELECTION)
+                                .property("imagePath", "module/_common/edit/sidebar/illustration-trace-area.svg")
+                            }))
+                        }
+                    }
+                }))
+            }))
+            .flatten()
+        )
+    })
+}
+
+fn render_tab(
+    _state: Rc<Step3>,
+    tab_kind: MenuTabKind,
+    selected_tab: Mutable<Option<MenuTabKind>>,
+) -> Dom {
+    MenuTab::render(
+        MenuTab::new(
+            tab_kind,
+            false,
+            true,
+            clone!(selected_tab => move || selected_tab.signal_ref(clone!(tab_kind => move |curr| {
+                match curr {
+                    Some(curr) => *curr == tab_kind,
+                    None => false
+                }
+            }))),
+            clone!(tab_kind => move || {
+                selected_tab.set_neq(Some(tab_kind));
+            }),
+        ),
+        Some("tabs"),
+    )
+}
+
+fn render_tab_body(state: Rc<Step3>, tab: Tab) -> Dom {
+    match tab {
+        Tab::Audio(audio_state) => AudioInput::render(audio_state, None),
+        Tab::Answer(index, item, audio_state) => {
+            let base = state.sidebar.base.clone();
+
+            html!("div", {
+                .child(html!("input-checkbox", {
+                    .property("label", STR_SPOKEN)
+                    .property_signal("checked", item.answer_kind.signal().map(|kind| kind == AnswerKind::Spoken))
+                    .event(clone!(base => move |evt:events::CustomToggle| {
+                        let answer_kind = if evt.value() { AnswerKind::Spoken } else { AnswerKind::Text };
+                        base.set_answer_kind(index, answer_kind);
+                    }))
+                }))
+                .child(html!("input-wrapper", {
+                    .property("label", STR_ANSWERS_LABEL)
+                    .child(html!("textarea" => HtmlTextAreaElement, {
+                        .with_node!(elem => {
+                            .property_signal("value", item.answers.signal_ref(|answers| answers.join("\n")))
+                            .property("placeholder", STR_ANSWERS_PLACEHOLDER)
+                            .property("rows", 4)
+                            .property("dir", "auto")
+                            //Change pushes history and sets at a higher level
+                            .event(clone!(base => move |_evt:events::Change| {
+                                base.set_answers(index, &elem.value());
+                            }))
+                        })
+                    }))
+                }))
+                .child(html!("div", {
+                    .text(STR_CORRECT_AUDIO)
+                }))
+                .child(AudioInput::render(audio_state, None))
+            })
+        }
+    }
+}
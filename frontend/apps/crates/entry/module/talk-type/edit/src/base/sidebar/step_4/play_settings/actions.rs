@@ -0,0 +1,125 @@
+use super::state::State;
+use shared::domain::jig::module::body::_groups::assessment::RetryPolicy;
+
+impl State {
+    pub fn set_show_hints(&self, show_hints: bool) {
+        self.base.play_settings.show_hints.set_neq(show_hints);
+
+        self.base.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                content.play_settings.show_hints = show_hints;
+            }
+        })
+    }
+
+    pub fn set_allow_typos(&self, flag: bool) {
+        let max_typos = if flag {
+            self.base.play_settings.typos_value.get()
+        } else {
+            0
+        };
+
+        self.base.play_settings.max_typos.set_neq(max_typos);
+
+        self.base.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                content.play_settings.max_typos = max_typos;
+            }
+        })
+    }
+
+    pub fn set_typos_value(&self, max_typos: u8) {
+        self.base.play_settings.typos_value.set_neq(max_typos);
+
+        if self.base.play_settings.max_typos.get() > 0 {
+            self.base.play_settings.max_typos.set_neq(max_typos);
+
+            self.base.history.push_modify(move |raw| {
+                if let Some(content) = &mut raw.content {
+                    content.play_settings.max_typos = max_typos;
+                }
+            })
+        }
+    }
+
+    pub fn set_has_time_limit(&self, flag: bool) {
+        self.base.play_settings.has_time_limit.set_neq(flag);
+
+        self.base.history.push_modify(|raw| {
+            if let Some(content) = &mut raw.content {
+                if !flag {
+                    content.play_settings.assessment.time_limit = None;
+                } else {
+                    let value = self.base.play_settings.time_limit.get();
+                    content.play_settings.assessment.time_limit = Some(value);
+                }
+            }
+        })
+    }
+
+    pub fn set_time_limit(&self, time_limit: u32) {
+        self.base.play_settings.time_limit.set_neq(time_limit);
+
+        if self.base.play_settings.has_time_limit.get() {
+            self.base.history.push_modify(|raw| {
+                if let Some(content) = &mut raw.content {
+                    content.play_settings.assessment.time_limit = Some(time_limit);
+                }
+            })
+        }
+    }
+
+    pub fn set_has_attempts_limit(&self, flag: bool) {
+        self.base.play_settings.has_attempts_limit.set_neq(flag);
+
+        self.base.history.push_modify(|raw| {
+            if let Some(content) = &mut raw.content {
+                if !flag {
+                    content.play_settings.assessment.attempts = None;
+                } else {
+                    let value = self.base.play_settings.attempts_limit.get();
+                    content.play_settings.assessment.attempts = Some(value);
+                }
+            }
+        })
+    }
+
+    pub fn set_attempts_limit(&self, n_attempts: u8) {
+        self.base.play_settings.attempts_limit.set_neq(n_attempts);
+
+        if self.base.play_settings.has_attempts_limit.get() {
+            self.base.history.push_modify(|raw| {
+                if let Some(content) = &mut raw.content {
+                    content.play_settings.assessment.attempts = Some(n_attempts);
+                }
+            })
+        }
+    }
+
+    pub fn toggle_show_answer(&self) {
+        let show_answer = !self.base.play_settings.show_answer.get();
+
+        self.base.play_settings.show_answer.set_neq(show_answer);
+
+        self.base.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                content.play_settings.assessment.show_answer = show_answer;
+            }
+        })
+    }
+
+    pub fn toggle_retry(&self) {
+        let retry = match self.base.play_settings.retry.get() {
+            RetryPolicy::Never => RetryPolicy::Later,
+            RetryPolicy::Later => RetryPolicy::Never,
+        };
+
+        self.base.play_settings.retry.set_neq(retry);
+
+        self.base.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                content.play_settings.assessment.retry = retry;
+            }
+        })
+    }
+}
@@ -0,0 +1,177 @@
+use dominator::{clone, Dom};
+use futures_signals::signal::SignalExt;
+use std::rc::Rc;
+
+use super::state::State;
+use shared::domain::jig::module::body::_groups::assessment::RetryPolicy;
+
+use components::module::_common::edit::settings::prelude::*;
+pub fn render(state: Rc<State>) -> Dom {
+    render_settings(Rc::new(ModuleSettings {
+        lines: vec![
+            (
+                LineKind::LetterHints,
+                vec![
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::LetterHint,
+                        clone!(state => move || {
+                            state.base.play_settings.show_hints.signal()
+                        }),
+                        clone!(state => move || {
+                            state.set_show_hints(true);
+                        }),
+                    )),
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::LetterHintOff,
+                        clone!(state => move || {
+                            state.base.play_settings.show_hints.signal_ref(|curr| !curr)
+                        }),
+                        clone!(state => move || {
+                            state.set_show_hints(false);
+                        }),
+                    )),
+                ],
+            ),
+            (
+                LineKind::Spelling,
+                vec![
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::ExactSpelling,
+                        clone!(state => move || {
+                            state.base.play_settings.max_typos
+                                .signal()
+                                .map(|max_typos| max_typos == 0)
+                        }),
+                        clone!(state => move || {
+                            state.set_allow_typos(false);
+                        }),
+                    )),
+                    Some(SettingsButton::new_value_click(
+                        SettingsButtonKind::AllowTypos,
+                        clone!(state => move || {
+                            state.base.play_settings.max_typos
+                                .signal()
+                                .map(|max_typos| max_typos > 0)
+                        }),
+                        SettingsValue::new(
+                            state.base.play_settings.typos_value.get(),
+                            clone!(state => move |value| {
+                                state.set_typos_value(value);
+                            }),
+                        ),
+                        clone!(state => move || {
+                            state.set_allow_typos(true);
+                        }),
+                    )),
+                ],
+            ),
+            (
+                LineKind::TimeLimit,
+                vec![
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::TimeLimitOff,
+                        clone!(state => move || {
+                            state.base.play_settings.has_time_limit
+                                .signal()
+                                .map(|flag| !flag)
+                        }),
+                        clone!(state => move || {
+                            state.set_has_time_limit(false);
+                        }),
+                    )),
+                    Some(SettingsButton::new_value_click(
+                        SettingsButtonKind::TimeLimit,
+                        clone!(state => move || {
+                            state.base.play_settings.has_time_limit
+                                .signal()
+                        }),
+                        SettingsValue::new(
+                            state.base.play_settings.time_limit.get(),
+                            clone!(state => move |value| {
+                                state.set_time_limit(value);
+                            }),
+                        ),
+                        clone!(state => move || {
+                            state.set_has_time_limit(true);
+                        }),
+                    )),
+                ],
+            ),
+            (
+                LineKind::Attempts,
+                vec![
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::NoLimit,
+                        clone!(state => move || {
+                            state.base.play_settings.has_attempts_limit
+                                .signal()
+                                .map(|flag| !flag)
+                        }),
+                        clone!(state => move || {
+                            state.set_has_attempts_limit(false);
+                        }),
+                    )),
+                    Some(SettingsButton::new_value_click(
+                        SettingsButtonKind::Attempts,
+                        clone!(state => move || {
+                            state.base.play_settings.has_attempts_limit
+                                .signal()
+                        }),
+                        SettingsValue::new(
+                            state.base.play_settings.attempts_limit.get(),
+                            clone!(state => move |value| {
+                                state.set_attempts_limit(value);
+                            }),
+                        ),
+                        clone!(state => move || {
+                            state.set_has_attempts_limit(true);
+                        }),
+                    )),
+                ],
+            ),
+            (
+                LineKind::AfterAttempts,
+                vec![
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::ShowAnswer,
+                        clone!(state => move || {
+                            state.base.play_settings.show_answer.signal()
+                        }),
+                        clone!(state => move || {
+                            state.toggle_show_answer();
+                        }),
+                    )),
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::RetryLater,
+                        clone!(state => move || {
+                            state.base.play_settings.retry
+                                .signal()
+                                .map(|retry| retry == RetryPolicy::Later)
+                        }),
+                        clone!(state => move || {
+                            state.toggle_retry();
+                        }),
+                    )),
+                ],
+            ),
+        ],
+    }))
+}
+
+fn render_snap_button(
+    state: Rc<State>,
+    kind: SettingsButtonKind,
+    snap_tolerance: SnapTolerance,
+) -> Option<Rc<SettingsButton>> {
+    Some(SettingsButton::new_click(
+        kind,
+        clone!(state => move || {
+            state.base.play_settings.snap_tolerance.signal_ref(move |curr| {
+                *curr == snap_tolerance
+            })
+        }),
+        clone!(state => move || {
+            state.set_snap_tolerance(snap_tolerance);
+        }),
+    ))
+}
@@ -0,0 +1,12 @@
+use crate::base::state::Base;
+use std::rc::Rc;
+
+pub struct State {
+    pub base: Rc<Base>,
+}
+
+impl State {
+    pub fn new(base: Rc<Base>) -> Self {
+        Self { base }
+    }
+}
@@ -0,0 +1,282 @@
+use components::module::_common::edit::prelude::*;
+
+use components::module::_groups::design::edit::design_ext::DesignExt;
+use components::{
+    backgrounds::{callbacks::Callbacks as BackgroundsCallbacks, state::Backgrounds},
+    stickers::{
+        callbacks::Callbacks as StickersCallbacks,
+        state::{Sticker, Stickers},
+    },
+    text_editor::{callbacks::Callbacks as TextEditorCallbacks, state::State as TextEditorState},
+    traces::edit::{TracesEdit, TracesEditCallbacks},
+};
+use dominator::clone;
+use futures_signals::{
+    signal::{self, Mutable, ReadOnlyMutable, Signal},
+    signal_vec::MutableVec,
+};
+use shared::domain::jig::module::body::BodyExt;
+use shared::domain::jig::{
+    module::{
+        body::{
+            Audio, Instructions,
+            _groups::{
+                assessment::RetryPolicy,
+                design::{Trace, TraceKind},
+            },
+            talk_type::{
+                AnswerKind, Mode, ModuleData as RawData, PlaySettings as RawPlaySettings, Step,
+                TalkTypeItem,
+            },
+        },
+        ModuleId,
+    },
+    JigId,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+use utils::prelude::*;
+pub struct Base {
+    pub history: Rc<HistoryStateImpl<RawData>>,
+    pub step: ReadOnlyMutable<Step>,
+    pub theme_id: Mutable<ThemeId>,
+    pub instructions: Mutable<Instructions>,
+    pub jig_id: JigId,
+    pub module_id: ModuleId,
+    // TalkType-specific
+    pub backgrounds: Rc<Backgrounds>,
+    pub stickers: Rc<Stickers<Sticker>>,
+    pub traces: Rc<TracesEdit>,
+    pub items: MutableVec<Rc<Item>>,
+    pub text_editor: Rc<TextEditorState>,
+    pub play_settings: Rc<PlaySettings>,
+}
+
+pub struct PlaySettings {
+    pub show_hints: Mutable<bool>,
+    pub max_typos: Mutable<u8>,
+    pub typos_value: Mutable<u8>,
+    pub attempts_limit: Mutable<u8>,
+    pub has_attempts_limit: Mutable<bool>,
+    pub time_limit: Mutable<u32>,
+    pub has_time_limit: Mutable<bool>,
+    pub show_answer: Mutable<bool>,
+    pub retry: Mutable<RetryPolicy>,
+}
+
+impl PlaySettings {
+    pub fn new(settings: RawPlaySettings) -> Self {
+        Self {
+            show_hints: Mutable::new(settings.show_hints),
+            max_typos: Mutable::new(settings.max_typos),
+            typos_value: Mutable::new(settings.max_typos.max(crate::config::DEFAULT_TYPOS)),
+            attempts_limit: Mutable::new(
+                settings
+                    .assessment
+                    .attempts
+                    .unwrap_or(crate::config::DEFAULT_ATTEMPTS_LIMIT),
+            ),
+            has_attempts_limit: Mutable::new(settings.assessment.attempts.is_some()),
+            time_limit: Mutable::new(
+                settings
+                    .assessment
+                    .time_limit
+                    .unwrap_or(crate::config::DEFAULT_TIME_LIMIT),
+            ),
+            has_time_limit: Mutable::new(settings.assessment.time_limit.is_some()),
+            show_answer: Mutable::new(settings.assessment.show_answer),
+            retry: Mutable::new(settings.assessment.retry),
+        }
+    }
+}
+
+/// The answer side of a slot, the trace itself is managed by [`TracesEdit`]
+pub struct Item {
+    pub answer_kind: Mutable<AnswerKind>,
+    pub answers: Mutable<Vec<String>>,
+    pub correct_audio: Mutable<Option<Audio>>,
+}
+
+impl Item {
+    pub fn new(raw: &TalkTypeItem) -> Rc<Self> {
+        Rc::new(Self {
+            answer_kind: Mutable::new(raw.answer_kind),
+            answers: Mutable::new(raw.answers.clone()),
+            correct_audio: Mutable::new(raw.correct_audio.clone()),
+        })
+    }
+}
+
+impl Base {
+    pub async fn new(init_args: BaseInitFromRawArgs<RawData, Mode, Step>) -> Rc<Self> {
+        let BaseInitFromRawArgs {
+            raw,
+            jig_id,
+            module_id,
+            history,
+            step,
+            theme_id,
+            ..
+        } = init_args;
+
+        let content = raw.content.unwrap_ji();
+
+        let _self_ref: Rc<RefCell<Option<Rc<Self>>>> = Rc::new(RefCell::new(None));
+
+        let instructions = Mutable::new(content.base.instructions);
+
+        let stickers_ref: Rc<RefCell<Option<Rc<Stickers<Sticker>>>>> = Rc::new(RefCell::new(None));
+
+        let text_editor = TextEditorState::new(
+            theme_id.read_only(),
+            None,
+            TextEditorCallbacks::new(
+                //New text
+                Some(clone!(stickers_ref => move |value:&str| {
+                    if let Some(stickers) = stickers_ref.borrow().as_ref() {
+                        Stickers::add_text(stickers.clone(), value.to_string());
+                    }
+                })),
+                //Text change
+                Some(clone!(stickers_ref => move |value:&str| {
+                    if let Some(stickers) = stickers_ref.borrow().as_ref() {
+                        stickers.set_current_text_value(value.to_string());
+                    }
+                })),
+                //Blur
+                Some(clone!(stickers_ref => move || {
+                    if let Some(stickers) = stickers_ref.borrow().as_ref() {
+                        stickers.stop_current_text_editing();
+                    }
+                })),
+            ),
+        );
+
+        let backgrounds = Rc::new(Backgrounds::from_raw(
+            &content.base.backgrounds,
+            theme_id.read_only(),
+            BackgroundsCallbacks::new(Some(clone!(history => move |raw_bgs| {
+                history.push_modify(|raw| {
+                    if let Some(content) = &mut raw.content {
+                        content.base.backgrounds = raw_bgs;
+                    }
+                });
+            }))),
+        ));
+
+        let stickers = Stickers::new(
+            text_editor.clone(),
+            StickersCallbacks::new(Some(clone!(history => move |stickers:&[Sticker]| {
+                history.push_modify(|raw| {
+                    if let Some(content) = &mut raw.content {
+                        content.base.stickers = stickers
+                            .iter()
+                            .map(|sticker| {
+                                sticker.to_raw()
+                            })
+                            .collect();
+                    }
+                });
+            }))),
+        );
+
+        stickers.replace_all(
+            content
+                .base
+                .stickers
+                .iter()
+                .map(|raw_sticker| Sticker::new(stickers.clone(), raw_sticker))
+                .collect::<Vec<Sticker>>(),
+        );
+
+        *stickers_ref.borrow_mut() = Some(stickers.clone());
+
+        let raw_traces: Vec<Trace> = content
+            .items
+            .iter()
+            .map(|item| item.trace.clone())
+            .collect();
+
+        let items = MutableVec::new_with_values(content.items.iter().map(Item::new).collect());
+
+        let traces = TracesEdit::from_raw(
+            &raw_traces,
+            crate::debug::settings()
+                .draw_kind
+                .unwrap_or(TraceKind::Regular),
+            TracesEditCallbacks::new(
+                Some(clone!(_self_ref => move |raw_trace| {
+                    if let Some(_self) = _self_ref.borrow().as_ref() {
+                        _self.on_trace_added(raw_trace);
+                    }
+                })),
+                Some(clone!(_self_ref => move |index| {
+                    if let Some(_self) = _self_ref.borrow().as_ref() {
+                        _self.on_trace_deleted(index);
+                    }
+                })),
+                Some(clone!(_self_ref => move |index, raw_trace| {
+                    if let Some(_self) = _self_ref.borrow().as_ref() {
+                        _self.on_trace_changed(index, raw_trace);
+                    }
+                })),
+            ),
+        );
+
+        let _self = Rc::new(Self {
+            jig_id,
+            module_id,
+            theme_id,
+            history,
+            step: step.read_only(),
+            instructions,
+            text_editor,
+            backgrounds,
+            stickers,
+            traces,
+            items,
+            play_settings: Rc::new(PlaySettings::new(content.play_settings)),
+        });
+
+        *_self_ref.borrow_mut() = Some(_self.clone());
+
+        _self
+    }
+}
+
+impl BaseExt<Step> for Base {
+    type NextStepAllowedSignal = impl Signal<Item = bool>;
+
+    fn allowed_step_change(&self, _from: Step, _to: Step) -> bool {
+        true
+    }
+
+    fn next_step_allowed_signal(&self) -> Self::NextStepAllowedSignal {
+        signal::always(true)
+    }
+
+    fn get_jig_id(&self) -> JigId {
+        self.jig_id
+    }
+    fn get_module_id(&self) -> ModuleId {
+        self.module_id
+    }
+}
+
+impl DesignExt for Base {
+    fn get_backgrounds(&self) -> Rc<Backgrounds> {
+        Rc::clone(&self.backgrounds)
+    }
+
+    fn get_theme(&self) -> Mutable<ThemeId> {
+        self.theme_id.clone()
+    }
+
+    fn set_theme(&self, theme: ThemeId) {
+        self.theme_id.set(theme);
+
+        self.history.push_modify(|raw| {
+            raw.set_theme(theme);
+        });
+    }
+}
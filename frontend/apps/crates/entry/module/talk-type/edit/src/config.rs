@@ -0,0 +1,4 @@
+/// The typos allowed when first switching typos on
+pub const DEFAULT_TYPOS: u8 = 1;
+pub const DEFAULT_TIME_LIMIT: u32 = 2;
+pub const DEFAULT_ATTEMPTS_LIMIT: u8 = 2;
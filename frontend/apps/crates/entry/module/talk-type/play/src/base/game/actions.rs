@@ -0,0 +1,154 @@
+use super::state::*;
+use components::{
+    audio::mixer::{AudioPath, AudioSourceExt, AUDIO_MIXER},
+    module::_common::play::prelude::*,
+};
+use shared::domain::jig::module::body::{_groups::assessment::AfterMistake, talk_type::AnswerKind};
+
+impl Game {
+    /// Slots which ran out of attempts get another go once everything else is answered
+    fn release_waiting(&self) {
+        if self.slots.iter().all(|slot| {
+            let phase = slot.phase.get();
+            phase.is_finished() || phase == SlotPhase::Waiting
+        }) {
+            for slot in self.slots.iter() {
+                if slot.phase.get() == SlotPhase::Waiting {
+                    slot.phase.set_neq(SlotPhase::Input);
+                }
+            }
+        }
+    }
+
+    fn evaluate_end(&self) {
+        if self.slots.iter().all(|slot| slot.phase.get().is_finished()) {
+            self.base
+                .set_play_phase(ModulePlayPhase::Ending(Some(ModuleEnding::Positive)));
+        }
+    }
+}
+
+impl Slot {
+    /// The trace audio is the question
+    pub fn play_question(&self) {
+        if let Some(audio) = self.item.trace.audio.as_ref() {
+            AUDIO_MIXER.with(|mixer| {
+                mixer.play_oneshot(audio.as_source());
+            });
+        }
+    }
+
+    pub fn on_focus(&self) {
+        if self.item.answer_kind == AnswerKind::Text {
+            self.play_question();
+        }
+    }
+
+    pub fn on_input(&self, value: String) {
+        if self.phase.get().can_answer() {
+            self.phase.set_neq(SlotPhase::Input);
+            self.value.set_neq(value);
+        }
+    }
+
+    pub fn evaluate(&self, game: &Game) {
+        if self.item.answer_kind != AnswerKind::Text || !self.phase.get().can_answer() {
+            return;
+        }
+
+        let value = self.value.get_cloned();
+
+        if self.item.is_correct(&value, game.base.settings.max_typos) {
+            self.set_correct(game);
+        } else {
+            AUDIO_MIXER.with(|mixer| {
+                let audio_path: AudioPath<'_> = mixer.get_random_negative().into();
+                mixer.play_oneshot(audio_path);
+            });
+
+            self.mistakes.set(self.mistakes.get() + 1);
+
+            if let AfterMistake::OutOfAttempts {
+                show_answer,
+                retry_later,
+            } = game
+                .base
+                .settings
+                .assessment
+                .after_mistake(self.mistakes.get())
+            {
+                self.out_of_attempts(game, show_answer, retry_later);
+                return;
+            }
+
+            if game.base.settings.show_hints {
+                let hint_letters = &mut *self.hint_letters.borrow_mut();
+
+                if let Some(index) = hint_letters.indices.pop() {
+                    hint_letters.letters[index].revealed = true;
+                    self.hint.set(hint_letters.to_string());
+                }
+            }
+
+            // not set_neq because we want it to always re-render
+            self.phase.set(SlotPhase::Wrong);
+        }
+    }
+
+    fn out_of_attempts(&self, game: &Game, show_answer: bool, retry_later: bool) {
+        if retry_later {
+            // Showing the answer would give the retry away
+            self.mistakes.set(0);
+            self.value.set_neq("".to_string());
+            self.phase.set_neq(SlotPhase::Waiting);
+        } else {
+            let answer = match self.item.answers.first() {
+                Some(answer) if show_answer => answer.clone(),
+                _ => "".to_string(),
+            };
+            self.value.set_neq(answer);
+            self.phase.set_neq(SlotPhase::Missed);
+        }
+
+        game.release_waiting();
+        game.evaluate_end();
+    }
+
+    /// Spoken slots ask the question on the first tap, and reveal the answer on the next
+    pub fn on_click(&self, game: &Game) {
+        if self.item.answer_kind != AnswerKind::Spoken {
+            return;
+        }
+
+        match self.phase.get() {
+            SlotPhase::Input if self.item.trace.audio.is_some() => {
+                self.play_question();
+                self.phase.set_neq(SlotPhase::Asked);
+            }
+            SlotPhase::Input | SlotPhase::Asked => {
+                if let Some(answer) = self.item.answers.first() {
+                    self.value.set_neq(answer.clone());
+                }
+                self.set_correct(game);
+            }
+            _ => {}
+        }
+    }
+
+    fn set_correct(&self, game: &Game) {
+        self.phase.set_neq(SlotPhase::Correct);
+        game.release_waiting();
+
+        AUDIO_MIXER.with(|mixer| match self.item.correct_audio.as_ref() {
+            Some(audio) => {
+                mixer.play_oneshot(audio.as_source());
+            }
+            None => {
+                let audio_path: AudioPath<'_> = mixer.get_random_positive().into();
+                mixer.play_oneshot(audio_path);
+            }
+        });
+
+        game.evaluate_end();
+    }
+}
@@ -0,0 +1,81 @@
+use super::state::*;
+use crate::config::WRONG_TIME_MS;
+use dominator::{clone, html, Dom};
+use futures_signals::signal::SignalExt;
+use gloo_timers::future::TimeoutFuture;
+use shared::domain::jig::module::body::talk_type::AnswerKind;
+use std::rc::Rc;
+use utils::{
+    prelude::*,
+    resize::{resize_info_signal, ResizeInfo},
+};
+
+pub fn render(state: Rc<Game>) -> Dom {
+    html!("div", {
+        .style("position", "absolute")
+        .style("top", "0")
+        .style("left", "0")
+        .style("width", "100%")
+        .style("height", "100%")
+        .style("pointer-events", "none")
+        .children_signal_vec(
+            resize_info_signal().map(clone!(state => move |resize_info| {
+                state.slots
+                    .iter()
+                    .map(|slot| render_slot(state.clone(), slot.clone(), &resize_info))
+                    .collect()
+            }))
+            .to_signal_vec()
+        )
+    })
+}
+
+fn render_slot(state: Rc<Game>, slot: Rc<Slot>, resize_info: &ResizeInfo) -> Dom {
+    let bounds = slot.bounds.denormalize(resize_info);
+
+    html!("talk-type-input", {
+        .future(slot.phase.signal().for_each(clone!(slot => move |phase| {
+            clone!(slot => async move {
+                if phase == SlotPhase::Wrong {
+                    TimeoutFuture::new(WRONG_TIME_MS).await;
+                    // it may have run out of attempts in the meantime
+                    if slot.phase.get() == SlotPhase::Wrong {
+                        slot.phase.set_neq(SlotPhase::Input);
+                    }
+                }
+            })
+        })))
+        .property("y", bounds.y)
+        .property("x", bounds.x)
+        .property("width", bounds.width)
+        .property("height", bounds.height)
+        .property("kind", match slot.item.answer_kind {
+            AnswerKind::Text => "text",
+            AnswerKind::Spoken => "spoken",
+        })
+        .property_signal("value", slot.value.signal_cloned())
+        .property_signal("hint", slot.hint.signal_cloned())
+        .property_signal("state", slot.phase.signal().map(|phase| {
+            match phase {
+                SlotPhase::Input => "",
+                SlotPhase::Asked => "asked",
+                SlotPhase::Wrong => "wrong",
+                SlotPhase::Correct => "correct",
+                SlotPhase::Waiting => "waiting",
+                SlotPhase::Missed => "missed",
+            }
+        }))
+        .event(clone!(slot => move |_evt:events::Focus| {
+            slot.on_focus();
+        }))
+        .event(clone!(slot => move |evt:events::CustomInput| {
+            slot.on_input(evt.value());
+        }))
+        .event(clone!(state, slot => move |_evt:events::Enter| {
+            slot.evaluate(&state);
+        }))
+        .event(clone!(state, slot => move |_evt:events::Click| {
+            slot.on_click(&state);
+        }))
+    })
+}
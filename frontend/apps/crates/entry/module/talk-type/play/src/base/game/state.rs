@@ -0,0 +1,140 @@
+use crate::base::state::*;
+use components::traces::utils::TraceExt;
+use futures_signals::signal::Mutable;
+use rand::prelude::*;
+use shared::domain::jig::module::body::talk_type::{AnswerKind, TalkTypeItem};
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+use unicode_segmentation::UnicodeSegmentation;
+use utils::math::BoundsF64;
+
+pub struct Game {
+    pub base: Rc<Base>,
+    pub slots: Vec<Rc<Slot>>,
+}
+
+impl Game {
+    pub fn new(base: Rc<Base>) -> Rc<Self> {
+        let mut rng = thread_rng();
+
+        let slots = base
+            .items
+            .iter()
+            .filter_map(|item| Slot::new(item.clone(), &mut rng))
+            .collect();
+
+        Rc::new(Self { base, slots })
+    }
+}
+
+pub struct Slot {
+    pub item: TalkTypeItem,
+    /// The bounds of the trace, with its transform applied
+    pub bounds: BoundsF64,
+    pub value: Mutable<String>,
+    pub hint_letters: RefCell<HintLetters>,
+    /// The first answer, with the letters revealed so far
+    pub hint: Mutable<String>,
+    pub phase: Mutable<SlotPhase>,
+    /// wrong answers since the slot was last (re)started
+    pub mistakes: Cell<u32>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SlotPhase {
+    Input,
+    /// A spoken slot whose question was played, waiting for the student to reveal the answer
+    Asked,
+    Wrong,
+    Correct,
+    /// Out of attempts, waiting for the other slots before it's retried
+    Waiting,
+    /// Out of attempts, the answer is shown if the settings allow it
+    Missed,
+}
+
+impl SlotPhase {
+    /// Whether the slot is done with, right or wrong
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Self::Correct | Self::Missed)
+    }
+
+    pub fn can_answer(&self) -> bool {
+        matches!(self, Self::Input | Self::Asked | Self::Wrong)
+    }
+}
+
+impl Slot {
+    pub fn new(item: TalkTypeItem, rng: &mut ThreadRng) -> Option<Rc<Self>> {
+        // a typed slot without answers could never be completed
+        if item.answer_kind == AnswerKind::Text && item.answers.is_empty() {
+            return None;
+        }
+
+        let bounds = item.trace.calc_bounds(true)?;
+
+        let hint_letters = HintLetters::new(item.answers.first(), rng);
+
+        Some(Rc::new(Self {
+            item,
+            bounds,
+            value: Mutable::new("".to_string()),
+            hint_letters: RefCell::new(hint_letters),
+            hint: Mutable::new("".to_string()),
+            phase: Mutable::new(SlotPhase::Input),
+            mistakes: Cell::new(0),
+        }))
+    }
+}
+
+pub struct HintLetters {
+    pub letters: Vec<HintLetter>,
+    /// The letters left to reveal, in the order they will be revealed
+    pub indices: Vec<usize>,
+}
+
+pub struct HintLetter {
+    pub letter: String,
+    pub revealed: bool,
+}
+
+impl HintLetters {
+    pub fn new(answer: Option<&String>, rng: &mut ThreadRng) -> Self {
+        let letters: Vec<HintLetter> = answer
+            .map(|answer| {
+                answer
+                    .graphemes(true)
+                    .map(|letter| HintLetter {
+                        letter: letter.to_string(),
+                        revealed: false,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // spaces are shown from the start, never revealed as a hint
+        let mut indices: Vec<usize> = letters
+            .iter()
+            .enumerate()
+            .filter(|(_, letter)| !letter.letter.trim().is_empty())
+            .map(|(index, _)| index)
+            .collect();
+        indices.shuffle(rng);
+
+        Self { letters, indices }
+    }
+}
+
+impl fmt::Display for HintLetters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for s in self.letters.iter() {
+            if s.revealed || s.letter.trim().is_empty() {
+                write!(f, "{}", s.letter)?;
+            } else {
+                write!(f, "_")?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,2 @@
+/// How long a wrong answer is shown before the slot can be answered again
+pub const WRONG_TIME_MS: u32 = 3000;
@@ -2,10 +2,14 @@ use super::{
     footer::state::Footer, header::state::Header, main::state::Main, overlay::state::Overlay,
     sidebar::state::Sidebar, state::*,
 };
-use components::module::_common::edit::prelude::*;
+use components::module::{
+    _common::edit::prelude::*, _groups::design::structured::date::event_label,
+};
 use shared::domain::jig::module::body::{
     _groups::design::Trace,
-    tapping_board::{Mode, ModuleData as RawData, Step},
+    tapping_board::{
+        family_tree::FamilyTree, timeline::Timeline, Mode, ModuleData as RawData, Step,
+    },
 };
 use std::rc::Rc;
 
@@ -68,3 +72,39 @@ impl Base {
         });
     }
 }
+
+impl Base {
+    /*
+     * Timeline and family tree traces are never drawn by hand
+     * Every change lays them out again from the structured content
+     */
+    pub fn update_timeline(&self, f: impl FnOnce(&mut Timeline)) {
+        let timeline = {
+            let mut timeline = self.timeline.lock_mut();
+            f(&mut timeline);
+            timeline.clone()
+        };
+
+        self.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                content.timeline = Some(timeline);
+                content.layout_traces(event_label);
+            }
+        });
+    }
+
+    pub fn update_family_tree(&self, f: impl FnOnce(&mut FamilyTree)) {
+        let family_tree = {
+            let mut family_tree = self.family_tree.lock_mut();
+            f(&mut family_tree);
+            family_tree.clone()
+        };
+
+        self.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                content.family_tree = Some(family_tree);
+                content.layout_traces(event_label);
+            }
+        });
+    }
+}
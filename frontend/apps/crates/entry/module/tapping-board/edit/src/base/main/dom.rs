@@ -1,12 +1,16 @@
 use super::state::*;
 use components::{
     backgrounds::dom::render_backgrounds,
-    module::_common::edit::prelude::*,
+    module::{
+        _common::edit::prelude::*,
+        _groups::design::structured::{dom::render as render_cards, state::Cards},
+    },
     stickers::dom::{render_stickers, render_stickers_raw},
     traces::{bubble::TraceBubble, edit::TracesEdit},
 };
 use dominator::{clone, html, Dom};
 use futures_signals::{signal::SignalExt, signal_vec::SignalVecExt};
+use shared::domain::jig::module::body::tapping_board::Mode;
 use std::rc::Rc;
 
 impl DomRenderable for Main {
@@ -19,6 +23,12 @@ impl DomRenderable for Main {
             .children_signal_vec(
                 state.phase_signal().map(clone!(state => move |phase| {
                     match phase {
+                        // the traces are laid out from the content, not drawn
+                        Phase::Trace if state.base.is_structured() => {
+                            vec![
+                                render_stickers_raw(&state.base.stickers.to_raw(), state.base.theme_id.get())
+                            ]
+                        },
                         Phase::Layout => {
                             vec![
                                 render_stickers(state.base.stickers.clone())
@@ -45,10 +55,29 @@ impl DomRenderable for Main {
                 }))
                 .to_signal_vec()
             )
+            .apply_if(state.base.is_structured(), clone!(state => move |dom| {
+                dom.child(render_structured(state))
+            }))
         })
     }
 }
 
+/// Preview of the timeline or family tree cards, as the student will see them
+fn render_structured(state: Rc<Main>) -> Dom {
+    let base = &state.base;
+
+    html!("empty-fragment", {
+        .child_signal(match base.mode {
+            Mode::Timeline => base.timeline.signal_ref(|timeline| {
+                Some(render_cards(Cards::timeline(timeline, &timeline.chronological(), true)))
+            }).boxed_local(),
+            _ => base.family_tree.signal_ref(|family_tree| {
+                Some(render_cards(Cards::family_tree(family_tree)))
+            }).boxed_local(),
+        })
+    })
+}
+
 impl MainDomRenderable for Main {
     fn render_bg(state: Rc<Main>) -> Option<Dom> {
         Some(render_backgrounds(state.base.backgrounds.clone(), None))
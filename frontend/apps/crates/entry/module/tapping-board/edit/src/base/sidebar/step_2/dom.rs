@@ -1,4 +1,8 @@
-use super::state::*;
+use super::{
+    family_tree::{dom::render as render_family_tree, state::FamilyTreeEditor},
+    state::*,
+    timeline::{dom::render as render_timeline, state::TimelineEditor},
+};
 use components::{
     image::search::dom::render as render_image_search,
     tabs::{MenuTab, MenuTabKind},
@@ -6,9 +10,27 @@ use components::{
 };
 use dominator::{clone, html, Dom};
 use futures_signals::signal::SignalExt;
+use shared::domain::jig::module::body::tapping_board::Mode;
 use std::rc::Rc;
 
 pub fn render(state: Rc<Step2>) -> Dom {
+    let base = state.sidebar.base.clone();
+
+    // timelines and family trees are edited as a list rather than placed on the stage
+    match base.mode {
+        Mode::Timeline => {
+            return html!("module-sidebar-body", {
+                .child(render_timeline(TimelineEditor::new(base)))
+            });
+        }
+        Mode::FamilyTree => {
+            return html!("module-sidebar-body", {
+                .child(render_family_tree(FamilyTreeEditor::new(base)))
+            });
+        }
+        _ => {}
+    }
+
     html!("menu-tabs", {
         .future(state.tab.signal_ref(|tab| tab.kind()).dedupe().for_each(clone!(state => move |kind| {
             state.sidebar.tab_kind.set(Some(kind));
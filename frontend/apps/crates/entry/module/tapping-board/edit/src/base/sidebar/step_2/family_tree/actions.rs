@@ -0,0 +1,60 @@
+use super::state::*;
+use shared::domain::jig::module::body::{tapping_board::family_tree::Person, Audio, Image};
+
+impl FamilyTreeEditor {
+    pub fn add_person(&self) {
+        let mut index = 0;
+
+        self.base.update_family_tree(|family_tree| {
+            index = family_tree.people.len();
+            family_tree.people.push(Person::default());
+        });
+
+        self.selected.set(Some(index));
+    }
+
+    pub fn delete_person(&self, index: usize) {
+        self.base
+            .update_family_tree(|family_tree| family_tree.remove_person(index));
+
+        self.selected.set(None);
+    }
+
+    pub fn set_name(&self, index: usize, name: String) {
+        self.update_person(index, |person| person.name = name);
+    }
+
+    pub fn set_image(&self, index: usize, image: Option<Image>) {
+        self.update_person(index, |person| person.image = image);
+    }
+
+    pub fn set_audio(&self, index: usize, audio: Option<Audio>) {
+        self.update_person(index, |person| person.audio = audio);
+    }
+
+    pub fn set_parent(&self, index: usize, parent: usize, is_parent: bool) {
+        self.base.update_family_tree(|family_tree| {
+            let mut parents = family_tree.parents_of(index);
+
+            parents.retain(|curr| *curr != parent);
+            if is_parent {
+                parents.push(parent);
+            }
+
+            family_tree.set_parents(index, &parents);
+        });
+    }
+
+    pub fn set_spouse(&self, index: usize, spouse: Option<usize>) {
+        self.base
+            .update_family_tree(|family_tree| family_tree.set_spouse(index, spouse));
+    }
+
+    fn update_person(&self, index: usize, f: impl FnOnce(&mut Person)) {
+        self.base.update_family_tree(|family_tree| {
+            if let Some(person) = family_tree.people.get_mut(index) {
+                f(person);
+            }
+        });
+    }
+}
@@ -0,0 +1,165 @@
+use super::state::*;
+use dominator::{clone, html, with_node, Dom};
+use futures_signals::{
+    signal::{Signal, SignalExt},
+    signal_vec::SignalVecExt,
+};
+use shared::domain::jig::module::body::tapping_board::family_tree::FamilyTree;
+use std::rc::Rc;
+use utils::prelude::*;
+use web_sys::HtmlInputElement;
+
+use super::super::media::render_media;
+use crate::strings::step_2::*;
+
+pub fn render(state: Rc<FamilyTreeEditor>) -> Dom {
+    html!("tapping-board-entries", {
+        .children_signal_vec(
+            people_len_signal(&state)
+                .map(clone!(state => move |len| {
+                    (0..len)
+                        .map(|index| render_person(state.clone(), index))
+                        .collect::<Vec<Dom>>()
+                }))
+                .to_signal_vec()
+        )
+        .child(html!("button-rect", {
+            .property("slot", "add")
+            .property("kind", "text")
+            .property("color", "blue")
+            .text(STR_ADD_PERSON)
+            .event(clone!(state => move |_: events::Click| {
+                state.add_person();
+            }))
+        }))
+        .child_signal(state.selected.signal().map(clone!(state => move |index| {
+            index.map(|index| {
+                render_media(
+                    tree_signal(&state, move |tree| tree.people.get(index).map_or(false, |person| person.image.is_some())),
+                    tree_signal(&state, move |tree| tree.people.get(index).and_then(|person| person.audio.clone())),
+                    clone!(state => move |image| state.set_image(index, image)),
+                    clone!(state => move |audio| state.set_audio(index, audio)),
+                )
+            })
+        })))
+    })
+}
+
+fn render_person(state: Rc<FamilyTreeEditor>, index: usize) -> Dom {
+    html!("tapping-board-entry", {
+        .property("slot", "entries")
+        .property_signal("heading", tree_signal(&state, move |tree| name(tree, index)))
+        .property_signal("selected", state.selected.signal_ref(move |selected| *selected == Some(index)))
+        .event(clone!(state => move |_: events::Click| {
+            state.selected.set_neq(Some(index));
+        }))
+        .child(html!("button-rect", {
+            .property("slot", "delete")
+            .property("kind", "text")
+            .property("color", "red")
+            .text(STR_DELETE)
+            .event(clone!(state => move |evt: events::Click| {
+                // otherwise the entry selects itself again
+                evt.stop_propagation();
+                state.delete_person(index);
+            }))
+        }))
+        .children(&mut [
+            html!("input-wrapper", {
+                .property("slot", "fields")
+                .property("label", STR_NAME)
+                .child(html!("input" => HtmlInputElement, {
+                    .with_node!(elem => {
+                        .property_signal("value", tree_signal(&state, move |tree| {
+                            tree.people.get(index).map(|person| person.name.clone()).unwrap_or_default()
+                        }))
+                        .event(clone!(state => move |_: events::Change| {
+                            state.set_name(index, elem.value());
+                        }))
+                    })
+                }))
+            }),
+            html!("input-select", {
+                .property("slot", "fields")
+                .property("label", STR_PARENTS)
+                .property("multiple", true)
+                .property_signal("value", tree_signal(&state, move |tree| {
+                    tree.parents_of(index)
+                        .into_iter()
+                        .map(|parent| name(tree, parent))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                }))
+                .children_signal_vec(others_signal(&state, index).map(clone!(state => move |others| {
+                    others
+                        .into_iter()
+                        .map(|parent| render_parent_option(state.clone(), index, parent))
+                        .collect::<Vec<Dom>>()
+                })).to_signal_vec())
+            }),
+            html!("input-select", {
+                .property("slot", "fields")
+                .property("label", STR_SPOUSE)
+                .property_signal("value", tree_signal(&state, move |tree| {
+                    tree.spouse_of(index).map_or_else(|| STR_NO_SPOUSE.to_string(), |spouse| name(tree, spouse))
+                }))
+                .child(render_spouse_option(state.clone(), index, None))
+                .children_signal_vec(others_signal(&state, index).map(clone!(state => move |others| {
+                    others
+                        .into_iter()
+                        .map(|spouse| render_spouse_option(state.clone(), index, Some(spouse)))
+                        .collect::<Vec<Dom>>()
+                })).to_signal_vec())
+            }),
+        ])
+    })
+}
+
+fn render_parent_option(state: Rc<FamilyTreeEditor>, index: usize, parent: usize) -> Dom {
+    html!("input-select-option", {
+        .text_signal(tree_signal(&state, move |tree| name(tree, parent)))
+        .property_signal("selected", tree_signal(&state, move |tree| tree.parents_of(index).contains(&parent)))
+        .event(clone!(state => move |evt: events::CustomSelectedChange| {
+            state.set_parent(index, parent, evt.selected());
+        }))
+    })
+}
+
+fn render_spouse_option(state: Rc<FamilyTreeEditor>, index: usize, spouse: Option<usize>) -> Dom {
+    html!("input-select-option", {
+        .apply(|dom| match spouse {
+            Some(spouse) => dom.text_signal(tree_signal(&state, move |tree| name(tree, spouse))),
+            None => dom.text(STR_NO_SPOUSE),
+        })
+        .property_signal("selected", tree_signal(&state, move |tree| tree.spouse_of(index) == spouse))
+        .event(clone!(state => move |evt: events::CustomSelectedChange| {
+            if evt.selected() {
+                state.set_spouse(index, spouse);
+            }
+        }))
+    })
+}
+
+fn name(tree: &FamilyTree, index: usize) -> String {
+    tree.people
+        .get(index)
+        .map(|person| person.name.clone())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| STR_UNNAMED.to_string())
+}
+
+fn people_len_signal(state: &FamilyTreeEditor) -> impl Signal<Item = usize> {
+    tree_signal(state, |tree| tree.people.len()).dedupe()
+}
+
+/// Everyone but `index`, who could be their parent or spouse
+fn others_signal(state: &FamilyTreeEditor, index: usize) -> impl Signal<Item = Vec<usize>> {
+    people_len_signal(state).map(move |len| (0..len).filter(|other| *other != index).collect())
+}
+
+fn tree_signal<A>(
+    state: &FamilyTreeEditor,
+    f: impl Fn(&FamilyTree) -> A + 'static,
+) -> impl Signal<Item = A> {
+    state.base.family_tree.signal_ref(f)
+}
@@ -0,0 +1,17 @@
+use crate::base::state::Base;
+use futures_signals::signal::Mutable;
+use std::rc::Rc;
+
+pub struct FamilyTreeEditor {
+    pub base: Rc<Base>,
+    /// The person whose fields, image and audio are showing
+    pub selected: Mutable<Option<usize>>,
+}
+
+impl FamilyTreeEditor {
+    pub fn new(base: Rc<Base>) -> Rc<Self> {
+        let selected = Mutable::new(None);
+
+        Rc::new(Self { base, selected })
+    }
+}
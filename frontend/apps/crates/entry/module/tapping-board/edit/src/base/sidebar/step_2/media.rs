@@ -0,0 +1,66 @@
+use components::{
+    audio::input::{AudioInput, AudioInputCallbacks, AudioInputOptions},
+    image::search::{
+        callbacks::Callbacks as ImageSearchCallbacks,
+        dom::render as render_image_search,
+        state::{ImageSearchKind, ImageSearchOptions, State as ImageSearchState},
+    },
+};
+use dominator::{clone, html, Dom};
+use futures_signals::signal::{Signal, SignalExt};
+use shared::domain::jig::module::body::{Audio, Image};
+use std::rc::Rc;
+use utils::prelude::*;
+
+use crate::strings::step_2::STR_REMOVE_IMAGE;
+
+/// Image and audio inputs for the selected timeline event or family tree person
+pub fn render_media(
+    has_image: impl Signal<Item = bool> + 'static,
+    audio: impl Signal<Item = Option<Audio>> + 'static,
+    on_image: impl Fn(Option<Image>) + 'static,
+    on_audio: impl Fn(Option<Audio>) + 'static,
+) -> Dom {
+    let on_image = Rc::new(on_image);
+    let on_audio = Rc::new(on_audio);
+
+    let image_search = ImageSearchState::new(
+        ImageSearchOptions {
+            kind: ImageSearchKind::Sticker,
+            ..ImageSearchOptions::default()
+        },
+        ImageSearchCallbacks::new(Some(clone!(on_image => move |image| {
+            on_image(Some(image));
+        }))),
+    );
+
+    let audio_input = AudioInput::new(
+        AudioInputOptions::new(Some(audio)),
+        AudioInputCallbacks::new(
+            Some(clone!(on_audio => move |audio: Audio| {
+                on_audio(Some(audio));
+            })),
+            Some(clone!(on_audio => move || {
+                on_audio(None);
+            })),
+        ),
+    );
+
+    html!("div", {
+        .property("slot", "selected")
+        .child_signal(has_image.map(clone!(on_image => move |has_image| {
+            has_image.then(|| {
+                html!("button-rect", {
+                    .property("kind", "text")
+                    .property("color", "blue")
+                    .text(STR_REMOVE_IMAGE)
+                    .event(clone!(on_image => move |_: events::Click| {
+                        on_image(None);
+                    }))
+                })
+            })
+        })))
+        .child(render_image_search(Rc::new(image_search), None))
+        .child(AudioInput::render(audio_input, None))
+    })
+}
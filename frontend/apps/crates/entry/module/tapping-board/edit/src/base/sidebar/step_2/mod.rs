@@ -1,2 +1,5 @@
 pub mod dom;
+pub mod family_tree;
+pub mod media;
 pub mod state;
+pub mod timeline;
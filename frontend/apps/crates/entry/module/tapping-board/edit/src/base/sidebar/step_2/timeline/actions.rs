@@ -0,0 +1,79 @@
+use super::state::*;
+use shared::domain::jig::module::body::{
+    tapping_board::timeline::{EventDate, TimelineEvent},
+    Audio, Image,
+};
+
+impl TimelineEditor {
+    pub fn add_event(&self) {
+        let mut index = 0;
+
+        self.base.update_timeline(|timeline| {
+            // new events start after the latest one, so they land at the end of the timeline
+            let year = timeline
+                .events
+                .iter()
+                .map(|event| event.date.year)
+                .max()
+                .unwrap_or_default();
+
+            index = timeline.events.len();
+            timeline.events.push(TimelineEvent {
+                date: EventDate {
+                    year,
+                    ..EventDate::default()
+                },
+                ..TimelineEvent::default()
+            });
+        });
+
+        self.selected.set(Some(index));
+    }
+
+    pub fn delete_event(&self, index: usize) {
+        self.base.update_timeline(|timeline| {
+            if index < timeline.events.len() {
+                timeline.events.remove(index);
+            }
+        });
+
+        self.selected.set(None);
+    }
+
+    pub fn set_year(&self, index: usize, year: i32) {
+        self.update_event(index, |event| event.date.year = year);
+    }
+
+    pub fn set_month(&self, index: usize, month: Option<u8>) {
+        self.update_event(index, |event| {
+            event.date.month = month;
+            if month.is_none() {
+                event.date.day = None;
+            }
+        });
+    }
+
+    pub fn set_day(&self, index: usize, day: Option<u8>) {
+        self.update_event(index, |event| event.date.day = day);
+    }
+
+    pub fn set_text(&self, index: usize, text: String) {
+        self.update_event(index, |event| event.text = text);
+    }
+
+    pub fn set_image(&self, index: usize, image: Option<Image>) {
+        self.update_event(index, |event| event.image = image);
+    }
+
+    pub fn set_audio(&self, index: usize, audio: Option<Audio>) {
+        self.update_event(index, |event| event.audio = audio);
+    }
+
+    fn update_event(&self, index: usize, f: impl FnOnce(&mut TimelineEvent)) {
+        self.base.update_timeline(|timeline| {
+            if let Some(event) = timeline.events.get_mut(index) {
+                f(event);
+            }
+        });
+    }
+}
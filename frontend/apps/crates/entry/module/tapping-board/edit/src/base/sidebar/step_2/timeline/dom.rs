@@ -0,0 +1,156 @@
+use super::state::*;
+use components::module::_groups::design::structured::date::{event_label, month_name};
+use dominator::{clone, html, with_node, Dom};
+use futures_signals::{
+    signal::{Signal, SignalExt},
+    signal_vec::SignalVecExt,
+};
+use shared::domain::jig::module::body::tapping_board::timeline::TimelineEvent;
+use std::rc::Rc;
+use utils::prelude::*;
+use web_sys::{HtmlInputElement, HtmlTextAreaElement};
+
+use super::super::media::render_media;
+use crate::strings::step_2::*;
+
+pub fn render(state: Rc<TimelineEditor>) -> Dom {
+    html!("tapping-board-entries", {
+        .children_signal_vec(
+            state.base.timeline
+                .signal_ref(|timeline| timeline.events.len())
+                .dedupe()
+                .map(clone!(state => move |len| {
+                    (0..len)
+                        .map(|index| render_event(state.clone(), index))
+                        .collect::<Vec<Dom>>()
+                }))
+                .to_signal_vec()
+        )
+        .child(html!("button-rect", {
+            .property("slot", "add")
+            .property("kind", "text")
+            .property("color", "blue")
+            .text(STR_ADD_EVENT)
+            .event(clone!(state => move |_: events::Click| {
+                state.add_event();
+            }))
+        }))
+        .child_signal(state.selected.signal().map(clone!(state => move |index| {
+            index.map(|index| {
+                render_media(
+                    event_signal(&state, index, |event| event.image.is_some()),
+                    event_signal(&state, index, |event| event.audio.clone()),
+                    clone!(state => move |image| state.set_image(index, image)),
+                    clone!(state => move |audio| state.set_audio(index, audio)),
+                )
+            })
+        })))
+    })
+}
+
+fn render_event(state: Rc<TimelineEditor>, index: usize) -> Dom {
+    html!("tapping-board-entry", {
+        .property("slot", "entries")
+        .property_signal("heading", event_signal(&state, index, event_label))
+        .property_signal("selected", state.selected.signal_ref(move |selected| *selected == Some(index)))
+        .event(clone!(state => move |_: events::Click| {
+            state.selected.set_neq(Some(index));
+        }))
+        .child(html!("button-rect", {
+            .property("slot", "delete")
+            .property("kind", "text")
+            .property("color", "red")
+            .text(STR_DELETE)
+            .event(clone!(state => move |evt: events::Click| {
+                // otherwise the entry selects itself again
+                evt.stop_propagation();
+                state.delete_event(index);
+            }))
+        }))
+        .children(&mut [
+            html!("input-wrapper", {
+                .property("slot", "fields")
+                .property("label", STR_YEAR)
+                .child(html!("input" => HtmlInputElement, {
+                    .with_node!(elem => {
+                        .property("type", "number")
+                        .property_signal("value", event_signal(&state, index, |event| event.date.year.to_string()))
+                        .event(clone!(state => move |_: events::Change| {
+                            if let Ok(year) = elem.value().parse() {
+                                state.set_year(index, year);
+                            }
+                        }))
+                    })
+                }))
+            }),
+            html!("input-select", {
+                .property("slot", "fields")
+                .property("label", STR_MONTH)
+                .property_signal("value", event_signal(&state, index, |event| {
+                    event.date.month
+                        .and_then(month_name)
+                        .unwrap_or(STR_NO_MONTH)
+                }))
+                .child(render_month_option(state.clone(), index, None))
+                .children((1..=12).map(|month| render_month_option(state.clone(), index, Some(month))))
+            }),
+            html!("input-wrapper", {
+                .property("slot", "fields")
+                .property("label", STR_DAY)
+                .child(html!("input" => HtmlInputElement, {
+                    .with_node!(elem => {
+                        .property("type", "number")
+                        .property("min", 1)
+                        .property("max", 31)
+                        .property_signal("disabled", event_signal(&state, index, |event| event.date.month.is_none()))
+                        .property_signal("value", event_signal(&state, index, |event| {
+                            event.date.day.map(|day| day.to_string()).unwrap_or_default()
+                        }))
+                        .event(clone!(state => move |_: events::Change| {
+                            let day = elem.value().parse().ok().filter(|day| (1..=31).contains(day));
+                            state.set_day(index, day);
+                        }))
+                    })
+                }))
+            }),
+            html!("input-wrapper", {
+                .property("slot", "fields")
+                .property("label", STR_EVENT_TEXT)
+                .child(html!("textarea" => HtmlTextAreaElement, {
+                    .with_node!(elem => {
+                        .property("rows", 3)
+                        .property_signal("value", event_signal(&state, index, |event| event.text.clone()))
+                        .event(clone!(state => move |_: events::Change| {
+                            state.set_text(index, elem.value());
+                        }))
+                    })
+                }))
+            }),
+        ])
+    })
+}
+
+fn render_month_option(state: Rc<TimelineEditor>, index: usize, month: Option<u8>) -> Dom {
+    let label = month.and_then(month_name).unwrap_or(STR_NO_MONTH);
+
+    html!("input-select-option", {
+        .text(label)
+        .property_signal("selected", event_signal(&state, index, move |event| event.date.month == month))
+        .event(clone!(state => move |evt: events::CustomSelectedChange| {
+            if evt.selected() {
+                state.set_month(index, month);
+            }
+        }))
+    })
+}
+
+fn event_signal<A: Default>(
+    state: &TimelineEditor,
+    index: usize,
+    f: impl Fn(&TimelineEvent) -> A + 'static,
+) -> impl Signal<Item = A> {
+    state
+        .base
+        .timeline
+        .signal_ref(move |timeline| timeline.events.get(index).map(&f).unwrap_or_default())
+}
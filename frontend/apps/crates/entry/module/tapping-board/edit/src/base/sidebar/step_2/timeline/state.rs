@@ -0,0 +1,17 @@
+use crate::base::state::Base;
+use futures_signals::signal::Mutable;
+use std::rc::Rc;
+
+pub struct TimelineEditor {
+    pub base: Rc<Base>,
+    /// The event whose fields, image and audio are showing
+    pub selected: Mutable<Option<usize>>,
+}
+
+impl TimelineEditor {
+    pub fn new(base: Rc<Base>) -> Rc<Self> {
+        let selected = Mutable::new(None);
+
+        Rc::new(Self { base, selected })
+    }
+}
@@ -13,6 +13,13 @@ use web_sys::HtmlTextAreaElement;
 const STR_EMPTY_SELECTION: &str = "Trace and select to add audio or label";
 
 pub fn render(state: Rc<Step3>) -> Dom {
+    if state.sidebar.base.is_structured() {
+        return html!("sidebar-empty", {
+            .property("label", crate::strings::step_3::STR_STRUCTURED)
+            .property("imagePath", "module/_common/edit/sidebar/illustration-trace-area.svg")
+        });
+    }
+
     html!("empty-fragment", {
         .style("display", "contents")
         .child_signal(
@@ -34,4 +34,14 @@ impl State {
     pub fn set_next_some(&self) {
         self.set_next(Next::SelectSome(self.base.play_settings.next_value.get()));
     }
+
+    pub fn set_put_in_order(&self, put_in_order: bool) {
+        self.base.play_settings.put_in_order.set_neq(put_in_order);
+
+        self.base.history.push_modify(move |raw| {
+            if let Some(content) = &mut raw.content {
+                content.play_settings.put_in_order = put_in_order;
+            }
+        })
+    }
 }
@@ -1,86 +1,115 @@
 use dominator::{clone, Dom};
+use futures_signals::signal::SignalExt;
 use std::rc::Rc;
 
 use super::state::State;
-use shared::domain::jig::module::body::tapping_board::{Hint, Next};
+use shared::domain::jig::module::body::tapping_board::{Hint, Mode, Next};
 
 use components::module::_common::edit::settings::prelude::*;
 pub fn render(state: Rc<State>) -> Dom {
-    render_settings(Rc::new(ModuleSettings {
-        lines: vec![
-            (
-                LineKind::Hint,
-                vec![
-                    Some(SettingsButton::new_click(
-                        SettingsButtonKind::Highlight,
-                        clone!(state => move || {
-                            state.base.play_settings.hint.signal_ref(|curr| {
-                                *curr == Hint::Highlight
-                            })
-                        }),
-                        clone!(state => move || {
-                            state.set_hint(Hint::Highlight);
-                        }),
-                    )),
-                    Some(SettingsButton::new_click(
-                        SettingsButtonKind::HighlightOff,
-                        clone!(state => move || {
-                            state.base.play_settings.hint.signal_ref(|curr| {
-                                *curr == Hint::None
-                            })
-                        }),
-                        clone!(state => move || {
-                            state.set_hint(Hint::None);
-                        }),
-                    )),
-                ],
-            ),
-            (
-                LineKind::Next,
-                vec![
-                    Some(SettingsButton::new_click(
-                        SettingsButtonKind::ContinueClick,
-                        clone!(state => move || {
-                            state.base.play_settings.next.signal_ref(|curr| {
-                                std::mem::discriminant(curr) == std::mem::discriminant(&Next::Continue)
-                            })
-                        }),
-                        clone!(state => move || {
-                            state.set_next(Next::Continue);
-                        }),
-                    )),
-                    Some(SettingsButton::new_click(
-                        SettingsButtonKind::ContinueAll,
-                        clone!(state => move || {
-                            state.base.play_settings.next.signal_ref(|curr| {
-                                std::mem::discriminant(curr) == std::mem::discriminant(&Next::SelectAll)
-                            })
-                        }),
-                        clone!(state => move || {
-                            state.set_next(Next::SelectAll);
-                        }),
-                    )),
-                    Some(SettingsButton::new_value_click(
-                        SettingsButtonKind::ContinueSome,
-                        clone!(state => move || {
-                            state.base.play_settings.next.signal_ref(|curr| {
-                                std::mem::discriminant(curr) == std::mem::discriminant(&Next::SelectSome(0))
-                            })
-                        }),
-                        SettingsValue::new(
-                            state.base.play_settings.next_value.get(),
-                            clone!(state => move |value| {
-                                state.set_next_value(value);
-                            }),
-                        ),
-                        clone!(state => move || {
-                            state.set_next_some();
+    let mut lines = Vec::new();
+
+    if state.base.mode == Mode::Timeline {
+        lines.push((
+            LineKind::Timeline,
+            vec![
+                Some(SettingsButton::new_click(
+                    SettingsButtonKind::Explore,
+                    clone!(state => move || {
+                        state.base.play_settings.put_in_order.signal().map(|put_in_order| !put_in_order)
+                    }),
+                    clone!(state => move || {
+                        state.set_put_in_order(false);
+                    }),
+                )),
+                Some(SettingsButton::new_click(
+                    SettingsButtonKind::PutInOrder,
+                    clone!(state => move || {
+                        state.base.play_settings.put_in_order.signal()
+                    }),
+                    clone!(state => move || {
+                        state.set_put_in_order(true);
+                    }),
+                )),
+            ],
+        ));
+    }
+
+    lines.extend(vec![
+        (
+            LineKind::Hint,
+            vec![
+                Some(SettingsButton::new_click(
+                    SettingsButtonKind::Highlight,
+                    clone!(state => move || {
+                        state.base.play_settings.hint.signal_ref(|curr| {
+                            *curr == Hint::Highlight
+                        })
+                    }),
+                    clone!(state => move || {
+                        state.set_hint(Hint::Highlight);
+                    }),
+                )),
+                Some(SettingsButton::new_click(
+                    SettingsButtonKind::HighlightOff,
+                    clone!(state => move || {
+                        state.base.play_settings.hint.signal_ref(|curr| {
+                            *curr == Hint::None
+                        })
+                    }),
+                    clone!(state => move || {
+                        state.set_hint(Hint::None);
+                    }),
+                )),
+            ],
+        ),
+        (
+            LineKind::Next,
+            vec![
+                Some(SettingsButton::new_click(
+                    SettingsButtonKind::ContinueClick,
+                    clone!(state => move || {
+                        state.base.play_settings.next.signal_ref(|curr| {
+                            std::mem::discriminant(curr) == std::mem::discriminant(&Next::Continue)
+                        })
+                    }),
+                    clone!(state => move || {
+                        state.set_next(Next::Continue);
+                    }),
+                )),
+                Some(SettingsButton::new_click(
+                    SettingsButtonKind::ContinueAll,
+                    clone!(state => move || {
+                        state.base.play_settings.next.signal_ref(|curr| {
+                            std::mem::discriminant(curr) == std::mem::discriminant(&Next::SelectAll)
+                        })
+                    }),
+                    clone!(state => move || {
+                        state.set_next(Next::SelectAll);
+                    }),
+                )),
+                Some(SettingsButton::new_value_click(
+                    SettingsButtonKind::ContinueSome,
+                    clone!(state => move || {
+                        state.base.play_settings.next.signal_ref(|curr| {
+                            std::mem::discriminant(curr) == std::mem::discriminant(&Next::SelectSome(0))
+                        })
+                    }),
+                    SettingsValue::new(
+                        state.base.play_settings.next_value.get(),
+                        clone!(state => move |value| {
+                            state.set_next_value(value);
                         }),
-                    )),
-                ],
-            ),
-        ],
-    }))
+                    ),
+                    clone!(state => move || {
+                        state.set_next_some();
+                    }),
+                )),
+            ],
+        ),
+    ]);
+
+    render_settings(Rc::new(ModuleSettings { lines }))
 }
 /*
 pub fn render(state: Rc<State>) -> Dom {
@@ -25,7 +25,8 @@ use shared::domain::jig::{
             Instructions,
             _groups::design::TraceKind,
             tapping_board::{
-                Hint, Mode, ModuleData as RawData, Next, PlaySettings as RawPlaySettings, Step,
+                family_tree::FamilyTree, timeline::Timeline, Hint, Mode, ModuleData as RawData,
+                Next, PlaySettings as RawPlaySettings, Step,
             },
         },
         ModuleId,
@@ -49,12 +50,17 @@ pub struct Base {
     pub traces_meta: MutableVec<TraceMeta>,
     pub text_editor: Rc<TextEditorState>,
     pub play_settings: Rc<PlaySettings>,
+    pub mode: Mode,
+    // Timeline and FamilyTree modes lay out the traces from these
+    pub timeline: Mutable<Timeline>,
+    pub family_tree: Mutable<FamilyTree>,
 }
 
 pub struct PlaySettings {
     pub hint: Mutable<Hint>,
     pub next: Mutable<Next>,
     pub next_value: Mutable<usize>,
+    pub put_in_order: Mutable<bool>,
 }
 
 impl PlaySettings {
@@ -67,6 +73,7 @@ impl PlaySettings {
             hint: Mutable::new(settings.hint),
             next: Mutable::new(settings.next),
             next_value,
+            put_in_order: Mutable::new(settings.put_in_order),
         }
     }
 }
@@ -213,6 +220,9 @@ impl Base {
             traces,
             traces_meta,
             play_settings: Rc::new(PlaySettings::new(content.play_settings)),
+            mode: content.mode,
+            timeline: Mutable::new(content.timeline.unwrap_or_default()),
+            family_tree: Mutable::new(content.family_tree.unwrap_or_default()),
         });
 
         *_self_ref.borrow_mut() = Some(_self.clone());
@@ -221,6 +231,12 @@ impl Base {
     }
 }
 
+impl Base {
+    pub fn is_structured(&self) -> bool {
+        matches!(self.mode, Mode::Timeline | Mode::FamilyTree)
+    }
+}
+
 impl BaseExt<Step> for Base {
     type NextStepAllowedSignal = impl Signal<Item = bool>;
 
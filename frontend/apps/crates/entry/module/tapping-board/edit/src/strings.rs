@@ -1,4 +1,22 @@
+pub mod step_2 {
+    pub const STR_ADD_EVENT: &str = "Add event";
+    pub const STR_ADD_PERSON: &str = "Add person";
+    pub const STR_DELETE: &str = "Delete";
+    pub const STR_REMOVE_IMAGE: &str = "Remove image";
+    pub const STR_YEAR: &str = "Year (negative for BCE)";
+    pub const STR_MONTH: &str = "Month";
+    pub const STR_NO_MONTH: &str = "None";
+    pub const STR_DAY: &str = "Day";
+    pub const STR_EVENT_TEXT: &str = "What happened";
+    pub const STR_NAME: &str = "Name";
+    pub const STR_PARENTS: &str = "Parents";
+    pub const STR_SPOUSE: &str = "Spouse";
+    pub const STR_NO_SPOUSE: &str = "None";
+    pub const STR_UNNAMED: &str = "Unnamed";
+}
+
 pub mod step_3 {
     pub const STR_LABEL: &str = "Text";
     pub const STR_PLACEHOLDER: &str = "Type the text";
+    pub const STR_STRUCTURED: &str = "Audio and labels come from the content you added";
 }
@@ -1,6 +1,10 @@
 use super::state::*;
 use components::{
-    backgrounds::dom::render_backgrounds_raw, module::_common::play::prelude::DomRenderable,
+    backgrounds::dom::render_backgrounds_raw,
+    module::{
+        _common::play::prelude::DomRenderable,
+        _groups::design::structured::dom::render as render_cards,
+    },
     stickers::dom::render_stickers_raw,
 };
 use dominator::{html, Dom};
@@ -15,8 +19,12 @@ impl DomRenderable for Base {
             .children(&mut [
                 render_backgrounds_raw(&state.backgrounds, state.theme_id, None),
                 render_stickers_raw(&state.stickers, state.theme_id),
-                render_game(Game::new(state.clone())),
             ])
+            .apply(|dom| match state.cards.as_ref() {
+                Some(cards) => dom.child(render_cards(cards.clone())),
+                None => dom,
+            })
+            .child(render_game(Game::new(state.clone())))
         })
     }
 }
@@ -1,5 +1,6 @@
 use super::{
     hints::{dom::render as render_hints, state::*},
+    ordering::{dom::render as render_ordering, state::*},
     playing::{dom::render as render_playing, state::*},
     state::*,
 };
@@ -13,6 +14,7 @@ pub fn render(state: Rc<Game>) -> Dom {
             match phase {
                 Phase::ShowHints => Some(render_hints(Hints::new(state.clone()))),
                 Phase::Playing => Some(render_playing(PlayState::new(state.clone()))),
+                Phase::Ordering => Some(render_ordering(Ordering::new(state.clone()))),
             }
         })))
     })
@@ -1,7 +1,7 @@
-use super::{super::state::Phase, state::*};
+use super::{super::state::Game, state::*};
 
 impl Hints {
     pub fn finish(&self) {
-        self.game.phase.set_neq(Phase::Playing);
+        self.game.phase.set_neq(Game::play_phase(&self.game.base));
     }
 }
@@ -1,4 +1,5 @@
 pub mod dom;
 pub mod hints;
+pub mod ordering;
 pub mod playing;
 pub mod state;
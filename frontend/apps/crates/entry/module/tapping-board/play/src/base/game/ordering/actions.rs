@@ -0,0 +1,67 @@
+use super::state::*;
+use components::{
+    audio::mixer::{AudioPath, AUDIO_MIXER},
+    module::_common::play::prelude::*,
+    traces::{bubble::TraceBubble, utils::TraceExt},
+};
+use dominator::clone;
+use std::rc::Rc;
+
+impl Ordering {
+    pub fn select(state: Rc<Self>, index: usize) {
+        if state.placed.lock_ref().contains(&index) {
+            return;
+        }
+
+        let next = state.next.get();
+        if state.order.get(next) != Some(&index) {
+            play_negative();
+            return;
+        }
+
+        state.next.set(next + 1);
+        state.placed.lock_mut().insert(index);
+
+        if let Some(cards) = state.game.base.cards.as_ref() {
+            cards.reveal(index);
+        }
+
+        let finished = next + 1 >= state.order.len();
+
+        let trace = state.game.base.traces.get(index);
+        match trace.and_then(|trace| Some((trace, trace.calc_bounds(true)?))) {
+            Some((trace, bounds)) => {
+                // the last event's date and text get a chance to show before the activity ends
+                let bubble = TraceBubble::new(
+                    bounds,
+                    trace.audio.clone(),
+                    trace.text.clone(),
+                    Some(clone!(state => move || {
+                        if finished {
+                            state.finish();
+                        }
+                    })),
+                );
+                state.bubble.set(Some(bubble));
+            }
+            None => {
+                if finished {
+                    state.finish();
+                }
+            }
+        }
+    }
+
+    fn finish(&self) {
+        self.game
+            .base
+            .set_play_phase(ModulePlayPhase::Ending(Some(ModuleEnding::Positive)));
+    }
+}
+
+fn play_negative() {
+    AUDIO_MIXER.with(|mixer| {
+        let audio_path: AudioPath<'_> = mixer.get_random_negative().into();
+        mixer.play_oneshot(audio_path);
+    });
+}
@@ -0,0 +1,26 @@
+use components::traces::{
+    bubble::TraceBubble,
+    show::{TracesShow, TracesShowMode},
+};
+use dominator::{clone, html, Dom};
+use futures_signals::signal::SignalExt;
+use std::rc::Rc;
+
+use super::state::*;
+
+pub fn render(state: Rc<Ordering>) -> Dom {
+    html!("empty-fragment", {
+        .child(TracesShow::render(TracesShow::new(
+                state.game.base.traces.clone(),
+                TracesShowMode::HiddenSolidMap(state.placed.clone()),
+                Some(clone!(state => move |index| {
+                    Ordering::select(state.clone(), index);
+                }))
+        )))
+        .child(html!("overlay-container", {
+            .child_signal(state.bubble.signal_cloned().map(|bubble| {
+                bubble.map(TraceBubble::render)
+            }))
+        }))
+    })
+}
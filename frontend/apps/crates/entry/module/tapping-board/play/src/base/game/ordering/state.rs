@@ -0,0 +1,29 @@
+use crate::base::game::state::*;
+use components::traces::bubble::TraceBubble;
+use futures_signals::signal::Mutable;
+use std::{cell::Cell, collections::HashSet, rc::Rc};
+
+/// Putting the timeline's events in order, the student taps them from earliest to latest
+pub struct Ordering {
+    pub game: Rc<Game>,
+    /// trace indices from earliest to latest
+    pub order: Vec<usize>,
+    /// how many events are in place
+    pub next: Cell<usize>,
+    pub placed: Mutable<HashSet<usize>>,
+    pub bubble: Mutable<Option<Rc<TraceBubble>>>,
+}
+
+impl Ordering {
+    pub fn new(game: Rc<Game>) -> Rc<Self> {
+        let order = game.base.order.clone().unwrap_or_default();
+
+        Rc::new(Self {
+            game,
+            order,
+            next: Cell::new(0),
+            placed: Mutable::new(HashSet::new()),
+            bubble: Mutable::new(None),
+        })
+    }
+}
@@ -13,15 +13,26 @@ impl Game {
     pub fn new(base: Rc<Base>) -> Rc<Self> {
         let phase = Mutable::new(match base.settings.hint {
             Hint::Highlight => Phase::ShowHints,
-            Hint::None => Phase::Playing,
+            Hint::None => Self::play_phase(&base),
         });
 
         Rc::new(Self { base, phase })
     }
+
+    /// The phase after any hints
+    pub fn play_phase(base: &Base) -> Phase {
+        if base.order.is_some() {
+            Phase::Ordering
+        } else {
+            Phase::Playing
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Phase {
     ShowHints,
     Playing,
+    /// Tapping the timeline's events from earliest to latest
+    Ordering,
 }
@@ -13,8 +13,11 @@ use shared::domain::jig::{
 use utils::prelude::*;
 
 use futures_signals::signal::Mutable;
+use rand::prelude::*;
 use std::rc::Rc;
 
+use components::module::_groups::design::structured::state::Cards;
+
 pub struct Base {
     pub jig_id: JigId,
     pub module_id: ModuleId,
@@ -26,6 +29,10 @@ pub struct Base {
     pub stickers: Vec<Sticker>,
     pub traces: Vec<Trace>,
     pub module_phase: Mutable<ModulePlayPhase>,
+    /// Cards laid out from a timeline or family tree
+    pub cards: Option<Rc<Cards>>,
+    /// Timeline events (and their traces) from earliest to latest, when they're put in order
+    pub order: Option<Vec<usize>>,
 }
 
 impl Base {
@@ -41,6 +48,30 @@ impl Base {
 
         let content = raw.content.unwrap_ji();
 
+        let mut traces = content.traces;
+        let mut order = None;
+
+        let cards = match content.mode {
+            Mode::Timeline => content.timeline.as_ref().map(|timeline| {
+                let chronological = timeline.chronological();
+
+                if content.play_settings.put_in_order {
+                    let mut shuffled = chronological.clone();
+                    shuffled.shuffle(&mut thread_rng());
+
+                    traces = timeline.layout_in(&shuffled);
+                    order = Some(chronological);
+
+                    // dates are only shown once the event is put in its place
+                    Cards::timeline(timeline, &shuffled, false)
+                } else {
+                    Cards::timeline(timeline, &chronological, true)
+                }
+            }),
+            Mode::FamilyTree => content.family_tree.as_ref().map(Cards::family_tree),
+            _ => None,
+        };
+
         Rc::new(Self {
             jig_id,
             module_id,
@@ -50,8 +81,10 @@ impl Base {
             settings: content.play_settings,
             backgrounds: content.base.backgrounds,
             stickers: content.base.stickers,
-            traces: content.traces,
+            traces,
             module_phase: init_args.play_phase,
+            cards,
+            order,
         })
     }
 }
@@ -0,0 +1,98 @@
+use dominator::{html, Dom, DomBuilder};
+use std::rc::Rc;
+
+use super::state::*;
+use futures_signals::signal::SignalExt;
+use shared::domain::jig::module::body::{
+    _groups::cards::CardContent,
+    word_grid::{Grid, GridKind},
+};
+use web_sys::HtmlElement;
+
+pub fn render(state: Rc<MainSettings>) -> Dom {
+    let grid_kind = state.base.extra.settings.grid_kind.clone();
+
+    html!("word-grid-main", {
+        .property("slot", "main")
+        .property_signal("rtl", state.base.extra.settings.rtl.signal())
+        .children_signal_vec(state.grid_signal().map(move |grid| {
+            render_grid(&state, &grid, grid_kind.get())
+        }).to_signal_vec())
+    })
+}
+
+/// Renders the grid with the answers showing, and the list of words or clues.
+fn render_grid(state: &MainSettings, grid: &Grid, grid_kind: GridKind) -> Vec<Dom> {
+    let mut children = Vec::new();
+
+    children.push(html!("word-grid-board", {
+        .property("slot", "grid")
+        .property("rows", grid.rows as u32)
+        .property("columns", grid.columns as u32)
+        .children((0..grid.rows).flat_map(|row| (0..grid.columns).map(move |column| (row, column))).map(|(row, column)| {
+            let number = grid
+                .placements
+                .iter()
+                .find(|placement| placement.cells.first() == Some(&(row, column)))
+                .and_then(|placement| placement.number);
+
+            let found = grid
+                .placements
+                .iter()
+                .any(|placement| placement.cells.contains(&(row, column)));
+
+            html!("word-grid-cell", {
+                .property("letter", grid.cell(row, column).map(String::from).unwrap_or_default())
+                .property("blocked", grid.cell(row, column).is_none())
+                .property("found", found && grid_kind == GridKind::WordSearch)
+                .apply(|dom| match number {
+                    Some(number) => dom.property("number", number),
+                    None => dom,
+                })
+            })
+        }))
+    }));
+
+    children.extend(grid.placements.iter().map(|placement| {
+        let clue = match grid_kind {
+            GridKind::WordSearch => Some(placement.word.clone()),
+            GridKind::Crossword => placement.clue.clone(),
+        };
+
+        html!("word-grid-clue", {
+            .property("slot", "clues")
+            .apply(|dom| match placement.number {
+                Some(number) => dom.property("number", number),
+                None => dom,
+            })
+            .apply(|dom| match clue {
+                Some(clue) => dom.text(&clue),
+                None => render_image_clue(dom, state, placement.pair_index),
+            })
+        })
+    }));
+
+    if !grid.skipped.is_empty() {
+        children.push(html!("word-grid-skipped", {
+            .property("slot", "skipped")
+            .property("count", grid.skipped.len() as u32)
+        }));
+    }
+
+    children
+}
+
+fn render_image_clue(
+    dom: DomBuilder<HtmlElement>,
+    state: &MainSettings,
+    pair_index: usize,
+) -> DomBuilder<HtmlElement> {
+    match state.pairs.get(pair_index).map(|pair| &pair.1.card_content) {
+        Some(CardContent::Image(Some(image))) => dom.child(html!("img-ji", {
+            .property("size", "thumb")
+            .property("id", image.id.0.to_string())
+            .property("lib", image.lib.to_str())
+        })),
+        _ => dom,
+    }
+}
@@ -0,0 +1,38 @@
+use crate::state::*;
+use futures_signals::signal::{Signal, SignalExt};
+use shared::domain::jig::module::body::{
+    _groups::cards::CardPair,
+    word_grid::{generate, Grid},
+};
+use std::rc::Rc;
+
+pub struct MainSettings {
+    pub base: Rc<Base>,
+    pub pairs: Rc<Vec<CardPair>>,
+}
+
+impl MainSettings {
+    pub fn new(base: Rc<Base>) -> Self {
+        let pairs = base
+            .clone_pairs_raw()
+            .into_iter()
+            .map(|(a, b)| CardPair(a, b))
+            .collect();
+
+        Self {
+            base,
+            pairs: Rc::new(pairs),
+        }
+    }
+
+    /// The grid the player will get, regenerated whenever a setting changes
+    pub fn grid_signal(&self) -> impl Signal<Item = Rc<Grid>> {
+        let pairs = self.pairs.clone();
+
+        self.base
+            .extra
+            .settings
+            .player_settings_signal()
+            .map(move |settings| Rc::new(generate(&pairs, &settings)))
+    }
+}
@@ -0,0 +1,3 @@
+pub mod main;
+pub mod sidebar;
+pub mod state;
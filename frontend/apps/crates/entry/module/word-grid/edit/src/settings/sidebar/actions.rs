@@ -0,0 +1,87 @@
+use super::state::*;
+use rand::prelude::*;
+use shared::domain::jig::module::body::word_grid::{GridKind, MAX_GRID_SIZE, MIN_GRID_SIZE};
+
+impl SidebarSettings {
+    pub fn set_grid_kind(&self, grid_kind: GridKind) {
+        self.base.extra.settings.grid_kind.set_neq(grid_kind);
+
+        self.base.history.push_modify(|raw| {
+            if let Some(content) = &mut raw.content {
+                content.player_settings.grid_kind = grid_kind;
+            }
+        })
+    }
+
+    pub fn set_rows(&self, rows: u8) {
+        let rows = rows.clamp(MIN_GRID_SIZE, MAX_GRID_SIZE);
+
+        self.base.extra.settings.rows.set_neq(rows);
+
+        self.base.history.push_modify(|raw| {
+            if let Some(content) = &mut raw.content {
+                content.player_settings.rows = rows;
+            }
+        })
+    }
+
+    pub fn set_columns(&self, columns: u8) {
+        let columns = columns.clamp(MIN_GRID_SIZE, MAX_GRID_SIZE);
+
+        self.base.extra.settings.columns.set_neq(columns);
+
+        self.base.history.push_modify(|raw| {
+            if let Some(content) = &mut raw.content {
+                content.player_settings.columns = columns;
+            }
+        })
+    }
+
+    pub fn toggle_diagonal(&self) {
+        let settings = &self.base.extra.settings;
+
+        settings.diagonal.set_neq(!settings.diagonal.get());
+        self.save_directions();
+    }
+
+    pub fn toggle_backward(&self) {
+        let settings = &self.base.extra.settings;
+
+        settings.backward.set_neq(!settings.backward.get());
+        self.save_directions();
+    }
+
+    fn save_directions(&self) {
+        let directions = self.base.extra.settings.directions();
+
+        self.base.history.push_modify(|raw| {
+            if let Some(content) = &mut raw.content {
+                content.player_settings.directions = directions;
+            }
+        })
+    }
+
+    pub fn toggle_rtl(&self) {
+        let rtl = !self.base.extra.settings.rtl.get();
+
+        self.base.extra.settings.rtl.set_neq(rtl);
+
+        self.base.history.push_modify(|raw| {
+            if let Some(content) = &mut raw.content {
+                content.player_settings.rtl = rtl;
+            }
+        })
+    }
+
+    pub fn reshuffle(&self) {
+        let seed = thread_rng().gen();
+
+        self.base.extra.settings.seed.set_neq(seed);
+
+        self.base.history.push_modify(|raw| {
+            if let Some(content) = &mut raw.content {
+                content.player_settings.seed = seed;
+            }
+        })
+    }
+}
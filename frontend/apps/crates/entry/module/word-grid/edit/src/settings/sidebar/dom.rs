@@ -0,0 +1,109 @@
+use dominator::{clone, Dom};
+use std::rc::Rc;
+
+use super::state::*;
+use futures_signals::signal::{always, SignalExt};
+use shared::domain::jig::module::body::word_grid::GridKind;
+
+use components::module::_common::edit::settings::prelude::*;
+
+pub fn render(state: Rc<SidebarSettings>) -> Dom {
+    render_settings(Rc::new(ModuleSettings {
+        lines: vec![
+            (
+                LineKind::GridKind,
+                vec![
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::WordSearch,
+                        clone!(state => move || {
+                            state.base.extra.settings.grid_kind
+                                .signal()
+                                .map(|grid_kind| grid_kind == GridKind::WordSearch)
+                        }),
+                        clone!(state => move || {
+                            state.set_grid_kind(GridKind::WordSearch);
+                        }),
+                    )),
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::Crossword,
+                        clone!(state => move || {
+                            state.base.extra.settings.grid_kind
+                                .signal()
+                                .map(|grid_kind| grid_kind == GridKind::Crossword)
+                        }),
+                        clone!(state => move || {
+                            state.set_grid_kind(GridKind::Crossword);
+                        }),
+                    )),
+                ],
+            ),
+            (
+                LineKind::GridSize,
+                vec![
+                    Some(SettingsButton::new_value(
+                        SettingsButtonKind::GridRows,
+                        || always(true),
+                        SettingsValue::new_mutable(
+                            state.settings().rows.clone(),
+                            clone!(state => move |value| {
+                                state.set_rows(value);
+                            }),
+                        ),
+                    )),
+                    Some(SettingsButton::new_value(
+                        SettingsButtonKind::GridColumns,
+                        || always(true),
+                        SettingsValue::new_mutable(
+                            state.settings().columns.clone(),
+                            clone!(state => move |value| {
+                                state.set_columns(value);
+                            }),
+                        ),
+                    )),
+                ],
+            ),
+            (
+                LineKind::WordDirections,
+                vec![
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::Diagonal,
+                        clone!(state => move || {
+                            state.base.extra.settings.diagonal.signal()
+                        }),
+                        clone!(state => move || {
+                            state.toggle_diagonal();
+                        }),
+                    )),
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::Backward,
+                        clone!(state => move || {
+                            state.base.extra.settings.backward.signal()
+                        }),
+                        clone!(state => move || {
+                            state.toggle_backward();
+                        }),
+                    )),
+                    Some(SettingsButton::new_click(
+                        SettingsButtonKind::Rtl,
+                        clone!(state => move || {
+                            state.base.extra.settings.rtl.signal()
+                        }),
+                        clone!(state => move || {
+                            state.toggle_rtl();
+                        }),
+                    )),
+                ],
+            ),
+            (
+                LineKind::Layout,
+                vec![Some(SettingsButton::new_click(
+                    SettingsButtonKind::Reshuffle,
+                    || always(false),
+                    clone!(state => move || {
+                        state.reshuffle();
+                    }),
+                ))],
+            ),
+        ],
+    }))
+}
@@ -0,0 +1,94 @@
+use futures_signals::{
+    map_ref,
+    signal::{Mutable, Signal},
+};
+use shared::domain::jig::module::body::word_grid::{Content, Direction, GridKind, PlayerSettings};
+
+pub struct Settings {
+    pub grid_kind: Mutable<GridKind>,
+    pub rows: Mutable<u8>,
+    pub columns: Mutable<u8>,
+    pub diagonal: Mutable<bool>,
+    pub backward: Mutable<bool>,
+    pub rtl: Mutable<bool>,
+    pub seed: Mutable<u64>,
+}
+
+impl Settings {
+    pub fn new(content: Content) -> Self {
+        let settings = content.player_settings;
+
+        let diagonal = settings.directions.iter().any(|direction| {
+            matches!(
+                direction,
+                Direction::DownForward
+                    | Direction::DownBackward
+                    | Direction::UpForward
+                    | Direction::UpBackward
+            )
+        });
+
+        let backward = settings.directions.iter().any(|direction| {
+            matches!(
+                direction,
+                Direction::Backward
+                    | Direction::Up
+                    | Direction::DownBackward
+                    | Direction::UpBackward
+            )
+        });
+
+        Self {
+            grid_kind: Mutable::new(settings.grid_kind),
+            rows: Mutable::new(settings.rows),
+            columns: Mutable::new(settings.columns),
+            diagonal: Mutable::new(diagonal),
+            backward: Mutable::new(backward),
+            rtl: Mutable::new(settings.rtl),
+            seed: Mutable::new(settings.seed),
+        }
+    }
+
+    /// The directions words can go in, from the diagonal and backward toggles
+    pub fn directions(&self) -> Vec<Direction> {
+        directions(self.diagonal.get(), self.backward.get())
+    }
+
+    pub fn player_settings_signal(&self) -> impl Signal<Item = PlayerSettings> {
+        map_ref! {
+            let grid_kind = self.grid_kind.signal(),
+            let rows = self.rows.signal(),
+            let columns = self.columns.signal(),
+            let diagonal = self.diagonal.signal(),
+            let backward = self.backward.signal(),
+            let rtl = self.rtl.signal(),
+            let seed = self.seed.signal()
+                => PlayerSettings {
+                    grid_kind: *grid_kind,
+                    rows: *rows,
+                    columns: *columns,
+                    directions: directions(*diagonal, *backward),
+                    rtl: *rtl,
+                    seed: *seed,
+                }
+        }
+    }
+}
+
+fn directions(diagonal: bool, backward: bool) -> Vec<Direction> {
+    let mut directions = vec![Direction::Forward, Direction::Down];
+
+    if diagonal {
+        directions.extend([Direction::DownForward, Direction::UpForward]);
+    }
+
+    if backward {
+        directions.extend([Direction::Backward, Direction::Up]);
+
+        if diagonal {
+            directions.extend([Direction::DownBackward, Direction::UpBackward]);
+        }
+    }
+
+    directions
+}
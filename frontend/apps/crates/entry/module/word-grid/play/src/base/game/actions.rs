@@ -0,0 +1,89 @@
+use super::state::*;
+
+use components::{
+    audio::mixer::{AudioPath, AUDIO_MIXER},
+    module::_common::play::prelude::*,
+};
+
+use crate::base::state::Phase;
+use std::rc::Rc;
+
+impl Game {
+    /// Word search: the first click starts a selection, the second one ends it.
+    pub fn select_cell(state: Rc<Self>, row: usize, column: usize) {
+        let start = match state.selection_start.replace(None) {
+            Some(start) => start,
+            None => {
+                state.selection_start.set(Some((row, column)));
+                return;
+            }
+        };
+
+        if start == (row, column) {
+            return;
+        }
+
+        let grid = &state.base.grid;
+
+        let index = grid
+            .find_placement(start, (row, column))
+            .and_then(|placement| grid.placements.iter().position(|it| it == placement));
+
+        match index {
+            Some(index) if !state.found.lock_ref().contains(&index) => {
+                state.found.lock_mut().insert(index);
+                play_feedback(true);
+                Self::check_ended(state);
+            }
+            _ => play_feedback(false),
+        }
+    }
+
+    /// Crossword: a letter was typed into a cell.
+    pub fn enter_letter(state: Rc<Self>, row: usize, column: usize, value: &str) {
+        let grid = &state.base.grid;
+
+        let letter = value.chars().flat_map(char::to_uppercase).last();
+        state.entries[row * grid.columns + column].set_neq(letter);
+
+        let newly_found: Vec<usize> = grid
+            .placements
+            .iter()
+            .enumerate()
+            .filter(|(index, placement)| {
+                !state.found.lock_ref().contains(index)
+                    && placement.cells.iter().all(|(row, column)| {
+                        state.entries[row * grid.columns + column].get() == grid.cell(*row, *column)
+                    })
+            })
+            .map(|(index, _)| index)
+            .collect();
+
+        if !newly_found.is_empty() {
+            state.found.lock_mut().extend(newly_found);
+            play_feedback(true);
+            Self::check_ended(state);
+        }
+    }
+
+    fn check_ended(state: Rc<Self>) {
+        if state.found.lock_ref().len() >= state.base.grid.placements.len() {
+            state.base.phase.set(Phase::Ending);
+            state
+                .base
+                .set_play_phase(ModulePlayPhase::Ending(Some(ModuleEnding::Positive)));
+        }
+    }
+}
+
+fn play_feedback(positive: bool) {
+    AUDIO_MIXER.with(|mixer| {
+        let audio_path: AudioPath<'_> = if positive {
+            mixer.get_random_positive().into()
+        } else {
+            mixer.get_random_negative().into()
+        };
+
+        mixer.play_oneshot(audio_path);
+    });
+}
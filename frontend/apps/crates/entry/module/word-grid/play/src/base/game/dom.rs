@@ -0,0 +1,106 @@
+use dominator::{clone, html, with_node, Dom};
+
+use super::state::*;
+use futures_signals::signal::{Signal, SignalExt};
+use shared::domain::jig::module::body::{_groups::cards::CardContent, word_grid::GridKind};
+use std::rc::Rc;
+use utils::prelude::*;
+use web_sys::HtmlInputElement;
+
+impl Game {
+    pub fn render(state: Rc<Self>) -> Dom {
+        let grid = state.base.grid.clone();
+
+        html!("word-grid-main", {
+            .property("slot", "main")
+            .property("rtl", state.base.settings.rtl)
+            .child(html!("word-grid-board", {
+                .property("slot", "grid")
+                .property("rows", grid.rows as u32)
+                .property("columns", grid.columns as u32)
+                .children((0..grid.rows).flat_map(|row| (0..grid.columns).map(move |column| (row, column))).map(|(row, column)| {
+                    render_cell(state.clone(), row, column)
+                }))
+            }))
+            .children(grid.placements.iter().enumerate().map(|(index, placement)| {
+                html!("word-grid-clue", {
+                    .property("slot", "clues")
+                    .property_signal("found", state.found.signal_ref(move |found| found.contains(&index)))
+                    .apply(|dom| match placement.number {
+                        Some(number) => dom.property("number", number),
+                        None => dom,
+                    })
+                    .apply(|dom| {
+                        let clue = match state.base.settings.grid_kind {
+                            GridKind::WordSearch => Some(placement.word.clone()),
+                            GridKind::Crossword => placement.clue.clone(),
+                        };
+
+                        match (clue, state.base.raw_pairs.get(placement.pair_index).map(|pair| &pair.1.card_content)) {
+                            (Some(clue), _) => dom.text(&clue),
+                            (None, Some(CardContent::Image(Some(image)))) => dom.child(html!("img-ji", {
+                                .property("size", "thumb")
+                                .property("id", image.id.0.to_string())
+                                .property("lib", image.lib.to_str())
+                            })),
+                            (None, _) => dom,
+                        }
+                    })
+                })
+            }))
+        })
+    }
+}
+
+fn render_cell(state: Rc<Game>, row: usize, column: usize) -> Dom {
+    let grid = &state.base.grid;
+    let letter = grid.cell(row, column);
+
+    let number = grid
+        .placements
+        .iter()
+        .find(|placement| placement.cells.first() == Some(&(row, column)))
+        .and_then(|placement| placement.number);
+
+    html!("word-grid-cell", {
+        .property("blocked", letter.is_none())
+        .property_signal("found", found_signal(&state, row, column))
+        .apply(|dom| match number {
+            Some(number) => dom.property("number", number),
+            None => dom,
+        })
+        .apply(|dom| match state.base.settings.grid_kind {
+            GridKind::WordSearch => {
+                dom
+                    .property("letter", letter.map(String::from).unwrap_or_default())
+                    .property_signal("selected", state.selection_start.signal().map(move |start| {
+                        start == Some((row, column))
+                    }))
+                    .event(clone!(state => move |_evt:events::Click| {
+                        Game::select_cell(state.clone(), row, column);
+                    }))
+            },
+            GridKind::Crossword => {
+                dom.apply_if(letter.is_some(), |dom| {
+                    dom.child(html!("input" => HtmlInputElement, {
+                        .attribute("maxlength", "2")
+                        .property_signal("value", state.entries[row * grid.columns + column].signal().map(|entry| {
+                            entry.map(String::from).unwrap_or_default()
+                        }))
+                        .with_node!(elem => {
+                            .event(clone!(state => move |_evt:events::Input| {
+                                Game::enter_letter(state.clone(), row, column, &elem.value());
+                            }))
+                        })
+                    }))
+                })
+            },
+        })
+    })
+}
+
+fn found_signal(state: &Rc<Game>, row: usize, column: usize) -> impl Signal<Item = bool> {
+    state.found.signal_ref(clone!(state => move |found| {
+        state.is_found(found, row, column)
+    }))
+}
@@ -0,0 +1,39 @@
+use crate::base::state::Base;
+use futures_signals::signal::Mutable;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+pub struct Game {
+    pub base: Rc<Base>,
+    /// Indexes of the placements the student has found (or filled in)
+    pub found: Mutable<HashSet<usize>>,
+    /// Word search: the first cell of the word being selected
+    pub selection_start: Mutable<Option<(usize, usize)>>,
+    /// Crossword: what the student typed in each cell, row by row
+    pub entries: Vec<Mutable<Option<char>>>,
+}
+
+impl Game {
+    pub fn new(base: Rc<Base>) -> Rc<Self> {
+        let entries = base.grid.cells.iter().map(|_| Mutable::new(None)).collect();
+
+        Rc::new(Self {
+            base,
+            found: Mutable::new(HashSet::new()),
+            selection_start: Mutable::new(None),
+            entries,
+        })
+    }
+
+    /// Whether the cell is part of one of the `found` placements
+    pub fn is_found(&self, found: &HashSet<usize>, row: usize, column: usize) -> bool {
+        self.base
+            .grid
+            .placements
+            .iter()
+            .enumerate()
+            .any(|(index, placement)| {
+                found.contains(&index) && placement.cells.contains(&(row, column))
+            })
+    }
+}
@@ -0,0 +1,84 @@
+use shared::domain::jig::{
+    module::{
+        body::{
+            _groups::cards::{CardPair, Mode, Step},
+            word_grid::{generate, Grid, ModuleData as RawData, PlayerSettings},
+            Background, Instructions,
+        },
+        ModuleId,
+    },
+    JigId,
+};
+
+use futures_signals::signal::Mutable;
+use std::rc::Rc;
+
+use components::module::_common::play::prelude::*;
+use utils::prelude::*;
+
+use super::game::state::Game;
+
+pub struct Base {
+    pub jig_id: JigId,
+    pub module_id: ModuleId,
+    pub mode: Mode,
+    pub theme_id: ThemeId,
+    pub background: Option<Background>,
+    pub instructions: Instructions,
+    pub settings: PlayerSettings,
+    pub raw_pairs: Vec<CardPair>,
+    pub grid: Rc<Grid>,
+    pub phase: Mutable<Phase>,
+    pub module_phase: Mutable<ModulePlayPhase>,
+}
+
+#[derive(Clone)]
+pub enum Phase {
+    Init,
+    Playing(Rc<Game>),
+    Ending,
+}
+
+impl Base {
+    pub async fn new(init_args: InitFromRawArgs<RawData, Mode, Step>) -> Rc<Self> {
+        let InitFromRawArgs {
+            jig_id,
+            module_id,
+            raw,
+            theme_id,
+            ..
+        } = init_args;
+
+        let content = raw.content.unwrap_ji();
+
+        let grid = Rc::new(generate(&content.base.pairs, &content.player_settings));
+
+        let _self = Rc::new(Self {
+            jig_id,
+            module_id,
+            mode: content.base.mode,
+            theme_id,
+            background: content.base.background,
+            instructions: content.base.instructions,
+            settings: content.player_settings,
+            raw_pairs: content.base.pairs,
+            grid,
+            phase: Mutable::new(Phase::Init),
+            module_phase: init_args.play_phase,
+        });
+
+        _self.phase.set(Phase::Playing(Game::new(_self.clone())));
+
+        _self
+    }
+}
+
+impl BaseExt for Base {
+    fn get_instructions(&self) -> Option<Instructions> {
+        Some(self.instructions.clone())
+    }
+
+    fn play_phase(&self) -> Mutable<ModulePlayPhase> {
+        self.module_phase.clone()
+    }
+}
@@ -45,6 +45,12 @@ pub fn pdf_lib_url(library_kind: MediaLibrary, id: PdfId) -> String {
     uploads_url(&path)
 }
 
+pub fn pdf_thumbnail_url(library_kind: MediaLibrary, id: PdfId) -> String {
+    let path = media_key(library_kind, id.0, FileKind::DocumentThumbnail);
+
+    uploads_url(&path)
+}
+
 pub fn uploads_url(path: &str) -> String {
     format!(
         "{}/{}",
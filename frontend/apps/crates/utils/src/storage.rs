@@ -3,6 +3,7 @@ use wasm_bindgen::prelude::*;
 use web_sys::{window, Storage};
 
 pub const CSRF_STORAGE_NAME: &str = "X-CSRF";
+pub const PLAYER_SESSION_TOKEN_STORAGE_NAME: &str = "X-PLAYER-SESSION";
 
 pub fn load_csrf_token() -> Option<String> {
     let res = get_local_storage()
@@ -29,6 +30,22 @@ pub fn delete_csrf_token() -> Result<(), JsValue> {
     local_storage.remove_item(CSRF_STORAGE_NAME)
 }
 
+/// The token of the player session instance a student joined with a code, if any.
+pub fn load_player_session_token() -> Option<String> {
+    get_local_storage()
+        .unwrap_ji()
+        .get(PLAYER_SESSION_TOKEN_STORAGE_NAME)
+        .unwrap_ji()
+}
+
+pub fn save_player_session_token(token: &str) {
+    let local_storage = get_local_storage().unwrap_ji();
+
+    local_storage
+        .set(PLAYER_SESSION_TOKEN_STORAGE_NAME, token)
+        .unwrap_ji()
+}
+
 pub fn get_local_storage() -> Result<Storage, JsValue> {
     window()
         .unwrap_ji()
@@ -21,6 +21,12 @@ const ALLOWED_APP_NAMES = [
     "module/flashcards/play",
     "module/card-quiz/edit",
     "module/card-quiz/play",
+    "module/word-grid/edit",
+    "module/word-grid/play",
+    "module/puzzle/edit",
+    "module/puzzle/play",
+    "module/talk-type/edit",
+    "module/talk-type/play",
     "module/matching/edit",
     "module/matching/play",
     "module/poster/edit",
@@ -1,3 +1,4 @@
 import "@elements/_bundles/_sub-bundles/module/play";
 import "@elements/_bundles/_sub-bundles/module/_groups/design/play";
+import "@elements/module/drag-drop/play/bucket-report";
 import "@elements/_bundles/_sub-bundles/all";
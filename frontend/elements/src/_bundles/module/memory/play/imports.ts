@@ -5,3 +5,4 @@ import "@elements/module/memory/play/container";
 import "@elements/module/memory/play/main";
 import "@elements/module/memory/play/sidebar";
 import "@elements/module/memory/play/ending";
+import "@elements/module/memory/play/players";
@@ -0,0 +1,4 @@
+import "@elements/_bundles/_sub-bundles/module/edit";
+import "@elements/_bundles/_sub-bundles/module/_groups/design/edit";
+import "@elements/_bundles/_sub-bundles/all";
+import "@elements/_bundles/_sub-bundles/hebrew-buttons";
@@ -0,0 +1,3 @@
+import "@elements/_bundles/_sub-bundles/module/play";
+import "@elements/_bundles/_sub-bundles/module/_groups/design/play";
+import "@elements/_bundles/_sub-bundles/all";
@@ -0,0 +1,4 @@
+import "@elements/_bundles/_sub-bundles/module/play";
+import "@elements/_bundles/_sub-bundles/module/_groups/design/play";
+import "@elements/_bundles/_sub-bundles/all";
+import "@elements/module/talk-type/play/input";
@@ -1,4 +1,6 @@
 import "@elements/_bundles/_sub-bundles/module/edit";
 import "@elements/_bundles/_sub-bundles/module/_groups/design/edit";
+import "@elements/module/tapping-board/_common/cards";
+import "@elements/module/tapping-board/edit/entries";
 import "@elements/_bundles/_sub-bundles/all";
 import "@elements/_bundles/_sub-bundles/hebrew-buttons";
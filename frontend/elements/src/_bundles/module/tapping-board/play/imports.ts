@@ -1,3 +1,4 @@
 import "@elements/_bundles/_sub-bundles/module/play";
 import "@elements/_bundles/_sub-bundles/module/_groups/design/play";
+import "@elements/module/tapping-board/_common/cards";
 import "@elements/_bundles/_sub-bundles/all";
@@ -0,0 +1,7 @@
+import "@elements/_bundles/_sub-bundles/module/edit";
+import "@elements/_bundles/_sub-bundles/module/_groups/cards/edit";
+import "@elements/module/word-grid/_common/main/container";
+import "@elements/module/word-grid/_common/main/cell";
+import "@elements/module/word-grid/_common/main/clue";
+import "@elements/_bundles/_sub-bundles/all";
+import "@elements/_bundles/_sub-bundles/hebrew-buttons";
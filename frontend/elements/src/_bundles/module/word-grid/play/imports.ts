@@ -0,0 +1,6 @@
+import "@elements/_bundles/_sub-bundles/module/play";
+import "@elements/_bundles/_sub-bundles/module/_groups/cards/play";
+import "@elements/module/word-grid/_common/main/container";
+import "@elements/module/word-grid/_common/main/cell";
+import "@elements/module/word-grid/_common/main/clue";
+import "@elements/_bundles/_sub-bundles/all";
@@ -15,6 +15,10 @@ const STR_LABEL: Partial<Record<Kind, string>> = {
     rounds: "Complete",
     n_choices: "Display",
     n_pairs: "Display",
+    "grid-rows": "Grid has",
+    "grid-columns": "Grid has",
+    "type-answer": "Allow",
+    players: "Play with",
 };
 
 const STR_LABEL_SUFFIX: Partial<Record<Kind, string[]>> = {
@@ -24,6 +28,10 @@ const STR_LABEL_SUFFIX: Partial<Record<Kind, string[]>> = {
     rounds: ["page", "pages"],
     n_choices: ["card", "cards"],
     n_pairs: ["pair", "pairs"],
+    "grid-rows": ["row", "rows"],
+    "grid-columns": ["column", "columns"],
+    "type-answer": ["typo", "typos"],
+    players: ["player", "players"],
 };
 
 @customElement("module-settings-bubble-content")
@@ -34,12 +34,44 @@ export type Kind =
     | "autoplay"
     | "mute"
     | "loop"
-    | "continue-automatically";
+    | "continue-automatically"
+    | "word-search"
+    | "crossword"
+    | "grid-rows"
+    | "grid-columns"
+    | "diagonal"
+    | "backward"
+    | "rtl"
+    | "reshuffle"
+    | "flip-through"
+    | "spaced-repetition"
+    | "choose-answer"
+    | "type-answer"
+    | "single-player"
+    | "players"
+    | "show-answer"
+    | "retry-later"
+    | "explore"
+    | "put-in-order"
+    | "preview"
+    | "preview-off"
+    | "snap-exact"
+    | "snap-close"
+    | "snap-loose"
+    | "fly-back"
+    | "stay-put"
+    | "letter-hint"
+    | "letter-hint-off"
+    | "exact-spelling"
+    | "allow-typos";
 
 const OneImage: Set<Kind> = new Set([
     "n_choices",
     "order",
     "rounds",
+    "grid-rows",
+    "grid-columns",
+    "reshuffle",
 ]);
 
 const STR_LABEL: Record<Kind, string> = {
@@ -67,6 +99,35 @@ const STR_LABEL: Record<Kind, string> = {
     "mute": "play without sound",
     "loop": "play on loop",
     "continue-automatically": "automatically after video",
+    "word-search": "word search",
+    "crossword": "crossword",
+    "grid-rows": "rows",
+    "grid-columns": "columns",
+    "diagonal": "diagonal words",
+    "backward": "backward words",
+    "rtl": "right to left",
+    "reshuffle": "shuffle the grid",
+    "flip-through": "flip through",
+    "spaced-repetition": "review what\nthey don't know",
+    "choose-answer": "choose the card",
+    "type-answer": "type the answer",
+    "single-player": "one player",
+    "players": "take turns",
+    "show-answer": "show the answer",
+    "retry-later": "try again at\nthe end",
+    "explore": "tap to explore",
+    "put-in-order": "put events\nin order",
+    "preview": "show before\nbreaking apart",
+    "preview-off": "start broken\napart",
+    "snap-exact": "exactly in place",
+    "snap-close": "close to its place",
+    "snap-loose": "anywhere nearby",
+    "fly-back": "fly back",
+    "stay-put": "stay where\nit was dropped",
+    "letter-hint": "reveal a letter",
+    "letter-hint-off": "try again\nwithout hints",
+    "exact-spelling": "exact spelling",
+    "allow-typos": "allow typos",
 };
 
 @customElement("module-settings-button")
@@ -15,9 +15,23 @@ export type Kind =
     | "next"
     | "time-limit"
     | "attempts"
+    | "after-attempts"
     | "score"
     | "video-play"
-    | "video-features";
+    | "video-features"
+    | "grid-kind"
+    | "grid-size"
+    | "word-directions"
+    | "layout"
+    | "review"
+    | "answer"
+    | "players"
+    | "timeline"
+    | "preview"
+    | "snap"
+    | "wrong-drop"
+    | "letter-hints"
+    | "spelling";
 
 const STR_LABEL: Record<Kind, string> = {
     "card-view": "How should your cards be displayed?",
@@ -27,9 +41,23 @@ const STR_LABEL: Record<Kind, string> = {
     "next": "Student finishes this activity by...",
     "time-limit": "Would you like to set a time limit?",
     "attempts": "How many tries does the student get?",
+    "after-attempts": "When the student runs out of tries...",
     "score": "Would you like to include score?",
     "video-play": "How to play your video:",
     "video-features": "Play features",
+    "grid-kind": "What kind of puzzle should the student solve?",
+    "grid-size": "How big should the grid be?",
+    "word-directions": "Which way can the words go?",
+    "layout": "Don't like the layout?",
+    "review": "How should the student practice?",
+    "answer": "How should the student answer?",
+    "players": "How many students are playing?",
+    "timeline": "How should the student play the timeline?",
+    "preview": "Show the whole picture first?",
+    "snap": "How close does a piece need to be dropped?",
+    "wrong-drop": "When a piece is dropped in the wrong place...",
+    "letter-hints": "After a wrong answer...",
+    "spelling": "How exact should typed answers be?",
 };
 
 @customElement("module-settings-line")
@@ -9,6 +9,9 @@ export type ModuleKind =
     | "tracing"
     | "video"
     | "card-quiz"
+    | "word-grid"
+    | "puzzle"
+    | "talk-type"
     | "drag-drop";
 
 export const moduleKinds: Array<ModuleKind> = [
@@ -22,6 +25,9 @@ export const moduleKinds: Array<ModuleKind> = [
     "tracing",
     "video",
     "card-quiz",
+    "word-grid",
+    "puzzle",
+    "talk-type",
     "drag-drop",
 ];
 
@@ -38,6 +44,9 @@ export const STR_MODULE_DISPLAY_NAME: { [key in ModuleKind]: string } = {
     tracing: "Tracing",
     video: "Video Player",
     "card-quiz": "Multiple Choice",
+    "word-grid": "Word Search & Crossword",
+    puzzle: "Puzzle",
+    "talk-type": "Talk Type",
     "drag-drop": "Drag & Drop",
 };
 
@@ -48,9 +57,12 @@ export const STR_MODULE_CHOOSE_HEADER: { [key in ModuleKind]: string } = {
     matching: "Create a Matching Game",
     memory: "Create a Memory Game",
     "card-quiz": "Create a Multiple Choice Activity",
+    "word-grid": "Create a Word Search or Crossword",
     "tapping-board": "Create a Listen & Learn Activity",
     poster: "Create a Talking Poster",
     "drag-drop": "Create a Drag & Drop Activity",
+    puzzle: "Create a Puzzle",
+    "talk-type": "Create a Talk Type",
     tracing: "Create a Tracing Activity",
     video: "Create a Video Activity",
 };
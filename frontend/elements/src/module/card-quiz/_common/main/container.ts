@@ -30,6 +30,29 @@ export class _ extends LitElement {
                     display: flex;
                     gap: 80rem;
                 }
+                .answer {
+                    display: flex;
+                    align-items: center;
+                    gap: 24rem;
+                }
+                ::slotted(input[slot="answer"]) {
+                    width: 420rem;
+                    padding: 12rem 24rem;
+                    font-size: 32rem;
+                    border: solid 2rem var(--light-blue-5);
+                    border-radius: 16rem;
+                    outline: none;
+                }
+                ::slotted(input.wrong) {
+                    border-color: var(--red-4);
+                }
+                ::slotted(input.correct) {
+                    border-color: var(--green-4);
+                }
+                ::slotted(input.revealed) {
+                    border-color: var(--light-blue-5);
+                    font-style: italic;
+                }
             `,
         ];
     }
@@ -43,6 +66,9 @@ export class _ extends LitElement {
                 <div class="options">
                     <slot name="options"></slot>
                 </div>
+                <div class="answer">
+                    <slot name="answer"></slot>
+                </div>
             </section>
         `;
     }
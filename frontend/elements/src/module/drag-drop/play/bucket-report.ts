@@ -0,0 +1,79 @@
+import { LitElement, html, css, customElement, property } from "lit-element";
+
+@customElement("drag-drop-bucket-report")
+export class _ extends LitElement {
+    static get styles() {
+        return [
+            css`
+                :host {
+                    position: absolute;
+                    top: 48rem;
+                    left: 50%;
+                    transform: translateX(-50%);
+                    display: flex;
+                    gap: 24rem;
+                    padding: 24rem 32rem;
+                    border-radius: 24rem;
+                    background-color: var(--white);
+                    box-shadow: 0 3rem 12rem 0 rgba(0, 0, 0, 0.16);
+                }
+            `,
+        ];
+    }
+
+    render() {
+        return html`<slot></slot>`;
+    }
+}
+
+@customElement("drag-drop-bucket-score")
+export class __ extends LitElement {
+    static get styles() {
+        return [
+            css`
+                :host {
+                    display: flex;
+                    flex-direction: column;
+                    align-items: center;
+                    gap: 8rem;
+                    font-size: 24rem;
+                    color: var(--dark-gray-6);
+                }
+                .label {
+                    font-weight: 600;
+                }
+                .scores {
+                    display: flex;
+                    gap: 16rem;
+                }
+                .correct {
+                    color: var(--main-green);
+                }
+                .incorrect {
+                    color: var(--red-4);
+                }
+            `,
+        ];
+    }
+
+    @property()
+    label: string = "";
+
+    @property({ type: Number })
+    correct: number = 0;
+
+    @property({ type: Number })
+    incorrect: number = 0;
+
+    render() {
+        const { label, correct, incorrect } = this;
+
+        return html`
+            <span class="label">${label}</span>
+            <div class="scores">
+                <span class="correct">✓ ${correct}</span>
+                <span class="incorrect">✗ ${incorrect}</span>
+            </div>
+        `;
+    }
+}
@@ -30,6 +30,15 @@ export class _ extends LitElement {
                     height: 48rem;
                     filter: drop-shadow(0 3rem 12rem rgba(0, 0, 0, 0.24));
                 }
+
+                ::slotted([slot="review"]) {
+                    position: absolute;
+                    bottom: 38rem;
+                    left: 50%;
+                    transform: translateX(-50%);
+                    display: flex;
+                    gap: 24rem;
+                }
             `,
         ];
     }
@@ -39,6 +48,7 @@ export class _ extends LitElement {
             <section>
                 <slot></slot>
                 <slot name="next"></slot>
+                <slot name="review"></slot>
             </section>
         `;
     }
@@ -0,0 +1,155 @@
+import { LitElement, html, css, customElement, property } from "lit-element";
+import { classMap } from "lit-html/directives/class-map";
+
+const STR_WHO_IS_PLAYING = "Who's playing?";
+const STR_TURN = "Your turn!";
+const STR_WINNER = "Winner!";
+
+@customElement("memory-players-setup")
+export class _ extends LitElement {
+    static get styles() {
+        return [
+            css`
+                :host {
+                    display: flex;
+                    width: calc(1320rem * (1920 / 1719));
+                    height: 100%;
+                    align-items: center;
+                    justify-content: center;
+                }
+                section {
+                    display: flex;
+                    flex-direction: column;
+                    align-items: center;
+                    gap: 32rem;
+                    padding: 48rem 64rem;
+                    border-radius: 24rem;
+                    background-color: var(--white);
+                    box-shadow: 0 3rem 12rem 0 rgba(0, 0, 0, 0.16);
+                }
+                .label {
+                    font-weight: 900;
+                    font-size: 40rem;
+                    color: var(--dark-blue-4);
+                }
+                .names {
+                    display: grid;
+                    grid-template-columns: repeat(2, 320rem);
+                    gap: 24rem;
+                }
+                ::slotted(input) {
+                    font-size: 24rem;
+                    padding: 12rem 16rem;
+                    border-radius: 12rem;
+                    border: solid 2rem var(--light-blue-5);
+                }
+            `,
+        ];
+    }
+
+    render() {
+        return html`
+            <section>
+                <div class="label">${STR_WHO_IS_PLAYING}</div>
+                <div class="names"><slot name="names"></slot></div>
+                <slot name="start"></slot>
+            </section>
+        `;
+    }
+}
+
+@customElement("memory-scoreboard")
+export class __ extends LitElement {
+    static get styles() {
+        return [
+            css`
+                :host {
+                    position: absolute;
+                    top: 24rem;
+                    left: 50%;
+                    transform: translateX(-50%);
+                    display: flex;
+                    gap: 24rem;
+                    padding: 16rem 32rem;
+                    border-radius: 24rem;
+                    background-color: var(--white);
+                    box-shadow: 0 3rem 12rem 0 rgba(0, 0, 0, 0.16);
+                }
+                :host([ended]) {
+                    top: 50%;
+                    transform: translate(-50%, -50%);
+                    flex-direction: column;
+                    padding: 48rem 64rem;
+                }
+            `,
+        ];
+    }
+
+    @property({ type: Boolean, reflect: true })
+    ended: boolean = false;
+
+    render() {
+        return html`<slot></slot>`;
+    }
+}
+
+@customElement("memory-player-score")
+export class ___ extends LitElement {
+    static get styles() {
+        return [
+            css`
+                :host {
+                    display: flex;
+                    flex-direction: column;
+                    align-items: center;
+                    gap: 8rem;
+                    padding: 8rem 16rem;
+                    border-radius: 16rem;
+                    font-size: 24rem;
+                    color: var(--dark-gray-6);
+                }
+                :host([current]) {
+                    background-color: var(--light-blue-3);
+                }
+                .name {
+                    font-weight: 600;
+                }
+                .score {
+                    font-weight: 900;
+                    font-size: 32rem;
+                }
+                .note {
+                    font-size: 16rem;
+                    color: var(--dark-blue-4);
+                }
+                .note.winner {
+                    color: var(--main-green);
+                }
+            `,
+        ];
+    }
+
+    @property()
+    name: string = "";
+
+    @property({ type: Number })
+    score: number = 0;
+
+    @property({ type: Boolean, reflect: true })
+    current: boolean = false;
+
+    @property({ type: Boolean, reflect: true })
+    winner: boolean = false;
+
+    render() {
+        const { name, score, current, winner } = this;
+
+        const note = winner ? STR_WINNER : current ? STR_TURN : "";
+
+        return html`
+            <span class="name">${name}</span>
+            <span class="score">${score}</span>
+            <span class=${classMap({ note: true, winner })}>${note}</span>
+        `;
+    }
+}
@@ -0,0 +1,200 @@
+import { LitElement, html, css, customElement, property } from "lit-element";
+import { nothing } from "lit-html";
+import { styleMap } from "lit-html/directives/style-map";
+
+export type Kind = "text" | "spoken";
+export type State = "" | "asked" | "wrong" | "correct" | "waiting" | "missed";
+
+const STR_SPOKEN = "?";
+const STR_ASKED = "Tap to check";
+
+@customElement("talk-type-input")
+export class _ extends LitElement {
+    static get styles() {
+        return [
+            css`
+                :host {
+                    position: absolute;
+                    pointer-events: auto;
+                }
+                input,
+                .spoken {
+                    position: absolute;
+                    box-sizing: border-box;
+                    text-align: center;
+                    border: solid 2px var(--light-blue-4);
+                    border-radius: 8px;
+                    background-color: var(--white);
+                    font-family: Poppins, sans-serif;
+                }
+                .spoken {
+                    display: flex;
+                    align-items: center;
+                    justify-content: center;
+                    cursor: pointer;
+                    font-size: 24px;
+                    color: var(--dark-blue-4);
+                }
+                :host([state="asked"]) .spoken {
+                    font-size: 16px;
+                    background-color: var(--light-blue-2);
+                }
+                :host([state="correct"]) input,
+                :host([state="correct"]) .spoken {
+                    background-color: var(--green-4);
+                    border-color: var(--green-4);
+                    color: var(--white);
+                    cursor: default;
+                }
+                :host([state="wrong"]) input {
+                    background-color: var(--red-alert);
+                    border-color: var(--red-alert);
+                    color: var(--white);
+                }
+                :host([state="waiting"]) input {
+                    opacity: 0.5;
+                }
+                :host([state="missed"]) input {
+                    border-color: var(--red-alert);
+                    color: var(--dark-blue-4);
+                }
+                .hint {
+                    position: absolute;
+                    transform: translateX(-50%);
+                    padding: 4px 12px;
+                    border-radius: 8px;
+                    background-color: var(--dark-blue-4);
+                    color: var(--white);
+                    font-size: 18px;
+                    letter-spacing: 2px;
+                    white-space: nowrap;
+                }
+            `,
+        ];
+    }
+
+    firstUpdated(_changed: any) {
+        this.resize();
+    }
+    updated(_changed: any) {
+        this.resize();
+    }
+
+    onInput() {
+        this.resize();
+
+        const input = this.shadowRoot?.getElementById("input") as HTMLInputElement;
+        this.dispatchEvent(
+            new CustomEvent("custom-input", {
+                detail: { value: input.value },
+            })
+        );
+    }
+
+    onKey(evt: KeyboardEvent) {
+        let { key } = evt;
+        key = key.toLowerCase();
+        if (key === "enter") {
+            this.dispatchEvent(new Event("enter"));
+        }
+    }
+
+    // fits the font to the traced area
+    resize() {
+        const input = this.shadowRoot?.getElementById("input") as HTMLInputElement | null;
+        if (!input) {
+            return;
+        }
+
+        const isOverflowing = () => {
+            return input.clientWidth < input.scrollWidth || input.clientHeight < input.scrollHeight;
+        };
+
+        let curr = 12;
+        const max = 128;
+        const margin = 3;
+
+        do {
+            input.style.fontSize = `${curr++}px`;
+        } while (!isOverflowing() && curr < max);
+
+        input.style.fontSize = `${curr - margin}px`;
+    }
+
+    @property({ type: Number })
+    x: number = 0;
+
+    @property({ type: Number })
+    y: number = 0;
+
+    @property({ type: Number })
+    width: number = 0;
+
+    @property({ type: Number })
+    height: number = 0;
+
+    @property()
+    value: string = "";
+
+    @property({ reflect: true })
+    kind: Kind = "text";
+
+    @property({ reflect: true })
+    state: State = "";
+
+    @property()
+    hint: string = "";
+
+    renderHint() {
+        const { x, y, width, height, hint, state } = this;
+
+        if (hint === "" || (state !== "" && state !== "wrong")) {
+            return nothing;
+        }
+
+        const style = styleMap({
+            top: `${y + height + 8}px`,
+            left: `${x + width / 2}px`,
+        });
+
+        return html`<div class="hint" dir="auto" style=${style}>${hint}</div>`;
+    }
+
+    render() {
+        const { x, y, width, height, value, kind, state } = this;
+
+        const style = styleMap({
+            top: `${y}px`,
+            left: `${x}px`,
+            width: `${Math.round(width)}px`,
+            height: `${Math.round(height)}px`,
+            lineHeight: `${Math.round(height)}px`,
+        });
+
+        if (kind === "spoken") {
+            const label = state === "correct" ? value : state === "asked" ? STR_ASKED : STR_SPOKEN;
+
+            return html`
+                <div class="spoken" dir="auto" style=${style}>${label}</div>
+            `;
+        }
+
+        return html`
+            <input
+                id="input"
+                type="text"
+                dir="auto"
+                autocomplete="off"
+                autocorrect="off"
+                autocapitalize="none"
+                spellcheck="false"
+                style=${style}
+                ?disabled=${state === "correct" || state === "waiting" || state === "missed"}
+                @input="${this.onInput}"
+                @keyup="${this.onKey}"
+                .value="${value}"
+            />
+            ${this.renderHint()}
+        `;
+    }
+}
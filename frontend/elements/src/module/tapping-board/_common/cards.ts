@@ -0,0 +1,106 @@
+import { LitElement, html, css, customElement, property } from "lit-element";
+import { nothing } from "lit-html";
+
+// Cards laid out from a timeline or family tree, positioned by the app as a percentage of the stage
+
+@customElement("tapping-board-cards")
+export class _ extends LitElement {
+    static get styles() {
+        return [
+            css`
+                :host {
+                    position: absolute;
+                    top: 0;
+                    left: 0;
+                    width: 100%;
+                    height: 100%;
+                    pointer-events: none;
+                }
+                ::slotted(svg) {
+                    position: absolute;
+                    top: 0;
+                    left: 0;
+                    width: 100%;
+                    height: 100%;
+                    stroke: var(--dark-blue-4);
+                    stroke-width: 3px;
+                }
+                ::slotted(svg) line {
+                    vector-effect: non-scaling-stroke;
+                }
+            `,
+        ];
+    }
+
+    render() {
+        return html`
+            <slot name="links"></slot>
+            <slot name="cards"></slot>
+        `;
+    }
+}
+
+@customElement("tapping-board-card")
+export class __ extends LitElement {
+    static get styles() {
+        return [
+            css`
+                :host {
+                    position: absolute;
+                    box-sizing: border-box;
+                    display: flex;
+                    flex-direction: column;
+                    align-items: center;
+                    justify-content: center;
+                    gap: 8rem;
+                    padding: 12rem;
+                    border-radius: 16rem;
+                    background-color: var(--white);
+                    box-shadow: 0 3rem 12rem 0 rgba(0, 0, 0, 0.16);
+                    overflow: hidden;
+                    text-align: center;
+                }
+                .image {
+                    flex: 1;
+                    min-height: 0;
+                    display: flex;
+                    justify-content: center;
+                }
+                ::slotted(img-ji) {
+                    max-width: 100%;
+                    max-height: 100%;
+                    object-fit: contain;
+                }
+                .heading {
+                    font-weight: 900;
+                    font-size: 24rem;
+                    color: var(--dark-blue-4);
+                }
+                .heading.hidden {
+                    visibility: hidden;
+                }
+                .text {
+                    font-size: 18rem;
+                    color: var(--dark-gray-6);
+                }
+            `,
+        ];
+    }
+
+    @property()
+    heading: string = "";
+
+    @property()
+    text: string = "";
+
+    render() {
+        const { heading, text } = this;
+
+        // an empty heading keeps its line, so revealing it doesn't move the card around
+        return html`
+            <div class="image"><slot name="image"></slot></div>
+            <div class="heading ${heading ? "" : "hidden"}">${heading || "-"}</div>
+            ${text ? html`<div class="text">${text}</div>` : nothing}
+        `;
+    }
+}
@@ -0,0 +1,98 @@
+import { LitElement, html, css, customElement, property } from "lit-element";
+
+// Sidebar list of timeline events or family tree people. The selected entry shows its fields,
+// its image and audio inputs go below the list.
+
+@customElement("tapping-board-entries")
+export class _ extends LitElement {
+    static get styles() {
+        return [
+            css`
+                :host {
+                    display: flex;
+                    flex-direction: column;
+                    gap: 16px;
+                }
+                .entries {
+                    display: flex;
+                    flex-direction: column;
+                    gap: 8px;
+                }
+                .add {
+                    display: flex;
+                    justify-content: center;
+                }
+            `,
+        ];
+    }
+
+    render() {
+        return html`
+            <div class="entries"><slot name="entries"></slot></div>
+            <div class="add"><slot name="add"></slot></div>
+            <slot name="selected"></slot>
+        `;
+    }
+}
+
+@customElement("tapping-board-entry")
+export class __ extends LitElement {
+    static get styles() {
+        return [
+            css`
+                :host {
+                    display: flex;
+                    flex-direction: column;
+                    gap: 12px;
+                    padding: 12px 16px;
+                    border-radius: 12px;
+                    border: solid 1px var(--light-blue-5);
+                    background-color: var(--white);
+                    cursor: pointer;
+                }
+                :host([selected]) {
+                    border-color: var(--main-blue);
+                    background-color: var(--light-blue-1);
+                    cursor: default;
+                }
+                header {
+                    display: flex;
+                    justify-content: space-between;
+                    align-items: center;
+                    gap: 8px;
+                }
+                .heading {
+                    font-weight: 600;
+                    color: var(--dark-gray-6);
+                    overflow: hidden;
+                    text-overflow: ellipsis;
+                    white-space: nowrap;
+                }
+                .fields {
+                    display: none;
+                    flex-direction: column;
+                    gap: 12px;
+                }
+                :host([selected]) .fields {
+                    display: flex;
+                }
+            `,
+        ];
+    }
+
+    @property()
+    heading: string = "";
+
+    @property({ type: Boolean, reflect: true })
+    selected: boolean = false;
+
+    render() {
+        return html`
+            <header>
+                <span class="heading">${this.heading}</span>
+                <slot name="delete"></slot>
+            </header>
+            <div class="fields"><slot name="fields"></slot></div>
+        `;
+    }
+}
@@ -0,0 +1,78 @@
+import { LitElement, html, css, customElement, property } from "lit-element";
+import { nothing } from "lit-html";
+
+@customElement("word-grid-cell")
+export class _ extends LitElement {
+    static get styles() {
+        return [
+            css`
+                :host {
+                    position: relative;
+                    display: flex;
+                    align-items: center;
+                    justify-content: center;
+                    background-color: var(--white);
+                    border: solid 2rem var(--light-blue-4);
+                    border-radius: 8rem;
+                    font-size: 32rem;
+                    font-weight: 600;
+                    color: var(--dark-gray-6);
+                    cursor: pointer;
+                }
+                :host([blocked]) {
+                    visibility: hidden;
+                }
+                :host([selected]) {
+                    border-color: var(--main-blue);
+                }
+                :host([found]) {
+                    background-color: var(--light-blue-2);
+                    border-color: var(--main-green);
+                }
+                .number {
+                    position: absolute;
+                    top: 2rem;
+                    left: 4rem;
+                    font-size: 12rem;
+                    font-weight: normal;
+                }
+                ::slotted(input) {
+                    width: 100%;
+                    height: 100%;
+                    border: none;
+                    background: transparent;
+                    text-align: center;
+                    font: inherit;
+                    text-transform: uppercase;
+                }
+            `,
+        ];
+    }
+
+    @property()
+    letter: string = "";
+
+    @property({ type: Number })
+    number?: number;
+
+    @property({ type: Boolean, reflect: true })
+    blocked: boolean = false;
+
+    @property({ type: Boolean, reflect: true })
+    selected: boolean = false;
+
+    @property({ type: Boolean, reflect: true })
+    found: boolean = false;
+
+    render() {
+        const { letter, number } = this;
+
+        return html`
+            ${number !== undefined
+                ? html`<span class="number">${number}</span>`
+                : nothing}
+            ${letter}
+            <slot></slot>
+        `;
+    }
+}
@@ -0,0 +1,48 @@
+import { LitElement, html, css, customElement, property } from "lit-element";
+import { nothing } from "lit-html";
+
+@customElement("word-grid-clue")
+export class _ extends LitElement {
+    static get styles() {
+        return [
+            css`
+                :host {
+                    display: flex;
+                    align-items: center;
+                    gap: 12rem;
+                    font-size: 24rem;
+                    color: var(--dark-gray-6);
+                }
+                :host([found]) .text {
+                    text-decoration: line-through;
+                    color: var(--main-green);
+                }
+                .number {
+                    font-weight: 600;
+                }
+                ::slotted(img-ji) {
+                    width: 96rem;
+                    height: 96rem;
+                    object-fit: contain;
+                }
+            `,
+        ];
+    }
+
+    @property({ type: Number })
+    number?: number;
+
+    @property({ type: Boolean, reflect: true })
+    found: boolean = false;
+
+    render() {
+        const { number } = this;
+
+        return html`
+            ${number !== undefined
+                ? html`<span class="number">${number}.</span>`
+                : nothing}
+            <span class="text"><slot></slot></span>
+        `;
+    }
+}
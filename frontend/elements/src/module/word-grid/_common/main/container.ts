@@ -0,0 +1,123 @@
+import { LitElement, html, css, customElement, property } from "lit-element";
+import { nothing } from "lit-html";
+
+@customElement("word-grid-main")
+export class _ extends LitElement {
+    static get styles() {
+        return [
+            css`
+                :host {
+                    position: absolute;
+                    top: 0;
+                    left: 0;
+                    display: flex;
+                    width: 100%;
+                    height: 100%;
+                    align-items: center;
+                    justify-content: center;
+                }
+                section {
+                    display: flex;
+                    gap: 80rem;
+                    align-items: flex-start;
+                }
+                :host([rtl]) section {
+                    flex-direction: row-reverse;
+                }
+                .clues {
+                    display: flex;
+                    flex-direction: column;
+                    gap: 16rem;
+                    max-height: 900rem;
+                    overflow-y: auto;
+                }
+                :host([rtl]) .clues {
+                    direction: rtl;
+                }
+            `,
+        ];
+    }
+
+    @property({ type: Boolean, reflect: true })
+    rtl: boolean = false;
+
+    render() {
+        return html`
+            <section>
+                <div class="grid">
+                    <slot name="grid"></slot>
+                    <slot name="skipped"></slot>
+                </div>
+                <div class="clues">
+                    <slot name="clues"></slot>
+                </div>
+            </section>
+        `;
+    }
+}
+
+@customElement("word-grid-board")
+export class __ extends LitElement {
+    static get styles() {
+        return [
+            css`
+                :host {
+                    display: grid;
+                    gap: 4rem;
+                    user-select: none;
+                }
+            `,
+        ];
+    }
+
+    @property({ type: Number })
+    rows: number = 0;
+
+    @property({ type: Number })
+    columns: number = 0;
+
+    render() {
+        const { rows, columns } = this;
+
+        this.style.setProperty(
+            "grid-template-columns",
+            `repeat(${columns}, 64rem)`
+        );
+        this.style.setProperty("grid-template-rows", `repeat(${rows}, 64rem)`);
+
+        return html`<slot></slot>`;
+    }
+}
+
+@customElement("word-grid-skipped")
+export class ___ extends LitElement {
+    static get styles() {
+        return [
+            css`
+                :host {
+                    display: block;
+                    margin-top: 24rem;
+                    color: var(--dark-gray-6);
+                    font-size: 16rem;
+                }
+            `,
+        ];
+    }
+
+    @property({ type: Number })
+    count: number = 0;
+
+    render() {
+        const { count } = this;
+
+        if (!count) {
+            return nothing;
+        }
+
+        return html`${count} ${count === 1 ? STR_WORD : STR_WORDS} ${STR_DIDNT_FIT}`;
+    }
+}
+
+const STR_WORD = "word";
+const STR_WORDS = "words";
+const STR_DIDNT_FIT = "didn't fit, try a bigger grid or shuffling";
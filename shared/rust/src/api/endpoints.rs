@@ -63,3 +63,6 @@ pub mod pdf;
 
 /// Learning Path endpoints
 pub mod learning_path;
+
+/// Webhook endpoints
+pub mod webhook;
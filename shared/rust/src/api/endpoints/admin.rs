@@ -2,7 +2,10 @@ use super::ApiEndpoint;
 use crate::{
     api::Method,
     domain::{
-        admin::{AdminListMediaResponse, ExportDataRequest},
+        admin::{
+            AdminListMediaResponse, AuditLogQuery, AuditLogResponse, ExportDataRequest,
+            StorageConsumersQuery, StorageConsumersResponse,
+        },
         session::NewSessionResponse,
     },
     error::{ApiError, EmptyError},
@@ -30,6 +33,18 @@ impl ApiEndpoint for RefreshFiles {
     const METHOD: Method = Method::Post;
 }
 
+/// Generate the WebP derivatives of an image that was processed before they existed.
+/// Responds with `412 - Precondition Failed` if the image already has them.
+/// NOTE: This route is super unstable (v0), and may change at any time, for any reason, in any way, including removal.
+pub struct GenerateWebpFiles;
+impl ApiEndpoint for GenerateWebpFiles {
+    type Req = ();
+    type Res = ();
+    type Err = EmptyError;
+    const PATH: &'static str = "/v0/admin/media/webp/{library}/image/{id}";
+    const METHOD: Method = Method::Post;
+}
+
 /// List all media
 /// Note that this media is *not* sorted in any particular way.
 /// NOTE: This route is super unstable (v0), and may change at any time, for any reason, in any way, including removal.
@@ -51,3 +66,42 @@ impl ApiEndpoint for ExportData {
     const PATH: &'static str = "/v1/admin/export";
     const METHOD: Method = Method::Get;
 }
+
+/// Browse the audit log.
+///
+/// # Authorization
+/// Admin
+pub struct AuditLog;
+impl ApiEndpoint for AuditLog {
+    type Req = AuditLogQuery;
+    type Res = AuditLogResponse;
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/admin/audit-log";
+    const METHOD: Method = Method::Get;
+}
+
+/// Export the audit log as CSV, with the `before` and `after` columns as JSON.
+///
+/// # Authorization
+/// Admin
+pub struct ExportAuditLog;
+impl ApiEndpoint for ExportAuditLog {
+    type Req = AuditLogQuery;
+    type Res = ();
+    type Err = ApiError<()>;
+    const PATH: &'static str = "/v1/admin/audit-log/export";
+    const METHOD: Method = Method::Get;
+}
+
+/// List the users whose image, audio and pdf libraries take up the most storage.
+///
+/// # Authorization
+/// Admin
+pub struct StorageConsumers;
+impl ApiEndpoint for StorageConsumers {
+    type Req = StorageConsumersQuery;
+    type Res = StorageConsumersResponse;
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/admin/storage-usage";
+    const METHOD: Method = Method::Get;
+}
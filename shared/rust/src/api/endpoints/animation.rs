@@ -44,6 +44,8 @@ impl ApiEndpoint for Create {
 /// # Notes:
 ///
 /// * Can be used to update the raw data associated with the animation.
+/// * The animation can be any [`AnimationFormat`](crate::domain::animation::AnimationFormat), it's converted
+///   into a spritesheet (see [`AnimationSpritesheet`](crate::domain::animation::AnimationSpritesheet)) when processed.
 /// * If the client wants to re-upload an image after it has been successfully processed, it must repeat
 /// the entire flow instead of uploading to the same session URI.
 ///
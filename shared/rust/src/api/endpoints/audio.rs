@@ -48,6 +48,15 @@ pub mod user {
     ///
     /// Notes:
     /// * can be used to update the raw data associated with the audio file.
+    /// * the file can be in any of the [`AudioFormat`](crate::domain::audio::AudioFormat)s, it's transcoded to mp3.
+    ///   Opus audio isn't supported, responds with
+    ///   [`415 - Unsupported Media Type`](http::StatusCode::UNSUPPORTED_MEDIA_TYPE) if the request's `content_type`
+    ///   is Opus (or webm, which browsers record Opus in).
+    /// * once uploaded, silence is trimmed from the start and end of the audio and its loudness is normalized.
+    /// * processing also generates the audio's waveform peaks, stored alongside it as
+    ///   [`FileKind::AudioWaveform`](crate::media::FileKind::AudioWaveform).
+    /// * responds with [`507 - Insufficient Storage`](http::StatusCode::INSUFFICIENT_STORAGE) if the file would take
+    ///   the user's audio library over its quota, see [`StorageUsage`](crate::api::endpoints::user::StorageUsage).
     pub struct Upload;
     impl ApiEndpoint for Upload {
         // raw bytes
@@ -3,8 +3,10 @@ use crate::domain::image::ImageUploadRequest;
 use crate::{
     api::Method,
     domain::image::{
-        CreateResponse, ImageBrowseQuery, ImageBrowseResponse, ImageCreateRequest, ImageResponse,
-        ImageSearchQuery, ImageSearchResponse, ImageUpdateRequest, ImageUploadResponse,
+        CreateResponse, ImageBrowseQuery, ImageBrowseResponse, ImageCreateRequest,
+        ImageDuplicatesResponse, ImageMergeRequest, ImageResponse, ImageSearchQuery,
+        ImageSearchResponse, ImageSimilarityQuery, ImageUpdateRequest, ImageUploadResponse,
+        SimilarImagesResponse,
     },
     error::{EmptyError, MetadataNotFound},
 };
@@ -100,7 +102,9 @@ impl ApiEndpoint for Create {
 ///     a. User uploads to processing bucket
 /// 2. Firestore is notified of `processing = true, ready = false` status at document `uploads/media/global/{id}`
 /// 3. Image is processed and uploaded to the final bucket
-/// 4. Firestore is notified of `processing = true, ready = true` status at document `uploads/media/global/{id}`
+/// 4. Firestore is notified of `processing = true, ready = true` status at document `uploads/media/global/{id}`,
+///    with `similar = true` if a similar image already exists in the library
+/// 5. If it does, the client can list the similar images with [`Similar`]
 ///
 /// # Notes:
 ///
@@ -143,3 +147,60 @@ impl ApiEndpoint for Delete {
     const PATH: &'static str = "/v1/image/{id}";
     const METHOD: Method = Method::Delete;
 }
+
+/// Find images in the global library which look like the given (processed) image.
+///
+/// The request should be supplied as a URL query string.
+///
+/// # Errors:
+///
+/// * [`401 - Unauthorized`](http::StatusCode::UNAUTHORIZED) if authorization is not valid.
+/// * [`403 - Forbidden`](http::StatusCode::FORBIDDEN) if the user does not have sufficient permission to perform the action.
+/// * [`404 - NotFound`](http::StatusCode::NOT_FOUND) if the image with the requested ID is not found.
+pub struct Similar;
+impl ApiEndpoint for Similar {
+    type Req = ImageSimilarityQuery;
+    type Res = SimilarImagesResponse;
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/image/{id}/similar";
+    const METHOD: Method = Method::Get;
+}
+
+/// List clusters of near-duplicate images in the global library.
+///
+/// Only the closest 1000 pairs of images are clustered, merging them reveals the next ones.
+///
+/// The request should be supplied as a URL query string.
+///
+/// # Errors:
+///
+/// * [`401 - Unauthorized`](http::StatusCode::UNAUTHORIZED) if authorization is not valid.
+/// * [`403 - Forbidden`](http::StatusCode::FORBIDDEN) if the user does not have sufficient permission to perform the action.
+pub struct Duplicates;
+impl ApiEndpoint for Duplicates {
+    type Req = ImageSimilarityQuery;
+    type Res = ImageDuplicatesResponse;
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/image/duplicates";
+    const METHOD: Method = Method::Get;
+}
+
+/// Merge duplicate images into one.
+///
+/// References to the duplicates (in JIG modules, including covers, and recently used images) are replaced with the kept image,
+/// and the duplicates are deleted.
+///
+/// # Errors:
+///
+/// * [`400 - BadRequest`](http::StatusCode::BAD_REQUEST) if the kept image is also listed as a duplicate.
+/// * [`401 - Unauthorized`](http::StatusCode::UNAUTHORIZED) if authorization is not valid.
+/// * [`403 - Forbidden`](http::StatusCode::FORBIDDEN) if the user does not have sufficient permission to perform the action.
+/// * [`404 - NotFound`](http::StatusCode::NOT_FOUND) if any of the images are not found.
+pub struct MergeDuplicates;
+impl ApiEndpoint for MergeDuplicates {
+    type Req = ImageMergeRequest;
+    type Res = ();
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/image/duplicates/merge";
+    const METHOD: Method = Method::Post;
+}
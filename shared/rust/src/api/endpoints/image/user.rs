@@ -8,7 +8,7 @@ use crate::{
                 UserImageCreateRequest, UserImageListQuery, UserImageListResponse,
                 UserImageResponse, UserImageUploadRequest, UserImageUploadResponse,
             },
-            ImageId,
+            ImageId, ImageSimilarityQuery, SimilarImagesResponse,
         },
         CreateResponse,
     },
@@ -61,7 +61,9 @@ impl ApiEndpoint for Create {
 ///     a. User uploads to processing bucket
 /// 2. Firestore is notified of `processing = true, ready = false` status at document `uploads/media/user/{id}`
 /// 3. Animation is processed and uploaded to the final bucket
-/// 4. Firestore is notified of `processing = true, ready = true` status at document `uploads/media/user/{id}`
+/// 4. Firestore is notified of `processing = true, ready = true` status at document `uploads/media/user/{id}`,
+///    with `similar = true` if a similar image already exists in the user's or the global library
+/// 5. If it does, the client can list the similar images with [`Similar`]
 ///
 /// # Notes:
 ///
@@ -74,6 +76,8 @@ impl ApiEndpoint for Create {
 /// * [`401 - Unauthorized`](http::StatusCode::UNAUTHORIZED) if authorization is not valid.
 /// * [`403 - Forbidden`](http::StatusCode::FORBIDDEN) if the user does not have sufficient permission to perform the action.
 /// * [`501 - NotImplemented`](http::StatusCode::NOT_IMPLEMENTED) when the s3/gcs service is disabled.
+/// * [`507 - InsufficientStorage`](http::StatusCode::INSUFFICIENT_STORAGE) if the image would take the user's image
+///   library over its quota, see [`StorageUsage`](crate::api::endpoints::user::StorageUsage).
 pub struct Upload;
 impl ApiEndpoint for Upload {
     // raw bytes
@@ -99,3 +103,18 @@ impl ApiEndpoint for Delete {
     const PATH: &'static str = "/v1/user/me/image/{id}";
     const METHOD: Method = Method::Delete;
 }
+
+/// Find images in the user's library and the global library which look like the given (processed) image.
+///
+/// The request should be supplied as a URL query string.
+///
+/// # Errors
+/// * [`NotFound`](http::StatusCode::NOT_FOUND) if the image with the requested ID is not found for the user.
+pub struct Similar;
+impl ApiEndpoint for Similar {
+    type Req = ImageSimilarityQuery;
+    type Res = SimilarImagesResponse;
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/user/me/image/{id}/similar";
+    const METHOD: Method = Method::Get;
+}
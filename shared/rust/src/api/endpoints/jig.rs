@@ -2,9 +2,9 @@ use crate::{
     api::Method,
     domain::{
         jig::{
-            JigBrowseQuery, JigBrowseResponse, JigCountResponse, JigCreateRequest, JigId,
-            JigLikedResponse, JigResponse, JigSearchQuery, JigSearchResponse,
-            JigUpdateAdminDataRequest, JigUpdateDraftDataRequest,
+            JigAttributionResponse, JigBrowseQuery, JigBrowseResponse, JigCountResponse,
+            JigCreateRequest, JigId, JigLikedResponse, JigResponse, JigSearchQuery,
+            JigSearchResponse, JigUpdateAdminDataRequest, JigUpdateDraftDataRequest,
         },
         CreateResponse,
     },
@@ -66,6 +66,22 @@ impl ApiEndpoint for GetLive {
     const METHOD: Method = Method::Get;
 }
 
+/// Get the licenses and sources of the media used in a JIG's live data, for crediting their authors.
+///
+/// # Authorization
+/// * None
+///
+/// # Errors
+/// * [`NotFound`](http::StatusCode::NOT_FOUND) if the jig doesn't exist.
+pub struct Attribution;
+impl ApiEndpoint for Attribution {
+    type Req = ();
+    type Res = JigAttributionResponse;
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/jig/{id}/live/attribution";
+    const METHOD: Method = Method::Get;
+}
+
 /// Get a JIG's draft data by ID.
 ///
 /// # Authorization
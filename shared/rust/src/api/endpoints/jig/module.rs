@@ -2,7 +2,9 @@ use crate::{
     api::Method,
     domain::{
         jig::module::{
-            ModuleCreateRequest, ModuleDeleteRequest, ModuleId, ModuleResponse, ModuleUpdateRequest,
+            printable::{PrintableRequest, PrintableResponse},
+            ModuleCreateRequest, ModuleDeleteRequest, ModuleId, ModuleResponse,
+            ModuleUpdateRequest,
         },
         CreateResponse,
     },
@@ -102,3 +104,82 @@ impl ApiEndpoint for Delete {
     const PATH: &'static str = "/v1/jig/{id}/draft/module";
     const METHOD: Method = Method::Delete;
 }
+
+/// Print a draft module and attach the pdf to the JIG as an additional resource.
+///
+/// The pdf is added to the user's pdf library, and the resource's content is its [`PdfId`](crate::domain::pdf::PdfId).
+///
+/// # Authorization
+///
+/// * Standard + [`UserScope::ManageJig`](crate::domain::user::UserScope)
+///
+/// # Errors
+///
+/// * [`Unauthorized`](http::StatusCode::UNAUTHORIZED) if authorization is not valid.
+/// * [`Forbidden`](http::StatusCode::FORBIDDEN) if the user does not have sufficient permission to perform the action.
+/// * [`NotFound`](http::StatusCode::NOT_FOUND) if the jig or module does not exist.
+/// * [`BadRequest`](http::StatusCode::BAD_REQUEST) if the module has nothing to print or its kind can't be printed.
+/// * [`InsufficientStorage`](http::StatusCode::INSUFFICIENT_STORAGE) if the pdf would take the user over their storage quota.
+pub struct Printable;
+impl ApiEndpoint for Printable {
+    type Req = PrintableRequest;
+    type Res = PrintableResponse;
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/jig/{id}/draft/module/{module_id}/printable";
+    const METHOD: Method = Method::Post;
+}
+
+/// Endpoints for the spaced repetition state of [`Flashcards`](crate::domain::jig::module::ModuleKind::Flashcards) modules.
+///
+/// The state belongs either to a player session instance, when its token is passed in the request, or to the logged-in user.
+/// Modules are identified by their [`StableModuleId`](crate::domain::jig::module::StableModuleId), so the state carries over
+/// when the JIG is published.
+pub mod flashcards {
+    use crate::{
+        api::{ApiEndpoint, Method},
+        domain::jig::module::body::flashcards::review::{
+            CardReview, FlashcardReviewQuery, FlashcardReviewRequest, FlashcardReviewResponse,
+        },
+        error::EmptyError,
+    };
+
+    /// Get the current player's review state for a flashcards module.
+    ///
+    /// # Authorization
+    /// * A player session instance token in the query, or
+    /// * Standard
+    ///
+    /// # Errors
+    ///
+    /// * [`Unauthorized`](http::StatusCode::UNAUTHORIZED) if there is no valid token and the user isn't logged in.
+    /// * [`NotFound`](http::StatusCode::NOT_FOUND) if the jig doesn't have a flashcards module with this stable ID.
+    pub struct GetReviews;
+    impl ApiEndpoint for GetReviews {
+        type Req = FlashcardReviewQuery;
+        type Res = FlashcardReviewResponse;
+        type Err = EmptyError;
+        const PATH: &'static str = "/v1/jig/{id}/module/{module_id}/flashcards/review";
+        const METHOD: Method = Method::Get;
+    }
+
+    /// Mark a card known or unknown, and reschedule it.
+    ///
+    /// # Authorization
+    /// * A player session instance token for this JIG in the request, or
+    /// * Standard
+    ///
+    /// # Errors
+    ///
+    /// * [`Unauthorized`](http::StatusCode::UNAUTHORIZED) if there is no valid token and the user isn't logged in.
+    /// * [`BadRequest`](http::StatusCode::BAD_REQUEST) if the module doesn't have a pair with this key.
+    /// * [`NotFound`](http::StatusCode::NOT_FOUND) if the jig doesn't have a flashcards module with this stable ID,
+    ///   or the token's player session isn't for this JIG.
+    pub struct Review;
+    impl ApiEndpoint for Review {
+        type Req = FlashcardReviewRequest;
+        type Res = CardReview;
+        type Err = EmptyError;
+        const PATH: &'static str = "/v1/jig/{id}/module/{module_id}/flashcards/review";
+        const METHOD: Method = Method::Put;
+    }
+}
@@ -99,6 +99,8 @@ pub mod instance {
     ///
     /// * [`400 - BadRequest`](http::StatusCode::BAD_REQUEST) if the request is malformed.
     /// * ['404 - NotFound'](http::StatusCode::NOT_FOUND) if the jig player session does not exist.
+    /// * [`429 - TooManyRequests`](http::StatusCode::TOO_MANY_REQUESTS) if there have been too many attempts from this IP address.
+    ///   See the `Retry-After` header for when to try again.
     pub struct Create;
     impl ApiEndpoint for Create {
         type Req = PlayerSessionInstanceCreateRequest;
@@ -56,3 +56,15 @@ impl ApiEndpoint for DeleteId {
     const PATH: &'static str = "/v1/media/id/{id}";
     const METHOD: Method = Method::Delete;
 }
+
+/// Redirect to a file of an image, in the best format that the client supports (as stated by its `Accept` header).
+///
+/// The path is `{library}/{id}/{file}`, where `file` is a [`PngImageFile`](crate::media::PngImageFile).
+pub struct GetImageFile;
+impl ApiEndpoint for GetImageFile {
+    type Req = ();
+    type Res = ();
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/media/image/{library}/{id}/{file}";
+    const METHOD: Method = Method::Get;
+}
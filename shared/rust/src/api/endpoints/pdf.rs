@@ -49,6 +49,8 @@ pub mod user {
     ///
     /// Notes:
     /// * can be used to update the raw data associated with the pdf file.
+    /// * responds with [`507 - Insufficient Storage`](http::StatusCode::INSUFFICIENT_STORAGE) if the file would take
+    ///   the user's pdf library over its quota, see [`StorageUsage`](crate::api::endpoints::user::StorageUsage).
     pub struct Upload;
     impl ApiEndpoint for Upload {
         // raw bytes
@@ -27,6 +27,8 @@ impl ApiEndpoint for CreateKey {
 
 /// Search for images over the web.
 ///
+/// Which search provider is used is configured on the server, if none is configured no images are returned.
+///
 /// # Authorization
 ///
 /// standard
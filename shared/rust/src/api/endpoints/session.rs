@@ -1,6 +1,9 @@
 use crate::{
     api::Method,
-    domain::session::{CreateSessionOAuthRequest, CreateSessionResponse, GetOAuthUrlResponse},
+    domain::session::{
+        CreateSecondFactorRequest, CreateSessionOAuthRequest, CreateSessionResponse,
+        GetOAuthUrlResponse,
+    },
     error::EmptyError,
 };
 
@@ -10,6 +13,12 @@ use super::ApiEndpoint;
 ///
 /// requires `Basic` auth in the form `BASE64(email:password)`
 /// see: <https://tools.ietf.org/html/rfc7617#section-2>
+///
+/// # Errors
+///
+/// * [`401 - Unauthorized`](http::StatusCode::UNAUTHORIZED) if the email or password is wrong.
+/// * [`429 - TooManyRequests`](http::StatusCode::TOO_MANY_REQUESTS) if there have been too many attempts from this IP address or for this account,
+///   or the account is locked out after too many failed logins. See the `Retry-After` header for when to try again.
 pub struct Create;
 impl ApiEndpoint for Create {
     type Req = ();
@@ -19,6 +28,29 @@ impl ApiEndpoint for Create {
     const METHOD: Method = Method::Post;
 }
 
+/// Pass the second factor for the current session.
+///
+/// Sessions belonging to users with a confirmed TOTP second factor (or using a scope that requires one)
+/// can't use admin-scoped routes until they've passed it.
+///
+/// # Flow
+/// 1. [`POST /v1/session`](Create)
+/// 2. `POST /v1/session/second-factor` (this route) with a TOTP code or a recovery code
+///
+/// # Errors
+///
+/// * [`401 - Unauthorized`](http::StatusCode::UNAUTHORIZED) if the code is wrong, or the user has no second factor.
+/// * [`429 - TooManyRequests`](http::StatusCode::TOO_MANY_REQUESTS) if there have been too many attempts from this IP address.
+///   See the `Retry-After` header for when to try again.
+pub struct CreateSecondFactor;
+impl ApiEndpoint for CreateSecondFactor {
+    type Req = CreateSecondFactorRequest;
+    type Res = ();
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/session/second-factor";
+    const METHOD: Method = Method::Post;
+}
+
 /// Sign in via oauth.
 ///
 /// Note: If the account doesn't exist, but the oauth token is valid, it'll return a token that can be used to create an account.
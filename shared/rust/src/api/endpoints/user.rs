@@ -16,6 +16,9 @@ use crate::{
 mod colors;
 mod fonts;
 mod profile;
+mod storage;
+mod token;
+mod totp;
 
 pub use colors::{
     Create as CreateColor, Delete as DeleteColor, Get as GetColors, Update as UpdateColor,
@@ -27,6 +30,15 @@ pub use fonts::{
 
 pub use profile::{Create as CreateProfile, Get as Profile, Patch as PatchProfile};
 
+pub use storage::Get as StorageUsage;
+
+pub use token::{Create as CreateToken, Delete as DeleteToken, List as ListTokens};
+
+pub use totp::{
+    Confirm as ConfirmTotp, Delete as DeleteTotp, Enroll as EnrollTotp, Get as GetTotp,
+    RegenerateRecoveryCodes,
+};
+
 /// Create a new user.
 ///
 /// # Flow
@@ -73,6 +85,11 @@ impl ApiEndpoint for Create {
 /// 2. Continue the flow you were in.
 ///
 /// If no verification is in progress, no email will be sent.
+///
+/// # Errors
+///
+/// * [`429 - TooManyRequests`](http::StatusCode::TOO_MANY_REQUESTS) if there have been too many requests from this IP address or for this email.
+///   See the `Retry-After` header for when to try again.
 pub struct VerifyEmail;
 impl ApiEndpoint for VerifyEmail {
     type Req = VerifyEmailRequest;
@@ -116,6 +133,11 @@ impl ApiEndpoint for ResetEmail {
 ///      * email gets sent to the included email address
 ///      * recieve [`204 - No Content`](http::StatusCode::NO_CONTENT)
 /// 2. [`PUT /v1/user/me/password`](ChangePassword)
+///
+/// # Errors
+///
+/// * [`429 - TooManyRequests`](http::StatusCode::TOO_MANY_REQUESTS) if there have been too many requests from this IP address or for this email.
+///   See the `Retry-After` header for when to try again.
 pub struct ResetPassword;
 impl ApiEndpoint for ResetPassword {
     type Req = ResetPasswordRequest;
@@ -0,0 +1,16 @@
+use super::ApiEndpoint;
+
+use crate::{api::method::Method, domain::user::StorageUsageResponse, error::EmptyError};
+
+/// Get how much storage the user's image, audio and pdf libraries take up, and their quotas.
+///
+/// Uploads are rejected with [`507 - Insufficient Storage`](http::StatusCode::INSUFFICIENT_STORAGE)
+/// when they would take a group over its quota.
+pub struct Get;
+impl ApiEndpoint for Get {
+    type Req = ();
+    type Res = StorageUsageResponse;
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/user/me/usage";
+    const METHOD: Method = Method::Get;
+}
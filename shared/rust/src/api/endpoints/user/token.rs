@@ -0,0 +1,61 @@
+use super::ApiEndpoint;
+
+use crate::{
+    api::method::Method,
+    domain::user::{CreateTokenRequest, CreateTokenResponse, ListTokensResponse},
+    error::EmptyError,
+};
+
+/// Create a personal access token.
+///
+/// Personal access tokens are meant for scripts and tooling, they're accepted
+/// as `Authorization: Bearer <token>` in place of a session, and don't need a CSRF header.
+///
+/// # Authorization
+/// A session (not a personal access token).
+/// If any of the requested scopes need a second factor, the session must have passed it.
+///
+/// # Errors
+///
+/// * [`400 - BadRequest`](http::StatusCode::BAD_REQUEST) if the name is empty, or the expiry is in the past
+///   or too far in the future.
+/// * [`403 - Forbidden`](http::StatusCode::FORBIDDEN) if the user doesn't have one of the requested scopes,
+///   or the session needs to pass a second factor first.
+pub struct Create;
+impl ApiEndpoint for Create {
+    type Req = CreateTokenRequest;
+    type Res = CreateTokenResponse;
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/user/me/token";
+    const METHOD: Method = Method::Post;
+}
+
+/// List the user's personal access tokens.
+///
+/// # Authorization
+/// A session (not a personal access token).
+pub struct List;
+impl ApiEndpoint for List {
+    type Req = ();
+    type Res = ListTokensResponse;
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/user/me/token";
+    const METHOD: Method = Method::Get;
+}
+
+/// Revoke a personal access token.
+///
+/// # Authorization
+/// A session (not a personal access token).
+///
+/// # Errors
+///
+/// * [`404 - NotFound`](http::StatusCode::NOT_FOUND) if the token doesn't exist or is already revoked.
+pub struct Delete;
+impl ApiEndpoint for Delete {
+    type Req = ();
+    type Res = ();
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/user/me/token/{id}";
+    const METHOD: Method = Method::Delete;
+}
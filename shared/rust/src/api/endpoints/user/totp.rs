@@ -0,0 +1,94 @@
+use super::ApiEndpoint;
+
+use crate::{
+    api::method::Method,
+    domain::user::{
+        RecoveryCodesResponse, TotpConfirmRequest, TotpEnrollResponse, TotpStatusResponse,
+    },
+    error::EmptyError,
+};
+
+/// Get the status of the user's TOTP second factor.
+pub struct Get;
+impl ApiEndpoint for Get {
+    type Req = ();
+    type Res = TotpStatusResponse;
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/user/me/totp";
+    const METHOD: Method = Method::Get;
+}
+
+/// Start enrolling a TOTP second factor.
+///
+/// Generates a new secret, replacing any unconfirmed one. The secret isn't used until it's confirmed.
+///
+/// # Flow
+/// 1. `POST` to this route
+///     * add the returned secret / uri to an authenticator app
+/// 2. [`POST /v1/user/me/totp/confirm`](Confirm)
+///
+/// # Errors
+///
+/// * [`409 - Conflict`](http::StatusCode::CONFLICT) if the user already has a confirmed second factor.
+pub struct Enroll;
+impl ApiEndpoint for Enroll {
+    type Req = ();
+    type Res = TotpEnrollResponse;
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/user/me/totp";
+    const METHOD: Method = Method::Post;
+}
+
+/// Confirm a TOTP second factor with a code from the authenticator app.
+///
+/// Enables the second factor, marks the current session as having passed it,
+/// and returns a fresh set of recovery codes.
+///
+/// # Errors
+///
+/// * [`400 - BadRequest`](http::StatusCode::BAD_REQUEST) if the code is wrong.
+/// * [`404 - NotFound`](http::StatusCode::NOT_FOUND) if there is no enrollment in progress.
+/// * [`409 - Conflict`](http::StatusCode::CONFLICT) if the second factor is already confirmed.
+pub struct Confirm;
+impl ApiEndpoint for Confirm {
+    type Req = TotpConfirmRequest;
+    type Res = RecoveryCodesResponse;
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/user/me/totp/confirm";
+    const METHOD: Method = Method::Post;
+}
+
+/// Remove the user's TOTP second factor and recovery codes.
+///
+/// # Authorization
+/// A session which has passed the second factor.
+///
+/// # Errors
+///
+/// * [`401 - Unauthorized`](http::StatusCode::UNAUTHORIZED) if the session hasn't passed the second factor.
+pub struct Delete;
+impl ApiEndpoint for Delete {
+    type Req = ();
+    type Res = ();
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/user/me/totp";
+    const METHOD: Method = Method::Delete;
+}
+
+/// Replace the user's recovery codes with a fresh set.
+///
+/// # Authorization
+/// A session which has passed the second factor.
+///
+/// # Errors
+///
+/// * [`401 - Unauthorized`](http::StatusCode::UNAUTHORIZED) if the session hasn't passed the second factor.
+/// * [`404 - NotFound`](http::StatusCode::NOT_FOUND) if the user doesn't have a confirmed second factor.
+pub struct RegenerateRecoveryCodes;
+impl ApiEndpoint for RegenerateRecoveryCodes {
+    type Req = ();
+    type Res = RecoveryCodesResponse;
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/user/me/totp/recovery-codes";
+    const METHOD: Method = Method::Post;
+}
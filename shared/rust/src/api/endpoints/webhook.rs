@@ -0,0 +1,72 @@
+use super::ApiEndpoint;
+
+use crate::{
+    api::method::Method,
+    domain::{
+        webhook::{CreateWebhookRequest, ListWebhooksResponse, UpdateWebhookRequest, WebhookId},
+        CreateResponse,
+    },
+    error::EmptyError,
+};
+
+/// Create a webhook subscription.
+///
+/// Subscriptions are notified about the user's own JIGs, or with `organization` set,
+/// about the JIGs of every user whose profile has that organization.
+///
+/// # Authorization
+/// Standard, or `Admin` to subscribe to an organization.
+///
+/// # Errors
+///
+/// * [`400 - BadRequest`](http::StatusCode::BAD_REQUEST) if the URL isn't `http(s)`, the secret is too short,
+///   or no events are given.
+/// * [`403 - Forbidden`](http::StatusCode::FORBIDDEN) if `organization` is set and the user isn't an admin.
+pub struct Create;
+impl ApiEndpoint for Create {
+    type Req = CreateWebhookRequest;
+    type Res = CreateResponse<WebhookId>;
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/webhook";
+    const METHOD: Method = Method::Post;
+}
+
+/// List the user's webhook subscriptions.
+pub struct List;
+impl ApiEndpoint for List {
+    type Req = ();
+    type Res = ListWebhooksResponse;
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/webhook";
+    const METHOD: Method = Method::Get;
+}
+
+/// Update a webhook subscription.
+///
+/// # Errors
+///
+/// * [`400 - BadRequest`](http::StatusCode::BAD_REQUEST) if the URL isn't `http(s)`, the secret is too short,
+///   or no events are given.
+/// * [`404 - NotFound`](http::StatusCode::NOT_FOUND) if the subscription doesn't exist.
+pub struct Update;
+impl ApiEndpoint for Update {
+    type Req = UpdateWebhookRequest;
+    type Res = ();
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/webhook/{id}";
+    const METHOD: Method = Method::Patch;
+}
+
+/// Delete a webhook subscription, along with any pending deliveries.
+///
+/// # Errors
+///
+/// * [`404 - NotFound`](http::StatusCode::NOT_FOUND) if the subscription doesn't exist.
+pub struct Delete;
+impl ApiEndpoint for Delete {
+    type Req = ();
+    type Res = ();
+    type Err = EmptyError;
+    const PATH: &'static str = "/v1/webhook/{id}";
+    const METHOD: Method = Method::Delete;
+}
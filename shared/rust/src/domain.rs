@@ -46,6 +46,7 @@ pub mod search;
 mod ser;
 pub mod session;
 pub mod user;
+pub mod webhook;
 
 #[deprecated]
 /// auth types (deprecated)
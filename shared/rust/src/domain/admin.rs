@@ -4,7 +4,10 @@ use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumIter};
 use uuid::Uuid;
 
-use crate::media::{MediaKind, MediaLibrary};
+use crate::{
+    domain::user::UserMediaGroup,
+    media::{MediaKind, MediaLibrary},
+};
 
 /// Response for [`ListMedia`](crate::api::endpoints::admin::ListMedia)
 /// Super unstable, may change at any time, for any reason.
@@ -57,3 +60,169 @@ pub struct ExportDataRequest {
     /// Optionally the date to export data to
     pub to_date: Option<chrono::DateTime<Utc>>,
 }
+
+/// An action recorded in the audit log.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "backend", derive(sqlx::Type))]
+#[serde(rename_all = "camelCase")]
+#[repr(i16)]
+pub enum AuditAction {
+    /// An admin started a session as another user. The target is the impersonated user.
+    Impersonate = 0,
+
+    /// A JIG's admin data (rating, blocked, curated) was updated. The target is the JIG.
+    UpdateJigAdminData = 1,
+
+    /// A category was created. The target is the category.
+    CreateCategory = 2,
+
+    /// A category was updated. The target is the category.
+    UpdateCategory = 3,
+
+    /// A category was deleted. The target is the category.
+    DeleteCategory = 4,
+
+    /// An image's metadata was updated. The target is the image.
+    UpdateImage = 5,
+
+    /// A user deleted their account. The target is the user.
+    DeleteUser = 6,
+
+    /// Duplicate images were merged into one. The target is the image that was kept.
+    MergeImages = 7,
+}
+
+/// An entry in the audit log.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogEntry {
+    /// The entry's ID.
+    pub id: Uuid,
+
+    /// When the action happened.
+    pub created_at: DateTime<Utc>,
+
+    /// The user who performed the action.
+    pub actor_id: Option<Uuid>,
+
+    /// What was done.
+    pub action: AuditAction,
+
+    /// What it was done to, see [`AuditAction`] for what kind of resource this is.
+    pub target_id: Option<Uuid>,
+
+    /// The state of the target before the action, if relevant.
+    pub before: Option<serde_json::Value>,
+
+    /// The state of the target after the action, if relevant.
+    pub after: Option<serde_json::Value>,
+
+    /// The IP address the action came from.
+    pub ip_address: Option<String>,
+
+    /// The user agent the action came from.
+    pub user_agent: Option<String>,
+}
+
+/// Query for [`AuditLog`](crate::api::endpoints::admin::AuditLog)
+/// and [`ExportAuditLog`](crate::api::endpoints::admin::ExportAuditLog)
+///
+/// All filters are optional and combined with a logical `AND`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogQuery {
+    /// Optionally filter by the user who performed the action.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<Uuid>,
+
+    /// Optionally filter by the target of the action.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<Uuid>,
+
+    /// Optionally filter by action.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<AuditAction>,
+
+    /// Optionally only include entries from this time on.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_date: Option<DateTime<Utc>>,
+
+    /// Optionally only include entries up to this time.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_date: Option<DateTime<Utc>>,
+
+    /// The page number of the entries to get. Ignored when exporting.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+
+    /// The entries per page to be returned. Ignored when exporting.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_limit: Option<u32>,
+}
+
+/// Response for [`AuditLog`](crate::api::endpoints::admin::AuditLog)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogResponse {
+    /// The entries found, newest first.
+    pub entries: Vec<AuditLogEntry>,
+
+    /// The number of pages found.
+    pub pages: u32,
+
+    /// The total number of entries found.
+    pub total_count: u64,
+}
+
+/// Query for [`StorageConsumers`](crate::api::endpoints::admin::StorageConsumers)
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageConsumersQuery {
+    /// Optionally rank users by a single media group, instead of by their total usage.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<UserMediaGroup>,
+
+    /// The number of users to return, defaults to 20, at most 100.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// A user and how much storage their media libraries take up.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageConsumer {
+    /// The user's id.
+    pub user_id: Uuid,
+
+    /// The user's username, if they have a profile.
+    pub username: Option<String>,
+
+    /// The number of bytes used across all media groups.
+    pub used_bytes: u64,
+
+    /// The number of bytes used by images.
+    pub image_bytes: u64,
+
+    /// The number of bytes used by audio files.
+    pub audio_bytes: u64,
+
+    /// The number of bytes used by pdf files.
+    pub pdf_bytes: u64,
+}
+
+/// Response for [`StorageConsumers`](crate::api::endpoints::admin::StorageConsumers)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageConsumersResponse {
+    /// The users using the most storage, most first.
+    pub users: Vec<StorageConsumer>,
+}
@@ -4,7 +4,7 @@ use super::{meta::AnimationStyleId, Publish};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "backend")]
-use sqlx::postgres::PgRow;
+use sqlx::{postgres::PgRow, types::Json};
 use uuid::Uuid;
 
 /// Animation Kinds
@@ -29,6 +29,44 @@ impl AnimationKind {
     }
 }
 
+/// Formats that animations can be uploaded in.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "backend", derive(sqlx::Type))]
+#[repr(i16)]
+pub enum AnimationFormat {
+    /// Animated GIF.
+    Gif = 0,
+
+    /// Animated PNG.
+    Apng = 1,
+
+    /// Animated `WebP`.
+    WebP = 2,
+}
+
+/// Layout and timing of an animation's spritesheet.
+///
+/// Every animation is converted into a spritesheet when it's processed, stored as
+/// [`FileKind::AnimationSpritesheet`](crate::media::FileKind::AnimationSpritesheet), with this stored
+/// alongside it as [`FileKind::AnimationSpritesheetJson`](crate::media::FileKind::AnimationSpritesheetJson).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AnimationSpritesheet {
+    /// The width of a single frame, in pixels.
+    pub frame_width: u32,
+
+    /// The height of a single frame, in pixels.
+    pub frame_height: u32,
+
+    /// How many frames there are in each row of the spritesheet.
+    ///
+    /// Frames are laid out left to right, top to bottom.
+    pub columns: u32,
+
+    /// How long each frame is shown for, in milliseconds.
+    pub frame_durations_ms: Vec<u32>,
+}
+
 /// Wrapper type around [`Uuid`], represents the ID of an animation.
 #[derive(Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Debug)]
 #[cfg_attr(feature = "backend", derive(sqlx::Type))]
@@ -69,6 +107,18 @@ pub struct AnimationMetadata {
     /// Should the animation loop?
     pub is_looping: bool,
 
+    /// The format the animation was uploaded in.
+    ///
+    /// `None` if the animation hasn't been processed yet.
+    #[serde(default)]
+    pub original_format: Option<AnimationFormat>,
+
+    /// The animation's spritesheet layout and frame timing.
+    ///
+    /// `None` if the animation hasn't been processed yet (or was processed before spritesheets were generated).
+    #[serde(default)]
+    pub spritesheet: Option<AnimationSpritesheet>,
+
     /// When the animation was originally created.
     pub created_at: DateTime<Utc>,
 
@@ -89,6 +139,8 @@ impl<'r> sqlx::FromRow<'r, PgRow> for AnimationMetadata {
             publish_at,
             styles,
             is_looping,
+            original_format,
+            spritesheet,
             created_at,
             updated_at,
         } = DbAnimation::from_row(row)?;
@@ -102,6 +154,8 @@ impl<'r> sqlx::FromRow<'r, PgRow> for AnimationMetadata {
             publish_at,
             styles: styles.into_iter().map(|(it,)| it).collect(),
             is_looping,
+            original_format,
+            spritesheet: spritesheet.map(|it| it.0),
             created_at,
             updated_at,
         })
@@ -119,6 +173,8 @@ struct DbAnimation {
     pub publish_at: Option<DateTime<Utc>>,
     pub styles: Vec<(AnimationStyleId,)>,
     pub is_looping: bool,
+    pub original_format: Option<AnimationFormat>,
+    pub spritesheet: Option<Json<AnimationSpritesheet>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
 }
@@ -11,7 +11,7 @@ use uuid::Uuid;
 pub mod user {
     use serde::{Deserialize, Serialize};
 
-    use super::AudioId;
+    use super::{AudioFormat, AudioId};
 
     /// Response for listing.
     #[derive(Serialize, Deserialize, Debug)]
@@ -32,6 +32,12 @@ pub mod user {
     pub struct UserAudio {
         /// The audio file's ID.
         pub id: AudioId,
+
+        /// The format the audio was uploaded in (it's always stored as mp3).
+        ///
+        /// `None` if the audio hasn't been processed yet.
+        #[serde(default)]
+        pub original_format: Option<AudioFormat>,
         // more fields to be added
     }
 
@@ -41,6 +47,12 @@ pub mod user {
         /// The size of the audio to be uploaded in bytes. Allows the API server to check that the file size is
         /// within limits and as a verification at GCS that the entire file was uploaded
         pub file_size: usize,
+
+        /// The file's MIME type, if the client knows it.
+        ///
+        /// Opus audio can't be transcoded, so it's rejected before the upload starts.
+        #[serde(default)]
+        pub content_type: Option<String>,
     }
 
     /// URL to upload an audio. Supports resumable uploading.
@@ -66,6 +78,46 @@ pub enum AudioKind {
     Mp3 = 0,
 }
 
+/// The peaks of an audio file, for drawing its waveform (and offering trim handles).
+///
+/// Generated when audio is processed, and stored alongside it as [`FileKind::AudioWaveform`](crate::media::FileKind::AudioWaveform).
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioWaveform {
+    /// The length of the (processed) audio, in milliseconds.
+    pub duration_ms: u64,
+
+    /// How many peaks there are per second of audio.
+    pub peaks_per_second: u32,
+
+    /// The loudest sample in each slice of the audio, from `0` (silence) to `255` (full scale).
+    pub peaks: Vec<u8>,
+}
+
+/// Formats that audio can be uploaded in, it gets transcoded to mp3 when processed.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "backend", derive(sqlx::Type))]
+#[repr(i16)]
+pub enum AudioFormat {
+    /// MPEG audio layer 3.
+    Mp3 = 0,
+
+    /// WAVE (usually uncompressed PCM).
+    Wav = 1,
+
+    /// Ogg, with Vorbis audio (Opus isn't supported).
+    Ogg = 2,
+
+    /// MPEG-4 audio (AAC), as recorded by Apple devices.
+    M4a = 3,
+
+    /// Matroska/WebM with Vorbis audio (Opus isn't supported).
+    WebM = 4,
+
+    /// FLAC.
+    Flac = 5,
+}
+
 /// Response for getting a single audio file.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct AudioResponse {
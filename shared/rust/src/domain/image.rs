@@ -6,9 +6,11 @@ pub mod user;
 
 use super::{
     category::CategoryId,
+    media::Provenance,
     meta::{AffiliationId, AgeRangeId, ImageStyleId, ImageTagIndex},
     Publish,
 };
+use crate::media::MediaLibrary;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "backend")]
@@ -27,6 +29,10 @@ pub enum ImageKind {
     Sticker = 1,
     /// The image is a user profile picture
     UserProfile = 2,
+    /// The image is a sticker, stored as vector art (SVG) so that it can be scaled without blurring.
+    ///
+    /// Rasterized (PNG) versions are still generated, for thumbnails and clients that can't use the SVG.
+    VectorSticker = 3,
 }
 
 impl ImageKind {
@@ -38,7 +44,7 @@ impl ImageKind {
     pub const fn size(self) -> (u32, u32) {
         match self {
             Self::Canvas => (1920, 1080),
-            Self::Sticker => (1440, 810),
+            Self::Sticker | Self::VectorSticker => (1440, 810),
             Self::UserProfile => (256, 256),
         }
     }
@@ -50,14 +56,21 @@ impl ImageKind {
             Self::Canvas => "Canvas",
             Self::Sticker => "Sticker",
             Self::UserProfile => "UserProfile",
+            Self::VectorSticker => "VectorSticker",
         }
     }
+
+    /// Whether images of this kind are uploaded as vector art (SVG).
+    #[must_use]
+    pub const fn is_vector(self) -> bool {
+        matches!(self, Self::VectorSticker)
+    }
 }
 
 /// Wrapper type around [`Uuid`], represents the ID of a image.
 ///
 /// [`Uuid`]: ../../uuid/struct.Uuid.html
-#[derive(Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Debug)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize, Debug)]
 #[cfg_attr(feature = "backend", derive(sqlx::Type))]
 #[cfg_attr(feature = "backend", sqlx(transparent))]
 pub struct ImageId(pub Uuid);
@@ -97,6 +110,9 @@ pub struct ImageCreateRequest {
 
     /// What kind of image this is.
     pub kind: ImageKind,
+
+    /// Where the image came from, and its license. Required for all images in the global library.
+    pub provenance: Provenance,
 }
 
 // todo: # errors doc section.
@@ -148,6 +164,11 @@ pub struct ImageUpdateRequest {
     /// If `Some` replace the image's tags with these.
     #[serde(default)]
     pub tags: Option<Vec<ImageTagIndex>>,
+
+    /// If `Some` replace the image's provenance with this.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Provenance>,
 }
 
 /// Search for images via the given query string.
@@ -340,6 +361,72 @@ pub struct ImageUploadResponse {
     pub session_uri: String,
 }
 
+/// Query for [`Similar`](crate::api::endpoints::image::Similar),
+/// [`user::Similar`](crate::api::endpoints::image::user::Similar)
+/// and [`Duplicates`](crate::api::endpoints::image::Duplicates).
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageSimilarityQuery {
+    /// How many bits (out of 64) the perceptual hashes of two images may differ by for them to be considered similar.
+    ///
+    /// Defaults to a fairly strict value, which only matches re-encoded, resized, or very slightly edited copies.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_distance: Option<u32>,
+}
+
+/// An image that looks like another one.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarImage {
+    /// The image's ID.
+    pub id: ImageId,
+
+    /// The library the image is in.
+    pub library: MediaLibrary,
+
+    /// How many bits (out of 64) the image's perceptual hash differs by, `0` is most likely the same picture.
+    pub distance: u32,
+}
+
+/// Response for [`Similar`](crate::api::endpoints::image::Similar)
+/// and [`user::Similar`](crate::api::endpoints::image::user::Similar).
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarImagesResponse {
+    /// The similar images found, most similar first.
+    ///
+    /// Empty if there are none (or the image hasn't been processed yet).
+    pub images: Vec<SimilarImage>,
+}
+
+/// A group of images in the global library which look alike.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageDuplicateCluster {
+    /// The images in the cluster, oldest first.
+    pub images: Vec<ImageResponse>,
+}
+
+/// Response for [`Duplicates`](crate::api::endpoints::image::Duplicates).
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageDuplicatesResponse {
+    /// The clusters of near-duplicate images found.
+    pub clusters: Vec<ImageDuplicateCluster>,
+}
+
+/// Request for [`MergeDuplicates`](crate::api::endpoints::image::MergeDuplicates).
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageMergeRequest {
+    /// The image to keep.
+    pub keep: ImageId,
+
+    /// The duplicates of `keep`, any references to them are replaced with `keep` and they are then deleted.
+    pub duplicates: Vec<ImageId>,
+}
+
 /// Over the wire representation of an image's metadata.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ImageMetadata {
@@ -384,6 +471,10 @@ pub struct ImageMetadata {
 
     /// When the image was last updated.
     pub updated_at: Option<DateTime<Utc>>,
+
+    /// Where the image came from, and its license.
+    #[serde(default)]
+    pub provenance: Provenance,
 }
 
 /// Response for successfully creating a Image.
@@ -409,6 +500,9 @@ impl<'r> sqlx::FromRow<'r, PgRow> for ImageMetadata {
             tags,
             created_at,
             updated_at,
+            license,
+            author,
+            source_url,
         } = DbImage::from_row(row)?;
 
         Ok(Self {
@@ -426,6 +520,11 @@ impl<'r> sqlx::FromRow<'r, PgRow> for ImageMetadata {
             tags: tags.into_iter().map(|(it,)| it).collect(),
             created_at,
             updated_at,
+            provenance: Provenance {
+                license,
+                author,
+                source_url,
+            },
         })
     }
 }
@@ -447,6 +546,9 @@ struct DbImage {
     pub tags: Vec<(ImageTagIndex,)>,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
+    pub license: super::media::License,
+    pub author: Option<String>,
+    pub source_url: Option<String>,
 }
 
 into_uuid![ImageId];
@@ -918,6 +918,14 @@ pub struct JigCountResponse {
     pub total_count: u64,
 }
 
+/// Response for [`Attribution`](crate::api::endpoints::jig::Attribution).
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct JigAttributionResponse {
+    /// The images from the global and web libraries used in the JIG's modules, with where they came from.
+    pub media: Vec<super::media::MediaAttribution>,
+}
+
 /// Response for whether a user has liked a JIG.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct JigLikedResponse {
@@ -1,6 +1,11 @@
 //! Types for additional resources for JIGs.
 
-use crate::domain::{audio::AudioId, image::ImageId, meta::ResourceTypeId, pdf::PdfId};
+use crate::domain::{
+    audio::AudioId,
+    image::ImageId,
+    meta::ResourceTypeId,
+    pdf::{PdfId, PdfPreview},
+};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -26,6 +31,13 @@ pub struct AdditionalResource {
     /// Content of additional resource
     #[serde(flatten)]
     pub resource_content: ResourceContent,
+
+    /// Preview of the Pdf, for processed [`ResourceContent::PdfId`] resources.
+    ///
+    /// Filled in by the server.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pdf_preview: Option<PdfPreview>,
 }
 
 /// Request to create a new `AdditionalResource`.
@@ -9,6 +9,8 @@ use uuid::Uuid;
 /// Module bodies
 pub mod body;
 
+pub mod printable;
+
 pub use body::Body as ModuleBody;
 
 /// Wrapper type around [`Uuid`](Uuid), represents the **unique ID** of a module.
@@ -121,8 +123,8 @@ pub enum ModuleKind {
     /// Video
     Video = 7,
 
-    /// Deprecated, next new module should use this slot
-    //VisualQuiz = 8,
+    /// Word Search & Crossword
+    WordGrid = 8,
 
     /// Quiz Game
     CardQuiz = 9,
@@ -135,6 +137,12 @@ pub enum ModuleKind {
 
     /// ResourceCover
     ResourceCover = 12,
+
+    /// Puzzle
+    Puzzle = 13,
+
+    /// Talk Type
+    TalkType = 14,
 }
 
 impl ModuleKind {
@@ -153,9 +161,34 @@ impl ModuleKind {
             Self::Tracing => "tracing",
             Self::Video => "video",
             Self::CardQuiz => "card-quiz",
+            Self::WordGrid => "word-grid",
+            Self::Puzzle => "puzzle",
+            Self::TalkType => "talk-type",
             Self::Legacy => "legacy",
         }
     }
+
+    /// The name of `self` shown to users.
+    #[must_use]
+    pub const fn display_name(self) -> &'static str {
+        match self {
+            Self::Cover => "Cover",
+            Self::ResourceCover => "Resource Cover",
+            Self::Flashcards => "Flashcards",
+            Self::Matching => "Matching",
+            Self::Memory => "Memory Game",
+            Self::Poster => "Talking Poster",
+            Self::TappingBoard => "Listen & Learn",
+            Self::DragDrop => "Drag & Drop",
+            Self::Tracing => "Tracing",
+            Self::Video => "Video",
+            Self::CardQuiz => "Quiz Game",
+            Self::WordGrid => "Word Search & Crossword",
+            Self::Puzzle => "Puzzle",
+            Self::TalkType => "Talk Type",
+            Self::Legacy => "Legacy",
+        }
+    }
 }
 
 impl FromStr for ModuleKind {
@@ -173,6 +206,9 @@ impl FromStr for ModuleKind {
             "tracing" => Self::Tracing,
             "video" => Self::Video,
             "card-quiz" => Self::CardQuiz,
+            "word-grid" => Self::WordGrid,
+            "puzzle" => Self::Puzzle,
+            "talk-type" => Self::TalkType,
             "legacy" => Self::Legacy,
             _ => anyhow::bail!("Invalid ModuleKind: {}", s),
         };
@@ -36,6 +36,15 @@ pub mod card_quiz;
 /// Matching
 pub mod matching;
 
+/// Word Search & Crossword
+pub mod word_grid;
+
+/// Puzzle
+pub mod puzzle;
+
+/// Talk Type
+pub mod talk_type;
+
 /// Legacy
 pub mod legacy;
 
@@ -59,6 +68,9 @@ pub enum Body {
     /// Module is a quiz game, and has a quiz game's body.
     CardQuiz(card_quiz::ModuleData),
 
+    /// Module is a word search or crossword, and has a word grid's body.
+    WordGrid(word_grid::ModuleData),
+
     /// Module is a poster, and has a talking poster's body.
     Poster(poster::ModuleData),
 
@@ -71,6 +83,12 @@ pub enum Body {
     /// Module is a drag & drop, and has a drag & drop's body.
     DragDrop(drag_drop::ModuleData),
 
+    /// Module is a puzzle, and has a puzzle's body.
+    Puzzle(puzzle::ModuleData),
+
+    /// Module is a talk type, and has a talk type's body.
+    TalkType(talk_type::ModuleData),
+
     /// Module is a [`Cover`](super::ModuleKind::Cover).
     ///
     /// Cover for Module type
@@ -93,10 +111,13 @@ impl Body {
             super::ModuleKind::CardQuiz => Self::CardQuiz(Default::default()),
             super::ModuleKind::Flashcards => Self::Flashcards(Default::default()),
             super::ModuleKind::Matching => Self::Matching(Default::default()),
+            super::ModuleKind::WordGrid => Self::WordGrid(Default::default()),
             super::ModuleKind::Poster => Self::Poster(Default::default()),
             super::ModuleKind::Video => Self::Video(Default::default()),
             super::ModuleKind::TappingBoard => Self::TappingBoard(Default::default()),
             super::ModuleKind::DragDrop => Self::DragDrop(Default::default()),
+            super::ModuleKind::Puzzle => Self::Puzzle(Default::default()),
+            super::ModuleKind::TalkType => Self::TalkType(Default::default()),
             super::ModuleKind::Legacy => Self::Legacy(Default::default()),
             _ => unimplemented!("TODO!"),
         }
@@ -109,10 +130,13 @@ impl Body {
             Self::Matching(data) => data.convert_to_body(kind),
             Self::Flashcards(data) => data.convert_to_body(kind),
             Self::CardQuiz(data) => data.convert_to_body(kind),
+            Self::WordGrid(data) => data.convert_to_body(kind),
             Self::Poster(data) => data.convert_to_body(kind),
             Self::Video(data) => data.convert_to_body(kind),
             Self::TappingBoard(data) => data.convert_to_body(kind),
             Self::DragDrop(data) => data.convert_to_body(kind),
+            Self::Puzzle(data) => data.convert_to_body(kind),
+            Self::TalkType(data) => data.convert_to_body(kind),
             Self::Cover(data) => data.convert_to_body(kind),
             Self::ResourceCover(data) => data.convert_to_body(kind),
             Self::Legacy(data) => data.convert_to_body(kind),
@@ -189,10 +213,13 @@ pub trait BodyExt<Mode: ModeExt, Step: StepExt>:
             ModuleKind::Matching => Ok(Body::Matching(self.convert_to_matching()?)),
             ModuleKind::Flashcards => Ok(Body::Flashcards(self.convert_to_flashcards()?)),
             ModuleKind::CardQuiz => Ok(Body::CardQuiz(self.convert_to_card_quiz()?)),
+            ModuleKind::WordGrid => Ok(Body::WordGrid(self.convert_to_word_grid()?)),
             ModuleKind::Poster => Ok(Body::Poster(self.convert_to_poster()?)),
             ModuleKind::Video => Ok(Body::Video(self.convert_to_video()?)),
             ModuleKind::TappingBoard => Ok(Body::TappingBoard(self.convert_to_tapping_board()?)),
             ModuleKind::DragDrop => Ok(Body::DragDrop(self.convert_to_drag_drop()?)),
+            ModuleKind::Puzzle => Ok(Body::Puzzle(self.convert_to_puzzle()?)),
+            ModuleKind::TalkType => Ok(Body::TalkType(self.convert_to_talk_type()?)),
             ModuleKind::Cover => Ok(Body::Cover(self.convert_to_cover()?)),
             ModuleKind::ResourceCover => Ok(Body::ResourceCover(self.convert_to_resource_cover()?)),
             ModuleKind::Legacy => Ok(Body::Legacy(self.convert_to_legacy()?)),
@@ -229,6 +256,10 @@ pub trait BodyConvert {
     fn convert_to_card_quiz(&self) -> Result<card_quiz::ModuleData, &'static str> {
         Err("cannot convert to quiz game!")
     }
+    /// Word search & crossword
+    fn convert_to_word_grid(&self) -> Result<word_grid::ModuleData, &'static str> {
+        Err("cannot convert to word grid!")
+    }
     /// Talking Poster
     fn convert_to_poster(&self) -> Result<poster::ModuleData, &'static str> {
         Err("cannot convert to talking poster!")
@@ -241,6 +272,14 @@ pub trait BodyConvert {
     fn convert_to_drag_drop(&self) -> Result<drag_drop::ModuleData, &'static str> {
         Err("cannot convert to drag & drop!")
     }
+    /// Puzzle
+    fn convert_to_puzzle(&self) -> Result<puzzle::ModuleData, &'static str> {
+        Err("cannot convert to puzzle!")
+    }
+    /// Talk Type
+    fn convert_to_talk_type(&self) -> Result<talk_type::ModuleData, &'static str> {
+        Err("cannot convert to talk type!")
+    }
     /// Cover
     fn convert_to_cover(&self) -> Result<cover::ModuleData, &'static str> {
         Err("cannot convert to cover!")
@@ -310,11 +349,14 @@ impl Body {
             Self::MemoryGame(_) => super::ModuleKind::Memory,
             Self::Flashcards(_) => super::ModuleKind::Flashcards,
             Self::CardQuiz(_) => super::ModuleKind::CardQuiz,
+            Self::WordGrid(_) => super::ModuleKind::WordGrid,
             Self::Matching(_) => super::ModuleKind::Matching,
             Self::Poster(_) => super::ModuleKind::Poster,
             Self::Video(_) => super::ModuleKind::Video,
             Self::TappingBoard(_) => super::ModuleKind::TappingBoard,
             Self::DragDrop(_) => super::ModuleKind::DragDrop,
+            Self::Puzzle(_) => super::ModuleKind::Puzzle,
+            Self::TalkType(_) => super::ModuleKind::TalkType,
             Self::Legacy(_) => super::ModuleKind::Legacy,
         }
     }
@@ -3,3 +3,6 @@ pub mod cards;
 
 /// Modules of the group "design"
 pub mod design;
+
+/// Modules the student can get wrong, i.e. that have attempts and a time limit
+pub mod assessment;
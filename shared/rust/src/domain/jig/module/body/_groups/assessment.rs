@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+/// Play settings shared by every module the student can get wrong.
+///
+/// Used by [`CardQuiz`](crate::domain::jig::module::ModuleKind::CardQuiz),
+/// [`Matching`](crate::domain::jig::module::ModuleKind::Matching),
+/// [`Memory`](crate::domain::jig::module::ModuleKind::Memory),
+/// [`DragDrop`](crate::domain::jig::module::ModuleKind::DragDrop),
+/// [`Puzzle`](crate::domain::jig::module::ModuleKind::Puzzle) and
+/// [`TalkType`](crate::domain::jig::module::ModuleKind::TalkType).
+/// A memory game has no wrong answers, only its time limit applies, and neither do
+/// spoken talk type answers, which the student checks themselves.
+#[derive(Clone, Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct PlaySettings {
+    /// time limit in minutes, the module ends when it runs out
+    pub time_limit: Option<u32>,
+
+    /// number of attempts at each question, `None` for unlimited
+    pub attempts: Option<u8>,
+
+    /// show the correct answer once the student is out of attempts
+    pub show_answer: bool,
+
+    /// what happens to a question the student is out of attempts for
+    pub retry: RetryPolicy,
+}
+
+impl PlaySettings {
+    /// What happens after the student's `mistakes`-th wrong answer to a question.
+    #[must_use]
+    pub fn after_mistake(&self, mistakes: u32) -> AfterMistake {
+        match self.attempts {
+            // zero attempts isn't a limit anyone would set on purpose, so it's treated as one
+            Some(attempts) if mistakes >= u32::from(attempts.max(1)) => {
+                AfterMistake::OutOfAttempts {
+                    show_answer: self.show_answer,
+                    retry_later: self.retry == RetryPolicy::Later,
+                }
+            }
+            _ => AfterMistake::TryAgain,
+        }
+    }
+}
+
+/// Retry policy
+#[derive(Clone, Copy, Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum RetryPolicy {
+    #[default]
+    /// Move on, the question counts as missed
+    Never,
+
+    /// Ask the question again after the others, with fresh attempts
+    Later,
+}
+
+/// What happens after a wrong answer, see [`PlaySettings::after_mistake`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AfterMistake {
+    /// The student has attempts left
+    TryAgain,
+
+    /// The student is out of attempts for this question
+    OutOfAttempts {
+        /// reveal the correct answer before moving on
+        show_answer: bool,
+
+        /// ask the question again after the others
+        retry_later: bool,
+    },
+}
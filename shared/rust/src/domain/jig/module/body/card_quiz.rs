@@ -1,11 +1,16 @@
 use crate::domain::jig::module::{
-    body::{Body, BodyConvert, BodyExt, ThemeId, _groups::cards::*},
+    body::{
+        _groups::{assessment::PlaySettings, cards::*},
+        Body, BodyConvert, BodyExt, ThemeId,
+    },
     ModuleKind,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::convert::TryFrom;
 
+pub mod answer;
+
 /// The body for [`CardQuiz`](crate::domain::jig::module::ModuleKind::CardQuiz) modules.
 #[derive(Default, Clone, Serialize, Deserialize, Debug)]
 pub struct ModuleData {
@@ -34,11 +39,19 @@ pub struct PlayerSettings {
     /// number of rounds to play
     pub n_rounds: u32,
 
-    /// time limit in minutes
-    pub time_limit: Option<u32>,
+    /// time limit, attempts and what happens when they run out
+    #[serde(default)]
+    pub assessment: PlaySettings,
+
+    /// how the student answers
+    #[serde(default)]
+    pub answer_mode: AnswerMode,
 
-    /// number of attempts
-    pub n_attempts: Option<u8>,
+    /// how many typos a typed answer can have and still be correct
+    ///
+    /// Short answers are allowed fewer, see [`is_correct_answer`](answer::is_correct_answer).
+    #[serde(default)]
+    pub max_typos: u8,
 }
 
 impl Default for PlayerSettings {
@@ -47,8 +60,32 @@ impl Default for PlayerSettings {
             n_choices: 3,
             swap: false,
             n_rounds: 3,
-            time_limit: None,
-            n_attempts: None,
+            assessment: PlaySettings::default(),
+            answer_mode: AnswerMode::default(),
+            max_typos: 1,
+        }
+    }
+}
+
+/// How the student answers
+#[derive(Clone, Copy, Default, Serialize, Deserialize, Debug, Eq, PartialEq)]
+pub enum AnswerMode {
+    #[default]
+    /// Choose the matching card among [`n_choices`](PlayerSettings::n_choices) cards
+    Choose,
+    /// Type the matching card's text
+    ///
+    /// Rounds where the matching card isn't text fall back to choosing.
+    Type,
+}
+
+impl AnswerMode {
+    /// Get it as a string
+    #[must_use]
+    pub const fn as_str_id(&self) -> &'static str {
+        match self {
+            Self::Choose => "choose",
+            Self::Type => "type",
         }
     }
 }
@@ -129,6 +166,7 @@ impl BodyConvert for ModuleData {
             ModuleKind::Memory,
             ModuleKind::Matching,
             ModuleKind::Flashcards,
+            ModuleKind::WordGrid,
         ]
     }
 
@@ -163,6 +201,10 @@ impl BodyConvert for ModuleData {
                 }),
         })
     }
+
+    fn convert_to_word_grid(&self) -> Result<super::word_grid::ModuleData, &'static str> {
+        super::word_grid::ModuleData::from_cards(self.content.as_ref().map(|content| &content.base))
+    }
 }
 
 impl TryFrom<Body> for ModuleData {
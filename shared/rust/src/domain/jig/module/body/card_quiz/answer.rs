@@ -0,0 +1,218 @@
+//! Checking typed answers.
+//!
+//! Answers are compared after [normalizing](normalize_answer) them, and can be off by a few typos
+//! as measured by [`edit_distance`].
+
+/// The number of letters in an answer for each typo it's allowed.
+///
+/// Short answers have to be exact, otherwise e.g. "cat" would accept "bat".
+pub const LETTERS_PER_TYPO: usize = 4;
+
+/// Whether `answer` matches `expected`, allowing up to `max_typos` typos.
+///
+/// Answers are allowed one typo for every [`LETTERS_PER_TYPO`] letters of the expected answer,
+/// up to `max_typos`.
+#[must_use]
+pub fn is_correct_answer(answer: &str, expected: &str, max_typos: u8) -> bool {
+    let answer = normalize_answer(answer);
+    let expected = normalize_answer(expected);
+
+    if answer.is_empty() {
+        return false;
+    }
+
+    let allowed = usize::from(max_typos).min(expected.chars().count() / LETTERS_PER_TYPO);
+
+    edit_distance(&answer, &expected) <= allowed
+}
+
+/// Normalizes an answer so that it can be compared to another.
+///
+/// * Letters are lowercased.
+/// * Hebrew niqqud and cantillation marks are removed.
+/// * Hebrew final letters are replaced with their regular forms.
+/// * Dashes (including the Hebrew maqaf) and slashes separate words, other punctuation is removed.
+/// * Words are separated by a single space.
+#[must_use]
+pub fn normalize_answer(answer: &str) -> String {
+    let mut normalized = String::with_capacity(answer.len());
+
+    for c in answer.chars().flat_map(char::to_lowercase) {
+        let c = match c {
+            'ך' => 'כ',
+            'ם' => 'מ',
+            'ן' => 'נ',
+            'ף' => 'פ',
+            'ץ' => 'צ',
+            c => c,
+        };
+
+        if is_hebrew_mark(c) {
+            continue;
+        }
+
+        if c.is_alphanumeric() {
+            normalized.push(c);
+        } else if is_separator(c) && !normalized.ends_with(' ') && !normalized.is_empty() {
+            normalized.push(' ');
+        }
+    }
+
+    if normalized.ends_with(' ') {
+        normalized.pop();
+    }
+
+    normalized
+}
+
+/// Whether `c` is Hebrew niqqud or a cantillation mark.
+///
+/// These count as letters to [`char::is_alphanumeric`], so they're checked first.
+const fn is_hebrew_mark(c: char) -> bool {
+    matches!(c, '\u{0591}'..='\u{05C7}')
+        && !matches!(c, '\u{05BE}' | '\u{05C0}' | '\u{05C3}' | '\u{05C6}')
+}
+
+/// Whether `c` separates words, other punctuation (and niqqud) is dropped.
+const fn is_separator(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '-' | '‐' | '‑' | '–' | '—' | '\u{05BE}' | '/' | '_')
+}
+
+/// The number of single character insertions, deletions, substitutions and transpositions of
+/// neighbouring characters needed to turn `a` into `b`.
+#[must_use]
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // `rows[0]` is two rows back, `rows[1]` the previous row and `rows[2]` the current one.
+    let mut rows = [
+        vec![0; b.len() + 1],
+        (0..=b.len()).collect(),
+        vec![0; b.len() + 1],
+    ];
+
+    for i in 1..=a.len() {
+        rows[2][0] = i;
+
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+
+            let mut distance = (rows[1][j] + 1)
+                .min(rows[2][j - 1] + 1)
+                .min(rows[1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[0][j - 2] + 1);
+            }
+
+            rows[2][j] = distance;
+        }
+
+        rows.rotate_left(1);
+    }
+
+    rows[1][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_ignores_case_and_punctuation() {
+        assert_eq!(normalize_answer("Hello, World!"), "hello world");
+        assert_eq!(normalize_answer("  don't   stop. "), "dont stop");
+        assert_eq!(normalize_answer("ice-cream"), "ice cream");
+        assert_eq!(normalize_answer("and/or"), "and or");
+        assert_eq!(normalize_answer("?!"), "");
+        assert_eq!(normalize_answer("ÉCOLE"), "école");
+    }
+
+    #[test]
+    fn normalize_strips_niqqud() {
+        assert_eq!(normalize_answer("שָׁלוֹם"), "שלומ");
+        assert_eq!(normalize_answer("בְּרֵאשִׁית"), "בראשית");
+        // cantillation
+        assert_eq!(normalize_answer("בְּרֵאשִׁ֖ית"), "בראשית");
+    }
+
+    #[test]
+    fn normalize_replaces_final_letters() {
+        assert_eq!(normalize_answer("ךםןףץ"), "כמנפצ");
+        assert_eq!(normalize_answer("שלום"), normalize_answer("שלומ"));
+    }
+
+    #[test]
+    fn normalize_treats_hebrew_punctuation() {
+        // maqaf joins words like a hyphen
+        assert_eq!(normalize_answer("בית־ספר"), "בית ספר");
+        // geresh and gershayim are dropped
+        assert_eq!(normalize_answer("צ׳יפס"), "ציפס");
+        assert_eq!(normalize_answer("צה״ל"), "צהל");
+        assert_eq!(normalize_answer("תשפ\"ב"), "תשפב");
+    }
+
+    #[test]
+    fn edit_distance_counts_edits() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("abc", ""), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("flaw", "lawn"), 2);
+        assert_eq!(edit_distance("same", "same"), 0);
+    }
+
+    #[test]
+    fn edit_distance_counts_transpositions_once() {
+        assert_eq!(edit_distance("teh", "the"), 1);
+        assert_eq!(edit_distance("abcd", "badc"), 2);
+        assert_eq!(edit_distance("שלומ", "שולמ"), 1);
+    }
+
+    #[test]
+    fn edit_distance_is_symmetric() {
+        for (a, b) in &[
+            ("apple", "aple"),
+            ("banana", "bananna"),
+            ("ab", "ba"),
+            ("ca", "abc"),
+        ] {
+            assert_eq!(edit_distance(a, b), edit_distance(b, a));
+        }
+    }
+
+    #[test]
+    fn exact_answers_are_correct() {
+        assert!(is_correct_answer("apple", "apple", 0));
+        assert!(is_correct_answer("Apple!", "apple", 0));
+        assert!(is_correct_answer("שָׁלוֹם", "שלום", 0));
+        assert!(is_correct_answer("new york", "New-York", 0));
+    }
+
+    #[test]
+    fn typos_are_tolerated_up_to_the_limit() {
+        assert!(is_correct_answer("elefant", "elephant", 2));
+        assert!(!is_correct_answer("elefant", "elephant", 1));
+        assert!(is_correct_answer("recieve", "receive", 1));
+        assert!(!is_correct_answer("recieve", "receive", 0));
+        assert!(is_correct_answer("מחשב", "מחשוב", 1));
+    }
+
+    #[test]
+    fn short_answers_must_be_exact() {
+        assert!(!is_correct_answer("bat", "cat", 3));
+        assert!(is_correct_answer("cat", "cat", 3));
+        // four letters allow one typo
+        assert!(is_correct_answer("bird", "bard", 3));
+        assert!(!is_correct_answer("bord", "bard", 0));
+        assert!(!is_correct_answer("bxrx", "bird", 3));
+    }
+
+    #[test]
+    fn empty_answers_are_wrong() {
+        assert!(!is_correct_answer("", "a", 5));
+        assert!(!is_correct_answer("...", "", 5));
+        assert!(!is_correct_answer("  ", "", 0));
+    }
+}
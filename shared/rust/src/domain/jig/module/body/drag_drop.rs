@@ -12,6 +12,9 @@ use std::convert::TryFrom;
 mod play_settings;
 pub use play_settings::*;
 
+mod buckets;
+pub use buckets::*;
+
 use super::_groups::design::Text;
 
 /// The body for [`DragDrop`](crate::domain::jig::module::ModuleKind::DragDrop) modules.
@@ -171,6 +174,10 @@ pub struct Interactive {
 
     /// target transform
     pub target_transform: Option<Transform>,
+
+    /// indices of the target areas this item may be sorted into
+    #[serde(default)]
+    pub target_areas: Vec<usize>,
 }
 
 /// drag & drop trace w/ metadata
@@ -178,6 +185,10 @@ pub struct Interactive {
 pub struct TargetArea {
     /// the trace
     pub trace: Trace,
+
+    /// the bucket label shown when sorting
+    #[serde(default)]
+    pub label: Option<String>,
 }
 
 #[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
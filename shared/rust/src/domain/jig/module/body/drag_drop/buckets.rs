@@ -0,0 +1,244 @@
+use super::{Content, Interactive, ItemKind, Mode, TargetArea};
+use serde::{Deserialize, Serialize};
+
+impl Mode {
+    /// Whether items are sorted into labeled target area buckets rather than
+    /// being placed at a single target transform
+    #[must_use]
+    pub const fn uses_buckets(self) -> bool {
+        matches!(self, Self::Sorting)
+    }
+}
+
+impl Interactive {
+    /// Whether dropping this item in the target area at `index` is correct
+    #[must_use]
+    pub fn accepts(&self, index: usize) -> bool {
+        self.target_areas.contains(&index)
+    }
+}
+
+impl Content {
+    /// Indices of the interactive items which can't be sorted because none of
+    /// their buckets refer to an existing target area
+    #[must_use]
+    pub fn items_missing_bucket(&self) -> Vec<usize> {
+        let n_areas = self.target_areas.len();
+
+        self.items
+            .iter()
+            .enumerate()
+            .filter_map(|(index, item)| match &item.kind {
+                ItemKind::Interactive(data)
+                    if !data.target_areas.iter().any(|area| *area < n_areas) =>
+                {
+                    Some(index)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Removes a target area and shifts the bucket indices of every item
+    /// which refers to a later area
+    ///
+    /// Returns `None` and leaves everything as it is if there's no target area at `index`
+    pub fn remove_target_area(&mut self, index: usize) -> Option<TargetArea> {
+        if index >= self.target_areas.len() {
+            return None;
+        }
+
+        let removed = self.target_areas.remove(index);
+
+        for item in &mut self.items {
+            if let ItemKind::Interactive(data) = &mut item.kind {
+                data.target_areas = data
+                    .target_areas
+                    .iter()
+                    .filter(|area| **area != index)
+                    .map(|area| if *area > index { area - 1 } else { *area })
+                    .collect();
+            }
+        }
+
+        Some(removed)
+    }
+}
+
+/// Result of sorting items into one target area bucket
+#[derive(Clone, Copy, Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct BucketScore {
+    /// Items dropped in this bucket which belong there
+    pub correct: u32,
+
+    /// Items dropped in this bucket which don't belong there
+    pub incorrect: u32,
+}
+
+impl BucketScore {
+    /// Records a single drop into the bucket
+    pub const fn record(&mut self, is_correct: bool) {
+        if is_correct {
+            self.correct += 1;
+        } else {
+            self.incorrect += 1;
+        }
+    }
+
+    /// Total drops into the bucket
+    #[must_use]
+    pub const fn attempts(&self) -> u32 {
+        self.correct + self.incorrect
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::jig::module::body::{
+        _groups::design::{Sticker, Text, Trace, TraceKind, TraceShape},
+        drag_drop::Item,
+        Transform,
+    };
+
+    fn area(label: &str) -> TargetArea {
+        TargetArea {
+            trace: Trace {
+                transform: Transform::identity(),
+                shape: TraceShape::Rect(0.1, 0.1),
+                kind: TraceKind::Regular,
+                audio: None,
+                text: None,
+            },
+            label: Some(label.to_owned()),
+        }
+    }
+
+    fn item(target_areas: Option<Vec<usize>>) -> Item {
+        Item {
+            sticker: Sticker::Text(Text::default()),
+            kind: match target_areas {
+                Some(target_areas) => ItemKind::Interactive(Interactive {
+                    target_areas,
+                    ..Interactive::default()
+                }),
+                None => ItemKind::Static,
+            },
+        }
+    }
+
+    fn buckets(content: &Content) -> Vec<Option<Vec<usize>>> {
+        content
+            .items
+            .iter()
+            .map(|item| match &item.kind {
+                ItemKind::Interactive(data) => Some(data.target_areas.clone()),
+                ItemKind::Static => None,
+            })
+            .collect()
+    }
+
+    fn content() -> Content {
+        Content {
+            mode: Mode::Sorting,
+            target_areas: vec![area("a"), area("b"), area("c")],
+            items: vec![
+                item(Some(vec![0])),
+                item(Some(vec![1, 2])),
+                item(None),
+                item(Some(vec![2])),
+                item(Some(vec![])),
+                item(Some(vec![0, 1, 2])),
+            ],
+            ..Content::default()
+        }
+    }
+
+    #[test]
+    fn finds_items_missing_a_bucket() {
+        let mut content = content();
+        assert_eq!(content.items_missing_bucket(), [4]);
+
+        // buckets past the last target area don't count.
+        content.target_areas.truncate(2);
+        assert_eq!(content.items_missing_bucket(), [3, 4]);
+
+        content.target_areas.clear();
+        assert_eq!(content.items_missing_bucket(), [0, 1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn removing_an_area_shifts_later_buckets() {
+        let mut content = content();
+
+        let removed = content.remove_target_area(1);
+        assert_eq!(removed.and_then(|area| area.label).as_deref(), Some("b"));
+
+        let labels: Vec<_> = content
+            .target_areas
+            .iter()
+            .map(|area| area.label.as_deref())
+            .collect();
+        assert_eq!(labels, [Some("a"), Some("c")]);
+
+        assert_eq!(
+            buckets(&content),
+            [
+                Some(vec![0]),
+                Some(vec![1]),
+                None,
+                Some(vec![1]),
+                Some(vec![]),
+                Some(vec![0, 1]),
+            ]
+        );
+
+        content.remove_target_area(0);
+        assert_eq!(content.items_missing_bucket(), [0, 4]);
+    }
+
+    #[test]
+    fn removing_a_missing_area_does_nothing() {
+        let mut content = content();
+
+        assert!(content.remove_target_area(3).is_none());
+        assert!(content.remove_target_area(usize::MAX).is_none());
+
+        assert_eq!(content.target_areas.len(), 3);
+        assert_eq!(buckets(&content), buckets(&self::content()));
+    }
+
+    #[test]
+    fn bucket_scores_count_drops() {
+        let mut score = BucketScore::default();
+        assert_eq!(score.attempts(), 0);
+
+        score.record(true);
+        score.record(false);
+        score.record(true);
+
+        assert_eq!(
+            score,
+            BucketScore {
+                correct: 2,
+                incorrect: 1,
+            }
+        );
+        assert_eq!(score.attempts(), 3);
+    }
+
+    #[test]
+    fn items_accept_their_buckets() {
+        let data = Interactive {
+            target_areas: vec![0, 2],
+            ..Interactive::default()
+        };
+
+        assert!(data.accepts(0));
+        assert!(!data.accepts(1));
+        assert!(data.accepts(2));
+
+        assert!(Mode::Sorting.uses_buckets());
+        assert!(!Mode::SettingTable.uses_buckets());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::domain::jig::module::body::_groups::assessment;
 use serde::{Deserialize, Serialize};
 
 /// Play settings
@@ -6,8 +7,9 @@ pub struct PlaySettings {
     /// next style
     pub next: Next,
 
-    /// time limit in minutes
-    pub time_limit: Option<u32>,
+    /// time limit, attempts and what happens when they run out
+    #[serde(default)]
+    pub assessment: assessment::PlaySettings,
 
     /// hint style
     pub hint: Hint,
@@ -6,6 +6,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::convert::TryFrom;
 
+pub mod review;
+
 /// The body for [`Flashcards`](crate::domain::jig::module::ModuleKind::Flashcards) modules.
 #[derive(Default, Clone, Serialize, Deserialize, Debug)]
 pub struct ModuleData {
@@ -31,6 +33,10 @@ pub struct PlayerSettings {
     /// swap the display to be primary left vs. right
     #[serde(default)]
     pub swap: bool,
+
+    /// review with spaced repetition, the student marks each card known or unknown and due cards come first
+    #[serde(default)]
+    pub spaced_repetition: bool,
 }
 
 /// Display Mode
@@ -141,6 +147,7 @@ impl BodyConvert for ModuleData {
             ModuleKind::Memory,
             ModuleKind::Matching,
             ModuleKind::CardQuiz,
+            ModuleKind::WordGrid,
         ]
     }
     fn convert_to_memory(&self) -> Result<super::memory::ModuleData, &'static str> {
@@ -174,6 +181,10 @@ impl BodyConvert for ModuleData {
                 }),
         })
     }
+
+    fn convert_to_word_grid(&self) -> Result<super::word_grid::ModuleData, &'static str> {
+        super::word_grid::ModuleData::from_cards(self.content.as_ref().map(|content| &content.base))
+    }
 }
 
 impl TryFrom<Body> for ModuleData {
@@ -0,0 +1,263 @@
+//! Spaced repetition for flashcards.
+//!
+//! Cards move through Leitner boxes. A card the student knows moves up a box and comes back after a
+//! longer interval, a card they don't know drops back to the first box and is due again straight away.
+//!
+//! State is kept per [`pair_key`] rather than per position, so it follows a pair when the pairs are
+//! reordered or some are deleted, and starts over when the pair itself is edited.
+
+use crate::domain::jig::module::body::_groups::cards::CardPair;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The number of days a card waits in each box before it's due again.
+pub const BOX_INTERVAL_DAYS: [i64; 6] = [0, 1, 3, 7, 14, 30];
+
+/// The highest box a card can reach.
+pub const MAX_BOX: u8 = 5;
+
+/// The review state of a single card pair.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CardReview {
+    /// The [`pair_key`] of the reviewed pair
+    pub pair_key: String,
+
+    /// The Leitner box the card is in, from `0` (reviewed every time) to [`MAX_BOX`]
+    pub leitner_box: u8,
+
+    /// When the card was last marked known or unknown
+    pub reviewed_at: DateTime<Utc>,
+
+    /// When the card should be reviewed again
+    pub due_at: DateTime<Utc>,
+}
+
+impl CardReview {
+    /// The state of a card after it's reviewed for the first time.
+    #[must_use]
+    pub fn new(pair_key: String, known: bool, now: DateTime<Utc>) -> Self {
+        Self::scheduled(pair_key, u8::from(known), now)
+    }
+
+    /// The state of the card after it's reviewed again.
+    #[must_use]
+    pub fn review(&self, known: bool, now: DateTime<Utc>) -> Self {
+        let leitner_box = if known {
+            (self.leitner_box + 1).min(MAX_BOX)
+        } else {
+            0
+        };
+
+        Self::scheduled(self.pair_key.clone(), leitner_box, now)
+    }
+
+    /// Whether the card should be reviewed at `now`.
+    #[must_use]
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.due_at <= now
+    }
+
+    fn scheduled(pair_key: String, leitner_box: u8, now: DateTime<Utc>) -> Self {
+        let leitner_box = leitner_box.min(MAX_BOX);
+
+        Self {
+            pair_key,
+            leitner_box,
+            reviewed_at: now,
+            due_at: now + Duration::days(BOX_INTERVAL_DAYS[usize::from(leitner_box)]),
+        }
+    }
+}
+
+/// Identifies a pair by the contents of its cards.
+///
+/// Pairs with the same cards share a key, and so share their review state.
+#[must_use]
+pub fn pair_key(pair: &CardPair) -> String {
+    // the content is hashed (64 bit FNV-1a) to keep the key short, text cards can be long.
+    let content =
+        serde_json::to_vec(&(&pair.0.card_content, &pair.1.card_content)).unwrap_or_default();
+
+    let hash = content
+        .iter()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+
+    format!("{hash:016x}")
+}
+
+/// The order to show `pairs` in, as indexes into `pairs`, given the student's `reviews`.
+///
+/// Due cards come first, lowest box and longest overdue first, then cards that were never reviewed,
+/// then the rest by when they're due. Reviews of pairs that no longer exist are ignored.
+#[must_use]
+pub fn review_order(pairs: &[CardPair], reviews: &[CardReview], now: DateTime<Utc>) -> Vec<usize> {
+    let mut order: Vec<(usize, Option<&CardReview>)> = pairs
+        .iter()
+        .enumerate()
+        .map(|(index, pair)| {
+            let key = pair_key(pair);
+            let review = reviews.iter().find(|review| review.pair_key == key);
+
+            (index, review)
+        })
+        .collect();
+
+    order.sort_by_key(|(index, review)| match review {
+        Some(review) if review.is_due(now) => (0, review.leitner_box, review.due_at, *index),
+        None => (1, 0, now, *index),
+        Some(review) => (2, 0, review.due_at, *index),
+    });
+
+    order.into_iter().map(|(index, _)| index).collect()
+}
+
+/// Query for the current player's review state.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FlashcardReviewQuery {
+    /// Token of a player session instance, for players who aren't logged in.
+    ///
+    /// Without it the review state belongs to the logged-in user.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+/// The current player's review state for a flashcards module.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FlashcardReviewResponse {
+    /// Review state of each pair that was reviewed, pairs which aren't listed were never reviewed
+    pub reviews: Vec<CardReview>,
+}
+
+/// Request to mark a card known or unknown.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FlashcardReviewRequest {
+    /// Token of a player session instance, for players who aren't logged in.
+    ///
+    /// Without it the review state belongs to the logged-in user.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+
+    /// The [`pair_key`] of the reviewed pair
+    pub pair_key: String,
+
+    /// Whether the student knew the card
+    pub known: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::jig::module::body::_groups::cards::{Card, CardContent};
+    use chrono::TimeZone;
+
+    fn pair(word: &str) -> CardPair {
+        let card = |text: &str| Card {
+            audio: None,
+            card_content: CardContent::Text(text.to_owned()),
+        };
+
+        CardPair(card(word), card(&word.to_uppercase()))
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2022, 5, 1, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn known_cards_move_up_a_box() {
+        let mut review = CardReview::new("a".to_owned(), true, now());
+        assert_eq!(review.leitner_box, 1);
+        assert_eq!(review.due_at, now() + Duration::days(1));
+
+        for expected in 2..=MAX_BOX {
+            review = review.review(true, now());
+            assert_eq!(review.leitner_box, expected);
+            assert_eq!(
+                review.due_at,
+                now() + Duration::days(BOX_INTERVAL_DAYS[usize::from(expected)])
+            );
+        }
+
+        review = review.review(true, now());
+        assert_eq!(review.leitner_box, MAX_BOX);
+        assert_eq!(review.pair_key, "a");
+    }
+
+    #[test]
+    fn unknown_cards_drop_to_the_first_box() {
+        let review = CardReview::new("a".to_owned(), false, now());
+        assert_eq!(review.leitner_box, 0);
+        assert!(review.is_due(now()));
+
+        let later = now() + Duration::days(5);
+        let review = review
+            .review(true, now())
+            .review(true, now())
+            .review(false, later);
+
+        assert_eq!(review.leitner_box, 0);
+        assert_eq!(review.reviewed_at, later);
+        assert_eq!(review.due_at, later);
+    }
+
+    #[test]
+    fn due_cards_come_first() {
+        let pairs: Vec<_> = ["a", "b", "c", "d", "e"]
+            .iter()
+            .map(|it| pair(it))
+            .collect();
+        let key = |index: usize| pair_key(&pairs[index]);
+
+        let earlier = now() - Duration::days(10);
+
+        let reviews = vec![
+            // not due for another day
+            CardReview::new(key(0), true, now()),
+            // due, box 1 and overdue by 9 days
+            CardReview::new(key(1), true, earlier),
+            // due, box 0
+            CardReview::new(key(2), false, now()),
+            // never reviewed: 3
+            // due, box 1 and overdue by 8 days
+            CardReview::new(key(4), true, earlier + Duration::days(1)),
+        ];
+
+        assert_eq!(review_order(&pairs, &reviews, now()), [2, 1, 4, 3, 0]);
+    }
+
+    #[test]
+    fn reviews_follow_their_pair() {
+        let pairs: Vec<_> = ["a", "b", "c"].iter().map(|it| pair(it)).collect();
+        let reviews = vec![CardReview::new(pair_key(&pairs[2]), false, now())];
+
+        assert_eq!(review_order(&pairs, &reviews, now()), [2, 0, 1]);
+
+        // "c" moves to the front and "a" is deleted.
+        let pairs = vec![pairs[2].clone(), pairs[1].clone()];
+        assert_eq!(review_order(&pairs, &reviews, now()), [0, 1]);
+
+        // and once "c" is deleted too, its review doesn't apply to anything.
+        let pairs = vec![pair("b"), pair("d")];
+        assert_eq!(review_order(&pairs, &reviews, now()), [0, 1]);
+        assert!(review_order(&[], &reviews, now()).is_empty());
+    }
+
+    #[test]
+    fn pair_keys_depend_on_both_cards() {
+        let mut swapped = pair("a");
+        std::mem::swap(&mut swapped.0, &mut swapped.1);
+
+        assert_eq!(pair_key(&pair("a")), pair_key(&pair("a")));
+        assert_ne!(pair_key(&pair("a")), pair_key(&pair("b")));
+        assert_ne!(pair_key(&pair("a")), pair_key(&swapped));
+        assert_eq!(pair_key(&pair("a")).len(), 16);
+    }
+}
@@ -1,5 +1,8 @@
 use crate::domain::jig::module::{
-    body::{Body, BodyConvert, BodyExt, ModeExt, ThemeId, _groups::cards::*},
+    body::{
+        _groups::{assessment::PlaySettings, cards::*},
+        Body, BodyConvert, BodyExt, ModeExt, ThemeId,
+    },
     ModuleKind,
 };
 use serde::{Deserialize, Serialize};
@@ -35,8 +38,9 @@ pub struct PlayerSettings {
     /// number of rounds to play
     pub n_rounds: u32,
 
-    /// time limit in minutes
-    pub time_limit: Option<u32>,
+    /// time limit, attempts and what happens when they run out
+    #[serde(default)]
+    pub assessment: PlaySettings,
 }
 
 impl Default for PlayerSettings {
@@ -45,7 +49,7 @@ impl Default for PlayerSettings {
             n_choices: 3,
             swap: false,
             n_rounds: 1,
-            time_limit: None,
+            assessment: PlaySettings::default(),
         }
     }
 }
@@ -132,6 +136,7 @@ impl BodyConvert for ModuleData {
             ModuleKind::Memory,
             ModuleKind::Flashcards,
             ModuleKind::CardQuiz,
+            ModuleKind::WordGrid,
         ]
     }
     fn convert_to_memory(&self) -> Result<super::memory::ModuleData, &'static str> {
@@ -165,6 +170,10 @@ impl BodyConvert for ModuleData {
                 }),
         })
     }
+
+    fn convert_to_word_grid(&self) -> Result<super::word_grid::ModuleData, &'static str> {
+        super::word_grid::ModuleData::from_cards(self.content.as_ref().map(|content| &content.base))
+    }
 }
 
 impl TryFrom<Body> for ModuleData {
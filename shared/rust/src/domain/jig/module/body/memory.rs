@@ -1,11 +1,16 @@
 use crate::domain::jig::module::{
-    body::{Body, BodyConvert, BodyExt, ThemeId, _groups::cards::*},
+    body::{
+        _groups::{assessment::PlaySettings, cards::*},
+        Body, BodyConvert, BodyExt, ThemeId,
+    },
     ModuleKind,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use std::convert::TryFrom;
 
+pub mod turns;
+
 /// The body for [`Memory`](crate::domain::jig::module::ModuleKind::Memory) modules.
 #[derive(Default, Clone, Serialize, Deserialize, Debug)]
 pub struct ModuleData {
@@ -25,10 +30,16 @@ pub struct Content {
 /// Player settings
 #[derive(Default, Clone, Serialize, Deserialize, Debug)]
 pub struct PlayerSettings {
-    /// time limit in minutes
-    pub time_limit: Option<u32>,
+    /// time limit, the other assessment settings don't apply to memory games
+    #[serde(default)]
+    pub assessment: PlaySettings,
     /// amount of pairs to render
     pub pairs_to_display: Option<u32>,
+    /// Number of players taking turns on the same device, `None` for a single player game.
+    ///
+    /// Between [`MIN_PLAYERS`](turns::MIN_PLAYERS) and [`MAX_PLAYERS`](turns::MAX_PLAYERS).
+    #[serde(default)]
+    pub players: Option<u8>,
 }
 
 impl BodyExt<Mode, Step> for ModuleData {
@@ -107,6 +118,7 @@ impl BodyConvert for ModuleData {
             ModuleKind::Matching,
             ModuleKind::Flashcards,
             ModuleKind::CardQuiz,
+            ModuleKind::WordGrid,
         ]
     }
     fn convert_to_matching(&self) -> Result<super::matching::ModuleData, &'static str> {
@@ -143,6 +155,10 @@ impl BodyConvert for ModuleData {
                 }),
         })
     }
+
+    fn convert_to_word_grid(&self) -> Result<super::word_grid::ModuleData, &'static str> {
+        super::word_grid::ModuleData::from_cards(self.content.as_ref().map(|content| &content.base))
+    }
 }
 
 impl TryFrom<Body> for ModuleData {
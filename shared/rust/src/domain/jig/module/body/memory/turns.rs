@@ -0,0 +1,178 @@
+//! Turn-based play, for several players sharing one device (e.g. a classroom smartboard).
+//!
+//! Players take turns flipping two cards. A player who finds a pair scores it and goes again,
+//! otherwise it's the next player's turn.
+
+/// The fewest players a turn-based game can have.
+pub const MIN_PLAYERS: u8 = 2;
+
+/// The most players a turn-based game can have.
+pub const MAX_PLAYERS: u8 = 4;
+
+/// Clamps a number of players to the supported range.
+#[must_use]
+pub fn clamp_players(players: u8) -> u8 {
+    players.clamp(MIN_PLAYERS, MAX_PLAYERS)
+}
+
+/// The name a player gets when they don't enter one, `index` is zero-based.
+#[must_use]
+pub fn default_player_name(index: usize) -> String {
+    format!("Player {}", index + 1)
+}
+
+/// A player in a turn-based game.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Player {
+    /// The player's name
+    pub name: String,
+
+    /// The number of pairs the player found
+    pub score: u32,
+}
+
+/// Whose turn it is and how many pairs everyone found.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Turns {
+    players: Vec<Player>,
+    current: usize,
+}
+
+impl Turns {
+    /// Starts a game with the first player's turn.
+    ///
+    /// Blank names are replaced with their [default](default_player_name). Missing players are
+    /// added and extra ones dropped, so that there are between [`MIN_PLAYERS`] and [`MAX_PLAYERS`].
+    #[must_use]
+    pub fn new(names: &[String]) -> Self {
+        let count = names
+            .len()
+            .clamp(usize::from(MIN_PLAYERS), usize::from(MAX_PLAYERS));
+
+        let players = (0..count)
+            .map(|index| {
+                let name = names
+                    .get(index)
+                    .map(|name| name.trim())
+                    .filter(|name| !name.is_empty())
+                    .map_or_else(|| default_player_name(index), ToOwned::to_owned);
+
+                Player { name, score: 0 }
+            })
+            .collect();
+
+        Self {
+            players,
+            current: 0,
+        }
+    }
+
+    /// Every player, in turn order.
+    #[must_use]
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    /// The index of the player whose turn it is.
+    #[must_use]
+    pub const fn current(&self) -> usize {
+        self.current
+    }
+
+    /// The player whose turn it is.
+    #[must_use]
+    pub fn current_player(&self) -> &Player {
+        &self.players[self.current]
+    }
+
+    /// Ends the current turn, after the player flipped two cards.
+    ///
+    /// Returns whether the same player goes again, which they do if the cards were a `matched` pair.
+    pub fn finish_turn(&mut self, matched: bool) -> bool {
+        if matched {
+            self.players[self.current].score += 1;
+        } else {
+            self.current = (self.current + 1) % self.players.len();
+        }
+
+        matched
+    }
+
+    /// The indices of the players with the highest score, more than one if they're tied.
+    #[must_use]
+    pub fn leaders(&self) -> Vec<usize> {
+        let best = self.players.iter().map(|it| it.score).max().unwrap_or(0);
+
+        self.players
+            .iter()
+            .enumerate()
+            .filter(|(_, player)| player.score == best)
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|&it| it.to_owned()).collect()
+    }
+
+    #[test]
+    fn fills_in_names_and_player_count() {
+        let turns = Turns::new(&names(&["Dana", "  "]));
+        let players: Vec<_> = turns.players().iter().map(|it| it.name.as_str()).collect();
+        assert_eq!(players, ["Dana", "Player 2"]);
+
+        let turns = Turns::new(&[]);
+        assert_eq!(turns.players().len(), usize::from(MIN_PLAYERS));
+
+        let turns = Turns::new(&names(&["a", "b", "c", "d", "e"]));
+        assert_eq!(turns.players().len(), usize::from(MAX_PLAYERS));
+    }
+
+    #[test]
+    fn miss_passes_the_turn() {
+        let mut turns = Turns::new(&names(&["a", "b", "c"]));
+
+        assert!(!turns.finish_turn(false));
+        assert_eq!(turns.current(), 1);
+
+        assert!(!turns.finish_turn(false));
+        assert!(!turns.finish_turn(false));
+        assert_eq!(turns.current(), 0);
+
+        assert!(turns.players().iter().all(|it| it.score == 0));
+    }
+
+    #[test]
+    fn match_scores_and_goes_again() {
+        let mut turns = Turns::new(&names(&["a", "b"]));
+
+        assert!(turns.finish_turn(true));
+        assert!(turns.finish_turn(true));
+        assert_eq!(turns.current(), 0);
+        assert_eq!(turns.current_player().score, 2);
+
+        turns.finish_turn(false);
+        turns.finish_turn(true);
+        assert_eq!(turns.current(), 1);
+        assert_eq!(turns.players()[1].score, 1);
+    }
+
+    #[test]
+    fn leaders_include_ties() {
+        let mut turns = Turns::new(&names(&["a", "b", "c"]));
+        assert_eq!(turns.leaders(), [0, 1, 2]);
+
+        turns.finish_turn(true);
+        turns.finish_turn(false);
+        turns.finish_turn(true);
+        assert_eq!(turns.leaders(), [0, 1]);
+
+        turns.finish_turn(true);
+        assert_eq!(turns.leaders(), [1]);
+    }
+}
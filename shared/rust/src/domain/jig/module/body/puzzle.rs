@@ -0,0 +1,462 @@
+use crate::domain::jig::module::{
+    body::{
+        Body, BodyConvert, BodyExt, ModeExt, StepExt, ThemeId,
+        _groups::{
+            assessment,
+            design::{BaseContent, Trace},
+        },
+    },
+    ModuleKind,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+/// The body for [`Puzzle`](crate::domain::jig::module::ModuleKind::Puzzle) modules.
+#[derive(Default, Clone, Serialize, Deserialize, Debug)]
+pub struct ModuleData {
+    /// The content
+    pub content: Option<Content>,
+}
+
+impl BodyExt<Mode, Step> for ModuleData {
+    fn as_body(&self) -> Body {
+        Body::Puzzle(self.clone())
+    }
+
+    fn is_complete(&self) -> bool {
+        matches!(&self.content, Some(content) if !content.items.is_empty())
+    }
+
+    fn kind() -> ModuleKind {
+        ModuleKind::Puzzle
+    }
+
+    fn new_with_mode_and_theme(mode: Mode, theme: ThemeId) -> Self {
+        Self {
+            content: Some(Content {
+                mode,
+                base: BaseContent {
+                    theme,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn mode(&self) -> Option<Mode> {
+        self.content.as_ref().map(|c| c.mode)
+    }
+
+    fn requires_choose_mode(&self) -> bool {
+        self.content.is_none()
+    }
+
+    fn set_editor_state_step(&mut self, step: Step) {
+        if let Some(content) = self.content.as_mut() {
+            content.editor_state.step = step;
+        }
+    }
+    fn set_editor_state_steps_completed(&mut self, steps_completed: HashSet<Step>) {
+        if let Some(content) = self.content.as_mut() {
+            content.editor_state.steps_completed = steps_completed;
+        }
+    }
+
+    fn get_editor_state_step(&self) -> Option<Step> {
+        self.content
+            .as_ref()
+            .map(|content| content.editor_state.step)
+    }
+
+    fn get_editor_state_steps_completed(&self) -> Option<HashSet<Step>> {
+        self.content
+            .as_ref()
+            .map(|content| content.editor_state.steps_completed.clone())
+    }
+
+    fn set_theme(&mut self, theme_id: ThemeId) {
+        if let Some(content) = self.content.as_mut() {
+            content.base.theme = theme_id;
+        }
+    }
+
+    fn get_theme(&self) -> Option<ThemeId> {
+        self.content.as_ref().map(|content| content.base.theme)
+    }
+}
+
+impl BodyConvert for ModuleData {}
+
+impl TryFrom<Body> for ModuleData {
+    type Error = &'static str;
+
+    fn try_from(body: Body) -> Result<Self, Self::Error> {
+        match body {
+            Body::Puzzle(data) => Ok(data),
+            _ => Err("cannot convert body to puzzle!"),
+        }
+    }
+}
+
+/// The content for [`Puzzle`](crate::domain::jig::module::ModuleKind::Puzzle) modules.
+#[derive(Default, Clone, Serialize, Deserialize, Debug)]
+pub struct Content {
+    /// The base content for all design modules
+    ///
+    /// The pieces are cut out of the top-most image background.
+    pub base: BaseContent,
+
+    /// The editor state
+    pub editor_state: EditorState,
+
+    /// The mode
+    pub mode: Mode,
+
+    /// The pieces
+    pub items: Vec<PuzzleItem>,
+
+    /// play settings
+    pub play_settings: PlaySettings,
+}
+
+/// A puzzle piece
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct PuzzleItem {
+    /// The outline of the piece, where it belongs on the background
+    ///
+    /// Its audio is played when the student picks the piece up.
+    pub trace: Trace,
+}
+
+/// Editor state
+#[derive(Default, Clone, Serialize, Deserialize, Debug)]
+pub struct EditorState {
+    /// the current step
+    pub step: Step,
+
+    /// the completed steps
+    pub steps_completed: HashSet<Step>,
+}
+
+/// Play settings
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct PlaySettings {
+    /// Show the finished picture before the pieces scatter
+    pub show_preview: bool,
+
+    /// How close a piece has to be dropped to its place to snap in
+    pub snap_tolerance: SnapTolerance,
+
+    /// A piece dropped in the wrong place goes back to where it was picked up from
+    pub fly_back_to_origin: bool,
+
+    /// time limit, attempts and what happens when they run out
+    #[serde(default)]
+    pub assessment: assessment::PlaySettings,
+}
+
+impl Default for PlaySettings {
+    fn default() -> Self {
+        Self {
+            show_preview: true,
+            snap_tolerance: SnapTolerance::default(),
+            fly_back_to_origin: false,
+            assessment: assessment::PlaySettings::default(),
+        }
+    }
+}
+
+/// Snap tolerance
+#[derive(Clone, Copy, Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum SnapTolerance {
+    /// The piece has to be dropped almost exactly in place
+    Exact,
+
+    /// The piece has to be dropped close to its place
+    #[default]
+    Close,
+
+    /// The piece snaps in from further away
+    Loose,
+}
+
+impl SnapTolerance {
+    /// The furthest a piece can be dropped from its place and still snap in,
+    /// as a fraction of the stage
+    #[must_use]
+    pub const fn distance(self) -> f64 {
+        match self {
+            Self::Exact => 0.02,
+            Self::Close => 0.05,
+            Self::Loose => 0.1,
+        }
+    }
+}
+
+/// The mode
+#[derive(Clone, Copy, Default, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// Jigsaw mode, pieces cut from a picture
+    #[default]
+    Jigsaw,
+
+    /// Shapes mode, pieces are the shapes in a picture
+    Shapes,
+
+    /// Map mode, pieces are countries or regions
+    Map,
+}
+
+impl ModeExt for Mode {
+    fn get_list() -> Vec<Self> {
+        vec![Self::Jigsaw, Self::Shapes, Self::Map]
+    }
+
+    fn as_str_id(&self) -> &'static str {
+        match self {
+            Self::Jigsaw => "jigsaw",
+            Self::Shapes => "shapes",
+            Self::Map => "map",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        const STR_JIGSAW_LABEL: &str = "Jigsaw";
+        const STR_SHAPES_LABEL: &str = "Shape Puzzle";
+        const STR_MAP_LABEL: &str = "Map Puzzle";
+
+        match self {
+            Self::Jigsaw => STR_JIGSAW_LABEL,
+            Self::Shapes => STR_SHAPES_LABEL,
+            Self::Map => STR_MAP_LABEL,
+        }
+    }
+}
+
+/// The Steps
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Step {
+    /// Step 1
+    #[default]
+    One,
+    /// Step 2
+    Two,
+    /// Step 3
+    Three,
+    /// Step 4
+    Four,
+    /// Step 5
+    Five,
+}
+
+impl StepExt for Step {
+    fn next(&self) -> Option<Self> {
+        match self {
+            Self::One => Some(Self::Two),
+            Self::Two => Some(Self::Three),
+            Self::Three => Some(Self::Four),
+            Self::Four => Some(Self::Five),
+            Self::Five => None,
+        }
+    }
+
+    fn as_number(&self) -> usize {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+            Self::Three => 3,
+            Self::Four => 4,
+            Self::Five => 5,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        const STR_BACKGROUND: &str = "Design";
+        const STR_CONTENT: &str = "Content";
+        const STR_PIECES: &str = "Pieces";
+        const STR_SETTINGS: &str = "Settings";
+        const STR_PREVIEW: &str = "Preview";
+        match self {
+            Self::One => STR_BACKGROUND,
+            Self::Two => STR_CONTENT,
+            Self::Three => STR_PIECES,
+            Self::Four => STR_SETTINGS,
+            Self::Five => STR_PREVIEW,
+        }
+    }
+
+    fn get_list() -> Vec<Self> {
+        vec![Self::One, Self::Two, Self::Three, Self::Four, Self::Five]
+    }
+    fn get_preview() -> Self {
+        Self::Five
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Content, Mode, ModuleData, PlaySettings, PuzzleItem, SnapTolerance, Step};
+    use crate::domain::jig::module::{
+        body::{
+            _groups::{
+                assessment::{self, RetryPolicy},
+                design::{Trace, TraceKind, TraceShape},
+            },
+            Body, BodyConvert, BodyExt, ThemeId, Transform,
+        },
+        ModuleKind,
+    };
+    use std::convert::TryFrom;
+
+    fn piece(text: &str) -> PuzzleItem {
+        PuzzleItem {
+            trace: Trace {
+                transform: Transform::identity(),
+                shape: TraceShape::Rect(0.2, 0.1),
+                kind: TraceKind::Regular,
+                audio: None,
+                text: Some(text.to_owned()),
+            },
+        }
+    }
+
+    fn puzzle() -> ModuleData {
+        let mut data = ModuleData::new_with_mode_and_theme(Mode::Shapes, ThemeId::Chalkboard);
+
+        if let Some(content) = data.content.as_mut() {
+            content.items = vec![piece("circle"), piece("square")];
+            content.play_settings = PlaySettings {
+                show_preview: false,
+                snap_tolerance: SnapTolerance::Exact,
+                fly_back_to_origin: true,
+                assessment: assessment::PlaySettings {
+                    time_limit: Some(2),
+                    attempts: Some(3),
+                    show_answer: true,
+                    retry: RetryPolicy::Later,
+                },
+            };
+        }
+
+        data.set_editor_state_step(Step::Three);
+        data.insert_editor_state_step_completed(Step::One);
+        data.insert_editor_state_step_completed(Step::Two);
+
+        data
+    }
+
+    #[test]
+    fn round_trips_through_a_body() {
+        let data = puzzle();
+
+        // the completed steps are a set, so they're compared separately below
+        let to_json = |data: &ModuleData| {
+            let mut json = serde_json::to_value(data.as_body()).unwrap();
+            json["puzzle"]["content"]["editor_state"]["steps_completed"].take();
+            json
+        };
+
+        let json = serde_json::to_value(data.as_body()).unwrap();
+        assert!(json["puzzle"]["content"]["editor_state"]["steps_completed"].is_array());
+
+        let body: Body = serde_json::from_value(json).unwrap();
+        assert_eq!(body.kind(), ModuleKind::Puzzle);
+
+        let round_tripped = ModuleData::try_from(body).unwrap();
+        assert_eq!(to_json(&round_tripped), to_json(&data));
+        assert_eq!(
+            round_tripped.get_editor_state_steps_completed(),
+            data.get_editor_state_steps_completed()
+        );
+
+        let content = round_tripped.content.unwrap();
+        assert_eq!(content.mode, Mode::Shapes);
+        assert_eq!(content.base.theme, ThemeId::Chalkboard);
+        assert_eq!(content.items.len(), 2);
+        assert_eq!(content.items[1].trace.text.as_deref(), Some("square"));
+        assert_eq!(content.play_settings.snap_tolerance, SnapTolerance::Exact);
+        assert!(!content.play_settings.show_preview);
+        assert!(content.play_settings.fly_back_to_origin);
+        assert_eq!(content.play_settings.assessment.attempts, Some(3));
+        assert_eq!(content.play_settings.assessment.retry, RetryPolicy::Later);
+        assert_eq!(content.editor_state.step, Step::Three);
+    }
+
+    #[test]
+    fn other_bodies_are_not_puzzles() {
+        assert!(ModuleData::try_from(Body::new(ModuleKind::DragDrop)).is_err());
+        assert!(ModuleData::try_from(Body::new(ModuleKind::Puzzle)).is_ok());
+    }
+
+    #[test]
+    fn complete_once_it_has_pieces() {
+        assert!(!ModuleData::default().is_complete());
+
+        let mut data = ModuleData::new_with_mode_and_theme(Mode::Jigsaw, ThemeId::Blank);
+        assert!(!data.requires_choose_mode());
+        assert!(!data.is_complete());
+
+        data.content = Some(Content {
+            items: vec![piece("corner")],
+            ..Content::default()
+        });
+        assert!(data.is_complete());
+    }
+
+    #[test]
+    fn cannot_be_converted() {
+        assert!(ModuleData::convertable_list().is_empty());
+
+        let data = puzzle();
+
+        for kind in [
+            ModuleKind::Memory,
+            ModuleKind::Matching,
+            ModuleKind::Flashcards,
+            ModuleKind::CardQuiz,
+            ModuleKind::WordGrid,
+            ModuleKind::Poster,
+            ModuleKind::Video,
+            ModuleKind::TappingBoard,
+            ModuleKind::DragDrop,
+            ModuleKind::Puzzle,
+            ModuleKind::TalkType,
+            ModuleKind::Cover,
+            ModuleKind::ResourceCover,
+            ModuleKind::Legacy,
+        ] {
+            assert!(data.convert_to_body(kind).is_err(), "{}", kind.as_str());
+            assert!(data.as_body().convert_to_body(kind).is_err());
+        }
+    }
+
+    #[test]
+    fn snap_tolerances_get_looser() {
+        let distances: Vec<f64> = [
+            SnapTolerance::Exact,
+            SnapTolerance::Close,
+            SnapTolerance::Loose,
+        ]
+        .iter()
+        .map(|tolerance| tolerance.distance())
+        .collect();
+
+        assert!(distances.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(PlaySettings::default().snap_tolerance, SnapTolerance::Close);
+    }
+
+    #[test]
+    fn settings_without_assessment_are_unlimited() {
+        let settings: PlaySettings = serde_json::from_value(serde_json::json!({
+            "show_preview": true,
+            "snap_tolerance": "Loose",
+            "fly_back_to_origin": false,
+        }))
+        .unwrap();
+
+        assert_eq!(settings.assessment, assessment::PlaySettings::default());
+        assert_eq!(settings.assessment.attempts, None);
+    }
+}
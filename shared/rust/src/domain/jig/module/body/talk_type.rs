@@ -0,0 +1,309 @@
+use crate::domain::jig::module::{
+    body::{
+        card_quiz::answer::is_correct_answer,
+        Audio, Body, BodyConvert, BodyExt, ModeExt, StepExt, ThemeId,
+        _groups::{
+            assessment,
+            design::{BaseContent, Trace},
+        },
+    },
+    ModuleKind,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+/// The body for [`TalkType`](crate::domain::jig::module::ModuleKind::TalkType) modules.
+#[derive(Default, Clone, Serialize, Deserialize, Debug)]
+pub struct ModuleData {
+    /// The content
+    pub content: Option<Content>,
+}
+
+impl BodyExt<Mode, Step> for ModuleData {
+    fn as_body(&self) -> Body {
+        Body::TalkType(self.clone())
+    }
+
+    fn is_complete(&self) -> bool {
+        matches!(&self.content, Some(content) if !content.items.is_empty())
+    }
+
+    fn kind() -> ModuleKind {
+        ModuleKind::TalkType
+    }
+
+    fn new_with_mode_and_theme(mode: Mode, theme: ThemeId) -> Self {
+        Self {
+            content: Some(Content {
+                mode,
+                base: BaseContent {
+                    theme,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn mode(&self) -> Option<Mode> {
+        self.content.as_ref().map(|c| c.mode)
+    }
+
+    fn requires_choose_mode(&self) -> bool {
+        self.content.is_none()
+    }
+
+    fn set_editor_state_step(&mut self, step: Step) {
+        if let Some(content) = self.content.as_mut() {
+            content.editor_state.step = step;
+        }
+    }
+    fn set_editor_state_steps_completed(&mut self, steps_completed: HashSet<Step>) {
+        if let Some(content) = self.content.as_mut() {
+            content.editor_state.steps_completed = steps_completed;
+        }
+    }
+
+    fn get_editor_state_step(&self) -> Option<Step> {
+        self.content
+            .as_ref()
+            .map(|content| content.editor_state.step)
+    }
+
+    fn get_editor_state_steps_completed(&self) -> Option<HashSet<Step>> {
+        self.content
+            .as_ref()
+            .map(|content| content.editor_state.steps_completed.clone())
+    }
+
+    fn set_theme(&mut self, theme_id: ThemeId) {
+        if let Some(content) = self.content.as_mut() {
+            content.base.theme = theme_id;
+        }
+    }
+
+    fn get_theme(&self) -> Option<ThemeId> {
+        self.content.as_ref().map(|content| content.base.theme)
+    }
+}
+
+impl BodyConvert for ModuleData {}
+
+impl TryFrom<Body> for ModuleData {
+    type Error = &'static str;
+
+    fn try_from(body: Body) -> Result<Self, Self::Error> {
+        match body {
+            Body::TalkType(data) => Ok(data),
+            _ => Err("cannot convert body to talk type!"),
+        }
+    }
+}
+
+/// The content for [`TalkType`](crate::domain::jig::module::ModuleKind::TalkType) modules.
+#[derive(Default, Clone, Serialize, Deserialize, Debug)]
+pub struct Content {
+    /// The base content for all design modules
+    pub base: BaseContent,
+
+    /// The editor state
+    pub editor_state: EditorState,
+
+    /// The mode
+    pub mode: Mode,
+
+    /// The answer slots
+    pub items: Vec<TalkTypeItem>,
+
+    /// play settings
+    pub play_settings: PlaySettings,
+}
+
+/// An answer slot
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct TalkTypeItem {
+    /// The area on the image where the student answers
+    ///
+    /// Its audio is the question, played when the student gets to the slot.
+    pub trace: Trace,
+
+    /// How the student answers
+    pub answer_kind: AnswerKind,
+
+    /// The accepted answers, any one of them is correct
+    ///
+    /// For spoken answers, the first one is shown once the student has answered.
+    pub answers: Vec<String>,
+
+    /// Played when the student answers correctly
+    pub correct_audio: Option<Audio>,
+}
+
+impl TalkTypeItem {
+    /// A new slot for `trace`, with no answers yet
+    #[must_use]
+    pub const fn new(trace: Trace) -> Self {
+        Self {
+            trace,
+            answer_kind: AnswerKind::Text,
+            answers: Vec::new(),
+            correct_audio: None,
+        }
+    }
+
+    /// Whether the typed `answer` matches any of the accepted answers.
+    ///
+    /// See [`is_correct_answer`] for how answers are compared.
+    #[must_use]
+    pub fn is_correct(&self, answer: &str, max_typos: u8) -> bool {
+        self.answers
+            .iter()
+            .any(|expected| is_correct_answer(answer, expected, max_typos))
+    }
+}
+
+/// How the student answers a slot
+#[derive(Clone, Copy, Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum AnswerKind {
+    /// The student types the answer
+    #[default]
+    Text,
+
+    /// The student says the answer out loud, then reveals it to check themselves
+    Spoken,
+}
+
+/// Editor state
+#[derive(Default, Clone, Serialize, Deserialize, Debug)]
+pub struct EditorState {
+    /// the current step
+    pub step: Step,
+
+    /// the completed steps
+    pub steps_completed: HashSet<Step>,
+}
+
+/// Play settings
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct PlaySettings {
+    /// Reveal a letter of the answer after each wrong answer
+    pub show_hints: bool,
+
+    /// how many typos a typed answer can have and still be correct
+    pub max_typos: u8,
+
+    /// time limit, attempts and what happens when they run out
+    #[serde(default)]
+    pub assessment: assessment::PlaySettings,
+}
+
+impl Default for PlaySettings {
+    fn default() -> Self {
+        Self {
+            show_hints: true,
+            max_typos: 0,
+            assessment: assessment::PlaySettings::default(),
+        }
+    }
+}
+
+/// The mode
+#[derive(Clone, Copy, Default, Serialize, Deserialize, Debug, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// Label the parts of a picture
+    #[default]
+    Labels,
+
+    /// Type what is heard
+    Listen,
+
+    /// Answer questions about a picture
+    Questions,
+}
+
+impl ModeExt for Mode {
+    fn get_list() -> Vec<Self> {
+        vec![Self::Labels, Self::Listen, Self::Questions]
+    }
+
+    fn as_str_id(&self) -> &'static str {
+        match self {
+            Self::Labels => "labels",
+            Self::Listen => "listen",
+            Self::Questions => "questions",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        const STR_LABELS_LABEL: &str = "Label the Picture";
+        const STR_LISTEN_LABEL: &str = "Listen & Type";
+        const STR_QUESTIONS_LABEL: &str = "Questions";
+
+        match self {
+            Self::Labels => STR_LABELS_LABEL,
+            Self::Listen => STR_LISTEN_LABEL,
+            Self::Questions => STR_QUESTIONS_LABEL,
+        }
+    }
+}
+
+/// The Steps
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Step {
+    /// Step 1
+    #[default]
+    One,
+    /// Step 2
+    Two,
+    /// Step 3
+    Three,
+    /// Step 4
+    Four,
+    /// Step 5
+    Five,
+}
+
+impl StepExt for Step {
+    fn next(&self) -> Option<Self> {
+        match self {
+            Self::One => Some(Self::Two),
+            Self::Two => Some(Self::Three),
+            Self::Three => Some(Self::Four),
+            Self::Four => Some(Self::Five),
+            Self::Five => None,
+        }
+    }
+
+    fn as_number(&self) -> usize {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+            Self::Three => 3,
+            Self::Four => 4,
+            Self::Five => 5,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        const STR_BACKGROUND: &str = "Design";
+        const STR_CONTENT: &str = "Content";
+        const STR_ANSWERS: &str = "Answers";
+        const STR_SETTINGS: &str = "Settings";
+        const STR_PREVIEW: &str = "Preview";
+        match self {
+            Self::One => STR_BACKGROUND,
+            Self::Two => STR_CONTENT,
+            Self::Three => STR_ANSWERS,
+            Self::Four => STR_SETTINGS,
+            Self::Five => STR_PREVIEW,
+        }
+    }
+
+    fn get_list() -> Vec<Self> {
+        vec![Self::One, Self::Two, Self::Three, Self::Four, Self::Five]
+    }
+    fn get_preview() -> Self {
+        Self::Five
+    }
+}
@@ -1,7 +1,7 @@
 use crate::domain::jig::module::{
     body::{
-        Body, BodyConvert, BodyExt, ModeExt, StepExt, ThemeId,
         _groups::design::{BaseContent, Trace},
+        Body, BodyConvert, BodyExt, ModeExt, StepExt, ThemeId,
     },
     ModuleKind,
 };
@@ -12,6 +12,15 @@ use std::convert::TryFrom;
 mod play_settings;
 pub use play_settings::*;
 
+mod layout;
+pub use layout::CardBounds;
+
+pub mod family_tree;
+pub mod timeline;
+
+use family_tree::FamilyTree;
+use timeline::{Timeline, TimelineEvent};
+
 /// The body for [`TappingBoard`](crate::domain::jig::module::ModuleKind::TappingBoard) modules.
 #[derive(Default, Clone, Serialize, Deserialize, Debug)]
 pub struct ModuleData {
@@ -117,6 +126,46 @@ pub struct Content {
 
     /// play settings
     pub play_settings: PlaySettings,
+
+    /// The events, for [`Mode::Timeline`]
+    #[serde(default)]
+    pub timeline: Option<Timeline>,
+
+    /// The people, for [`Mode::FamilyTree`]
+    #[serde(default)]
+    pub family_tree: Option<FamilyTree>,
+}
+
+impl Content {
+    /// Whether the traces are laid out from structured content rather than drawn by hand
+    #[must_use]
+    pub const fn is_structured(&self) -> bool {
+        matches!(self.mode, Mode::Timeline | Mode::FamilyTree)
+    }
+
+    /// Lays out the structured content for the mode into [`traces`](Self::traces), timeline
+    /// events are labelled with `event_label`.
+    ///
+    /// Does nothing for modes where the teacher draws the traces.
+    pub fn layout_traces(&mut self, event_label: impl Fn(&TimelineEvent) -> String) {
+        match self.mode {
+            Mode::Timeline => {
+                self.traces = self
+                    .timeline
+                    .as_ref()
+                    .map(|timeline| timeline.layout(event_label))
+                    .unwrap_or_default();
+            }
+            Mode::FamilyTree => {
+                self.traces = self
+                    .family_tree
+                    .as_ref()
+                    .map(FamilyTree::layout)
+                    .unwrap_or_default();
+            }
+            _ => {}
+        }
+    }
 }
 
 /// Editor state
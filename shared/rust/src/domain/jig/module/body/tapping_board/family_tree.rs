@@ -0,0 +1,399 @@
+//! Structured content for [`Mode::FamilyTree`](super::Mode::FamilyTree).
+//!
+//! People are laid out a generation to a row, oldest at the top. Within a row, children sit
+//! under their parents and spouses sit next to each other.
+
+use super::layout::{self, to_f64, CardBounds};
+use crate::domain::jig::module::body::{Audio, Image, _groups::design::Trace};
+use serde::{Deserialize, Serialize};
+
+/// A family tree
+#[derive(Clone, Default, Serialize, Deserialize, Debug, PartialEq)]
+pub struct FamilyTree {
+    /// The people in the tree
+    pub people: Vec<Person>,
+
+    /// How they're related, by index into [`people`](Self::people)
+    pub relationships: Vec<Relationship>,
+}
+
+/// A person in a family tree
+#[derive(Clone, Default, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Person {
+    /// Their name
+    pub name: String,
+
+    /// Optional picture of them
+    pub image: Option<Image>,
+
+    /// Optional audio, played when they're tapped
+    pub audio: Option<Audio>,
+}
+
+/// A relationship between two people
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub struct Relationship {
+    /// The kind of relationship
+    pub kind: RelationshipKind,
+
+    /// Index of the first person
+    pub from: usize,
+
+    /// Index of the second person
+    pub to: usize,
+}
+
+/// Relationship kind
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq, Eq)]
+pub enum RelationshipKind {
+    /// `from` is a parent of `to`
+    Parent,
+
+    /// `from` and `to` are married
+    Spouse,
+}
+
+impl FamilyTree {
+    /// The parents of `person`
+    #[must_use]
+    pub fn parents_of(&self, person: usize) -> Vec<usize> {
+        self.relationships
+            .iter()
+            .filter(|rel| rel.kind == RelationshipKind::Parent && rel.to == person)
+            .map(|rel| rel.from)
+            .collect()
+    }
+
+    /// The spouse of `person`, if any
+    #[must_use]
+    pub fn spouse_of(&self, person: usize) -> Option<usize> {
+        self.relationships
+            .iter()
+            .filter(|rel| rel.kind == RelationshipKind::Spouse)
+            .find_map(|rel| {
+                if rel.from == person {
+                    Some(rel.to)
+                } else if rel.to == person {
+                    Some(rel.from)
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// Replaces the parents of `person`
+    pub fn set_parents(&mut self, person: usize, parents: &[usize]) {
+        self.relationships
+            .retain(|rel| !(rel.kind == RelationshipKind::Parent && rel.to == person));
+
+        for parent in parents {
+            if *parent != person && *parent < self.people.len() {
+                self.relationships.push(Relationship {
+                    kind: RelationshipKind::Parent,
+                    from: *parent,
+                    to: person,
+                });
+            }
+        }
+    }
+
+    /// Replaces the spouse of `person`, `None` to unset it
+    pub fn set_spouse(&mut self, person: usize, spouse: Option<usize>) {
+        self.relationships.retain(|rel| {
+            !(rel.kind == RelationshipKind::Spouse
+                && (rel.from == person
+                    || rel.to == person
+                    || Some(rel.from) == spouse
+                    || Some(rel.to) == spouse))
+        });
+
+        if let Some(spouse) = spouse {
+            if spouse != person && spouse < self.people.len() {
+                self.relationships.push(Relationship {
+                    kind: RelationshipKind::Spouse,
+                    from: person,
+                    to: spouse,
+                });
+            }
+        }
+    }
+
+    /// Removes a person along with their relationships
+    pub fn remove_person(&mut self, person: usize) {
+        if person >= self.people.len() {
+            return;
+        }
+
+        self.people.remove(person);
+
+        self.relationships
+            .retain(|rel| rel.from != person && rel.to != person);
+
+        for rel in &mut self.relationships {
+            if rel.from > person {
+                rel.from -= 1;
+            }
+            if rel.to > person {
+                rel.to -= 1;
+            }
+        }
+    }
+
+    /// The generation of each person, `0` being the oldest.
+    ///
+    /// Children are always at least a generation below their parents and spouses share a
+    /// generation.
+    #[must_use]
+    pub fn generations(&self) -> Vec<usize> {
+        let n_people = self.people.len();
+        let mut generations = vec![0; n_people];
+
+        let relationships: Vec<&Relationship> = self
+            .relationships
+            .iter()
+            .filter(|rel| rel.from < n_people && rel.to < n_people && rel.from != rel.to)
+            .collect();
+
+        // Each pass settles at least one more person, unless someone is their own ancestor.
+        // Capping the generation keeps that from going on forever.
+        for _ in 0..=n_people {
+            let mut changed = false;
+
+            for rel in &relationships {
+                let (lower, min_generation) = match rel.kind {
+                    RelationshipKind::Parent => (rel.to, generations[rel.from] + 1),
+                    RelationshipKind::Spouse => {
+                        if generations[rel.from] < generations[rel.to] {
+                            (rel.from, generations[rel.to])
+                        } else {
+                            (rel.to, generations[rel.from])
+                        }
+                    }
+                };
+
+                let min_generation = min_generation.min(n_people);
+                if generations[lower] < min_generation {
+                    generations[lower] = min_generation;
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        generations
+    }
+
+    /// Where each person's card goes, indexed like [`people`](Self::people).
+    #[must_use]
+    pub fn layout_bounds(&self) -> Vec<CardBounds> {
+        let generations = self.generations();
+        let n_generations = generations.iter().max().map_or(0, |max| max + 1);
+
+        // horizontal position of each person once their row is placed, to order the next row
+        let mut positions: Vec<Option<f64>> = vec![None; self.people.len()];
+        let mut rows: Vec<Vec<usize>> = Vec::with_capacity(n_generations);
+
+        for generation in 0..n_generations {
+            let mut members: Vec<(f64, usize)> = generations
+                .iter()
+                .enumerate()
+                .filter(|(_, g)| **g == generation)
+                .map(|(person, _)| (self.order_key(person, &positions), person))
+                .collect();
+
+            members.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+            let mut row: Vec<usize> = Vec::with_capacity(members.len());
+            for (_, person) in members {
+                if row.contains(&person) {
+                    continue;
+                }
+                row.push(person);
+
+                if let Some(spouse) = self.spouse_of(person) {
+                    if generations.get(spouse) == Some(&generation) && !row.contains(&spouse) {
+                        row.push(spouse);
+                    }
+                }
+            }
+
+            let len = to_f64(row.len().max(1));
+            for (col, person) in row.iter().enumerate() {
+                positions[*person] = Some(to_f64(col) / len);
+            }
+
+            rows.push(row);
+        }
+
+        let counts: Vec<usize> = rows.iter().map(Vec::len).collect();
+
+        let mut bounds = vec![
+            CardBounds {
+                x: 0.0,
+                y: 0.0,
+                width: 0.0,
+                height: 0.0,
+            };
+            self.people.len()
+        ];
+
+        for (row, row_bounds) in rows.iter().zip(layout::rows(&counts)) {
+            for (person, card) in row.iter().zip(row_bounds) {
+                bounds[*person] = card;
+            }
+        }
+
+        bounds
+    }
+
+    /// A trace for each person, labelled with their name.
+    #[must_use]
+    pub fn layout(&self) -> Vec<Trace> {
+        self.people
+            .iter()
+            .zip(self.layout_bounds())
+            .map(|(person, bounds)| {
+                bounds.to_trace(Some(person.name.clone()), person.audio.clone())
+            })
+            .collect()
+    }
+
+    /// Sorts a row by where the person's parents are, or their spouse's parents if they
+    /// married in. People without either keep the order they were added in, after the rest.
+    fn order_key(&self, person: usize, positions: &[Option<f64>]) -> f64 {
+        let parents_position = |person: usize| {
+            let placed: Vec<f64> = self
+                .parents_of(person)
+                .into_iter()
+                .filter_map(|parent| positions.get(parent).copied().flatten())
+                .collect();
+
+            if placed.is_empty() {
+                None
+            } else {
+                Some(placed.iter().sum::<f64>() / to_f64(placed.len()))
+            }
+        };
+
+        parents_position(person)
+            .or_else(|| self.spouse_of(person).and_then(parents_position))
+            .unwrap_or(1.0 + to_f64(person))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::layout::assert_laid_out, FamilyTree, Person};
+
+    fn tree(names: &[&str]) -> FamilyTree {
+        FamilyTree {
+            people: names
+                .iter()
+                .map(|name| Person {
+                    name: (*name).to_owned(),
+                    ..Person::default()
+                })
+                .collect(),
+            relationships: Vec::new(),
+        }
+    }
+
+    /// Abraham and Sarah, their son Isaac who married Rebecca, and their twins Esau and
+    /// Jacob. Added youngest first, so the layout can't lean on the order.
+    fn patriarchs() -> FamilyTree {
+        let mut tree = tree(&["Jacob", "Esau", "Rebecca", "Isaac", "Sarah", "Abraham"]);
+
+        tree.set_spouse(5, Some(4));
+        tree.set_parents(3, &[5, 4]);
+        tree.set_spouse(3, Some(2));
+        tree.set_parents(1, &[3, 2]);
+        tree.set_parents(0, &[3, 2]);
+
+        tree
+    }
+
+    #[test]
+    fn generations_go_down_from_parents() {
+        assert_eq!(patriarchs().generations(), vec![2, 2, 1, 1, 0, 0]);
+    }
+
+    #[test]
+    fn cards_fit_without_overlapping() {
+        let tree = patriarchs();
+        let bounds = tree.layout_bounds();
+
+        assert_eq!(bounds.len(), 6);
+        assert_laid_out(&bounds);
+
+        // a row per generation, oldest at the top
+        assert!(bounds[4].y < bounds[3].y && bounds[3].y < bounds[0].y);
+
+        // spouses sit next to each other
+        let (isaac, rebecca) = (bounds[3], bounds[2]);
+        let between = isaac.x.max(rebecca.x) - isaac.x.min(rebecca.x) - isaac.width;
+        assert!(between < isaac.width);
+
+        assert_eq!(tree.layout().len(), 6);
+    }
+
+    #[test]
+    fn children_sit_under_their_parents() {
+        let mut tree = tree(&["a", "b", "a's child", "b's child"]);
+        tree.set_parents(3, &[1]);
+        tree.set_parents(2, &[0]);
+
+        let bounds = tree.layout_bounds();
+
+        assert_laid_out(&bounds);
+        assert!(bounds[0].x < bounds[1].x);
+        assert!(bounds[2].x < bounds[3].x);
+    }
+
+    #[test]
+    fn cycles_end() {
+        let mut tree = tree(&["a", "b"]);
+        tree.set_parents(0, &[1]);
+        tree.set_parents(1, &[0]);
+
+        let generations = tree.generations();
+        assert!(generations.iter().all(|generation| *generation <= 2));
+
+        assert_laid_out(&tree.layout_bounds());
+    }
+
+    #[test]
+    fn removing_a_person_keeps_the_other_relationships() {
+        let mut tree = patriarchs();
+
+        // Sarah
+        tree.remove_person(4);
+
+        assert_eq!(tree.people.len(), 5);
+        assert_eq!(tree.spouse_of(4), None);
+        assert_eq!(tree.parents_of(3), vec![4]);
+        assert_eq!(tree.spouse_of(3), Some(2));
+        assert_eq!(tree.parents_of(0), vec![3, 2]);
+
+        // out of range does nothing
+        tree.remove_person(10);
+        assert_eq!(tree.people.len(), 5);
+    }
+
+    #[test]
+    fn a_new_spouse_replaces_the_old_one() {
+        let mut tree = tree(&["a", "b", "c"]);
+
+        tree.set_spouse(0, Some(1));
+        tree.set_spouse(2, Some(1));
+
+        assert_eq!(tree.spouse_of(0), None);
+        assert_eq!(tree.spouse_of(1), Some(2));
+
+        // nobody marries themselves
+        tree.set_spouse(0, Some(0));
+        assert_eq!(tree.spouse_of(0), None);
+    }
+}
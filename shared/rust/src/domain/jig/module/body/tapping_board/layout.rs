@@ -0,0 +1,199 @@
+use crate::domain::jig::module::body::{
+    Audio, Transform, Vec3,
+    _groups::design::{Trace, TraceKind, TraceShape},
+};
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+/// space around the cards, as a fraction of the stage
+const MARGIN: f64 = 0.05;
+
+/// space between neighbouring cards, as a fraction of the stage
+const GAP: f64 = 0.02;
+
+const MAX_CARD_WIDTH: f64 = 0.2;
+const MAX_CARD_HEIGHT: f64 = 0.3;
+
+/// Where a laid out card sits on the stage, in normalized coordinates
+/// (`0.0..=1.0` of the stage width and height, from the top left).
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
+pub struct CardBounds {
+    /// left edge
+    pub x: f64,
+    /// top edge
+    pub y: f64,
+    /// width
+    pub width: f64,
+    /// height
+    pub height: f64,
+}
+
+impl CardBounds {
+    /// Horizontal center
+    #[must_use]
+    pub fn center_x(&self) -> f64 {
+        self.x + self.width / 2.0
+    }
+
+    /// Bottom edge
+    #[must_use]
+    pub fn bottom(&self) -> f64 {
+        self.y + self.height
+    }
+
+    /// A rectangular trace covering the card
+    #[must_use]
+    pub fn to_trace(self, text: Option<String>, audio: Option<Audio>) -> Trace {
+        Trace {
+            transform: Transform {
+                translation: Vec3([self.x, self.y, 0.0]),
+                ..Transform::identity()
+            },
+            shape: TraceShape::Rect(self.width, self.height),
+            kind: TraceKind::Regular,
+            audio,
+            text,
+        }
+    }
+}
+
+/// Lays out `rows` (each a number of cards) top to bottom, each row centered.
+pub(super) fn rows(rows: &[usize]) -> Vec<Vec<CardBounds>> {
+    let n_rows = rows.len();
+    if n_rows == 0 {
+        return Vec::new();
+    }
+
+    // every card is the same size, so the widest row decides it
+    let width = fit(rows.iter().copied().max().unwrap_or(1), MAX_CARD_WIDTH);
+    let height = fit(n_rows, MAX_CARD_HEIGHT);
+    let total_height = span(n_rows, height);
+    let top = (1.0 - total_height) / 2.0;
+
+    rows.iter()
+        .enumerate()
+        .map(|(row, &n_cards)| {
+            let left = (1.0 - span(n_cards, width)) / 2.0;
+            let y = top + to_f64(row) * (height + GAP);
+
+            (0..n_cards)
+                .map(|col| CardBounds {
+                    x: left + to_f64(col) * (width + GAP),
+                    y,
+                    width,
+                    height,
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Lays out `n_cards` in reading order, at most `per_row` to a row.
+pub(super) fn grid(n_cards: usize, per_row: usize) -> Vec<CardBounds> {
+    let per_row = per_row.max(1);
+    let n_rows = n_cards.div_ceil(per_row);
+
+    let counts: Vec<usize> = (0..n_rows)
+        .map(|row| (n_cards - row * per_row).min(per_row))
+        .collect();
+
+    rows(&counts).into_iter().flatten().collect()
+}
+
+/// The largest card size that fits `count` cards into the stage
+fn fit(count: usize, max: f64) -> f64 {
+    let count = to_f64(count.max(1));
+    let available = 1.0 - 2.0 * MARGIN - (count - 1.0) * GAP;
+
+    (available / count).min(max)
+}
+
+/// The space `count` cards of `size` take up, gaps included
+fn span(count: usize, size: f64) -> f64 {
+    let count = to_f64(count);
+
+    count * size + (count - 1.0).max(0.0) * GAP
+}
+
+/// Card counts are tiny, so they always fit
+pub(super) fn to_f64(n: usize) -> f64 {
+    f64::from(u32::try_from(n).unwrap_or(u32::MAX))
+}
+
+/// Checks every card is on the stage and no two cards overlap.
+#[cfg(test)]
+pub(super) fn assert_laid_out(bounds: &[CardBounds]) {
+    for (index, card) in bounds.iter().enumerate() {
+        let on_stage = card.width > 0.0
+            && card.height > 0.0
+            && card.x >= 0.0
+            && card.x + card.width <= 1.0
+            && card.y >= 0.0
+            && card.bottom() <= 1.0;
+
+        assert!(on_stage, "card {} is off the stage: {card:?}", index);
+    }
+
+    for (index, a) in bounds.iter().enumerate() {
+        for (other, b) in bounds.iter().enumerate().skip(index + 1) {
+            let apart = a.x + a.width <= b.x
+                || b.x + b.width <= a.x
+                || a.bottom() <= b.y
+                || b.bottom() <= a.y;
+
+            assert!(apart, "cards {} and {} overlap: {a:?} {b:?}", index, other);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assert_laid_out, grid, rows};
+
+    #[test]
+    fn grids_fit_the_stage() {
+        for per_row in 1..=6 {
+            for n_cards in 0..=30 {
+                let bounds = grid(n_cards, per_row);
+
+                assert_eq!(bounds.len(), n_cards);
+                assert_laid_out(&bounds);
+            }
+        }
+    }
+
+    #[test]
+    fn grids_read_left_to_right_then_down() {
+        let bounds = grid(7, 3);
+
+        for pair in bounds.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+
+            let same_row = (a.y - b.y).abs() < 1e-9;
+
+            assert!(if same_row { a.x < b.x } else { a.y < b.y });
+        }
+
+        // the last row has a single card, centered
+        assert!((bounds[6].center_x() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rows_are_centered() {
+        let laid_out = rows(&[1, 4, 2]);
+
+        assert_eq!(
+            laid_out.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![1, 4, 2]
+        );
+
+        for row in &laid_out {
+            let left = row[0].x;
+            let right = 1.0 - (row[row.len() - 1].x + row[row.len() - 1].width);
+            assert!((left - right).abs() < 1e-9);
+        }
+
+        assert_laid_out(&laid_out.concat());
+        assert!(rows(&[]).is_empty());
+    }
+}
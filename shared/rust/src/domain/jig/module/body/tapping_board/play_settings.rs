@@ -8,6 +8,10 @@ pub struct PlaySettings {
 
     /// next style
     pub next: Next,
+
+    /// Timeline only, the events are shuffled and the student taps them from earliest to latest
+    #[serde(default)]
+    pub put_in_order: bool,
 }
 
 /// Hint
@@ -0,0 +1,173 @@
+//! Structured content for [`Mode::Timeline`](super::Mode::Timeline).
+//!
+//! Events are laid out left to right in chronological order, wrapping onto more rows once a row
+//! is full. The student taps each event to hear or read about it, or, with
+//! [`PlaySettings::put_in_order`](super::PlaySettings::put_in_order), taps the shuffled events
+//! from earliest to latest.
+
+use super::layout::{self, CardBounds};
+use crate::domain::jig::module::body::{Audio, Image, _groups::design::Trace};
+use serde::{Deserialize, Serialize};
+
+/// The most events laid out on a single row.
+pub const EVENTS_PER_ROW: usize = 5;
+
+/// A timeline
+#[derive(Clone, Default, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Timeline {
+    /// The events, in the order the teacher added them
+    pub events: Vec<TimelineEvent>,
+}
+
+impl Timeline {
+    /// Indices into [`events`](Self::events) from earliest to latest.
+    ///
+    /// Events on the same date keep the order they were added in.
+    #[must_use]
+    pub fn chronological(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.events.len()).collect();
+        order.sort_by_key(|index| &self.events[*index].date);
+        order
+    }
+
+    /// Where each event's card goes, indexed like [`events`](Self::events).
+    ///
+    /// `order` lists the events in the order their cards are laid out, see
+    /// [`chronological`](Self::chronological).
+    #[must_use]
+    pub fn layout_bounds(&self, order: &[usize]) -> Vec<CardBounds> {
+        let slots = layout::grid(order.len(), EVENTS_PER_ROW);
+
+        let mut bounds = slots.clone();
+        for (slot, index) in slots.into_iter().zip(order) {
+            bounds[*index] = slot;
+        }
+
+        bounds
+    }
+
+    /// A trace for each event in chronological order, with the text from `label`.
+    ///
+    /// Dates are shown in the user's language, so the label is up to the caller.
+    #[must_use]
+    pub fn layout(&self, label: impl Fn(&TimelineEvent) -> String) -> Vec<Trace> {
+        self.layout_in(&self.chronological(), label)
+    }
+
+    /// A trace for each event, with the cards laid out in `order`, see
+    /// [`layout_bounds`](Self::layout_bounds).
+    #[must_use]
+    pub fn layout_in(
+        &self,
+        order: &[usize],
+        label: impl Fn(&TimelineEvent) -> String,
+    ) -> Vec<Trace> {
+        self.events
+            .iter()
+            .zip(self.layout_bounds(order))
+            .map(|(event, bounds)| bounds.to_trace(Some(label(event)), event.audio.clone()))
+            .collect()
+    }
+}
+
+/// An event on a timeline
+#[derive(Clone, Default, Serialize, Deserialize, Debug, PartialEq)]
+pub struct TimelineEvent {
+    /// When it happened
+    pub date: EventDate,
+
+    /// What happened
+    pub text: String,
+
+    /// Optional picture of the event
+    pub image: Option<Image>,
+
+    /// Optional audio, played when the event is tapped
+    pub audio: Option<Audio>,
+}
+
+/// The date of an event, as precise as the teacher knows it.
+///
+/// The month is kept as a number, the frontend shows it with the month names for the user's language.
+///
+/// Dates order chronologically, a date without a month comes before any date in that year
+/// which has one.
+#[derive(Clone, Copy, Default, Serialize, Deserialize, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EventDate {
+    /// The year, negative years are BCE
+    pub year: i32,
+
+    /// The month, `1..=12`
+    pub month: Option<u8>,
+
+    /// The day of the month, only shown together with a month
+    pub day: Option<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::layout::assert_laid_out, EventDate, Timeline, TimelineEvent};
+
+    fn event(year: i32, month: Option<u8>, day: Option<u8>, text: &str) -> TimelineEvent {
+        TimelineEvent {
+            date: EventDate { year, month, day },
+            text: text.to_owned(),
+            ..TimelineEvent::default()
+        }
+    }
+
+    fn timeline(events: Vec<TimelineEvent>) -> Timeline {
+        Timeline { events }
+    }
+
+    #[test]
+    fn chronological_orders_by_date() {
+        let timeline = timeline(vec![
+            event(1948, Some(5), Some(14), "independence"),
+            event(-586, None, None, "first temple destroyed"),
+            event(1948, None, None, "sometime in 1948"),
+            event(1917, Some(11), Some(2), "balfour declaration"),
+            event(1948, Some(5), None, "sometime in may"),
+            event(70, None, None, "second temple destroyed"),
+            event(1948, Some(1), Some(31), "january"),
+        ]);
+
+        assert_eq!(timeline.chronological(), vec![1, 5, 3, 2, 6, 4, 0]);
+    }
+
+    #[test]
+    fn same_dates_keep_their_order() {
+        let timeline = timeline(vec![
+            event(2000, None, None, "b"),
+            event(1999, None, None, "a"),
+            event(2000, None, None, "c"),
+            event(2000, None, None, "d"),
+        ]);
+
+        assert_eq!(timeline.chronological(), vec![1, 0, 2, 3]);
+    }
+
+    #[test]
+    fn cards_are_laid_out_in_order() {
+        let timeline = timeline(
+            (0..12)
+                .map(|year| event(2000 - year, None, None, ""))
+                .collect(),
+        );
+
+        let order = timeline.chronological();
+        let bounds = timeline.layout_bounds(&order);
+
+        assert_eq!(bounds.len(), 12);
+        assert_laid_out(&bounds);
+
+        // the earliest event is in the top left, and each later one reads after it
+        for pair in order.windows(2) {
+            let (a, b) = (bounds[pair[0]], bounds[pair[1]]);
+            assert!(b.y > a.y || ((b.y - a.y).abs() < 1e-9 && b.x > a.x));
+        }
+
+        assert_eq!(order[0], 11);
+        assert_eq!(timeline.layout(|event| event.text.clone()).len(), 12);
+    }
+}
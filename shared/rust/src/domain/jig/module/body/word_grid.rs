@@ -0,0 +1,326 @@
+use crate::domain::jig::module::{
+    body::{Body, BodyConvert, BodyExt, ModeExt, ThemeId, _groups::cards::*},
+    ModuleKind,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+mod generator;
+pub use generator::*;
+
+/// The smallest number of rows or columns a grid can have.
+pub const MIN_GRID_SIZE: u8 = 5;
+
+/// The largest number of rows or columns a grid can have.
+pub const MAX_GRID_SIZE: u8 = 20;
+
+/// The body for [`WordGrid`](crate::domain::jig::module::ModuleKind::WordGrid) modules.
+#[derive(Default, Clone, Serialize, Deserialize, Debug)]
+pub struct ModuleData {
+    /// The content
+    pub content: Option<Content>,
+}
+
+impl ModuleData {
+    /// Converts the base content of another cards module.
+    ///
+    /// # Errors
+    ///
+    /// Fails for [`Mode::Images`], which has no words to lay out.
+    pub fn from_cards(base: Option<&BaseContent>) -> Result<Self, &'static str> {
+        if matches!(base, Some(base) if base.mode == Mode::Images) {
+            return Err("cannot convert image pairs to a word grid!");
+        }
+
+        Ok(Self {
+            content: base.map(|base| Content {
+                base: base.clone(),
+                player_settings: PlayerSettings::default(),
+            }),
+        })
+    }
+}
+
+/// The content for [`WordGrid`](crate::domain::jig::module::ModuleKind::WordGrid) modules.
+#[derive(Default, Clone, Serialize, Deserialize, Debug)]
+pub struct Content {
+    /// The base content for all cards modules
+    ///
+    /// The first card of each pair is the word, the second one is its clue.
+    pub base: BaseContent,
+    /// Settings for playback
+    pub player_settings: PlayerSettings,
+}
+
+/// Player settings
+///
+/// These are also the generator's settings, the same settings always lay out the same grid.
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct PlayerSettings {
+    /// word search or crossword
+    pub grid_kind: GridKind,
+
+    /// number of rows in the grid
+    pub rows: u8,
+
+    /// number of columns in the grid
+    pub columns: u8,
+
+    /// directions words can be laid out in (word search only, crosswords are always across and down)
+    pub directions: Vec<Direction>,
+
+    /// whether the words are read right to left (e.g. Hebrew)
+    #[serde(default)]
+    pub rtl: bool,
+
+    /// seed for the generator, changing it shuffles the grid
+    pub seed: u64,
+}
+
+impl Default for PlayerSettings {
+    fn default() -> Self {
+        Self {
+            grid_kind: GridKind::default(),
+            rows: 12,
+            columns: 12,
+            directions: vec![Direction::Forward, Direction::Down, Direction::DownForward],
+            rtl: false,
+            seed: 0,
+        }
+    }
+}
+
+/// Grid kind
+#[derive(Clone, Copy, Default, Serialize, Deserialize, Debug, Eq, PartialEq)]
+pub enum GridKind {
+    #[default]
+    /// Find the words hidden among random letters
+    WordSearch,
+    /// Fill in the words from their clues
+    Crossword,
+}
+
+impl GridKind {
+    /// Get it as a string
+    #[must_use]
+    pub const fn as_str_id(&self) -> &'static str {
+        match self {
+            Self::WordSearch => "word-search",
+            Self::Crossword => "crossword",
+        }
+    }
+}
+
+/// The direction a word reads in.
+///
+/// Forward and backward are relative to the reading direction, so with
+/// [`rtl`](PlayerSettings::rtl) a forward word reads right to left.
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Eq, PartialEq, Hash)]
+pub enum Direction {
+    /// In the reading direction
+    Forward,
+    /// Against the reading direction
+    Backward,
+    /// Top to bottom
+    Down,
+    /// Bottom to top
+    Up,
+    /// Diagonally down, in the reading direction
+    DownForward,
+    /// Diagonally down, against the reading direction
+    DownBackward,
+    /// Diagonally up, in the reading direction
+    UpForward,
+    /// Diagonally up, against the reading direction
+    UpBackward,
+}
+
+impl Direction {
+    /// Get a list of all the directions
+    #[must_use]
+    pub fn get_list() -> Vec<Self> {
+        vec![
+            Self::Forward,
+            Self::Backward,
+            Self::Down,
+            Self::Up,
+            Self::DownForward,
+            Self::DownBackward,
+            Self::UpForward,
+            Self::UpBackward,
+        ]
+    }
+
+    /// Get it as a string
+    #[must_use]
+    pub const fn as_str_id(&self) -> &'static str {
+        match self {
+            Self::Forward => "forward",
+            Self::Backward => "backward",
+            Self::Down => "down",
+            Self::Up => "up",
+            Self::DownForward => "down-forward",
+            Self::DownBackward => "down-backward",
+            Self::UpForward => "up-forward",
+            Self::UpBackward => "up-backward",
+        }
+    }
+
+    /// The (row, column) step between consecutive letters, for left to right reading.
+    #[must_use]
+    pub const fn delta(&self) -> (isize, isize) {
+        match self {
+            Self::Forward => (0, 1),
+            Self::Backward => (0, -1),
+            Self::Down => (1, 0),
+            Self::Up => (-1, 0),
+            Self::DownForward => (1, 1),
+            Self::DownBackward => (1, -1),
+            Self::UpForward => (-1, 1),
+            Self::UpBackward => (-1, -1),
+        }
+    }
+}
+
+impl BodyExt<Mode, Step> for ModuleData {
+    fn as_body(&self) -> Body {
+        Body::WordGrid(self.clone())
+    }
+
+    fn choose_mode_list() -> Vec<Mode> {
+        Mode::get_list()
+            .into_iter()
+            .filter(|mode| *mode != Mode::Images)
+            .collect()
+    }
+
+    fn is_complete(&self) -> bool {
+        matches!(&self.content, Some(content) if content.base.is_valid())
+    }
+
+    fn kind() -> ModuleKind {
+        ModuleKind::WordGrid
+    }
+
+    fn new_with_mode_and_theme(mode: Mode, theme: ThemeId) -> Self {
+        Self {
+            content: Some(Content {
+                base: BaseContent {
+                    mode,
+                    theme,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn mode(&self) -> Option<Mode> {
+        self.content.as_ref().map(|c| c.base.mode)
+    }
+
+    fn requires_choose_mode(&self) -> bool {
+        self.content.is_none()
+    }
+
+    fn set_editor_state_step(&mut self, step: Step) {
+        if let Some(content) = self.content.as_mut() {
+            content.base.editor_state.step = step;
+        }
+    }
+    fn set_editor_state_steps_completed(&mut self, steps_completed: HashSet<Step>) {
+        if let Some(content) = self.content.as_mut() {
+            content.base.editor_state.steps_completed = steps_completed;
+        }
+    }
+
+    fn get_editor_state_step(&self) -> Option<Step> {
+        self.content
+            .as_ref()
+            .map(|content| content.base.editor_state.step)
+    }
+
+    fn get_editor_state_steps_completed(&self) -> Option<HashSet<Step>> {
+        self.content
+            .as_ref()
+            .map(|content| content.base.editor_state.steps_completed.clone())
+    }
+
+    fn set_theme(&mut self, theme_id: ThemeId) {
+        if let Some(content) = self.content.as_mut() {
+            content.base.theme = theme_id;
+        }
+    }
+
+    fn get_theme(&self) -> Option<ThemeId> {
+        self.content.as_ref().map(|content| content.base.theme)
+    }
+}
+
+impl BodyConvert for ModuleData {
+    fn convertable_list() -> Vec<ModuleKind> {
+        vec![
+            ModuleKind::Memory,
+            ModuleKind::Matching,
+            ModuleKind::Flashcards,
+            ModuleKind::CardQuiz,
+        ]
+    }
+
+    fn convert_to_memory(&self) -> Result<super::memory::ModuleData, &'static str> {
+        Ok(super::memory::ModuleData {
+            content: self.content.as_ref().map(|content| super::memory::Content {
+                base: content.base.clone(),
+                player_settings: super::memory::PlayerSettings::default(),
+            }),
+        })
+    }
+
+    fn convert_to_matching(&self) -> Result<super::matching::ModuleData, &'static str> {
+        Ok(super::matching::ModuleData {
+            content: self
+                .content
+                .as_ref()
+                .map(|content| super::matching::Content {
+                    base: content.base.clone(),
+                    player_settings: super::matching::PlayerSettings::default(),
+                }),
+        })
+    }
+
+    fn convert_to_flashcards(&self) -> Result<super::flashcards::ModuleData, &'static str> {
+        Ok(super::flashcards::ModuleData {
+            content: self
+                .content
+                .as_ref()
+                .map(|content| super::flashcards::Content {
+                    base: content.base.clone(),
+                    player_settings: super::flashcards::PlayerSettings::default(),
+                }),
+        })
+    }
+
+    fn convert_to_card_quiz(&self) -> Result<super::card_quiz::ModuleData, &'static str> {
+        Ok(super::card_quiz::ModuleData {
+            content: self
+                .content
+                .as_ref()
+                .map(|content| super::card_quiz::Content {
+                    base: content.base.clone(),
+                    player_settings: super::card_quiz::PlayerSettings::default(),
+                }),
+        })
+    }
+}
+
+impl TryFrom<Body> for ModuleData {
+    type Error = &'static str;
+
+    fn try_from(body: Body) -> Result<Self, Self::Error> {
+        match body {
+            Body::WordGrid(data) => Ok(data),
+            _ => Err("cannot convert body to word grid!"),
+        }
+    }
+}
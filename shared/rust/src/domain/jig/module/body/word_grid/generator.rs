@@ -0,0 +1,860 @@
+/*
+ * Lays out a word search or a crossword from the card pairs.
+ *
+ * Everything is driven by a small seeded PRNG (splitmix64) rather than `rand`,
+ * so that the editor preview and every player get exactly the same grid.
+ *
+ * Words are laid out left to right and the finished grid is mirrored for rtl,
+ * which keeps the placement logic the same for every language.
+ */
+use super::{Direction, GridKind, PlayerSettings, MAX_GRID_SIZE, MIN_GRID_SIZE};
+use crate::domain::jig::module::body::_groups::cards::{CardContent, CardPair};
+use std::{cmp::Reverse, convert::TryFrom};
+
+/// A generated grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid {
+    /// Number of rows
+    pub rows: usize,
+
+    /// Number of columns
+    pub columns: usize,
+
+    /// The letters, row by row
+    ///
+    /// Crosswords have `None` for the blocked out cells. Word searches only have `None` if no word fit.
+    pub cells: Vec<Option<char>>,
+
+    /// Where each word ended up
+    pub placements: Vec<Placement>,
+
+    /// Indexes of the pairs whose word didn't fit, or had no letters
+    pub skipped: Vec<usize>,
+}
+
+impl Grid {
+    /// The letter at `row`, `column`
+    #[must_use]
+    pub fn cell(&self, row: usize, column: usize) -> Option<char> {
+        if row < self.rows && column < self.columns {
+            self.cells[row * self.columns + column]
+        } else {
+            None
+        }
+    }
+
+    /// The placement spelled by the cells from `start` to `end`, in either direction.
+    #[must_use]
+    pub fn find_placement(&self, start: (usize, usize), end: (usize, usize)) -> Option<&Placement> {
+        self.placements.iter().find(|placement| {
+            let first = placement.cells.first().copied();
+            let last = placement.cells.last().copied();
+
+            (first == Some(start) && last == Some(end))
+                || (first == Some(end) && last == Some(start))
+        })
+    }
+}
+
+/// A word laid out in the grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Placement {
+    /// Index of the pair the word comes from
+    pub pair_index: usize,
+
+    /// The word's letters, as they appear in the grid
+    pub word: String,
+
+    /// The text of the pair's other card, if it has any
+    pub clue: Option<String>,
+
+    /// The direction the word reads in
+    pub direction: Direction,
+
+    /// The (row, column) of each letter, in reading order
+    pub cells: Vec<(usize, usize)>,
+
+    /// The crossword clue number (word searches aren't numbered)
+    pub number: Option<u32>,
+}
+
+/// Lays out the pairs' words in a grid.
+///
+/// The same pairs and settings always give the same grid.
+#[must_use]
+pub fn generate(pairs: &[CardPair], settings: &PlayerSettings) -> Grid {
+    let rows = settings.rows.clamp(MIN_GRID_SIZE, MAX_GRID_SIZE) as usize;
+    let columns = settings.columns.clamp(MIN_GRID_SIZE, MAX_GRID_SIZE) as usize;
+
+    let mut rng = Rng::new(settings.seed);
+    let mut skipped = Vec::new();
+
+    let mut words: Vec<Word> = pairs
+        .iter()
+        .enumerate()
+        .filter_map(|(pair_index, pair)| {
+            let letters = match &pair.0.card_content {
+                CardContent::Text(text) => letters(text),
+                CardContent::Image(_) => Vec::new(),
+            };
+
+            if letters.len() < 2 || letters.len() > rows.max(columns) {
+                skipped.push(pair_index);
+                return None;
+            }
+
+            let clue = match &pair.1.card_content {
+                CardContent::Text(text) if !text.trim().is_empty() => Some(text.trim().to_owned()),
+                _ => None,
+            };
+
+            Some(Word {
+                pair_index,
+                letters,
+                clue,
+            })
+        })
+        .collect();
+
+    // longest first, ties in a seeded order.
+    rng.shuffle(&mut words);
+    words.sort_by_key(|word| Reverse(word.letters.len()));
+
+    let mut layout = Layout::new(rows, columns);
+
+    match settings.grid_kind {
+        GridKind::WordSearch => {
+            let directions = if settings.directions.is_empty() {
+                vec![Direction::Forward]
+            } else {
+                settings.directions.clone()
+            };
+
+            for word in words {
+                if !layout.place_anywhere(&word, &directions, &mut rng) {
+                    skipped.push(word.pair_index);
+                }
+            }
+
+            layout.fill(&mut rng);
+        }
+
+        GridKind::Crossword => {
+            layout.place_crossword(words, &mut rng, &mut skipped);
+            layout.crop();
+        }
+    }
+
+    skipped.sort_unstable();
+
+    layout.into_grid(settings.grid_kind, settings.rtl, skipped)
+}
+
+/// The letters of a word as they go in the grid.
+///
+/// Only letters and digits are kept (so no spaces, punctuation or Hebrew points), and they're uppercased.
+fn letters(text: &str) -> Vec<char> {
+    text.chars()
+        .filter(|c| c.is_alphanumeric() && !('\u{0591}'..='\u{05C7}').contains(c))
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+#[derive(Clone)]
+struct Word {
+    pair_index: usize,
+    letters: Vec<char>,
+    clue: Option<String>,
+}
+
+struct Placed {
+    word: Word,
+    direction: Direction,
+    start: Cell,
+}
+
+/// A (row, column) in the grid.
+type Cell = (usize, usize);
+
+/// The cell `count` steps from `cell` in the direction of `delta`, if it's in a `rows` by `columns` grid.
+fn step(
+    cell: Cell,
+    delta: (isize, isize),
+    count: usize,
+    rows: usize,
+    columns: usize,
+) -> Option<Cell> {
+    let offset = |value: usize, delta: isize| match delta {
+        0 => Some(value),
+        delta if delta > 0 => value.checked_add(count),
+        _ => value.checked_sub(count),
+    };
+
+    let row = offset(cell.0, delta.0).filter(|row| *row < rows)?;
+    let column = offset(cell.1, delta.1).filter(|column| *column < columns)?;
+
+    Some((row, column))
+}
+
+const fn reverse((d_row, d_column): (isize, isize)) -> (isize, isize) {
+    (-d_row, -d_column)
+}
+
+struct Layout {
+    rows: usize,
+    columns: usize,
+    cells: Vec<Option<char>>,
+    /// The directions of the words going through each cell
+    directions: Vec<Vec<Direction>>,
+    placed: Vec<Placed>,
+}
+
+impl Layout {
+    fn new(rows: usize, columns: usize) -> Self {
+        Self {
+            rows,
+            columns,
+            cells: vec![None; rows * columns],
+            directions: vec![Vec::new(); rows * columns],
+            placed: Vec::new(),
+        }
+    }
+
+    fn step(&self, cell: Cell, delta: (isize, isize), count: usize) -> Option<Cell> {
+        step(cell, delta, count, self.rows, self.columns)
+    }
+
+    const fn index(&self, (row, column): Cell) -> usize {
+        row * self.columns + column
+    }
+
+    /// The letter in `cell`, if it's in the grid and filled.
+    fn get(&self, cell: Option<Cell>) -> Option<char> {
+        cell.and_then(|cell| self.cells[self.index(cell)])
+    }
+
+    /// The cells `word` would take up if it starts at `start`, or `None` if it doesn't fit in the grid.
+    fn cells_of(&self, word: &[char], direction: Direction, start: Cell) -> Option<Vec<Cell>> {
+        (0..word.len())
+            .map(|index| self.step(start, direction.delta(), index))
+            .collect()
+    }
+
+    /// The number of letters `word` would share with the grid if it starts at `start`,
+    /// or `None` if it doesn't fit there.
+    fn overlaps(&self, word: &[char], direction: Direction, start: Cell) -> Option<usize> {
+        let mut overlaps = 0;
+
+        for (cell, letter) in self.cells_of(word, direction, start)?.into_iter().zip(word) {
+            match self.cells[self.index(cell)] {
+                Some(existing) if existing == *letter => overlaps += 1,
+                Some(_) => return None,
+                None => {}
+            }
+        }
+
+        Some(overlaps)
+    }
+
+    fn place(&mut self, word: &Word, direction: Direction, start: Cell) {
+        let cells = self
+            .cells_of(&word.letters, direction, start)
+            .expect("placements are checked to fit");
+
+        for (cell, letter) in cells.into_iter().zip(&word.letters) {
+            let index = self.index(cell);
+            self.cells[index] = Some(*letter);
+            self.directions[index].push(direction);
+        }
+
+        self.placed.push(Placed {
+            word: word.clone(),
+            direction,
+            start,
+        });
+    }
+
+    /// Word search placement: anywhere it fits, in any of the `directions`.
+    fn place_anywhere(&mut self, word: &Word, directions: &[Direction], rng: &mut Rng) -> bool {
+        let mut candidates = Vec::new();
+
+        for direction in directions {
+            for row in 0..self.rows {
+                for column in 0..self.columns {
+                    let overlaps = self.overlaps(&word.letters, *direction, (row, column));
+
+                    // a word lying entirely on top of another one can't be found.
+                    if matches!(overlaps, Some(overlaps) if overlaps < word.letters.len()) {
+                        candidates.push((*direction, (row, column)));
+                    }
+                }
+            }
+        }
+
+        if candidates.is_empty() {
+            return false;
+        }
+
+        let (direction, start) = candidates[rng.below(candidates.len())];
+        self.place(word, direction, start);
+
+        true
+    }
+
+    /// Fills the empty cells with letters taken from the placed words, so they match the alphabet.
+    fn fill(&mut self, rng: &mut Rng) {
+        let pool: Vec<char> = self
+            .placed
+            .iter()
+            .flat_map(|placed| placed.word.letters.iter().copied())
+            .collect();
+
+        if pool.is_empty() {
+            return;
+        }
+
+        for cell in self.cells.iter_mut().filter(|cell| cell.is_none()) {
+            *cell = Some(pool[rng.below(pool.len())]);
+        }
+    }
+
+    /// Crossword placement: every word after the first has to cross one that's already placed.
+    ///
+    /// Words which can't cross anything yet are retried after the others, until nothing else fits.
+    fn place_crossword(&mut self, words: Vec<Word>, rng: &mut Rng, skipped: &mut Vec<usize>) {
+        let mut remaining = words;
+
+        while !remaining.is_empty() {
+            let mut unplaced = Vec::new();
+            let placed_before = self.placed.len();
+
+            for word in remaining {
+                let placed = if self.placed.is_empty() {
+                    self.place_first(&word)
+                } else {
+                    self.place_crossing(&word, rng)
+                };
+
+                if !placed {
+                    unplaced.push(word);
+                }
+            }
+
+            if self.placed.len() == placed_before {
+                skipped.extend(unplaced.iter().map(|word| word.pair_index));
+                break;
+            }
+
+            remaining = unplaced;
+        }
+    }
+
+    /// The first crossword word goes across the middle.
+    fn place_first(&mut self, word: &Word) -> bool {
+        if word.letters.len() > self.columns {
+            return false;
+        }
+
+        let start = (self.rows / 2, (self.columns - word.letters.len()) / 2);
+
+        self.place(word, Direction::Forward, start);
+
+        true
+    }
+
+    fn place_crossing(&mut self, word: &Word, rng: &mut Rng) -> bool {
+        let mut best: Option<(usize, Direction, Cell)> = None;
+        let mut ties = 0;
+
+        for direction in &[Direction::Forward, Direction::Down] {
+            let direction = *direction;
+
+            for (index, letter) in word.letters.iter().enumerate() {
+                for row in 0..self.rows {
+                    for column in 0..self.columns {
+                        if self.cells[self.index((row, column))] != Some(*letter) {
+                            continue;
+                        }
+
+                        let Some(start) =
+                            self.step((row, column), reverse(direction.delta()), index)
+                        else {
+                            continue;
+                        };
+
+                        let Some(crossings) = self.crossings(&word.letters, direction, start)
+                        else {
+                            continue;
+                        };
+
+                        let candidate = (crossings, direction, start);
+
+                        // reservoir sampling keeps the choice between equally good spots seeded.
+                        match best {
+                            Some((most, ..)) if crossings < most => {}
+                            Some((most, ..)) if crossings == most => {
+                                ties += 1;
+                                if rng.below(ties + 1) == 0 {
+                                    best = Some(candidate);
+                                }
+                            }
+                            _ => {
+                                ties = 0;
+                                best = Some(candidate);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        match best {
+            Some((_, direction, start)) => {
+                self.place(word, direction, start);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The number of words `word` would cross if it starts at `start`, or `None` if
+    /// it can't go there.
+    ///
+    /// It can't touch any letter it doesn't cross, or run on from another word.
+    fn crossings(&self, word: &[char], direction: Direction, start: Cell) -> Option<usize> {
+        let delta = direction.delta();
+        let side = (delta.1, delta.0);
+
+        let crossings = self.overlaps(word, direction, start)?;
+
+        if crossings == 0 || crossings == word.len() {
+            return None;
+        }
+
+        if self.get(self.step(start, reverse(delta), 1)).is_some()
+            || self.get(self.step(start, delta, word.len())).is_some()
+        {
+            return None;
+        }
+
+        for cell in self.cells_of(word, direction, start)? {
+            if self.get(Some(cell)).is_some() {
+                // a crossing, unless it's part of a word going the same way.
+                if self.directions[self.index(cell)].contains(&direction) {
+                    return None;
+                }
+
+                continue;
+            }
+
+            if self.get(self.step(cell, side, 1)).is_some()
+                || self.get(self.step(cell, reverse(side), 1)).is_some()
+            {
+                return None;
+            }
+        }
+
+        Some(crossings)
+    }
+
+    /// Shrinks a crossword down to its words.
+    fn crop(&mut self) {
+        if self.placed.is_empty() {
+            return;
+        }
+
+        let filled: Vec<Cell> = (0..self.rows)
+            .flat_map(|row| (0..self.columns).map(move |column| (row, column)))
+            .filter(|cell| self.cells[self.index(*cell)].is_some())
+            .collect();
+
+        let top = filled.iter().map(|cell| cell.0).min().unwrap_or(0);
+        let bottom = filled.iter().map(|cell| cell.0).max().unwrap_or(0);
+        let left = filled.iter().map(|cell| cell.1).min().unwrap_or(0);
+        let right = filled.iter().map(|cell| cell.1).max().unwrap_or(0);
+
+        let columns = right - left + 1;
+        let rows = bottom - top + 1;
+
+        let mut cells = Vec::with_capacity(rows * columns);
+        let mut directions = Vec::with_capacity(rows * columns);
+        for row in top..=bottom {
+            let range = self.index((row, left))..=self.index((row, right));
+            cells.extend_from_slice(&self.cells[range.clone()]);
+            directions.extend_from_slice(&self.directions[range]);
+        }
+
+        for placed in &mut self.placed {
+            placed.start = (placed.start.0 - top, placed.start.1 - left);
+        }
+
+        self.rows = rows;
+        self.columns = columns;
+        self.cells = cells;
+        self.directions = directions;
+    }
+
+    fn into_grid(self, grid_kind: GridKind, rtl: bool, skipped: Vec<usize>) -> Grid {
+        let mirror = |(row, column): Cell| {
+            if rtl {
+                (row, self.columns - 1 - column)
+            } else {
+                (row, column)
+            }
+        };
+
+        let mut placements: Vec<Placement> = self
+            .placed
+            .iter()
+            .map(|placed| Placement {
+                pair_index: placed.word.pair_index,
+                word: placed.word.letters.iter().collect(),
+                clue: placed.word.clue.clone(),
+                direction: placed.direction,
+                cells: self
+                    .cells_of(&placed.word.letters, placed.direction, placed.start)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(mirror)
+                    .collect(),
+                number: None,
+            })
+            .collect();
+
+        if grid_kind == GridKind::Crossword {
+            number(&mut placements, self.columns, rtl);
+        }
+
+        placements.sort_by_key(|placement| (placement.number, placement.pair_index));
+
+        let cells = if rtl {
+            self.cells
+                .chunks(self.columns)
+                .flat_map(|row| row.iter().rev().copied())
+                .collect()
+        } else {
+            self.cells
+        };
+
+        Grid {
+            rows: self.rows,
+            columns: self.columns,
+            cells,
+            placements,
+            skipped,
+        }
+    }
+}
+
+/// Numbers crossword clues in reading order, words starting in the same cell share a number.
+fn number(placements: &mut [Placement], columns: usize, rtl: bool) {
+    let reading_order = |(row, column): (usize, usize)| {
+        if rtl {
+            (row, columns - 1 - column)
+        } else {
+            (row, column)
+        }
+    };
+
+    let mut starts: Vec<(usize, usize)> = placements
+        .iter()
+        .filter_map(|placement| placement.cells.first().copied())
+        .collect();
+
+    starts.sort_by_key(|start| reading_order(*start));
+    starts.dedup();
+
+    for placement in placements {
+        placement.number = placement
+            .cells
+            .first()
+            .and_then(|start| starts.iter().position(|it| it == start))
+            .and_then(|index| u32::try_from(index + 1).ok());
+    }
+}
+
+/// splitmix64
+struct Rng(u64);
+
+impl Rng {
+    const fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    const fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, `n` must not be 0.
+    fn below(&mut self, n: usize) -> usize {
+        usize::try_from(self.next_u64() % n as u64).unwrap_or_default()
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            let other = self.below(index + 1);
+            items.swap(index, other);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::jig::module::body::_groups::cards::Card;
+    use std::collections::HashSet;
+
+    const WORDS: &[(&str, &str)] = &[
+        ("apple", "red or green"),
+        ("pear", "pointy"),
+        ("grape", "grows in bunches"),
+        ("lemon", "sour"),
+        ("melon", "big and round"),
+        ("plum", "purple"),
+        ("peach", "fuzzy"),
+        ("orange", "also a colour"),
+    ];
+
+    fn text_card(text: &str) -> Card {
+        Card {
+            audio: None,
+            card_content: CardContent::Text(text.to_owned()),
+        }
+    }
+
+    fn pairs(words: &[(&str, &str)]) -> Vec<CardPair> {
+        words
+            .iter()
+            .map(|(word, clue)| CardPair(text_card(word), text_card(clue)))
+            .collect()
+    }
+
+    fn settings(grid_kind: GridKind, seed: u64) -> PlayerSettings {
+        PlayerSettings {
+            grid_kind,
+            seed,
+            ..PlayerSettings::default()
+        }
+    }
+
+    fn read_back(grid: &Grid, placement: &Placement) -> String {
+        placement
+            .cells
+            .iter()
+            .map(|(row, column)| grid.cell(*row, *column).unwrap_or('_'))
+            .collect()
+    }
+
+    #[test]
+    fn same_seed_same_grid() {
+        let pairs = pairs(WORDS);
+
+        for grid_kind in [GridKind::WordSearch, GridKind::Crossword] {
+            let grid = generate(&pairs, &settings(grid_kind, 7));
+            assert_eq!(grid, generate(&pairs, &settings(grid_kind, 7)));
+            assert_ne!(grid, generate(&pairs, &settings(grid_kind, 8)));
+        }
+    }
+
+    #[test]
+    fn words_read_back_in_every_direction() {
+        let pairs = pairs(WORDS);
+
+        for direction in Direction::get_list() {
+            let settings = PlayerSettings {
+                directions: vec![direction],
+                ..settings(GridKind::WordSearch, 3)
+            };
+
+            let grid = generate(&pairs, &settings);
+
+            assert!(grid.cells.iter().all(Option::is_some));
+            assert_eq!(grid.placements.len() + grid.skipped.len(), WORDS.len());
+            assert!(!grid.placements.is_empty());
+
+            for placement in &grid.placements {
+                assert_eq!(placement.direction, direction);
+                assert_eq!(placement.number, None);
+                assert_eq!(placement.word, WORDS[placement.pair_index].0.to_uppercase());
+                assert_eq!(read_back(&grid, placement), placement.word);
+
+                for pair in placement.cells.windows(2) {
+                    let next = step(pair[0], direction.delta(), 1, grid.rows, grid.columns);
+                    assert_eq!(next, Some(pair[1]), "{direction:?}");
+                }
+
+                let start = placement.cells[0];
+                let end = placement.cells[placement.cells.len() - 1];
+                assert_eq!(grid.find_placement(end, start), Some(placement));
+            }
+        }
+    }
+
+    #[test]
+    fn crossword_words_cross() {
+        let pairs = pairs(WORDS);
+
+        for seed in 0..20 {
+            let grid = generate(&pairs, &settings(GridKind::Crossword, seed));
+            let placed = grid.placements.len();
+            assert!(placed > 2, "seed {seed}: {} placed", placed);
+
+            for placement in &grid.placements {
+                assert!(matches!(
+                    placement.direction,
+                    Direction::Forward | Direction::Down
+                ));
+                assert_eq!(read_back(&grid, placement), placement.word);
+                assert_eq!(
+                    placement.clue.as_deref(),
+                    Some(WORDS[placement.pair_index].1)
+                );
+
+                let cells: HashSet<_> = placement.cells.iter().collect();
+                let crosses = grid.placements.iter().any(|other| {
+                    other.pair_index != placement.pair_index
+                        && other.direction != placement.direction
+                        && other.cells.iter().any(|cell| cells.contains(cell))
+                });
+                assert!(crosses, "seed {seed}: {} doesn't cross", placement.word);
+            }
+
+            // blocked out cells are only the ones no word goes through.
+            let used: HashSet<_> = grid
+                .placements
+                .iter()
+                .flat_map(|placement| placement.cells.iter().copied())
+                .collect();
+            for row in 0..grid.rows {
+                for column in 0..grid.columns {
+                    assert_eq!(
+                        grid.cell(row, column).is_some(),
+                        used.contains(&(row, column))
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn skips_words_without_letters_or_too_long() {
+        let pairs = pairs(&[
+            ("apple", ""),
+            ("?!", ""),
+            ("a", ""),
+            ("abcdefghijklmnopqrstuvwxyz", ""),
+        ]);
+
+        let grid = generate(&pairs, &settings(GridKind::WordSearch, 0));
+
+        assert_eq!(grid.skipped, [1, 2, 3]);
+        assert_eq!(grid.placements.len(), 1);
+        assert_eq!(grid.placements[0].clue, None);
+    }
+
+    #[test]
+    fn rtl_mirrors_the_grid() {
+        let pairs = pairs(WORDS);
+
+        for grid_kind in [GridKind::WordSearch, GridKind::Crossword] {
+            let ltr = generate(&pairs, &settings(grid_kind, 5));
+            let rtl = generate(
+                &pairs,
+                &PlayerSettings {
+                    rtl: true,
+                    ..settings(grid_kind, 5)
+                },
+            );
+
+            assert_eq!((rtl.rows, rtl.columns), (ltr.rows, ltr.columns));
+
+            for row in 0..ltr.rows {
+                for column in 0..ltr.columns {
+                    assert_eq!(
+                        rtl.cell(row, ltr.columns - 1 - column),
+                        ltr.cell(row, column)
+                    );
+                }
+            }
+
+            for placement in &rtl.placements {
+                let mirrored = ltr
+                    .placements
+                    .iter()
+                    .find(|it| it.pair_index == placement.pair_index)
+                    .expect("same words are placed");
+
+                let cells: Vec<_> = mirrored
+                    .cells
+                    .iter()
+                    .map(|(row, column)| (*row, ltr.columns - 1 - column))
+                    .collect();
+
+                assert_eq!(placement.cells, cells);
+                assert_eq!(read_back(&rtl, placement), placement.word);
+            }
+        }
+    }
+
+    #[test]
+    fn hebrew_points_are_dropped() {
+        let pairs = pairs(&[("שָׁלוֹם", "")]);
+        let grid = generate(&pairs, &settings(GridKind::Crossword, 0));
+
+        assert_eq!(grid.placements[0].word, "שלום");
+    }
+
+    #[test]
+    fn clues_are_numbered_in_reading_order() {
+        let pairs = pairs(WORDS);
+
+        for rtl in [false, true] {
+            for seed in 0..20 {
+                let grid = generate(
+                    &pairs,
+                    &PlayerSettings {
+                        rtl,
+                        ..settings(GridKind::Crossword, seed)
+                    },
+                );
+
+                let reading_order = |(row, column): (usize, usize)| {
+                    if rtl {
+                        (row, grid.columns - 1 - column)
+                    } else {
+                        (row, column)
+                    }
+                };
+
+                let mut starts: Vec<_> = grid
+                    .placements
+                    .iter()
+                    .map(|placement| reading_order(placement.cells[0]))
+                    .collect();
+                starts.sort_unstable();
+                starts.dedup();
+
+                for placement in &grid.placements {
+                    let start = reading_order(placement.cells[0]);
+                    let expected = starts
+                        .iter()
+                        .position(|it| *it == start)
+                        .and_then(|index| u32::try_from(index + 1).ok());
+
+                    assert_eq!(placement.number, expected, "seed {seed}");
+                }
+
+                let numbers: Vec<_> = grid.placements.iter().map(|it| it.number).collect();
+                let mut sorted = numbers.clone();
+                sorted.sort_unstable();
+                assert_eq!(numbers, sorted);
+                assert_eq!(numbers.first(), Some(&Some(1)));
+            }
+        }
+    }
+}
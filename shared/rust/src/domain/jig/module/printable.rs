@@ -0,0 +1,79 @@
+//! Types for printable worksheets generated from modules.
+
+use serde::{Deserialize, Serialize};
+
+use super::ModuleKind;
+use crate::domain::{
+    jig::additional_resource::AdditionalResourceId, meta::ResourceTypeId, pdf::PdfId,
+};
+
+/// How card pairs are laid out on the printed pages.
+///
+/// Only used for modules made of card pairs, every other printable module has a single layout.
+#[derive(Clone, Copy, Default, Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum PrintableLayout {
+    /// Both cards of every pair next to each other, with dashed lines to cut along.
+    #[default]
+    CutOut,
+
+    /// A numbered column and a lettered, shuffled column to match with each other.
+    Worksheet,
+}
+
+/// Request to print a module and attach the pdf to its JIG as an additional resource.
+///
+/// [`module::Printable`](crate::api::endpoints::jig::module::Printable)
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintableRequest {
+    /// How card pairs are laid out.
+    #[serde(default)]
+    pub layout: PrintableLayout,
+
+    /// Whether to end the document with an answer key page.
+    ///
+    /// Labels of Listen & Learn traces are left blank for students to fill in when there is one.
+    #[serde(default)]
+    pub answer_key: bool,
+
+    /// Display name of the additional resource.
+    ///
+    /// Defaults to the [display name](ModuleKind::display_name) of the module's kind.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+
+    /// Type of the additional resource.
+    pub resource_type_id: ResourceTypeId,
+}
+
+/// Response for printing a module.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PrintableResponse {
+    /// The generated pdf, in the user's pdf library.
+    pub pdf_id: PdfId,
+
+    /// The additional resource the pdf was attached as.
+    pub additional_resource_id: AdditionalResourceId,
+}
+
+impl ModuleKind {
+    /// Whether modules of this kind can be printed.
+    #[must_use]
+    pub const fn is_printable(self) -> bool {
+        matches!(
+            self,
+            Self::Cover
+                | Self::ResourceCover
+                | Self::Flashcards
+                | Self::Matching
+                | Self::Memory
+                | Self::CardQuiz
+                | Self::Poster
+                | Self::TappingBoard
+                | Self::DragDrop
+        )
+    }
+}
@@ -5,7 +5,86 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 use uuid::Uuid;
 
-use crate::media::MediaKind;
+use crate::media::{MediaKind, MediaLibrary};
+
+/// Licenses that media can be used under.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "backend", derive(sqlx::Type))]
+#[repr(i16)]
+pub enum License {
+    /// The license hasn't been recorded (media added before licenses were tracked).
+    #[default]
+    Unknown = 0,
+
+    /// The media was created by (or for) us.
+    Owned = 1,
+
+    /// The media is in the public domain.
+    PublicDomain = 2,
+
+    /// [CC0](https://creativecommons.org/publicdomain/zero/1.0/), no attribution required.
+    Cc0 = 3,
+
+    /// [CC BY](https://creativecommons.org/licenses/by/4.0/), the author must be credited.
+    CcBy = 4,
+
+    /// [CC BY-SA](https://creativecommons.org/licenses/by-sa/4.0/), the author must be credited.
+    CcBySa = 5,
+
+    /// The media is licensed from a stock media provider.
+    Stock = 6,
+}
+
+impl License {
+    /// Whether the media's author must be credited wherever it's used.
+    #[must_use]
+    pub const fn requires_attribution(self) -> bool {
+        matches!(self, Self::CcBy | Self::CcBySa)
+    }
+}
+
+/// Where a piece of media came from, and under what terms it can be used.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Provenance {
+    /// The license the media can be used under.
+    pub license: License,
+
+    /// Who created the media.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+
+    /// Where the media was found, for example the page it was published on.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<String>,
+}
+
+impl Provenance {
+    /// Checks that the provenance is complete enough for media in the global library.
+    ///
+    /// # Errors
+    /// Returns a message describing the missing field.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.license == License::Unknown {
+            return Err("A license is required");
+        }
+
+        let is_blank =
+            |it: &Option<String>| !matches!(it.as_deref(), Some(it) if !it.trim().is_empty());
+
+        if self.license.requires_attribution() && is_blank(&self.author) {
+            return Err("The author is required for this license");
+        }
+
+        if self.license != License::Owned && is_blank(&self.source_url) {
+            return Err("The source is required for media that isn't owned");
+        }
+
+        Ok(())
+    }
+}
 
 /// Response for adding a URL to the Web Media Library
 #[derive(Serialize, Deserialize, Debug)]
@@ -22,6 +101,13 @@ pub struct UrlCreatedResponse {
 pub struct WebMediaUrlCreateRequest {
     /// The url.
     pub url: Url,
+
+    /// Where the media came from (such as the page a search result was found on) and its license.
+    ///
+    /// Ignored if the library already knows the media's license. If missing, the license is unknown and the source is `url`.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Provenance>,
 }
 
 /// Response for getting metadata for media from the web media library.
@@ -41,4 +127,22 @@ pub struct WebMediaMetadataResponse {
 
     /// When this media was last updated, if ever.
     pub updated_at: Option<DateTime<Utc>>,
+
+    /// Where the media came from, and its license.
+    #[serde(default)]
+    pub provenance: Provenance,
+}
+
+/// Attribution for a single piece of media.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaAttribution {
+    /// The ID of the media.
+    pub id: Uuid,
+
+    /// The library the media is in.
+    pub library: MediaLibrary,
+
+    /// Where the media came from, and its license.
+    pub provenance: Provenance,
 }
@@ -8,7 +8,7 @@ use uuid::Uuid;
 pub mod user {
     use serde::{Deserialize, Serialize};
 
-    use super::PdfId;
+    use super::{PdfId, PdfPreview};
 
     /// Response for listing.
     #[derive(Serialize, Deserialize, Debug)]
@@ -29,6 +29,13 @@ pub mod user {
     pub struct UserPdf {
         /// The Pdf file's ID.
         pub id: PdfId,
+
+        /// What was extracted from the Pdf when it was processed.
+        ///
+        /// `None` if the Pdf hasn't been processed yet. Once it has, a rendering of the first page is
+        /// available as [`FileKind::DocumentThumbnail`](crate::media::FileKind::DocumentThumbnail).
+        #[serde(default)]
+        pub preview: Option<PdfPreview>,
         // more fields to be added
     }
 
@@ -54,6 +61,17 @@ pub mod user {
 #[cfg_attr(feature = "backend", sqlx(transparent))]
 pub struct PdfId(pub Uuid);
 
+/// Details of a Pdf's contents, for previewing it without downloading it.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PdfPreview {
+    /// How many pages the Pdf has.
+    pub page_count: u32,
+
+    /// The title from the Pdf's document information, if it has one.
+    pub title: Option<String>,
+}
+
 /// Response for getting a single Pdf file.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct PdfResponse {
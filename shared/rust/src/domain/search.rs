@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::media::Provenance;
+
 /// Represents the response given when an api key for algolia is requested.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct CreateSearchKeyResponse {
@@ -21,6 +23,44 @@ pub struct WebImageSearchQuery {
     /// Image type string
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image_type: Option<ImageType>,
+
+    /// How strictly adult content is filtered out.
+    ///
+    /// If missing, defaults to [`SafeSearch::Strict`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub safe_search: Option<SafeSearch>,
+
+    /// Only return images available under these licenses.
+    ///
+    /// If missing, images are returned regardless of their license.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<LicenseFilter>,
+}
+
+/// How strictly adult content is filtered out of web image search results.
+///
+/// Adult content is always filtered out, there is no way to turn it off.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum SafeSearch {
+    /// Filter out adult and racy content.
+    #[default]
+    Strict,
+    /// Filter out adult content.
+    Moderate,
+}
+
+/// Licenses to restrict web image search results to.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum LicenseFilter {
+    /// Any license, including unknown ones.
+    #[default]
+    Any,
+    /// Creative Commons licenses which allow reuse and modification (possibly requiring attribution), and public domain.
+    CreativeCommons,
+    /// Public domain (or CC0) only, which don't require attribution.
+    PublicDomain,
 }
 
 /// Represents different types of images
@@ -61,6 +101,12 @@ pub struct WebImageSearchItem {
     pub thumbnail_url: url::Url,
     /// A URL to the original image.
     pub url: url::Url,
+    /// Where the image came from and its license, if the search provider knows.
+    ///
+    /// Should be passed along when adding the image to the web library.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<Provenance>,
 }
 
 /// Response for successful search.
@@ -111,6 +111,32 @@ pub struct NewSessionResponse {
     pub csrf: String,
 }
 
+/// Request for [`CreateSecondFactor`](crate::api::endpoints::session::CreateSecondFactor)
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CreateSecondFactorRequest {
+    /// The current code from the user's authenticator app.
+    Totp {
+        /// The 6 digit code.
+        code: String,
+    },
+
+    /// One of the user's unused recovery codes.
+    RecoveryCode {
+        /// The recovery code.
+        code: String,
+    },
+}
+
+impl fmt::Debug for CreateSecondFactorRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Totp { .. } => f.debug_struct("Totp").finish(),
+            Self::RecoveryCode { .. } => f.debug_struct("RecoveryCode").finish(),
+        }
+    }
+}
+
 /// Which URL to use for OAuth callback.
 #[derive(Serialize, Deserialize, Debug, Copy, Clone)]
 #[serde(rename_all = "camelCase")]
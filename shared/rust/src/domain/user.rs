@@ -2,7 +2,7 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize, Serializer};
-use std::convert::TryFrom;
+use std::{convert::TryFrom, fmt};
 use uuid::Uuid;
 
 use crate::domain::{
@@ -511,3 +511,153 @@ pub struct UserFontResponse {
     /// Names of the user's fonts.
     pub names: Vec<String>,
 }
+
+/// Response for [`GetTotp`](crate::api::endpoints::user::GetTotp)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TotpStatusResponse {
+    /// Whether the user has a confirmed TOTP second factor.
+    pub enabled: bool,
+
+    /// The number of unused recovery codes the user has left.
+    pub recovery_codes_remaining: u32,
+}
+
+/// Response for [`EnrollTotp`](crate::api::endpoints::user::EnrollTotp)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TotpEnrollResponse {
+    /// The base32 encoded shared secret, for manually entering into an authenticator app.
+    pub secret: String,
+
+    /// An `otpauth://` URI containing the secret, to be shown as a QR code.
+    pub uri: String,
+}
+
+/// Request for [`ConfirmTotp`](crate::api::endpoints::user::ConfirmTotp)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TotpConfirmRequest {
+    /// The current 6 digit code from the authenticator app.
+    pub code: String,
+}
+
+/// Response for [`ConfirmTotp`](crate::api::endpoints::user::ConfirmTotp)
+/// and [`RegenerateRecoveryCodes`](crate::api::endpoints::user::RegenerateRecoveryCodes)
+///
+/// Note: These codes are only ever returned once.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecoveryCodesResponse {
+    /// Single use codes which can be used in place of a TOTP code.
+    pub recovery_codes: Vec<String>,
+}
+
+/// Request for [`CreateToken`](crate::api::endpoints::user::CreateToken)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTokenRequest {
+    /// A name to recognize the token by, e.g. the script it's used in.
+    pub name: String,
+
+    /// The scopes the token carries. Must be a subset of the user's scopes.
+    #[serde(default)]
+    pub scopes: Vec<UserScope>,
+
+    /// When the token stops being valid.
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Response for [`CreateToken`](crate::api::endpoints::user::CreateToken)
+///
+/// Note: The token is only ever returned once.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTokenResponse {
+    /// The token's id.
+    pub id: Uuid,
+
+    /// The token itself, to be sent as `Authorization: Bearer <token>`.
+    pub token: String,
+}
+
+impl fmt::Debug for CreateTokenResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreateTokenResponse")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A personal access token, without the secret part.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalAccessToken {
+    /// The token's id.
+    pub id: Uuid,
+
+    /// The token's name.
+    pub name: String,
+
+    /// The scopes the token carries.
+    pub scopes: Vec<UserScope>,
+
+    /// When the token was created.
+    pub created_at: DateTime<Utc>,
+
+    /// When the token stops being valid.
+    pub expires_at: DateTime<Utc>,
+
+    /// When the token was last used, if ever.
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+/// Response for [`ListTokens`](crate::api::endpoints::user::ListTokens)
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListTokensResponse {
+    /// The user's unrevoked tokens, including expired ones.
+    pub tokens: Vec<PersonalAccessToken>,
+}
+
+/// A group of media in a user's library, storage quotas apply to each group separately.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum UserMediaGroup {
+    /// Images (stickers and profile images).
+    Image,
+
+    /// Audio files.
+    Audio,
+
+    /// Pdf files.
+    Pdf,
+}
+
+/// How much storage a user takes up in one of their media groups.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaGroupUsage {
+    /// The media group.
+    pub group: UserMediaGroup,
+
+    /// The number of bytes used by uploaded files (failed uploads aren't counted).
+    pub used_bytes: u64,
+
+    /// The number of uploaded files.
+    pub file_count: u64,
+
+    /// The most bytes the user can upload to this group, if it's limited.
+    pub quota_bytes: Option<u64>,
+}
+
+/// Response for [`StorageUsage`](crate::api::endpoints::user::StorageUsage)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageUsageResponse {
+    /// The number of bytes used across all groups.
+    pub used_bytes: u64,
+
+    /// Usage per media group.
+    pub groups: Vec<MediaGroupUsage>,
+}
@@ -0,0 +1,153 @@
+//! Types for webhooks.
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::jig::{
+    report::{JigReportType, ReportId},
+    JigId,
+};
+
+/// Wrapper type around [`Uuid`], represents the ID of a webhook subscription.
+#[derive(Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "backend", derive(sqlx::Type))]
+#[cfg_attr(feature = "backend", sqlx(transparent))]
+pub struct WebhookId(pub Uuid);
+
+/// Something that happened to a JIG which webhooks can be notified about.
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "backend", derive(sqlx::Type))]
+#[serde(rename_all = "camelCase")]
+#[repr(i16)]
+pub enum WebhookEvent {
+    /// A JIG's draft was published.
+    JigPublished = 0,
+
+    /// A JIG was deleted.
+    JigDeleted = 1,
+
+    /// A JIG was blocked by an admin.
+    JigBlocked = 2,
+
+    /// A JIG was reported.
+    JigReported = 3,
+}
+
+impl WebhookEvent {
+    /// The event's name, as sent in the `X-Jigzi-Event` header.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::JigPublished => "jigPublished",
+            Self::JigDeleted => "jigDeleted",
+            Self::JigBlocked => "jigBlocked",
+            Self::JigReported => "jigReported",
+        }
+    }
+}
+
+/// A webhook subscription.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Webhook {
+    /// The subscription's ID.
+    pub id: WebhookId,
+
+    /// The URL deliveries are `POST`ed to.
+    pub url: String,
+
+    /// The events this subscription is notified about.
+    pub events: Vec<WebhookEvent>,
+
+    /// If set, the subscription is notified about JIGs of every user in this organization,
+    /// otherwise only about JIGs of the user who created it.
+    pub organization: Option<String>,
+
+    /// When the subscription was created.
+    pub created_at: DateTime<Utc>,
+
+    /// When the subscription was last updated.
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Request for [`Create`](crate::api::endpoints::webhook::Create)
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWebhookRequest {
+    /// The URL deliveries are `POST`ed to.
+    pub url: String,
+
+    /// The secret deliveries are signed with, see [`WebhookPayload`].
+    pub secret: String,
+
+    /// The events to be notified about.
+    pub events: Vec<WebhookEvent>,
+
+    /// Subscribe to JIGs of every user in this organization rather than just your own.
+    ///
+    /// Requires the `Admin` scope.
+    #[serde(default)]
+    pub organization: Option<String>,
+}
+
+/// Request for [`Update`](crate::api::endpoints::webhook::Update)
+///
+/// Fields which are `None` are left unchanged.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateWebhookRequest {
+    /// The URL deliveries are `POST`ed to.
+    #[serde(default)]
+    pub url: Option<String>,
+
+    /// The secret deliveries are signed with.
+    #[serde(default)]
+    pub secret: Option<String>,
+
+    /// The events to be notified about.
+    #[serde(default)]
+    pub events: Option<Vec<WebhookEvent>>,
+}
+
+/// Response for [`List`](crate::api::endpoints::webhook::List)
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ListWebhooksResponse {
+    /// The user's webhook subscriptions.
+    pub webhooks: Vec<Webhook>,
+}
+
+/// The body of a webhook delivery.
+///
+/// Deliveries are `POST`ed as JSON with the following headers:
+/// * `X-Jigzi-Event`: the event, see [`WebhookEvent::as_str`].
+/// * `X-Jigzi-Delivery`: a unique ID for the delivery, which stays the same across retries.
+/// * `X-Jigzi-Timestamp`: when the delivery was attempted, in seconds since the unix epoch.
+/// * `X-Jigzi-Signature`: `sha256=` followed by the hex encoded HMAC-SHA256 of
+///   `{timestamp}.{body}`, keyed with the subscription's secret.
+///
+/// Any `2xx` response counts as delivered, anything else is retried with backoff.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookPayload {
+    /// What happened.
+    pub event: WebhookEvent,
+
+    /// The JIG it happened to.
+    pub jig_id: JigId,
+
+    /// When it happened.
+    pub occurred_at: DateTime<Utc>,
+
+    /// The report, for [`WebhookEvent::JigReported`].
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report_id: Option<ReportId>,
+
+    /// The kind of report, for [`WebhookEvent::JigReported`].
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report_type: Option<JigReportType>,
+}
+
+into_uuid![WebhookId];
@@ -36,7 +36,7 @@ impl MediaGroupKind {
 }
 
 /// Image size Variants
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Eq, PartialEq)]
 pub enum PngImageFile {
     /// The original image
     Original,
@@ -114,22 +114,52 @@ pub enum FileKind {
     /// Files for a PNG Image
     ImagePng(PngImageFile),
 
-    // Spritesheet(Image,JSON),
+    /// Files for a WebP Image, derived from the PNG ones
+    ImageWebp(PngImageFile),
+
+    /// File for an SVG Image (the original of a vector image)
+    ImageSvg,
+
+    /// Spritesheet of an animation's frames, as a WebP
+    AnimationSpritesheet,
+
+    /// Layout and timing of an animation's spritesheet, see [`AnimationSpritesheet`](crate::domain::animation::AnimationSpritesheet).
+    AnimationSpritesheetJson,
+
     /// File for Mp3 audio
     AudioMp3,
 
+    /// Waveform peaks of processed audio, see [`AudioWaveform`](crate::domain::audio::AudioWaveform).
+    AudioWaveform,
+
     /// File for pdf documents
     DocumentPdf,
+
+    /// Rendering of the first page of a pdf document, as a PNG
+    DocumentThumbnail,
 }
 
 impl FileKind {
+    /// The file that images of the given `kind` are uploaded as.
+    #[must_use]
+    pub const fn original_image(kind: ImageKind) -> Self {
+        if kind.is_vector() {
+            Self::ImageSvg
+        } else {
+            Self::ImagePng(PngImageFile::Original)
+        }
+    }
+
     /// Returns the content type of the represented file
     #[must_use]
     pub const fn content_type(self) -> &'static str {
         match self {
             Self::AnimationGif => "image/gif",
-            Self::ImagePng(_) => "image/png",
+            Self::ImagePng(_) | Self::DocumentThumbnail => "image/png",
+            Self::ImageWebp(_) | Self::AnimationSpritesheet => "image/webp",
+            Self::ImageSvg => "image/svg+xml",
             Self::AudioMp3 => "audio/mp3",
+            Self::AudioWaveform | Self::AnimationSpritesheetJson => "application/json",
             Self::DocumentPdf => "application/pdf",
         }
     }
@@ -138,11 +168,19 @@ impl FileKind {
     const fn suffix(self) -> &'static str {
         match self {
             Self::AnimationGif => "animation.gif",
+            Self::AnimationSpritesheet => "spritesheet.webp",
+            Self::AnimationSpritesheetJson => "spritesheet.json",
             Self::ImagePng(PngImageFile::Original) => "original.png",
             Self::ImagePng(PngImageFile::Thumbnail) => "thumbnail.png",
             Self::ImagePng(PngImageFile::Resized) => "resized.png",
+            Self::ImageWebp(PngImageFile::Original) => "original.webp",
+            Self::ImageWebp(PngImageFile::Thumbnail) => "thumbnail.webp",
+            Self::ImageWebp(PngImageFile::Resized) => "resized.webp",
+            Self::ImageSvg => "original.svg",
             Self::AudioMp3 => "audio.mp3",
+            Self::AudioWaveform => "waveform.json",
             Self::DocumentPdf => "document.pdf",
+            Self::DocumentThumbnail => "document-thumbnail.png",
         }
     }
 }
@@ -153,16 +191,75 @@ impl std::str::FromStr for FileKind {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "animation.gif" => Ok(Self::AnimationGif),
+            "spritesheet.webp" => Ok(Self::AnimationSpritesheet),
+            "spritesheet.json" => Ok(Self::AnimationSpritesheetJson),
             "original.png" => Ok(Self::ImagePng(PngImageFile::Original)),
             "thumbnail.png" => Ok(Self::ImagePng(PngImageFile::Thumbnail)),
             "resized.png" => Ok(Self::ImagePng(PngImageFile::Resized)),
+            "original.webp" => Ok(Self::ImageWebp(PngImageFile::Original)),
+            "thumbnail.webp" => Ok(Self::ImageWebp(PngImageFile::Thumbnail)),
+            "resized.webp" => Ok(Self::ImageWebp(PngImageFile::Resized)),
+            "original.svg" => Ok(Self::ImageSvg),
             "audio.mp3" => Ok(Self::AudioMp3),
+            "waveform.json" => Ok(Self::AudioWaveform),
             "document.pdf" => Ok(Self::DocumentPdf),
+            "document-thumbnail.png" => Ok(Self::DocumentThumbnail),
             _ => Err(anyhow::anyhow!("media type not recognized")),
         }
     }
 }
 
+/// Does the `Accept` header list `content_type`, without rejecting it (`q=0`)?
+///
+/// If `wildcards` is set, `image/*` and `*/*` count too.
+fn accepts(accept: Option<&str>, content_type: &str, wildcards: bool) -> bool {
+    accept
+        .into_iter()
+        .flat_map(|accept| accept.split(','))
+        .any(|range| {
+            let mut parts = range.split(';').map(str::trim);
+
+            let matches = match parts.next() {
+                Some(it) if it.eq_ignore_ascii_case(content_type) => true,
+                Some("image/*" | "*/*") => wildcards,
+                _ => false,
+            };
+
+            // `q=0` means "not acceptable".
+            let rejected = parts
+                .filter_map(|param| param.strip_prefix("q="))
+                .filter_map(|q| q.parse::<f32>().ok())
+                .any(|q| q <= 0.0);
+
+            matches && !rejected
+        })
+}
+
+/// Picks the best stored encoding of an image `file` for a client, given its `Accept` header.
+///
+/// WebP is only picked if the client explicitly lists `image/webp` (browsers do for images),
+/// and `webp_available` is set (it isn't until the derivatives have been generated).
+///
+/// For vector images (`is_vector`) the SVG is picked over the full size files, so that they can be scaled without blurring.
+#[must_use]
+pub fn preferred_image_file(
+    accept: Option<&str>,
+    file: PngImageFile,
+    webp_available: bool,
+    is_vector: bool,
+) -> FileKind {
+    if is_vector
+        && !matches!(file, PngImageFile::Thumbnail)
+        && accepts(accept, "image/svg+xml", true)
+    {
+        FileKind::ImageSvg
+    } else if webp_available && accepts(accept, "image/webp", false) {
+        FileKind::ImageWebp(file)
+    } else {
+        FileKind::ImagePng(file)
+    }
+}
+
 /// FCM Data Message format for signalling processing completion.
 ///
 /// Contains the information necessary to find the media from the GCS project.